@@ -0,0 +1,70 @@
+use std::fs;
+
+use bevy::log::error;
+use serde::{Deserialize, Serialize};
+
+use crate::app_dirs::data_path;
+
+pub const ADDRESS_BOOK_PATH: &str = "address_book.toml";
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct AddressBookEntry {
+    pub name: String,
+    pub address: String,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, Default)]
+pub struct AddressBook {
+    #[serde(default)]
+    pub entries: Vec<AddressBookEntry>,
+    /// Why the file on disk couldn't be loaded. Saving is refused while set,
+    /// so a broken file isn't replaced by an empty book.
+    #[serde(skip)]
+    load_error: Option<String>,
+}
+
+impl AddressBook {
+    /// A missing address book is empty. One that can't be read or parsed
+    /// is also shown as empty, but kept on disk until it's fixed.
+    pub fn load() -> Self {
+        let path = data_path(ADDRESS_BOOK_PATH);
+        if !path.exists() {
+            return Self::default();
+        }
+
+        let loaded = fs::read_to_string(&path)
+            .map_err(|e| format!("Failed to read {}: {}", path.display(), e))
+            .and_then(|s| toml::from_str(&s).map_err(|e| format!("{} is invalid: {}", path.display(), e)));
+        match loaded {
+            Ok(book) => book,
+            Err(e) => {
+                error!("{}", e);
+                Self {
+                    load_error: Some(e),
+                    ..Default::default()
+                }
+            },
+        }
+    }
+
+    pub fn save(&self) -> Result<(), String> {
+        if let Some(e) = &self.load_error {
+            return Err(format!("{}, not overwriting it", e));
+        }
+        let toml_string = toml::to_string(self).map_err(|e| e.to_string())?;
+        fs::write(data_path(ADDRESS_BOOK_PATH), toml_string).map_err(|e| e.to_string())
+    }
+
+    /// Adds the entry, or renames it if the address is already saved.
+    pub fn upsert(&mut self, name: String, address: String) {
+        if let Some(entry) = self.entries.iter_mut().find(|e| e.address == address) {
+            entry.name = name;
+        } else {
+            self.entries.push(AddressBookEntry { name, address });
+        }
+    }
+
+    pub fn remove(&mut self, address: &str) {
+        self.entries.retain(|e| e.address != address);
+    }
+}
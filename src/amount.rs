@@ -0,0 +1,289 @@
+use std::{env, fmt, str::FromStr, sync::OnceLock};
+
+use bevy::reflect::Reflect;
+use serde::{Deserialize, Serialize};
+
+use crate::ore_utils::ORE_TOKEN_DECIMALS;
+
+pub const SOL_DECIMALS: u8 = 9;
+
+/// Parses a decimal amount string into base units without going through f64.
+pub fn parse_units(amount: &str, decimals: u8) -> Result<u64, String> {
+    let amount = amount.trim();
+    if amount.is_empty() {
+        return Err("Enter an amount".to_string());
+    }
+
+    let (whole, fraction) = match amount.split_once('.') {
+        // "1." and "." are typos, not amounts
+        Some((_, "")) => return Err("Invalid amount".to_string()),
+        Some((whole, fraction)) => (whole, fraction),
+        None => (amount, ""),
+    };
+    if fraction.len() > decimals as usize {
+        return Err(format!("Amount has more than {} decimals", decimals));
+    }
+    if !whole.chars().chain(fraction.chars()).all(|c| c.is_ascii_digit()) {
+        return Err("Invalid amount".to_string());
+    }
+
+    let whole: u64 = if whole.is_empty() {
+        0
+    } else {
+        whole.parse().map_err(|_| "Amount is too large".to_string())?
+    };
+    let mut fraction_units = 0u64;
+    if !fraction.is_empty() {
+        let padded = format!("{:0<width$}", fraction, width = decimals as usize);
+        fraction_units = padded.parse().map_err(|_| "Invalid amount".to_string())?;
+    }
+
+    whole
+        .checked_mul(10u64.pow(decimals as u32))
+        .and_then(|w| w.checked_add(fraction_units))
+        .ok_or("Amount is too large".to_string())
+}
+
+/// `units` as an exact decimal, trailing zeros trimmed. `max_decimals`
+/// truncates, so a balance is never shown as more than it is.
+pub fn format_units(units: u64, decimals: u8, max_decimals: Option<usize>) -> String {
+    format_parts(units, decimals, max_decimals, false, '.', None)
+}
+
+fn format_parts(
+    units: u64,
+    decimals: u8,
+    max_decimals: Option<usize>,
+    pad: bool,
+    decimal_separator: char,
+    group_separator: Option<char>,
+) -> String {
+    let one = 10u64.pow(decimals as u32);
+    let mut whole = (units / one).to_string();
+    if let Some(separator) = group_separator {
+        whole = group_digits(&whole, separator);
+    }
+
+    let mut fraction = format!("{:0width$}", units % one, width = decimals as usize);
+    if let Some(max_decimals) = max_decimals {
+        fraction.truncate(max_decimals);
+        if pad {
+            fraction = format!("{:0<width$}", fraction, width = max_decimals);
+        }
+    }
+    if !pad {
+        fraction = fraction.trim_end_matches('0').to_string();
+    }
+
+    if fraction.is_empty() {
+        whole
+    } else {
+        format!("{}{}{}", whole, decimal_separator, fraction)
+    }
+}
+
+fn group_digits(digits: &str, separator: char) -> String {
+    let mut grouped = String::with_capacity(digits.len() + digits.len() / 3);
+    for (i, c) in digits.chars().enumerate() {
+        if i > 0 && (digits.len() - i).is_multiple_of(3) {
+            grouped.push(separator);
+        }
+        grouped.push(c);
+    }
+    grouped
+}
+
+/// Decimal and group separators for the language in `LC_ALL`, `LC_NUMERIC`
+/// or `LANG`. Without any of them, as on Windows, amounts show as `1,234.5`.
+fn locale_separators() -> (char, char) {
+    static SEPARATORS: OnceLock<(char, char)> = OnceLock::new();
+    *SEPARATORS.get_or_init(|| {
+        let locale = ["LC_ALL", "LC_NUMERIC", "LANG"]
+            .iter()
+            .filter_map(|var| env::var(var).ok())
+            .find(|value| !value.is_empty())
+            .unwrap_or_default();
+        let language = locale
+            .split(['_', '-', '.', '@'])
+            .next()
+            .unwrap_or_default()
+            .to_lowercase();
+        match language.as_str() {
+            "de" | "es" | "it" | "pt" | "nl" | "id" | "tr" | "da" | "el" => (',', '.'),
+            "fr" | "ru" | "uk" | "pl" | "cs" | "sk" | "sv" | "fi" | "nb" | "no" | "hu" => (',', ' '),
+            _ => ('.', ','),
+        }
+    })
+}
+
+/// Fixed-point amount types over base units. `{}` formats the exact
+/// decimal, `{:.N}` truncates to N decimals. Serialized as base units.
+macro_rules! amount_type {
+    ($(#[$meta:meta])* $name:ident, $decimals:expr) => {
+        $(#[$meta])*
+        #[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize, Reflect)]
+        #[serde(transparent)]
+        pub struct $name(pub u64);
+
+        impl $name {
+            pub const ZERO: Self = Self(0);
+            pub const DECIMALS: u8 = $decimals;
+
+            pub fn is_zero(&self) -> bool {
+                self.0 == 0
+            }
+
+            pub fn checked_add(self, other: Self) -> Option<Self> {
+                self.0.checked_add(other.0).map(Self)
+            }
+
+            pub fn checked_sub(self, other: Self) -> Option<Self> {
+                self.0.checked_sub(other.0).map(Self)
+            }
+
+            pub fn saturating_add(self, other: Self) -> Self {
+                Self(self.0.saturating_add(other.0))
+            }
+
+            pub fn saturating_sub(self, other: Self) -> Self {
+                Self(self.0.saturating_sub(other.0))
+            }
+
+            /// For amounts that only arrive as floating point tokens, like the
+            /// pool's reward figures. Rounds to the nearest base unit, negative
+            /// and NaN become zero.
+            pub fn from_f64_lossy(tokens: f64) -> Self {
+                Self((tokens * 10f64.powi(Self::DECIMALS as i32)).round() as u64)
+            }
+
+            /// Tokens as a float, only for a wire format that needs one.
+            pub fn to_f64_lossy(&self) -> f64 {
+                self.0 as f64 / 10f64.powi(Self::DECIMALS as i32)
+            }
+
+            /// Whole tokens, the fraction dropped.
+            pub fn whole(&self) -> u64 {
+                self.0 / 10u64.pow(Self::DECIMALS as u32)
+            }
+
+            /// Grouped with the system locale's separators, for the UI.
+            pub fn to_locale_string(&self, max_decimals: Option<usize>) -> String {
+                let (decimal_separator, group_separator) = locale_separators();
+                format_parts(self.0, Self::DECIMALS, max_decimals, false, decimal_separator, Some(group_separator))
+            }
+        }
+
+        impl fmt::Display for $name {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                let pad = f.precision().is_some();
+                f.pad_integral(true, "", &format_parts(self.0, Self::DECIMALS, f.precision(), pad, '.', None))
+            }
+        }
+
+        impl FromStr for $name {
+            type Err = String;
+
+            fn from_str(s: &str) -> Result<Self, Self::Err> {
+                parse_units(s, Self::DECIMALS).map(Self)
+            }
+        }
+    };
+}
+
+amount_type!(
+    /// ORE in base units, 10^-11 ORE each.
+    OreAmount,
+    ORE_TOKEN_DECIMALS
+);
+
+amount_type!(
+    /// SOL in lamports. Displays and parses as SOL.
+    Lamports,
+    SOL_DECIMALS
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_units_reads_decimals_exactly() {
+        assert_eq!(parse_units("1", SOL_DECIMALS), Ok(1_000_000_000));
+        assert_eq!(parse_units(" 1.5 ", SOL_DECIMALS), Ok(1_500_000_000));
+        assert_eq!(parse_units(".5", SOL_DECIMALS), Ok(500_000_000));
+        assert_eq!(parse_units("0.000000001", SOL_DECIMALS), Ok(1));
+        // 0.1 + 0.2 style float error can't creep in
+        assert_eq!(parse_units("0.3", ORE_TOKEN_DECIMALS), Ok(30_000_000_000));
+    }
+
+    #[test]
+    fn parse_units_rejects_malformed_amounts() {
+        for input in ["", " ", ".", "1.", "-1", "1e9", "1,5", "1.2.3", "abc", "+1"] {
+            assert!(parse_units(input, SOL_DECIMALS).is_err(), "{:?} parsed", input);
+        }
+        assert_eq!(
+            parse_units("0.0000000001", SOL_DECIMALS),
+            Err("Amount has more than 9 decimals".to_string())
+        );
+    }
+
+    #[test]
+    fn parse_units_rejects_overflow() {
+        assert_eq!(parse_units("18446744073.709551615", SOL_DECIMALS), Ok(u64::MAX));
+        assert_eq!(
+            parse_units("18446744073.709551616", SOL_DECIMALS),
+            Err("Amount is too large".to_string())
+        );
+        assert_eq!(
+            parse_units("18446744074", SOL_DECIMALS),
+            Err("Amount is too large".to_string())
+        );
+        assert_eq!(
+            parse_units("99999999999999999999999", SOL_DECIMALS),
+            Err("Amount is too large".to_string())
+        );
+    }
+
+    #[test]
+    fn format_units_round_trips() {
+        for units in [0, 1, 10, 999_999_999, 1_000_000_000, 1_500_000_001, u64::MAX] {
+            for decimals in [0, SOL_DECIMALS, ORE_TOKEN_DECIMALS] {
+                let formatted = format_units(units, decimals, None);
+                assert_eq!(parse_units(&formatted, decimals), Ok(units), "{}", formatted);
+            }
+        }
+    }
+
+    #[test]
+    fn format_units_truncates_instead_of_rounding() {
+        assert_eq!(format_units(1_999_999_999, SOL_DECIMALS, Some(2)), "1.99");
+        assert_eq!(format_units(1_999_999_999, SOL_DECIMALS, Some(0)), "1");
+        assert_eq!(format_units(1_500_000_000, SOL_DECIMALS, None), "1.5");
+        assert_eq!(format_units(1_000_000_000, SOL_DECIMALS, Some(4)), "1");
+        assert_eq!(format_units(0, SOL_DECIMALS, None), "0");
+    }
+
+    #[test]
+    fn display_pads_to_the_precision() {
+        assert_eq!(Lamports(1_500_000_000).to_string(), "1.5");
+        assert_eq!(format!("{:.2}", Lamports(1_000_000_000)), "1.00");
+        assert_eq!(format!("{:.4}", OreAmount(123_456_789_000)), "1.2345");
+        assert_eq!(format!("{:>6}", Lamports(1_500_000_000)), "   1.5");
+    }
+
+    #[test]
+    fn locale_parts_group_the_whole_part() {
+        assert_eq!(format_parts(1_234_567_500_000_000, SOL_DECIMALS, None, false, '.', Some(',')), "1,234,567.5");
+        assert_eq!(format_parts(1_234_567_500_000_000, SOL_DECIMALS, None, false, ',', Some('.')), "1.234.567,5");
+        assert_eq!(format_parts(123_000_000_000, SOL_DECIMALS, None, false, '.', Some(',')), "123");
+    }
+
+    #[test]
+    fn from_f64_lossy_rounds_to_base_units() {
+        assert_eq!(OreAmount::from_f64_lossy(1.5), OreAmount(150_000_000_000));
+        assert_eq!(OreAmount::from_f64_lossy(0.00000000001), OreAmount(1));
+        assert_eq!(OreAmount::from_f64_lossy(-1.0), OreAmount::ZERO);
+        assert_eq!(OreAmount::from_f64_lossy(f64::NAN), OreAmount::ZERO);
+        assert_eq!(OreAmount(150_000_000_000).to_f64_lossy(), 1.5);
+    }
+}
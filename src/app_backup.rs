@@ -0,0 +1,584 @@
+use std::{
+    collections::{BTreeMap, HashSet},
+    fs,
+    io::Write,
+    path::{Component, Path, PathBuf},
+};
+
+use chrono::{DateTime, SecondsFormat, Utc};
+use cocoon::Cocoon;
+use serde::{Deserialize, Serialize};
+use solana_sdk::hash::hash;
+
+use crate::{
+    address_book::{AddressBook, ADDRESS_BOOK_PATH},
+    app_dirs::data_path,
+    config::config_file,
+    mining_history::MINING_HISTORY_PATH,
+    wallet_file::write_file_atomic,
+    wallet_profiles::{
+        validate_profile_name, WalletProfile, WalletProfiles, DEFAULT_PROFILE_NAME,
+        LEGACY_WALLET_PATH, MAX_PROFILE_NAME_LEN, PROFILES_PATH,
+    },
+};
+
+pub const BACKUPS_DIR: &str = "backups";
+pub const BACKUP_EXTENSION: &str = "orebak";
+/// Backup files start with this, followed by the format version and a
+/// cocoon container holding the manifest and the files.
+pub const BACKUP_MAGIC: &[u8; 8] = b"OREHQBAK";
+pub const BACKUP_FORMAT_VERSION: u16 = 1;
+const PREAMBLE_LEN: usize = 8 + 2;
+
+/// `config.toml` can live outside the data directory, so it gets its own
+/// entry and everything else is stored under `data/`.
+const CONFIG_ENTRY: &str = "config.toml";
+const DATA_PREFIX: &str = "data/";
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct BackupEntry {
+    pub path: String,
+    pub size: u64,
+    /// Hex sha256 of the file.
+    pub sha256: String,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct BackupManifest {
+    pub format_version: u16,
+    pub app_version: String,
+    /// Unix timestamp in seconds.
+    pub created_at: i64,
+    pub profiles: Vec<String>,
+    pub entries: Vec<BackupEntry>,
+}
+
+#[derive(Serialize, Deserialize)]
+struct BackupPayload {
+    manifest: BackupManifest,
+    files: Vec<BackupFile>,
+}
+
+#[derive(Serialize, Deserialize)]
+struct BackupFile {
+    path: String,
+    data: Vec<u8>,
+}
+
+/// How a restore treats what is already on disk.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum RestoreMode {
+    /// Adds the backup's profiles, contacts and history next to the current
+    /// ones. Nothing existing is overwritten.
+    Merge,
+    /// Makes the app state match the backup. The current files are copied
+    /// to a `pre-restore` folder first.
+    Replace,
+}
+
+/// A decrypted backup whose files all matched the manifest.
+pub struct BackupArchive {
+    pub manifest: BackupManifest,
+    files: BTreeMap<String, Vec<u8>>,
+}
+
+fn sha256_hex(data: &[u8]) -> String {
+    hash(data).to_bytes().iter().map(|b| format!("{:02x}", b)).collect()
+}
+
+/// Where an archive entry is read from and restored to.
+fn entry_target(entry: &str) -> Option<PathBuf> {
+    if entry == CONFIG_ENTRY {
+        return Some(config_file());
+    }
+    let relative = Path::new(entry.strip_prefix(DATA_PREFIX)?);
+    // entries come from a file anyone could have edited, keep them inside the data dir
+    let safe = relative.components().count() > 0
+        && relative.components().all(|c| matches!(c, Component::Normal(_)));
+    safe.then(|| data_path(relative))
+}
+
+fn data_entry(relative: &str) -> String {
+    format!("{}{}", DATA_PREFIX, relative.replace('\\', "/"))
+}
+
+/// Archive entry of a profile's wallet file, `None` for watch-only
+/// profiles and wallets kept outside the data directory.
+fn wallet_entry(profile: &WalletProfile) -> Option<String> {
+    if profile.is_watch_only() || !Path::new(&profile.wallet_path).is_relative() {
+        return None;
+    }
+    Some(data_entry(&profile.wallet_path))
+}
+
+/// Config, profiles, wallet files, address book and mining history, as
+/// archive entries. Missing files are left out.
+fn collect_files(profiles: &WalletProfiles) -> Result<Vec<BackupFile>, String> {
+    let mut entries = vec![
+        CONFIG_ENTRY.to_string(),
+        data_entry(PROFILES_PATH),
+        data_entry(ADDRESS_BOOK_PATH),
+        data_entry(MINING_HISTORY_PATH),
+    ];
+    for profile in &profiles.profiles {
+        if let Some(entry) = wallet_entry(profile) {
+            if !entries.contains(&entry) {
+                entries.push(entry);
+            }
+        }
+    }
+
+    let mut files = Vec::new();
+    for entry in entries {
+        let path = match entry_target(&entry) {
+            Some(path) if path.exists() => path,
+            _ => continue,
+        };
+        let data = fs::read(&path).map_err(|e| format!("Failed to read {}: {}", path.display(), e))?;
+        files.push(BackupFile { path: entry, data });
+    }
+    Ok(files)
+}
+
+/// `backups/ore-hq-backup-<time>.orebak` in the data directory.
+pub fn backup_file_path(created_at: i64) -> PathBuf {
+    let time = DateTime::from_timestamp(created_at, 0)
+        .map(|dt| dt.format("%Y%m%d-%H%M%S").to_string())
+        .unwrap_or_else(|| created_at.to_string());
+    data_path(BACKUPS_DIR).join(format!("ore-hq-backup-{}.{}", time, BACKUP_EXTENSION))
+}
+
+/// Bundles the app state into one file encrypted with `password`. Returns
+/// the files it holds. Runs off the main thread.
+pub fn create_backup(output: &Path, password: &str) -> Result<usize, String> {
+    let profiles = WalletProfiles::load();
+    let payload = build_payload(&profiles, collect_files(&profiles)?);
+    let count = payload.files.len();
+    write_file_atomic(output, &encrypt_payload(&payload, password)?)?;
+    Ok(count)
+}
+
+fn build_payload(profiles: &WalletProfiles, files: Vec<BackupFile>) -> BackupPayload {
+    let manifest = BackupManifest {
+        format_version: BACKUP_FORMAT_VERSION,
+        app_version: env!("CARGO_PKG_VERSION").to_string(),
+        created_at: Utc::now().timestamp(),
+        profiles: profiles.profiles.iter().map(|p| p.name.clone()).collect(),
+        entries: files
+            .iter()
+            .map(|file| BackupEntry {
+                path: file.path.clone(),
+                size: file.data.len() as u64,
+                sha256: sha256_hex(&file.data),
+            })
+            .collect(),
+    };
+    BackupPayload { manifest, files }
+}
+
+/// The backup file contents: magic, format version and the encrypted payload.
+fn encrypt_payload(payload: &BackupPayload, password: &str) -> Result<Vec<u8>, String> {
+    let body = bincode::serialize(payload).map_err(|e| e.to_string())?;
+    let mut data = Vec::with_capacity(PREAMBLE_LEN + body.len());
+    data.extend_from_slice(BACKUP_MAGIC);
+    data.extend_from_slice(&BACKUP_FORMAT_VERSION.to_le_bytes());
+    let cocoon = Cocoon::new(password.as_bytes());
+    cocoon
+        .dump(body, &mut data)
+        .map_err(|e| format!("Failed to encrypt backup: {:?}", e))?;
+    Ok(data)
+}
+
+/// Decrypts a backup and checks every file against the manifest.
+pub fn open_backup(path: &Path, password: &str) -> Result<BackupArchive, String> {
+    let bytes = fs::read(path).map_err(|e| format!("Failed to open {}: {}", path.display(), e))?;
+    if bytes.len() < PREAMBLE_LEN || &bytes[..BACKUP_MAGIC.len()] != BACKUP_MAGIC {
+        return Err(format!("{} is not an ORE HQ backup", path.display()));
+    }
+    let version = u16::from_le_bytes([bytes[8], bytes[9]]);
+    if version > BACKUP_FORMAT_VERSION {
+        return Err(format!(
+            "Backup format {} is newer than this app supports ({})",
+            version, BACKUP_FORMAT_VERSION
+        ));
+    }
+
+    let cocoon = Cocoon::new(password.as_bytes());
+    let body = cocoon
+        .parse(&mut &bytes[PREAMBLE_LEN..])
+        .map_err(|_| "Failed to decrypt backup, wrong password?".to_string())?;
+    let payload: BackupPayload =
+        bincode::deserialize(&body).map_err(|e| format!("Backup is corrupt: {}", e))?;
+
+    let files: BTreeMap<String, Vec<u8>> =
+        payload.files.into_iter().map(|file| (file.path, file.data)).collect();
+    if files.len() != payload.manifest.entries.len() {
+        return Err("Backup files don't match its manifest".to_string());
+    }
+    for entry in &payload.manifest.entries {
+        if entry_target(&entry.path).is_none() {
+            return Err(format!("Backup has an invalid path: {}", entry.path));
+        }
+        let data = files
+            .get(&entry.path)
+            .ok_or_else(|| format!("Backup is missing {}", entry.path))?;
+        if data.len() as u64 != entry.size || sha256_hex(data) != entry.sha256 {
+            return Err(format!("Checksum mismatch for {}", entry.path));
+        }
+    }
+
+    let archive = BackupArchive {
+        manifest: payload.manifest,
+        files,
+    };
+    // a profiles.toml that doesn't parse would make the restore useless
+    archive.profiles()?;
+    Ok(archive)
+}
+
+impl BackupArchive {
+    fn file(&self, relative: &str) -> Option<&Vec<u8>> {
+        self.files.get(&data_entry(relative))
+    }
+
+    fn text_file(&self, relative: &str) -> Result<Option<String>, String> {
+        self.file(relative)
+            .map(|data| {
+                String::from_utf8(data.clone()).map_err(|_| format!("{} in the backup is not text", relative))
+            })
+            .transpose()
+    }
+
+    /// The backup's profiles, a legacy `save.data` counts as the default one.
+    pub fn profiles(&self) -> Result<WalletProfiles, String> {
+        if let Some(profiles) = self.text_file(PROFILES_PATH)? {
+            return toml::from_str(&profiles).map_err(|e| format!("{} in the backup is invalid: {}", PROFILES_PATH, e));
+        }
+        let mut profiles = WalletProfiles::default();
+        if self.file(LEGACY_WALLET_PATH).is_some() {
+            profiles.profiles.push(WalletProfile {
+                wallet_path: LEGACY_WALLET_PATH.to_string(),
+                ..WalletProfile::new(DEFAULT_PROFILE_NAME.to_string())
+            });
+            profiles.active = DEFAULT_PROFILE_NAME.to_string();
+        }
+        Ok(profiles)
+    }
+
+    fn address_book(&self) -> Result<AddressBook, String> {
+        match self.text_file(ADDRESS_BOOK_PATH)? {
+            Some(book) => toml::from_str(&book).map_err(|e| format!("{} in the backup is invalid: {}", ADDRESS_BOOK_PATH, e)),
+            None => Ok(AddressBook::default()),
+        }
+    }
+
+    fn history_lines(&self) -> Result<Vec<String>, String> {
+        Ok(self
+            .text_file(MINING_HISTORY_PATH)?
+            .map(|history| history.lines().filter(|l| !l.trim().is_empty()).map(String::from).collect())
+            .unwrap_or_default())
+    }
+
+    pub fn has_config(&self) -> bool {
+        self.files.contains_key(CONFIG_ENTRY)
+    }
+
+    /// What the backup holds, one line each, for the restore screen.
+    pub fn summary(&self) -> Vec<String> {
+        let created = DateTime::from_timestamp(self.manifest.created_at, 0)
+            .map(|dt| dt.to_rfc3339_opts(SecondsFormat::Secs, true))
+            .unwrap_or_else(|| self.manifest.created_at.to_string());
+        let size: u64 = self.manifest.entries.iter().map(|e| e.size).sum();
+        let profiles = self
+            .profiles()
+            .map(|profiles| {
+                profiles
+                    .profiles
+                    .iter()
+                    .map(|p| if p.is_watch_only() { format!("{} (watch-only)", p.name) } else { p.name.clone() })
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            })
+            .unwrap_or_default();
+        vec![
+            format!(
+                "Created {} by version {}, {} files, {} bytes, checksums OK",
+                created,
+                self.manifest.app_version,
+                self.manifest.entries.len(),
+                size
+            ),
+            format!("Profiles: {}", if profiles.is_empty() { "none".to_string() } else { profiles }),
+            format!("Address book: {} entries", self.address_book().map(|b| b.entries.len()).unwrap_or(0)),
+            format!("Mining history: {} records", self.history_lines().map(|l| l.len()).unwrap_or(0)),
+            format!("Config: {}", if self.has_config() { "included" } else { "not included" }),
+        ]
+    }
+
+    /// Restores the backup and returns what was done, one line each.
+    pub fn restore(&self, mode: RestoreMode) -> Result<Vec<String>, String> {
+        match mode {
+            RestoreMode::Merge => self.merge(),
+            RestoreMode::Replace => self.replace(),
+        }
+    }
+
+    fn merge(&self) -> Result<Vec<String>, String> {
+        let mut report = Vec::new();
+        let mut current = WalletProfiles::load();
+        let mut added = Vec::new();
+        let mut skipped = Vec::new();
+
+        for profile in self.profiles()?.profiles {
+            let wallet_data = match wallet_entry(&profile) {
+                Some(entry) => match self.files.get(&entry) {
+                    Some(data) => Some(data),
+                    None => {
+                        skipped.push(format!("{} (wallet file missing)", profile.name));
+                        continue;
+                    },
+                },
+                None if profile.is_watch_only() => None,
+                None => {
+                    skipped.push(format!("{} (wallet outside the data folder)", profile.name));
+                    continue;
+                },
+            };
+
+            if let Some(existing) = current.get(&profile.name) {
+                let same = match wallet_data {
+                    Some(data) => fs::read(existing.wallet_file()).is_ok_and(|d| &d == data),
+                    None => existing.watch_pubkey == profile.watch_pubkey,
+                };
+                if same {
+                    skipped.push(format!("{} (already present)", profile.name));
+                    continue;
+                }
+            }
+
+            let name = unused_profile_name(&current, &profile.name);
+            let mut restored = WalletProfile { name: name.clone(), ..profile.clone() };
+            if let Some(data) = wallet_data {
+                restored.wallet_path = WalletProfile::new(name.clone()).wallet_path;
+                write_file_atomic(&restored.wallet_file(), data)?;
+            }
+            if current.profiles.is_empty() {
+                current.active = name.clone();
+            }
+            current.profiles.push(restored);
+            added.push(if name == profile.name { name } else { format!("{} as {}", profile.name, name) });
+        }
+        current.save()?;
+        report.push(format!("Profiles added: {}", if added.is_empty() { "none".to_string() } else { added.join(", ") }));
+        if !skipped.is_empty() {
+            report.push(format!("Profiles skipped: {}", skipped.join(", ")));
+        }
+
+        let mut address_book = AddressBook::load();
+        let mut contacts = 0;
+        for entry in self.address_book()?.entries {
+            if !address_book.entries.iter().any(|e| e.address == entry.address) {
+                address_book.entries.push(entry);
+                contacts += 1;
+            }
+        }
+        if contacts > 0 {
+            address_book.save()?;
+        }
+        report.push(format!("Address book entries added: {}", contacts));
+
+        let history_path = data_path(MINING_HISTORY_PATH);
+        let existing = fs::read_to_string(&history_path).unwrap_or_default();
+        let existing: HashSet<&str> = existing.lines().collect();
+        let new_lines: Vec<String> = self
+            .history_lines()?
+            .into_iter()
+            .filter(|line| !existing.contains(line.as_str()))
+            .collect();
+        if !new_lines.is_empty() {
+            let mut file = fs::OpenOptions::new()
+                .create(true)
+                .append(true)
+                .open(&history_path)
+                .map_err(|e| format!("Failed to open {}: {}", history_path.display(), e))?;
+            for line in &new_lines {
+                writeln!(file, "{}", line).map_err(|e| format!("Failed to write {}: {}", history_path.display(), e))?;
+            }
+        }
+        report.push(format!("Mining history records added: {}", new_lines.len()));
+
+        // the running config wins, the backup's only fills a gap
+        if let Some(config) = self.files.get(CONFIG_ENTRY) {
+            if !config_file().exists() {
+                write_file_atomic(&config_file(), config)?;
+                report.push("Config restored".to_string());
+            } else {
+                report.push("Config kept, the current one was not changed".to_string());
+            }
+        }
+        Ok(report)
+    }
+
+    fn replace(&self) -> Result<Vec<String>, String> {
+        let current_profiles = WalletProfiles::load();
+        let current = collect_files(&current_profiles)?;
+        let pre_restore = data_path(BACKUPS_DIR).join(format!("pre-restore-{}", Utc::now().format("%Y%m%d-%H%M%S")));
+        for file in &current {
+            let relative = file.path.strip_prefix(DATA_PREFIX).unwrap_or(&file.path);
+            write_file_atomic(&pre_restore.join(relative), &file.data)?;
+        }
+
+        for file in &current {
+            if !self.files.contains_key(&file.path) {
+                if let Some(path) = entry_target(&file.path) {
+                    fs::remove_file(&path).map_err(|e| format!("Failed to remove {}: {}", path.display(), e))?;
+                }
+            }
+        }
+        for (entry, data) in &self.files {
+            if let Some(path) = entry_target(entry) {
+                write_file_atomic(&path, data)?;
+            }
+        }
+
+        let mut report = vec![format!("Restored {} files", self.files.len())];
+        if !current.is_empty() {
+            report.push(format!("Previous files kept in {}", pre_restore.display()));
+        }
+        Ok(report)
+    }
+}
+
+/// `name`, or `name-restored`, `name-restored-2` and so on when a profile
+/// or wallet file already uses it.
+fn unused_profile_name(profiles: &WalletProfiles, name: &str) -> String {
+    let taken = |candidate: &str| {
+        profiles.get(candidate).is_some() || WalletProfile::new(candidate.to_string()).wallet_file().exists()
+    };
+    if !taken(name) && validate_profile_name(name).is_ok() {
+        return name.to_string();
+    }
+    let mut i = 1;
+    loop {
+        let suffix = if i == 1 { "-restored".to_string() } else { format!("-restored-{}", i) };
+        let base: String = name
+            .chars()
+            .filter(|c| c.is_ascii_alphanumeric() || *c == '-' || *c == '_')
+            .take(MAX_PROFILE_NAME_LEN - suffix.len())
+            .collect();
+        let candidate = format!("{}{}", base, suffix);
+        if !taken(&candidate) {
+            return candidate;
+        }
+        i += 1;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn profiles_file(names: &[&str]) -> BackupFile {
+        let mut profiles = WalletProfiles::default();
+        for name in names {
+            profiles.profiles.push(WalletProfile::new(name.to_string()));
+        }
+        BackupFile {
+            path: data_entry(PROFILES_PATH),
+            data: toml::to_string(&profiles).unwrap().into_bytes(),
+        }
+    }
+
+    fn wallet_backup_file(name: &str, data: &[u8]) -> BackupFile {
+        BackupFile {
+            path: wallet_entry(&WalletProfile::new(name.to_string())).unwrap(),
+            data: data.to_vec(),
+        }
+    }
+
+    /// Writes an encrypted payload to its own file in the temp dir.
+    fn write_backup(name: &str, payload: &BackupPayload, password: &str) -> PathBuf {
+        let path = std::env::temp_dir().join(format!("ore-hq-backup-test-{}-{}.{}", std::process::id(), name, BACKUP_EXTENSION));
+        fs::write(&path, encrypt_payload(payload, password).unwrap()).unwrap();
+        path
+    }
+
+    fn open(name: &str, payload: &BackupPayload, password: &str) -> Result<BackupArchive, String> {
+        let path = write_backup(name, payload, password);
+        let archive = open_backup(&path, password);
+        let _ = fs::remove_file(&path);
+        archive
+    }
+
+    #[test]
+    fn create_and_open_round_trip() {
+        let files = vec![profiles_file(&["main", "rig"]), wallet_backup_file("main", b"main wallet"), wallet_backup_file("rig", b"rig wallet")];
+        let payload = build_payload(&WalletProfiles::default(), files);
+
+        let archive = open("round-trip", &payload, "correct horse").unwrap();
+
+        assert_eq!(archive.manifest.format_version, BACKUP_FORMAT_VERSION);
+        assert_eq!(archive.manifest.entries.len(), 3);
+        assert_eq!(archive.file("wallets/main.data").unwrap(), b"main wallet");
+        assert_eq!(archive.file("wallets/rig.data").unwrap(), b"rig wallet");
+        let names: Vec<String> = archive.profiles().unwrap().profiles.into_iter().map(|p| p.name).collect();
+        assert_eq!(names, ["main", "rig"]);
+    }
+
+    #[test]
+    fn wrong_password_is_rejected() {
+        let payload = build_payload(&WalletProfiles::default(), vec![wallet_backup_file("main", b"main wallet")]);
+        let path = write_backup("wrong-password", &payload, "correct horse");
+
+        let result = open_backup(&path, "battery staple");
+        let _ = fs::remove_file(&path);
+
+        assert_eq!(result.err().unwrap(), "Failed to decrypt backup, wrong password?");
+    }
+
+    #[test]
+    fn tampered_entry_is_rejected() {
+        let mut payload = build_payload(&WalletProfiles::default(), vec![wallet_backup_file("main", b"main wallet")]);
+        payload.files[0].data = b"evil wallet".to_vec();
+
+        let result = open("tampered", &payload, "correct horse");
+
+        assert_eq!(result.err().unwrap(), "Checksum mismatch for data/wallets/main.data");
+    }
+
+    #[test]
+    fn entry_outside_the_data_dir_is_rejected() {
+        let escape = BackupFile {
+            path: "data/../x".to_string(),
+            data: b"x".to_vec(),
+        };
+        let payload = build_payload(&WalletProfiles::default(), vec![escape]);
+
+        let result = open("escape", &payload, "correct horse");
+
+        assert_eq!(result.err().unwrap(), "Backup has an invalid path: data/../x");
+        assert!(entry_target("data/../x").is_none());
+        assert!(entry_target("data/").is_none());
+        assert!(entry_target("/etc/passwd").is_none());
+        assert!(entry_target("data//etc/passwd").is_none());
+        assert!(entry_target("data/wallets/main.data").is_some());
+    }
+
+    #[test]
+    fn merge_renames_taken_profile_names() {
+        let mut profiles = WalletProfiles::default();
+        assert_eq!(unused_profile_name(&profiles, "main"), "main");
+
+        profiles.profiles.push(WalletProfile::new("main".to_string()));
+        assert_eq!(unused_profile_name(&profiles, "main"), "main-restored");
+
+        profiles.profiles.push(WalletProfile::new("main-restored".to_string()));
+        assert_eq!(unused_profile_name(&profiles, "main"), "main-restored-2");
+
+        // an invalid name from an edited backup is cleaned up to fit
+        let long = "x".repeat(MAX_PROFILE_NAME_LEN + 5);
+        let renamed = unused_profile_name(&profiles, &format!("{}!", long));
+        assert_eq!(renamed.len(), MAX_PROFILE_NAME_LEN);
+        assert!(validate_profile_name(&renamed).is_ok());
+    }
+}
@@ -0,0 +1,242 @@
+use std::{
+    env, fs,
+    path::{Path, PathBuf},
+    sync::OnceLock,
+};
+
+use crate::{
+    config::CONFIG_PATH,
+    wallet_profiles::{LEGACY_WALLET_PATH, PROFILES_PATH},
+};
+
+pub const APP_DIR_NAME: &str = "ore-hq-desktop";
+/// Files only an older version of this app leaves in a directory.
+const LEGACY_MARKERS: [&str; 3] = [LEGACY_WALLET_PATH, PROFILES_PATH, CONFIG_PATH];
+
+static APP_DIRS: OnceLock<AppDirs> = OnceLock::new();
+
+/// Where the app keeps its files. On Linux these default to the XDG config
+/// and data directories.
+#[derive(Clone, Debug)]
+pub struct AppDirs {
+    /// `config.toml`, unless `--config` points elsewhere.
+    pub config_file: PathBuf,
+    /// Profiles, wallets, address book, logs and everything else.
+    pub data_dir: PathBuf,
+}
+
+impl AppDirs {
+    /// Config and data in one place, from `--data-dir` or `ORE_HQ_DATA_DIR`.
+    pub fn portable(dir: PathBuf) -> Self {
+        Self {
+            config_file: dir.join(CONFIG_PATH),
+            data_dir: dir,
+        }
+    }
+}
+
+pub fn default_config_dir() -> PathBuf {
+    dirs::config_dir()
+        .map(|dir| dir.join(APP_DIR_NAME))
+        .unwrap_or_else(|| PathBuf::from("."))
+}
+
+pub fn default_data_dir() -> PathBuf {
+    dirs::data_dir()
+        .map(|dir| dir.join(APP_DIR_NAME))
+        .unwrap_or_else(|| PathBuf::from("."))
+}
+
+/// Creates the directories and makes them the ones `config_file` and
+/// `data_path` use. Only the first call has an effect.
+pub fn init(app_dirs: AppDirs) -> Result<(), String> {
+    let config_dir = app_dirs.config_file.parent().unwrap_or(Path::new("."));
+    for dir in [config_dir, app_dirs.data_dir.as_path()] {
+        fs::create_dir_all(dir)
+            .map_err(|e| format!("Failed to create directory {}: {}", dir.display(), e))?;
+    }
+    let _ = APP_DIRS.set(app_dirs);
+    Ok(())
+}
+
+/// The directories in use, the working directory until `init` is called.
+pub fn app_dirs() -> &'static AppDirs {
+    APP_DIRS.get_or_init(|| AppDirs::portable(PathBuf::from(".")))
+}
+
+/// Absolute paths are returned as they are.
+pub fn data_path(name: impl AsRef<Path>) -> PathBuf {
+    app_dirs().data_dir.join(name)
+}
+
+/// Directories older versions kept their files in: the working directory
+/// and the one holding the executable.
+fn legacy_dirs() -> Vec<PathBuf> {
+    let exe_dir = env::current_exe().ok().and_then(|exe| exe.parent().map(Path::to_path_buf));
+    legacy_dirs_in([env::current_dir().ok(), exe_dir].into_iter().flatten())
+}
+
+/// Either candidate could be any directory, e.g. a project the app was
+/// started from or a shared `bin`, so a directory only counts when it has a
+/// wallet, profiles or config from an older version in it.
+fn legacy_dirs_in(candidates: impl IntoIterator<Item = PathBuf>) -> Vec<PathBuf> {
+    let mut dirs: Vec<PathBuf> = candidates
+        .into_iter()
+        .filter(|dir| LEGACY_MARKERS.iter().any(|name| dir.join(name).exists()))
+        .collect();
+    dirs.dedup_by(|a, b| same_dir(a, b));
+    dirs
+}
+
+fn same_dir(a: &Path, b: &Path) -> bool {
+    match (a.canonicalize(), b.canonicalize()) {
+        (Ok(a), Ok(b)) => a == b,
+        _ => a == b,
+    }
+}
+
+/// Moves each of `names` found in a legacy directory into `target_dir`,
+/// unless `target_dir` already has one. Returns a line per moved file.
+pub fn migrate_legacy_files(names: &[&str], target_dir: &Path) -> Result<Vec<String>, String> {
+    migrate_files_from(&legacy_dirs(), names, target_dir)
+}
+
+fn migrate_files_from(legacy_dirs: &[PathBuf], names: &[&str], target_dir: &Path) -> Result<Vec<String>, String> {
+    fs::create_dir_all(target_dir)
+        .map_err(|e| format!("Failed to create directory {}: {}", target_dir.display(), e))?;
+
+    let mut moved = Vec::new();
+    for legacy_dir in legacy_dirs {
+        if same_dir(legacy_dir, target_dir) {
+            continue;
+        }
+        for name in names {
+            let from = legacy_dir.join(name);
+            let to = target_dir.join(name);
+            if !from.exists() || to.exists() {
+                continue;
+            }
+            move_path(&from, &to)?;
+            moved.push(format!("Moved {} to {}", from.display(), to.display()));
+        }
+    }
+    Ok(moved)
+}
+
+/// Renames, or copies and then removes when `to` is on another filesystem.
+fn move_path(from: &Path, to: &Path) -> Result<(), String> {
+    if fs::rename(from, to).is_ok() {
+        return Ok(());
+    }
+
+    copy_path(from, to)?;
+    let removed = if from.is_dir() {
+        fs::remove_dir_all(from)
+    } else {
+        fs::remove_file(from)
+    };
+    removed.map_err(|e| format!("Copied {} but failed to remove it: {}", from.display(), e))
+}
+
+fn copy_path(from: &Path, to: &Path) -> Result<(), String> {
+    if from.is_dir() {
+        fs::create_dir_all(to)
+            .map_err(|e| format!("Failed to create directory {}: {}", to.display(), e))?;
+        let entries = fs::read_dir(from)
+            .map_err(|e| format!("Failed to read directory {}: {}", from.display(), e))?;
+        for entry in entries {
+            let entry = entry.map_err(|e| e.to_string())?;
+            copy_path(&entry.path(), &to.join(entry.file_name()))?;
+        }
+        Ok(())
+    } else {
+        fs::copy(from, to)
+            .map(|_| ())
+            .map_err(|e| format!("Failed to copy {} to {}: {}", from.display(), to.display(), e))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// An empty directory of its own in the temp dir.
+    fn temp_dir(name: &str) -> PathBuf {
+        let dir = env::temp_dir().join(format!("ore-hq-dirs-test-{}-{}", std::process::id(), name));
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(&dir).unwrap();
+        dir
+    }
+
+    #[test]
+    fn only_dirs_with_a_marker_are_legacy() {
+        let root = temp_dir("markers");
+        let (plain, wallet, config) = (root.join("plain"), root.join("wallet"), root.join("config"));
+        for dir in [&plain, &wallet, &config] {
+            fs::create_dir_all(dir).unwrap();
+        }
+        fs::write(plain.join("notes.txt"), "").unwrap();
+        fs::write(wallet.join(LEGACY_WALLET_PATH), "").unwrap();
+        fs::write(config.join(CONFIG_PATH), "").unwrap();
+
+        let dirs = legacy_dirs_in([plain, wallet.clone(), config.clone(), wallet.clone()]);
+        let _ = fs::remove_dir_all(&root);
+
+        assert_eq!(dirs, [wallet.clone(), config, wallet]);
+    }
+
+    #[test]
+    fn same_dir_is_deduplicated() {
+        let root = temp_dir("dedup");
+        fs::write(root.join(PROFILES_PATH), "").unwrap();
+
+        let dirs = legacy_dirs_in([root.clone(), root.join(".")]);
+        let _ = fs::remove_dir_all(&root);
+
+        assert_eq!(dirs, [root]);
+    }
+
+    #[test]
+    fn legacy_files_are_moved_once() {
+        let root = temp_dir("migrate");
+        let (legacy, target) = (root.join("legacy"), root.join("target"));
+        fs::create_dir_all(legacy.join("wallets")).unwrap();
+        fs::write(legacy.join(LEGACY_WALLET_PATH), "old wallet").unwrap();
+        fs::write(legacy.join("wallets").join("main.data"), "main wallet").unwrap();
+        fs::write(legacy.join(PROFILES_PATH), "old profiles").unwrap();
+        fs::create_dir_all(&target).unwrap();
+        fs::write(target.join(PROFILES_PATH), "new profiles").unwrap();
+
+        let names = [LEGACY_WALLET_PATH, PROFILES_PATH, "wallets", "missing.toml"];
+        let moved = migrate_files_from(std::slice::from_ref(&legacy), &names, &target).unwrap();
+        let again = migrate_files_from(std::slice::from_ref(&legacy), &names, &target).unwrap();
+        let wallet = fs::read_to_string(target.join(LEGACY_WALLET_PATH));
+        let main = fs::read_to_string(target.join("wallets").join("main.data"));
+        let profiles = fs::read_to_string(target.join(PROFILES_PATH));
+        let left_behind = legacy.join(PROFILES_PATH).exists();
+        let wallet_left = legacy.join(LEGACY_WALLET_PATH).exists();
+        let _ = fs::remove_dir_all(&root);
+
+        assert_eq!(moved.len(), 2);
+        assert!(again.is_empty());
+        assert_eq!(wallet.unwrap(), "old wallet");
+        assert_eq!(main.unwrap(), "main wallet");
+        // the target's own file wins and the legacy one stays where it was
+        assert_eq!(profiles.unwrap(), "new profiles");
+        assert!(left_behind);
+        assert!(!wallet_left);
+    }
+
+    #[test]
+    fn target_dir_is_not_migrated_into_itself() {
+        let root = temp_dir("self");
+        fs::write(root.join(LEGACY_WALLET_PATH), "wallet").unwrap();
+
+        let moved = migrate_files_from(std::slice::from_ref(&root), &[LEGACY_WALLET_PATH], &root);
+        let kept = root.join(LEGACY_WALLET_PATH).exists();
+        let _ = fs::remove_dir_all(&root);
+
+        assert!(moved.unwrap().is_empty());
+        assert!(kept);
+    }
+}
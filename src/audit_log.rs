@@ -0,0 +1,46 @@
+use std::{fs::OpenOptions, io::Write};
+
+use chrono::DateTime;
+
+use crate::{app_dirs::data_path, utils::get_unix_timestamp};
+
+pub const AUDIT_LOG_PATH: &str = "audit.log";
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SecretAccessOutcome {
+    /// The secret was shown, exported or copied.
+    Revealed,
+    /// Wrong password.
+    Denied,
+    /// The password was right but nothing was revealed, e.g. no seed phrase
+    /// is stored or the export failed.
+    Failed,
+}
+
+/// Appends one line per secret access: time, action, profile, pubkey and
+/// the outcome. The secret itself is never written.
+pub fn record_secret_access(
+    action: &str,
+    profile: &str,
+    pubkey: &str,
+    outcome: SecretAccessOutcome,
+) -> Result<(), String> {
+    let timestamp = get_unix_timestamp();
+    let time = DateTime::from_timestamp(timestamp as i64, 0)
+        .map(|dt| dt.format("%Y-%m-%d %H:%M:%S UTC").to_string())
+        .unwrap_or_else(|| timestamp.to_string());
+    let outcome = match outcome {
+        SecretAccessOutcome::Revealed => "ok",
+        SecretAccessOutcome::Denied => "denied",
+        SecretAccessOutcome::Failed => "failed",
+    };
+
+    let path = data_path(AUDIT_LOG_PATH);
+    let mut file = OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(&path)
+        .map_err(|e| format!("Failed to open {}: {}", path.display(), e))?;
+    writeln!(file, "{}\t{}\t{}\t{}\t{}", time, action, profile, pubkey, outcome)
+        .map_err(|e| format!("Failed to write {}: {}", path.display(), e))
+}
@@ -0,0 +1,81 @@
+use rand::seq::{index, SliceRandom};
+use zeroize::Zeroize;
+
+/// Number of words the user has to pick to prove the phrase was written down.
+pub const BACKUP_CHALLENGE_WORDS: usize = 4;
+
+/// Asks for a few randomly chosen words of a freshly generated seed phrase,
+/// in the order they appear in the phrase.
+pub struct BackupChallenge {
+    words: Vec<String>,
+    /// Indexes into `words`, ascending.
+    picks: Vec<usize>,
+    progress: usize,
+    /// The last pick was wrong.
+    mistake: bool,
+}
+
+impl BackupChallenge {
+    pub fn new(phrase: &str) -> Self {
+        let words: Vec<String> = phrase.split_whitespace().map(|w| w.to_string()).collect();
+        let mut rng = rand::thread_rng();
+        let mut picks = index::sample(&mut rng, words.len(), BACKUP_CHALLENGE_WORDS.min(words.len())).into_vec();
+        picks.sort();
+
+        Self {
+            words,
+            picks,
+            progress: 0,
+            mistake: false,
+        }
+    }
+
+    /// All words of the phrase in random order, for the pick grid.
+    pub fn shuffled_words(&self) -> Vec<String> {
+        let mut words = self.words.clone();
+        words.shuffle(&mut rand::thread_rng());
+        words
+    }
+
+    /// Returns whether the word was the expected one. A wrong pick starts
+    /// the challenge over.
+    pub fn pick(&mut self, word: &str) -> bool {
+        if self.is_complete() {
+            return true;
+        }
+        self.mistake = self.words[self.picks[self.progress]] != word;
+        if self.mistake {
+            self.progress = 0;
+        } else {
+            self.progress += 1;
+        }
+        !self.mistake
+    }
+
+    pub fn is_complete(&self) -> bool {
+        self.progress >= self.picks.len()
+    }
+
+    pub fn prompt(&self) -> String {
+        if self.is_complete() {
+            return "Backup verified.".to_string();
+        }
+        let positions: Vec<String> = self.picks.iter().map(|i| format!("#{}", i + 1)).collect();
+        let prompt = format!(
+            "Verify your backup: pick words {} in order. Next: #{}",
+            positions.join(", "),
+            self.picks[self.progress] + 1,
+        );
+        if self.mistake {
+            format!("Wrong word, start again. {}", prompt)
+        } else {
+            prompt
+        }
+    }
+}
+
+impl Drop for BackupChallenge {
+    fn drop(&mut self) {
+        self.words.zeroize();
+    }
+}
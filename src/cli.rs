@@ -0,0 +1,272 @@
+use std::path::{Path, PathBuf};
+
+use bevy::log::Level;
+use clap::{parser::ValueSource, ArgMatches, CommandFactory, FromArgMatches, Parser, ValueEnum};
+
+use crate::{wallet_profiles::WalletProfile, AppConfig, AppScreenState};
+
+/// Command line options. Each can also be set with its `ORE_HQ_*`
+/// environment variable, the command line wins over the environment and
+/// both win over `config.toml` and profile overrides.
+#[derive(Parser, Debug)]
+#[command(name = "ore-hq-desktop", version, about = "Ore HQ desktop miner")]
+struct Cli {
+    /// config.toml to read and save, instead of the one in the config directory
+    #[arg(long, env = "ORE_HQ_CONFIG", value_name = "PATH")]
+    config: Option<PathBuf>,
+    /// Keep config and data together in this directory, for portable installs
+    #[arg(long, env = "ORE_HQ_DATA_DIR", value_name = "DIR")]
+    data_dir: Option<PathBuf>,
+    /// Profile the lock screen starts on
+    #[arg(long, env = "ORE_HQ_PROFILE", value_name = "NAME")]
+    profile: Option<String>,
+    /// Screen shown after unlocking
+    #[arg(long, env = "ORE_HQ_SCREEN", value_enum)]
+    screen: Option<StartScreen>,
+    /// Pool server, replaces `server_url`
+    #[arg(long, env = "ORE_HQ_POOL_URL", value_name = "URL")]
+    pool_url: Option<String>,
+    #[arg(long, env = "ORE_HQ_RPC_URL", value_name = "URL")]
+    rpc_url: Option<String>,
+    /// Mining threads
+    #[arg(long, env = "ORE_HQ_THREADS")]
+    threads: Option<u64>,
+    #[arg(long, env = "ORE_HQ_LOG_LEVEL", value_enum, default_value_t = LogLevel::Info)]
+    log_level: LogLevel,
+    /// Start mining once the wallet is unlocked
+    #[arg(long, env = "ORE_HQ_START_MINING")]
+    start_mining: bool,
+    /// Skip the single instance check, for deliberate multi-instance setups
+    #[arg(long, env = "ORE_HQ_ALLOW_MULTIPLE_INSTANCES")]
+    allow_multiple_instances: bool,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, ValueEnum)]
+pub enum StartScreen {
+    Dashboard,
+    Mining,
+    History,
+    Send,
+    Settings,
+}
+
+impl StartScreen {
+    pub fn state(&self) -> AppScreenState {
+        match self {
+            StartScreen::Dashboard => AppScreenState::Dashboard,
+            StartScreen::Mining => AppScreenState::Mining,
+            StartScreen::History => AppScreenState::History,
+            StartScreen::Send => AppScreenState::Send,
+            StartScreen::Settings => AppScreenState::SettingsGeneral,
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, ValueEnum)]
+pub enum LogLevel {
+    Error,
+    Warn,
+    Info,
+    Debug,
+    Trace,
+}
+
+impl LogLevel {
+    pub fn level(&self) -> Level {
+        match self {
+            LogLevel::Error => Level::ERROR,
+            LogLevel::Warn => Level::WARN,
+            LogLevel::Info => Level::INFO,
+            LogLevel::Debug => Level::DEBUG,
+            LogLevel::Trace => Level::TRACE,
+        }
+    }
+}
+
+/// Where an effective setting came from, lowest precedence first.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub enum SettingSource {
+    Default,
+    ConfigFile,
+    Profile,
+    Environment,
+    CommandLine,
+}
+
+impl SettingSource {
+    pub fn label(&self) -> &'static str {
+        match self {
+            SettingSource::Default => "default",
+            SettingSource::ConfigFile => "config.toml",
+            SettingSource::Profile => "profile",
+            SettingSource::Environment => "environment",
+            SettingSource::CommandLine => "command line",
+        }
+    }
+}
+
+/// A value set on the command line or in the environment.
+#[derive(Clone, Debug)]
+pub struct Override<T> {
+    pub value: T,
+    pub source: SettingSource,
+}
+
+/// The parsed command line and environment.
+#[derive(Clone, Debug)]
+pub struct LaunchOptions {
+    pub config: Option<Override<PathBuf>>,
+    pub data_dir: Option<Override<PathBuf>>,
+    pub profile: Option<Override<String>>,
+    pub screen: Option<Override<StartScreen>>,
+    pub pool_url: Option<Override<String>>,
+    pub rpc_url: Option<Override<String>>,
+    pub threads: Option<Override<u64>>,
+    pub log_level: Override<LogLevel>,
+    pub start_mining: Override<bool>,
+    pub allow_multiple_instances: Override<bool>,
+}
+
+impl LaunchOptions {
+    /// Parses the command line, exiting with usage on bad arguments.
+    pub fn parse() -> Self {
+        let matches = Cli::command().get_matches();
+        let cli = Cli::from_arg_matches(&matches).unwrap_or_else(|e| e.exit());
+
+        Self {
+            config: with_source(&matches, "config", cli.config),
+            data_dir: with_source(&matches, "data_dir", cli.data_dir),
+            profile: with_source(&matches, "profile", cli.profile),
+            screen: with_source(&matches, "screen", cli.screen),
+            pool_url: with_source(&matches, "pool_url", cli.pool_url),
+            rpc_url: with_source(&matches, "rpc_url", cli.rpc_url),
+            threads: with_source(&matches, "threads", cli.threads),
+            log_level: Override {
+                value: cli.log_level,
+                source: source_of(&matches, "log_level"),
+            },
+            start_mining: Override {
+                value: cli.start_mining,
+                source: source_of(&matches, "start_mining"),
+            },
+            allow_multiple_instances: Override {
+                value: cli.allow_multiple_instances,
+                source: source_of(&matches, "allow_multiple_instances"),
+            },
+        }
+    }
+
+    /// Layers the command line and environment over `config`, which
+    /// already has the profile overrides applied.
+    pub fn apply(&self, config: &AppConfig) -> AppConfig {
+        let mut config = config.clone();
+        if let Some(pool_url) = &self.pool_url {
+            config.server_url = pool_url.value.clone();
+        }
+        if let Some(rpc_url) = &self.rpc_url {
+            config.rpc_url = rpc_url.value.clone();
+        }
+        if let Some(threads) = &self.threads {
+            config.threads = threads.value;
+        }
+        config
+    }
+
+    /// One line per setting with its effective value and where it came from.
+    pub fn describe(
+        &self,
+        effective: &AppConfig,
+        profile: Option<&WalletProfile>,
+        config_file: &Path,
+        data_dir: &Path,
+    ) -> Vec<String> {
+        let profile_overrides = |set: bool| {
+            if set {
+                SettingSource::Profile
+            } else {
+                SettingSource::ConfigFile
+            }
+        };
+        let source = |o: Option<SettingSource>, fallback: SettingSource| o.unwrap_or(fallback).label();
+
+        let mut lines = vec![
+            format!(
+                "Config file: {} ({})",
+                config_file.display(),
+                source(self.config.as_ref().map(|o| o.source), SettingSource::Default),
+            ),
+            format!(
+                "Data directory: {} ({})",
+                data_dir.display(),
+                source(
+                    self.data_dir.as_ref().map(|o| o.source),
+                    if effective.data_dir.is_some() {
+                        SettingSource::ConfigFile
+                    } else {
+                        SettingSource::Default
+                    },
+                ),
+            ),
+            format!(
+                "Profile: {} ({})",
+                profile.map(|p| p.name.as_str()).unwrap_or("none"),
+                source(self.profile.as_ref().map(|o| o.source), SettingSource::Default),
+            ),
+            format!(
+                "Pool URL: {} ({})",
+                effective.server_url,
+                source(
+                    self.pool_url.as_ref().map(|o| o.source),
+                    profile_overrides(profile.is_some_and(|p| p.server_url.is_some())),
+                ),
+            ),
+            format!(
+                "RPC URL: {} ({})",
+                effective.rpc_url,
+                source(
+                    self.rpc_url.as_ref().map(|o| o.source),
+                    profile_overrides(profile.is_some_and(|p| p.rpc_url.is_some())),
+                ),
+            ),
+            format!(
+                "Threads: {} ({})",
+                effective.threads,
+                source(self.threads.as_ref().map(|o| o.source), SettingSource::ConfigFile),
+            ),
+            format!(
+                "Log level: {:?} ({})",
+                self.log_level.value,
+                self.log_level.source.label(),
+            ),
+            format!(
+                "Start mining: {} ({})",
+                self.start_mining.value,
+                self.start_mining.source.label(),
+            ),
+            format!(
+                "Allow multiple instances: {} ({})",
+                self.allow_multiple_instances.value,
+                self.allow_multiple_instances.source.label(),
+            ),
+        ];
+        if let Some(screen) = &self.screen {
+            lines.push(format!("Start screen: {:?} ({})", screen.value, screen.source.label()));
+        }
+        lines
+    }
+}
+
+fn source_of(matches: &ArgMatches, id: &str) -> SettingSource {
+    match matches.value_source(id) {
+        Some(ValueSource::CommandLine) => SettingSource::CommandLine,
+        Some(ValueSource::EnvVariable) => SettingSource::Environment,
+        _ => SettingSource::Default,
+    }
+}
+
+fn with_source<T>(matches: &ArgMatches, id: &str, value: Option<T>) -> Option<Override<T>> {
+    value.map(|value| Override {
+        value,
+        source: source_of(matches, id),
+    })
+}
@@ -0,0 +1,285 @@
+use std::{fs, path::{Path, PathBuf}, thread};
+
+use crate::{app_dirs::app_dirs, priority_fees::PriorityFeeStrategy, AppConfig};
+
+pub const CONFIG_PATH: &str = "config.toml";
+
+/// `config.toml` in the config directory, or the `--config` path.
+pub fn config_file() -> PathBuf {
+    app_dirs().config_file.clone()
+}
+
+/// Schema version written to `config.toml`. Files without one predate it,
+/// every field added since has a serde default so they load as is.
+pub const CONFIG_VERSION: u32 = 1;
+
+const MIN_UI_FETCH_INTERVAL_MS: u64 = 250;
+const MIN_TX_SEND_INTERVAL_MS: u64 = 100;
+const MAX_INTERVAL_MS: u64 = 10 * 60 * 1000;
+const MAX_FIXED_MICRO_LAMPORTS: u64 = 100_000_000;
+const MAX_PRIORITY_FEE_LAMPORTS: u64 = 100_000_000;
+
+/// The fields editable on the config screen, in display order.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum ConfigField {
+    PoolUrls,
+    RpcUrl,
+    Threads,
+    UiFetchInterval,
+    TxSendInterval,
+    FeeStrategy,
+    FeeFixedMicroLamports,
+    FeePercentile,
+    FeeMaxLamports,
+    DataDir,
+}
+
+pub type ConfigErrors = Vec<(ConfigField, String)>;
+
+impl ConfigField {
+    pub const ALL: [ConfigField; 10] = [
+        ConfigField::PoolUrls,
+        ConfigField::RpcUrl,
+        ConfigField::Threads,
+        ConfigField::UiFetchInterval,
+        ConfigField::TxSendInterval,
+        ConfigField::FeeStrategy,
+        ConfigField::FeeFixedMicroLamports,
+        ConfigField::FeePercentile,
+        ConfigField::FeeMaxLamports,
+        ConfigField::DataDir,
+    ];
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            ConfigField::PoolUrls => "Pool URLs: ",
+            ConfigField::RpcUrl => "RPC URL: ",
+            ConfigField::Threads => "Threads: ",
+            ConfigField::UiFetchInterval => "UI Fetch Interval (ms): ",
+            ConfigField::TxSendInterval => "Tx Send Interval (ms): ",
+            ConfigField::FeeStrategy => "Priority Fee Strategy: ",
+            ConfigField::FeeFixedMicroLamports => "Fixed Fee (micro-lamports/CU): ",
+            ConfigField::FeePercentile => "Fee Percentile: ",
+            ConfigField::FeeMaxLamports => "Max Priority Fee (lamports): ",
+            ConfigField::DataDir => "Data Directory: ",
+        }
+    }
+
+    pub fn numbers_only(&self) -> bool {
+        matches!(
+            self,
+            ConfigField::Threads
+                | ConfigField::UiFetchInterval
+                | ConfigField::TxSendInterval
+                | ConfigField::FeeFixedMicroLamports
+                | ConfigField::FeePercentile
+                | ConfigField::FeeMaxLamports
+        )
+    }
+
+    /// The field's value in `config`, as typed into its input.
+    pub fn value(&self, config: &AppConfig) -> String {
+        match self {
+            ConfigField::PoolUrls => {
+                let mut urls = vec![config.server_url.clone()];
+                urls.extend(config.fallback_server_urls.iter().cloned());
+                urls.join(", ")
+            },
+            ConfigField::RpcUrl => config.rpc_url.clone(),
+            ConfigField::Threads => config.threads.to_string(),
+            ConfigField::UiFetchInterval => config.ui_fetch_interval.to_string(),
+            ConfigField::TxSendInterval => config.tx_send_interval.to_string(),
+            ConfigField::FeeStrategy => config.priority_fee.strategy.to_string(),
+            ConfigField::FeeFixedMicroLamports => config.priority_fee.fixed_micro_lamports.to_string(),
+            ConfigField::FeePercentile => config.priority_fee.percentile.to_string(),
+            ConfigField::FeeMaxLamports => config.priority_fee.max_fee_lamports.to_string(),
+            ConfigField::DataDir => config.data_dir.clone().unwrap_or_default(),
+        }
+    }
+}
+
+/// Settings only read at startup, as `config.toml` keys, that differ
+/// between `running` and `new`. Everything else is applied live.
+pub fn restart_required_changes(running: &AppConfig, new: &AppConfig) -> Vec<&'static str> {
+    let mut changed = Vec::new();
+    if running.data_dir != new.data_dir {
+        changed.push("data_dir");
+    }
+    if running.log != new.log {
+        changed.push("log");
+    }
+    changed
+}
+
+/// Reads and migrates `path`, `Ok(None)` if it doesn't exist yet. Parse
+/// errors carry the line so they can be shown to the user.
+pub fn load_config(path: &Path) -> Result<Option<AppConfig>, String> {
+    if !path.exists() {
+        return Ok(None);
+    }
+
+    let config_string = fs::read_to_string(path)
+        .map_err(|e| format!("Failed to read {}: {}", path.display(), e))?;
+    let mut config: AppConfig = toml::from_str(&config_string).map_err(|e| {
+        match e.span() {
+            Some(span) => {
+                let line = config_string[..span.start].lines().count().max(1);
+                format!("{} is malformed at line {}: {}", path.display(), line, e.message())
+            },
+            None => format!("{} is malformed: {}", path.display(), e.message()),
+        }
+    })?;
+
+    if config.version > CONFIG_VERSION {
+        return Err(format!(
+            "{} was written by a newer version (schema {}, this build reads up to {})",
+            path.display(),
+            config.version,
+            CONFIG_VERSION,
+        ));
+    }
+    config.version = CONFIG_VERSION;
+
+    Ok(Some(config))
+}
+
+/// Checks every field, returning one message per invalid field.
+pub fn validate_config(config: &AppConfig) -> ConfigErrors {
+    let mut errors = ConfigErrors::new();
+
+    let pool_urls = std::iter::once(&config.server_url).chain(config.fallback_server_urls.iter());
+    for url in pool_urls {
+        if url.is_empty() {
+            errors.push((ConfigField::PoolUrls, "Enter at least one pool URL".to_string()));
+            break;
+        }
+        if url.chars().any(char::is_whitespace) {
+            errors.push((ConfigField::PoolUrls, format!("Invalid URL: {}", url)));
+            break;
+        }
+    }
+
+    let rpc_url = config.rpc_url.as_str();
+    let rpc_host = rpc_url
+        .strip_prefix("https://")
+        .or_else(|| rpc_url.strip_prefix("http://"));
+    match rpc_host {
+        Some(host) if !host.is_empty() && !host.chars().any(char::is_whitespace) => {},
+        _ => errors.push((ConfigField::RpcUrl, "Must be an http(s):// URL".to_string())),
+    }
+
+    let cpu_count = thread::available_parallelism().map_or(1, |n| n.get()) as u64;
+    if config.threads == 0 || config.threads > cpu_count {
+        errors.push((ConfigField::Threads, format!("Must be 1 to {}", cpu_count)));
+    }
+
+    if config.ui_fetch_interval < MIN_UI_FETCH_INTERVAL_MS || config.ui_fetch_interval > MAX_INTERVAL_MS {
+        errors.push((
+            ConfigField::UiFetchInterval,
+            format!("Must be {} to {}", MIN_UI_FETCH_INTERVAL_MS, MAX_INTERVAL_MS),
+        ));
+    }
+
+    if config.tx_send_interval < MIN_TX_SEND_INTERVAL_MS || config.tx_send_interval > MAX_INTERVAL_MS {
+        errors.push((
+            ConfigField::TxSendInterval,
+            format!("Must be {} to {}", MIN_TX_SEND_INTERVAL_MS, MAX_INTERVAL_MS),
+        ));
+    }
+
+    let fee = &config.priority_fee;
+    if fee.fixed_micro_lamports > MAX_FIXED_MICRO_LAMPORTS {
+        errors.push((
+            ConfigField::FeeFixedMicroLamports,
+            format!("Must be at most {}", MAX_FIXED_MICRO_LAMPORTS),
+        ));
+    }
+    if fee.percentile == 0 || fee.percentile > 100 {
+        errors.push((ConfigField::FeePercentile, "Must be 1 to 100".to_string()));
+    }
+    if fee.max_fee_lamports == 0 || fee.max_fee_lamports > MAX_PRIORITY_FEE_LAMPORTS {
+        errors.push((
+            ConfigField::FeeMaxLamports,
+            format!("Must be 1 to {}", MAX_PRIORITY_FEE_LAMPORTS),
+        ));
+    }
+
+    if let Some(data_dir) = &config.data_dir {
+        if Path::new(data_dir).is_file() {
+            errors.push((ConfigField::DataDir, "Is a file, not a directory".to_string()));
+        }
+    }
+
+    errors
+}
+
+/// Builds a config from the screen's inputs on top of `base`, so fields the
+/// screen doesn't show are kept. Returns every parse or validation error.
+pub fn parse_config_inputs(
+    base: &AppConfig,
+    inputs: &[(ConfigField, String)],
+) -> Result<AppConfig, ConfigErrors> {
+    let mut config = base.clone();
+    config.version = CONFIG_VERSION;
+    let mut errors = ConfigErrors::new();
+
+    for (field, input) in inputs {
+        let input = input.trim();
+        let number = || input.parse::<u64>().map_err(|_| "Must be a whole number".to_string());
+        let result = match field {
+            ConfigField::PoolUrls => {
+                let mut urls = input
+                    .split(',')
+                    .map(|url| url.trim().to_string())
+                    .filter(|url| !url.is_empty());
+                config.server_url = urls.next().unwrap_or_default();
+                config.fallback_server_urls = urls.collect();
+                Ok(())
+            },
+            ConfigField::RpcUrl => {
+                config.rpc_url = input.to_string();
+                Ok(())
+            },
+            ConfigField::Threads => number().map(|n| config.threads = n),
+            ConfigField::UiFetchInterval => number().map(|n| config.ui_fetch_interval = n),
+            ConfigField::TxSendInterval => number().map(|n| config.tx_send_interval = n),
+            ConfigField::FeeStrategy => match input.to_lowercase().as_str() {
+                "fixed" => Ok(PriorityFeeStrategy::Fixed),
+                "percentile" => Ok(PriorityFeeStrategy::Percentile),
+                "capped" => Ok(PriorityFeeStrategy::Capped),
+                _ => Err("Fixed, Percentile or Capped".to_string()),
+            }
+            .map(|strategy| config.priority_fee.strategy = strategy),
+            ConfigField::FeeFixedMicroLamports => number().map(|n| config.priority_fee.fixed_micro_lamports = n),
+            ConfigField::FeePercentile => input
+                .parse::<u8>()
+                .map_err(|_| "Must be 1 to 100".to_string())
+                .map(|n| config.priority_fee.percentile = n),
+            ConfigField::FeeMaxLamports => number().map(|n| config.priority_fee.max_fee_lamports = n),
+            ConfigField::DataDir => {
+                config.data_dir = if input.is_empty() {
+                    None
+                } else {
+                    Some(input.to_string())
+                };
+                Ok(())
+            },
+        };
+        if let Err(e) = result {
+            errors.push((*field, e));
+        }
+    }
+
+    // only validate fields that parsed, a parse error is the more useful message
+    for (field, error) in validate_config(&config) {
+        if !errors.iter().any(|(f, _)| *f == field) {
+            errors.push((field, error));
+        }
+    }
+
+    if errors.is_empty() {
+        Ok(config)
+    } else {
+        Err(errors)
+    }
+}
@@ -0,0 +1,84 @@
+use std::{fs, time::{Duration, SystemTime}};
+
+use bevy::prelude::*;
+
+use crate::{
+    config::{config_file, load_config, validate_config, CONFIG_PATH},
+    events::EventConfigChanged,
+    ConfigWatchResource, ProfilesResource,
+};
+
+/// How often `config.toml` is checked for changes.
+pub const CONFIG_POLL_INTERVAL: Duration = Duration::from_secs(2);
+
+pub struct ConfigPollTimer {
+    timer: Timer,
+}
+
+impl Default for ConfigPollTimer {
+    fn default() -> Self {
+        Self {
+            timer: Timer::new(CONFIG_POLL_INTERVAL, TimerMode::Repeating)
+        }
+    }
+}
+
+pub fn config_modified_at() -> Option<SystemTime> {
+    fs::metadata(config_file()).and_then(|m| m.modified()).ok()
+}
+
+/// Reloads `config.toml` when its modification time changes. A file that
+/// doesn't parse or validate is reported and the running config is kept.
+pub fn watch_config_file(
+    time: Res<Time>,
+    profiles_res: Res<ProfilesResource>,
+    mut config_watch: ResMut<ConfigWatchResource>,
+    mut poll_timer: Local<ConfigPollTimer>,
+    mut event_writer: EventWriter<EventConfigChanged>,
+) {
+    poll_timer.timer.tick(time.delta());
+    if !poll_timer.timer.just_finished() {
+        return;
+    }
+
+    let modified = config_modified_at();
+    if modified == config_watch.modified {
+        return;
+    }
+    config_watch.modified = modified;
+    if modified.is_none() {
+        // removed, keep running with what we have
+        return;
+    }
+
+    let config = match load_config(&config_file()) {
+        Ok(Some(config)) => config,
+        Ok(None) => return,
+        Err(e) => {
+            error!("Not reloading {}: {}", CONFIG_PATH, e);
+            config_watch.reload_error = Some(e);
+            return;
+        }
+    };
+
+    let errors = validate_config(&config);
+    if let Some((field, error)) = errors.first() {
+        let message = format!(
+            "{} has invalid values, keeping the running config. {}{}",
+            CONFIG_PATH,
+            field.label(),
+            error,
+        );
+        error!("{}", message);
+        config_watch.reload_error = Some(message);
+        return;
+    }
+    config_watch.reload_error = None;
+
+    // our own saves land here too
+    if config == profiles_res.base_config {
+        return;
+    }
+    info!("{} changed on disk, applying it.", CONFIG_PATH);
+    event_writer.send(EventConfigChanged(config));
+}
@@ -0,0 +1,280 @@
+use std::{
+    backtrace::Backtrace,
+    fs::{self, File},
+    io::Write,
+    panic,
+    path::{Path, PathBuf},
+    sync::{Mutex, OnceLock},
+    thread,
+};
+
+use chrono::{SecondsFormat, Utc};
+use serde::{Deserialize, Serialize};
+use sysinfo::System;
+use zip::{write::SimpleFileOptions, CompressionMethod, ZipWriter};
+
+use crate::{app_dirs::data_path, file_log, wallet_file::write_file_atomic, AppConfig};
+
+pub const CRASHES_DIR: &str = "crashes";
+pub const SUPPORT_DIR: &str = "support";
+/// Log lines included in a crash report.
+pub const CRASH_LOG_LINES: usize = 200;
+/// Left by the panic hook, read and removed on the next start.
+const PENDING_CRASH_PATH: &str = "pending.toml";
+
+static CRASH_CONTEXT: OnceLock<Mutex<CrashContext>> = OnceLock::new();
+
+/// What the panic hook can't get from the ECS world.
+#[derive(Default)]
+struct CrashContext {
+    config: String,
+    profile: Option<String>,
+    mining: bool,
+}
+
+fn crash_context() -> &'static Mutex<CrashContext> {
+    CRASH_CONTEXT.get_or_init(|| Mutex::new(CrashContext::default()))
+}
+
+/// A crash from the last run, used to offer resuming mining.
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct PendingCrash {
+    pub report: PathBuf,
+    pub time: String,
+    pub message: String,
+    /// Whether mining was running when it crashed.
+    pub mining: bool,
+    pub profile: Option<String>,
+}
+
+/// Keeps scheme and host, RPC and pool URLs often carry an API key.
+fn redact_url(url: &str) -> String {
+    let (scheme, rest) = match url.split_once("://") {
+        Some(parts) => parts,
+        None => return "<redacted>".to_string(),
+    };
+    let authority = rest.split(['/', '?', '#']).next().unwrap_or_default();
+    let host = authority.rsplit('@').next().unwrap_or_default();
+    let tail = &rest[authority.len()..];
+    if (tail.is_empty() || tail == "/") && host.len() == authority.len() {
+        url.to_string()
+    } else {
+        format!("{}://{}/<redacted>", scheme, host)
+    }
+}
+
+/// The config as TOML with the URLs redacted.
+pub fn redact_config(config: &AppConfig) -> String {
+    let mut config = config.clone();
+    config.server_url = redact_url(&config.server_url);
+    config.rpc_url = redact_url(&config.rpc_url);
+    config.fallback_server_urls = config
+        .fallback_server_urls
+        .iter()
+        .map(|url| redact_url(url))
+        .collect();
+    toml::to_string(&config).unwrap_or_else(|e| format!("# failed to serialize config: {}", e))
+}
+
+/// Called at startup and whenever the config is reloaded.
+pub fn set_config(config: &AppConfig) {
+    if let Ok(mut context) = crash_context().lock() {
+        context.config = redact_config(config);
+    }
+}
+
+pub fn set_session(profile: Option<String>, mining: bool) {
+    if let Ok(mut context) = crash_context().lock() {
+        context.profile = profile;
+        context.mining = mining;
+    }
+}
+
+/// The redacted config last passed to `set_config`.
+pub fn redacted_config() -> String {
+    crash_context()
+        .lock()
+        .map(|context| context.config.clone())
+        .unwrap_or_default()
+}
+
+/// Writes a crash report after the previous hook runs, so the panic logged
+/// by `file_log::log_panics` is in its log lines. Only a panic that ends the
+/// app leaves a marker for the next start.
+pub fn install_panic_hook() {
+    let previous_hook = panic::take_hook();
+    panic::set_hook(Box::new(move |info| {
+        previous_hook(info);
+        match write_crash_report(&info.to_string(), is_fatal_panic()) {
+            Ok(path) => eprintln!("Crash report written to {}", path.display()),
+            Err(e) => eprintln!("Failed to write crash report: {}", e),
+        }
+    }));
+}
+
+/// The main thread's panics, and those of systems on the compute pool, which
+/// Bevy re-raises on the main thread. A panic in a background thread or task
+/// only ends that thread.
+///
+/// Goes by thread name: `main` is the std main thread, and the compute pool
+/// threads are `Compute Task Pool (n)` as named by Bevy's `TaskPoolOptions`.
+/// If an upgrade renames those, system panics are reported as non fatal and
+/// the next start offers no recovery, which the tests below catch.
+fn is_fatal_panic() -> bool {
+    thread::current()
+        .name()
+        .is_some_and(|name| name == "main" || name.starts_with("Compute Task Pool"))
+}
+
+fn write_crash_report(message: &str, fatal: bool) -> Result<PathBuf, String> {
+    let now = Utc::now();
+    let dir = data_path(CRASHES_DIR);
+    fs::create_dir_all(&dir).map_err(|e| format!("Failed to create {}: {}", dir.display(), e))?;
+    let path = dir.join(format!("crash-{}.txt", now.format("%Y%m%d-%H%M%S")));
+
+    // a panic while the context is locked must not deadlock the hook
+    let (config, profile, mining) = match crash_context().try_lock() {
+        Ok(context) => (context.config.clone(), context.profile.clone(), context.mining),
+        Err(_) => (String::new(), None, false),
+    };
+    let time = now.to_rfc3339_opts(SecondsFormat::Secs, true);
+    let mut report = format!(
+        "ORE HQ Desktop crash report\nversion: {}\ntime: {}\nthread: {}\npanic: {}\nprofile: {}\nmining: {}\n",
+        env!("CARGO_PKG_VERSION"),
+        time,
+        thread::current().name().unwrap_or("unnamed"),
+        message,
+        profile.as_deref().unwrap_or("none"),
+        mining,
+    );
+    report.push_str(&format!("\nbacktrace:\n{}\n", Backtrace::force_capture()));
+    report.push_str(&format!("\nconfig (urls redacted):\n{}\n", config));
+    report.push_str(&format!("\nlast {} log lines:\n", CRASH_LOG_LINES));
+    for line in file_log::last_lines(CRASH_LOG_LINES) {
+        report.push_str(&line);
+        report.push('\n');
+    }
+    write_file_atomic(&path, report.as_bytes())?;
+    if !fatal {
+        return Ok(path);
+    }
+
+    let pending = PendingCrash {
+        report: path.clone(),
+        time,
+        message: message.replace('\n', " "),
+        mining,
+        profile,
+    };
+    let pending = toml::to_string(&pending).map_err(|e| e.to_string())?;
+    write_file_atomic(&dir.join(PENDING_CRASH_PATH), pending.as_bytes())?;
+    Ok(path)
+}
+
+/// The crash the last run left behind, if any. Removes the marker so it's
+/// only offered once.
+pub fn take_pending_crash() -> Option<PendingCrash> {
+    let path = data_path(CRASHES_DIR).join(PENDING_CRASH_PATH);
+    let pending = fs::read_to_string(&path).ok()?;
+    let _ = fs::remove_file(&path);
+    toml::from_str(&pending).ok()
+}
+
+/// Newest `crash-*.txt`, the names sort by time.
+pub fn latest_crash_report() -> Option<PathBuf> {
+    fs::read_dir(data_path(CRASHES_DIR))
+        .ok()?
+        .filter_map(|entry| entry.ok().map(|e| e.path()))
+        .filter(|path| {
+            path.file_name()
+                .and_then(|name| name.to_str())
+                .is_some_and(|name| name.starts_with("crash-") && name.ends_with(".txt"))
+        })
+        .max()
+}
+
+pub fn system_info() -> String {
+    let mut sys = System::new_all();
+    sys.refresh_all();
+    let cpu = sys.cpus().first().map(|cpu| cpu.brand().to_string()).unwrap_or_default();
+    format!(
+        "app version: {}\nos: {} {}\nkernel: {}\narch: {}\ncpu: {}\ncpu cores: {}\nmemory: {} MB total, {} MB used\n",
+        env!("CARGO_PKG_VERSION"),
+        System::name().unwrap_or_default(),
+        System::os_version().unwrap_or_default(),
+        System::kernel_version().unwrap_or_default(),
+        std::env::consts::ARCH,
+        cpu,
+        sys.cpus().len(),
+        sys.total_memory() / 1024 / 1024,
+        sys.used_memory() / 1024 / 1024,
+    )
+}
+
+/// `support/ore-hq-support-<time>.zip` in the data directory.
+pub fn support_bundle_path() -> PathBuf {
+    data_path(SUPPORT_DIR).join(format!("ore-hq-support-{}.zip", Utc::now().format("%Y%m%d-%H%M%S")))
+}
+
+/// Zips system info, the redacted config, the latest crash report and the
+/// current log file. Runs off the main thread.
+pub fn create_support_bundle(output: &Path, config: String) -> Result<usize, String> {
+    if let Some(dir) = output.parent() {
+        fs::create_dir_all(dir).map_err(|e| format!("Failed to create {}: {}", dir.display(), e))?;
+    }
+    let file = File::create(output).map_err(|e| format!("Failed to create {}: {}", output.display(), e))?;
+    let mut zip = ZipWriter::new(file);
+    let options = SimpleFileOptions::default().compression_method(CompressionMethod::Deflated);
+
+    let mut files: Vec<(String, Vec<u8>)> = vec![
+        ("system-info.txt".to_string(), system_info().into_bytes()),
+        ("config-redacted.toml".to_string(), config.into_bytes()),
+    ];
+    let attachments = [latest_crash_report(), file_log::log_file_path()];
+    for path in attachments.into_iter().flatten() {
+        let name = path.file_name().and_then(|n| n.to_str()).unwrap_or_default().to_string();
+        let data = fs::read(&path).map_err(|e| format!("Failed to read {}: {}", path.display(), e))?;
+        files.push((name, data));
+    }
+
+    for (name, data) in &files {
+        zip.start_file(name.as_str(), options)
+            .and_then(|_| zip.write_all(data).map_err(Into::into))
+            .map_err(|e| format!("Failed to write {}: {}", output.display(), e))?;
+    }
+    zip.finish().map_err(|e| format!("Failed to write {}: {}", output.display(), e))?;
+    Ok(files.len())
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy::{
+        core::TaskPoolOptions,
+        tasks::{block_on, AsyncComputeTaskPool, ComputeTaskPool, IoTaskPool},
+    };
+
+    use super::*;
+
+    #[test]
+    fn compute_pool_panics_are_fatal() {
+        TaskPoolOptions::default().create_default_pools();
+
+        assert!(block_on(ComputeTaskPool::get().spawn(async { is_fatal_panic() })));
+        assert!(!block_on(AsyncComputeTaskPool::get().spawn(async { is_fatal_panic() })));
+        assert!(!block_on(IoTaskPool::get().spawn(async { is_fatal_panic() })));
+    }
+
+    #[test]
+    fn background_thread_panics_are_not_fatal() {
+        let unnamed = thread::spawn(is_fatal_panic).join().unwrap();
+        let named = thread::Builder::new()
+            .name("ore-hq-log".to_string())
+            .spawn(is_fatal_panic)
+            .unwrap()
+            .join()
+            .unwrap();
+
+        assert!(!unnamed);
+        assert!(!named);
+    }
+}
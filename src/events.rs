@@ -1,32 +1,38 @@
 use async_compat::Compat;
 use bevy::{
+    ecs::system::SystemParam,
     prelude::*,
     tasks::{AsyncComputeTaskPool, IoTaskPool},
 };
 use bip39::{Language, Mnemonic, MnemonicType, Seed};
-use cocoon::Cocoon;
-use crossbeam_channel::{bounded, unbounded};
+use copypasta::{ClipboardContext, ClipboardProvider};
+use crossbeam_channel::bounded;
 use drillx_2::{Solution};
 use solana_client::{nonblocking::rpc_client::RpcClient, rpc_config::RpcSendTransactionConfig};
 
 use crate::{
+    amount::{Lamports, OreAmount},
     ore_utils::
         get_ore_mint
-    , tasks::{
-        TaskProcessTx, TaskProcessTxData
-    }, ui::{
-        components::{TextGeneratedKeypair, TextInput, TextMnemonicLine1, TextMnemonicLine2, TextMnemonicLine3, TextPasswordInput, ToggleAutoMine},
+    , priority_fees::{build_tx_with_priority_fee, PriorityFeeConfig, PriorityFeeEstimate}, tasks::{
+        TaskCreateAppBackup, TaskCreateSupportBundle, TaskExportMiningHistory, TaskFetchTxHistory, TaskPrepareSend, TaskProcessTx, TaskProcessTxData
+    }, transfer::{prepare_send, validate_send}, tx_history::{fetch_tx_history_page, HISTORY_PAGE_SIZE}, ui::{
+        components::{AddressBookList, HistoryScreenTxList, MiningScreenNode, MiningScreenTxResultList, SendConfirmModal, TextSendAmount, TextSendStatus, TextGeneratedKeypair, TextInput, TextMnemonicLine1, TextMnemonicLine2, TextMnemonicLine3, TextPasswordInput, TextProfileNameInput, TextDerivationPathInput, TextMnemonicInput, TextMnemonicPassphraseInput, TextRestoreStatus, RestorePreviewList, TextChangePasswordConfirm, TextChangePasswordNew, TextChangePasswordOld, TextChangePasswordStatus, TextLockedWalletLabel, TextLockedWalletPubkey, TextWatchPubkeyInput, TextConfigFieldError, TextConfigStatus, BackupWordGrid, TextRevealPassword, TextExportPath, TextRevealStatus, TextRevealedMnemonic, TextMiningExportStatus, TextAppBackupConfirmInput, TextAppBackupPasswordInput, TextAppBackupStatus, TextAppRestorePasswordInput, TextAppRestorePathInput, TextAppRestoreStatus, TextSupportBundleStatus, ToggleAutoMine},
+        screens::{screen_locked::locked_wallet_info, screen_send::spawn_address_book_entry, screen_setup_wallet::{spawn_backup_word_button, spawn_restore_account_item}},
+        spawn_utils::{spawn_new_list_item, UiListItem},
         styles::{MINE_TOGGLE_OFF, MINE_TOGGLE_ON},
-    }, AppConfig, AppScreenState, AppWallet, BussesResource, EntityTaskFetchUiData, EntityTaskHandler, HashStatus, HashrateResource, MinerStatusResource, MiningDataChannelMessage, MiningDataChannelResource, OreAppState, ProofAccountResource, TreasuryAccountResource, TxStatus
+    }, app_backup::{backup_file_path, create_backup, open_backup, RestoreMode}, address_book::AddressBook, app_dirs::data_path, crash_report::{create_support_bundle, redacted_config, support_bundle_path}, instance_lock::{wallet_lock_path, InstanceLock, LockError}, audit_log::{record_secret_access, SecretAccessOutcome}, mining_history::{append_record, export_path, export_records, parse_export_date, ExportFormat, MiningRecord, MINING_HISTORY_PATH}, ore_hq_api::ServerMessagePoolSubmissionResult, backup_verification::BackupChallenge, config::{config_file, parse_config_inputs, restart_required_changes, ConfigField, CONFIG_PATH}, secret_string::SecretString, password_strength::validate_new_password, session_signer::SessionSigner, utils::{get_unix_timestamp, shorten_string}, wallet_file::{change_wallet_password, migrate_wallet_file, read_wallet_file, read_wallet_header, read_wallet_secrets, write_wallet_file, WalletHeader}, wallet_profiles::{WalletProfile, WalletProfiles}, wallet_restore::{derive_keypair, parse_mnemonic, preview_accounts, DEFAULT_DERIVATION_PATH, RESTORE_PREVIEW_ACCOUNTS}, AppScreenState, AppWallet, BackupVerificationResource, BussesResource, EntityTaskFetchUiData, EntityTaskHandler, HashStatus, HashrateResource, MinerStatusResource, MiningDataChannelMessage, MiningDataChannelResource, MiningSessionResource, OreAppState, ProfilesResource, ProofAccountResource, SecretClipboardResource, SendResource, TreasuryAccountResource, TxCommitmentTimes, TxHistoryResource, TxStatus, TxType, UnlockThrottleResource, ConfigStatusResource, ConfigWatchResource, LaunchResource, AppConfig, AppRestoreResource, CrashRecoveryResource, InstanceLockResource, MNEMONIC_REVEAL_SECONDS, SECRET_CLIPBOARD_SECONDS
 };
 
 use std::{
-    fs::File, io::{stdout, Write}, path::{Path, PathBuf}, str::FromStr, sync::{atomic::AtomicBool, Arc, Mutex}, time::{Duration, Instant}
+    fs, path::{Path, PathBuf}, str::FromStr, sync::Arc, time::{Duration, Instant}
 };
 
 use solana_sdk::{
-    bs58, commitment_config::CommitmentLevel, compute_budget::ComputeBudgetInstruction, derivation_path::DerivationPath, keccak::{hashv, Hash as KeccakHash}, native_token::LAMPORTS_PER_SOL, pubkey::Pubkey, signature::{read_keypair_file, Keypair, Signer}, signer::SeedDerivable, transaction::Transaction
+    commitment_config::CommitmentLevel, derivation_path::DerivationPath, instruction::Instruction, native_token::LAMPORTS_PER_SOL, pubkey::Pubkey, signature::{read_keypair_file, write_keypair_file, Keypair, Signer}, signer::SeedDerivable, transaction::Transaction
 };
+use spl_associated_token_account::{get_associated_token_address, instruction::create_associated_token_account_idempotent};
+use zeroize::Zeroize;
 
 // Events
 #[derive(Event)]
@@ -41,6 +47,40 @@ pub struct EventLoadKeypairFile(pub PathBuf);
 #[derive(Event)]
 pub struct EventSaveWallet;
 
+#[derive(Event)]
+pub struct EventRestoreMnemonic;
+
+#[derive(Event)]
+pub struct EventChangePassword;
+
+#[derive(Event)]
+pub struct EventAddWatchOnly;
+
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum SecretAction {
+    ShowMnemonic,
+    ExportKeypair,
+    CopySecret,
+}
+
+impl SecretAction {
+    /// Name written to the audit log.
+    pub fn audit_name(&self) -> &'static str {
+        match self {
+            SecretAction::ShowMnemonic => "show-mnemonic",
+            SecretAction::ExportKeypair => "export-keypair",
+            SecretAction::CopySecret => "copy-secret",
+        }
+    }
+}
+
+#[derive(Event)]
+pub struct EventBackupWordPicked(pub String);
+
+/// Re-checks the wallet password before handing out any secret material.
+#[derive(Event)]
+pub struct EventRevealSecret(pub SecretAction);
+
 #[derive(Event)]
 pub struct EventMineForHash;
 
@@ -60,11 +100,13 @@ pub struct TxResult {
 
 #[derive(Event)]
 pub struct EventTxResult {
-    pub tx_type: String,
+    pub tx_type: TxType,
     pub sig: String,
     pub tx_time: u64,
     pub hash_status: Option<HashStatus>,
     pub tx_status: TxStatus,
+    pub commitment_times: TxCommitmentTimes,
+    pub priority_fee: Option<PriorityFeeEstimate>,
 }
 
 #[derive(Event)]
@@ -96,14 +138,25 @@ pub struct EventLock;
 pub struct EventUnlock;
 
 #[derive(Event)]
-pub struct EventSaveConfig(pub AppConfig);
+/// The config screen's inputs, parsed and validated by the handler.
+pub struct EventSaveConfig(pub Vec<(ConfigField, String)>);
+
+#[derive(Event)]
+/// `config.toml` changed on disk, already validated.
+pub struct EventConfigChanged(pub AppConfig);
+
+#[derive(Event)]
+pub struct EventSelectProfile(pub String);
+
+#[derive(Event)]
+pub struct EventSwitchProfile;
 
 pub fn handle_event_start_stop_mining_clicked(
     mut ev_start_stop_mining: EventReader<EventStartStopMining>,
     mut event_writer: EventWriter<EventMineForHash>,
     mut event_writer_cancel_mining: EventWriter<EventCancelMining>,
     mut event_writer_register: EventWriter<EventRegisterWallet>,
-    app_wallet: Res<AppWallet>,
+    _app_wallet: Res<AppWallet>,
     mut miner_status: ResMut<MinerStatusResource>,
     proof_account: Res<ProofAccountResource>,
     asset_server: Res<AssetServer>,
@@ -141,23 +194,23 @@ pub fn handle_event_start_stop_mining_clicked(
 }
 
 pub fn handle_event_mine_for_hash(
-    mut commands: Commands,
+    _commands: Commands,
     mut event_reader: EventReader<EventMineForHash>,
-    app_wallet: Res<AppWallet>,
+    mining_session: Res<MiningSessionResource>,
     ore_config_res: Res<TreasuryAccountResource>,
-    mut miner_status: ResMut<MinerStatusResource>,
+    miner_status: ResMut<MinerStatusResource>,
     query_task_handler: Query<Entity, With<EntityTaskHandler>>,
     mut next_state: ResMut<NextState<AppScreenState>>,
     mut mining_channels_res: ResMut<MiningDataChannelResource>,
 ) {
     for _ev in event_reader.read() {
-        if let Ok(task_handler_entity) = query_task_handler.get_single() {
+        if let Ok(_task_handler_entity) = query_task_handler.get_single() {
             let pool = AsyncComputeTaskPool::get();
-            let wallet = if let Some(wallet) =  &app_wallet.wallet {
-                wallet.clone()
+            let _signer = if let Some(signer) = &mining_session.signer {
+                signer.clone()
             } else {
                 next_state.set(AppScreenState::Unlock);
-                error!("wallet is None, switching to wallet unlock screen");
+                error!("mining session signer is None, switching to wallet unlock screen");
                 continue;
             }; 
             if mining_channels_res.sender.is_none() {
@@ -168,22 +221,22 @@ pub fn handle_event_mine_for_hash(
 
             let sys_info = &miner_status.sys_info;
             let cpu_count = sys_info.cpus().len() as u64;
-            let threads = miner_status.miner_threads.clamp(1, cpu_count);
+            let _threads = miner_status.miner_threads.clamp(1, cpu_count);
 
             let channel_rec = mining_channels_res.receiver.as_ref().unwrap();
             let channel_sender = mining_channels_res.sender.as_ref().unwrap();
 
             let receiver = channel_rec.clone();
-            let sender = channel_sender.clone();
+            let _sender = channel_sender.clone();
 
-            while let Ok(_) = receiver.try_recv() {
+            while receiver.try_recv().is_ok() {
                 // clear out any current messages
             }
 
-            let min_difficulty = ore_config_res.min_difficulty;
+            let _min_difficulty = ore_config_res.min_difficulty;
 
-            let task = pool.spawn(Compat::new(async move {
-                let hash_time = Instant::now();
+            let _task = pool.spawn(Compat::new(async move {
+                let _hash_time = Instant::now();
                 // let (solution, best_difficulty, best_hash, total_nonces_checked) = find_hash_par(
                 //     proof,
                 //     cutoff,
@@ -204,43 +257,32 @@ pub fn handle_event_mine_for_hash(
     }
 }
 
-pub struct CurrentBus {
-    bus: usize
-}
-
-impl Default for CurrentBus {
-    fn default() -> Self {
-        Self { bus: 0 }
-    }
-}
-
 pub fn handle_event_submit_hash_tx(
-    mut commands: Commands,
+    _commands: Commands,
     mut ev_submit_hash_tx: EventReader<EventSubmitHashTx>,
     query_task_handler: Query<Entity, With<EntityTaskHandler>>,
-    app_wallet: Res<AppWallet>,
-    treasury: Res<TreasuryAccountResource>,
+    mining_session: Res<MiningSessionResource>,
+    _treasury: Res<TreasuryAccountResource>,
     mut miner_status: ResMut<MinerStatusResource>,
-    mut busses_res: ResMut<BussesResource>,
+    _busses_res: ResMut<BussesResource>,
     mut next_state: ResMut<NextState<AppScreenState>>,
-    mut hashrate_res: ResMut<HashrateResource>,
+    hashrate_res: ResMut<HashrateResource>,
 ) {
-    for ev in ev_submit_hash_tx.read() {
-        let wallet = if let Some(wallet) =  &app_wallet.wallet {
-            wallet.clone()
+    for _ev in ev_submit_hash_tx.read() {
+        let _signer = if let Some(signer) = &mining_session.signer {
+            signer.clone()
         } else {
             next_state.set(AppScreenState::Unlock);
-            error!("wallet is None, switching to wallet unlock screen");
+            error!("mining session signer is None, switching to wallet unlock screen");
             continue;
         }; 
-        if let Ok(task_handler_entity) = query_task_handler.get_single() {
+        if let Ok(_task_handler_entity) = query_task_handler.get_single() {
             let pool = IoTaskPool::get();
 
             info!("Hashrate: {}/second", hashrate_res.hashrate);
 
-            let task = pool.spawn(Compat::new(async move {
-                let signer = wallet;
-                let mut attempts = 3;
+            let _task = pool.spawn(Compat::new(async move {
+                let _attempts = 3;
 
                 // Submit a hash on the websocket
 
@@ -277,25 +319,23 @@ pub fn handle_event_submit_hash_tx(
 }
 
 pub fn handle_event_fetch_ui_data_from_rpc(
-    mut commands: Commands,
+    _commands: Commands,
     app_wallet: Res<AppWallet>,
     mut event_reader: EventReader<EventFetchUiDataFromRpc>,
     query_task_handler: Query<Entity, With<EntityTaskFetchUiData>>,
     mut next_state: ResMut<NextState<AppScreenState>>,
 ) {
     for _ev in event_reader.read() {
-        let wallet = if let Some(wallet) =  &app_wallet.wallet {
-            wallet.clone()
+        let _pubkey = if let Some(pubkey) = app_wallet.pubkey() {
+            pubkey
         } else {
             next_state.set(AppScreenState::Unlock);
             error!("wallet is None, switching to wallet unlock screen");
             continue;
         }; 
-        if let Ok(task_handler_entity) = query_task_handler.get_single() {
-            let pubkey = wallet.pubkey();
-
-            let pool = IoTaskPool::get();
-            let ore_mint = get_ore_mint();
+        if let Ok(_task_handler_entity) = query_task_handler.get_single() {
+            let _pool = IoTaskPool::get();
+            let _ore_mint = get_ore_mint();
             // let task = pool.spawn(Compat::new(async move {
             //     Ok(TaskUpdateAppWalletSolBalanceData {
             //         sol_balance: 0.0,
@@ -319,6 +359,7 @@ pub fn handle_event_register_wallet(
     mut commands: Commands,
     mut event_reader: EventReader<EventRegisterWallet>,
     app_wallet: Res<AppWallet>,
+    ore_app_state: Res<OreAppState>,
     query_task_handler: Query<Entity, With<EntityTaskHandler>>,
     mut next_state: ResMut<NextState<AppScreenState>>,
 ) {
@@ -332,12 +373,19 @@ pub fn handle_event_register_wallet(
         }; 
         if let Ok(task_handler_entity) = query_task_handler.get_single() {
             let pool = IoTaskPool::get();
-            let wallet = wallet;
+            let rpc_url = ore_app_state.config.rpc_url.clone();
+            let fee_config = ore_app_state.config.priority_fee.clone();
+            let task = pool.spawn(Compat::new(async move {
+                let client = RpcClient::new(rpc_url);
+                let pubkey = wallet.pubkey();
+                let ix = ore_api::instruction::open(pubkey, pubkey, pubkey);
 
-            // Register Wallet with HttpRequest task
-            // commands
-            //     .entity(task_handler_entity)
-            //     .insert(TaskProcessTx { task });
+                send_tx_with_priority_fee(&client, TxType::Register, &wallet, vec![ix], &fee_config).await
+            }));
+
+            commands
+                .entity(task_handler_entity)
+                .insert(TaskProcessTx { task });
         } else {
             error!("Failed to get task_entity_handler. handle_event_register_wallet");
         }
@@ -348,6 +396,8 @@ pub fn handle_event_claim_ore_rewards(
     mut commands: Commands,
     mut event_reader: EventReader<EventClaimOreRewards>,
     app_wallet: Res<AppWallet>,
+    ore_app_state: Res<OreAppState>,
+    proof_account: Res<ProofAccountResource>,
     query_task_handler: Query<Entity, With<EntityTaskHandler>>,
     mut next_state: ResMut<NextState<AppScreenState>>,
 ) {
@@ -359,26 +409,260 @@ pub fn handle_event_claim_ore_rewards(
             error!("wallet is None, switching to wallet unlock screen");
             continue;
         }; 
+        if proof_account.stake.is_zero() {
+            error!("No rewards to claim. handle_event_claim_ore_rewards.");
+            continue;
+        }
         if let Ok(task_handler_entity) = query_task_handler.get_single() {
+            let pool = IoTaskPool::get();
+            let rpc_url = ore_app_state.config.rpc_url.clone();
+            let fee_config = ore_app_state.config.priority_fee.clone();
+            let amount = proof_account.stake;
+            let task = pool.spawn(Compat::new(async move {
+                let client = RpcClient::new(rpc_url);
+                let pubkey = wallet.pubkey();
+                let ore_mint = get_ore_mint();
+                let token_account = get_associated_token_address(&pubkey, &ore_mint);
+
+                let mut ixs = vec![];
+                if client.get_token_account_balance(&token_account).await.is_err() {
+                    // idempotent, in case the account is created before this lands
+                    ixs.push(create_associated_token_account_idempotent(
+                        &pubkey,
+                        &pubkey,
+                        &ore_mint,
+                        &spl_token::id(),
+                    ));
+                }
+                ixs.push(ore_api::instruction::claim(pubkey, token_account, amount.0));
 
-            // Send Claim request to Http Task handler
+                send_tx_with_priority_fee(&client, TxType::Claim, &wallet, ixs, &fee_config).await
+            }));
 
-            // commands
-            //     .entity(task_handler_entity)
-            //     .insert(TaskProcessTx { task });
+            commands
+                .entity(task_handler_entity)
+                .insert(TaskProcessTx { task });
         } else {
             error!("Failed to get task_handler_entity. handle_event_claim_ore_rewards.");
         }
     }
 }
 
-pub fn handle_event_lock(
+pub fn handle_event_stake_ore(
     mut commands: Commands,
+    mut event_reader: EventReader<EventStakeOre>,
+    app_wallet: Res<AppWallet>,
+    ore_app_state: Res<OreAppState>,
+    query_task_handler: Query<Entity, With<EntityTaskHandler>>,
+    mut next_state: ResMut<NextState<AppScreenState>>,
+) {
+    for _ev in event_reader.read() {
+        let wallet = if let Some(wallet) =  &app_wallet.wallet {
+            wallet.clone()
+        } else {
+            next_state.set(AppScreenState::Unlock);
+            error!("wallet is None, switching to wallet unlock screen");
+            continue;
+        }; 
+        if let Ok(task_handler_entity) = query_task_handler.get_single() {
+            let pool = IoTaskPool::get();
+            let rpc_url = ore_app_state.config.rpc_url.clone();
+            let fee_config = ore_app_state.config.priority_fee.clone();
+            let task = pool.spawn(Compat::new(async move {
+                let client = RpcClient::new(rpc_url);
+                let pubkey = wallet.pubkey();
+                let token_account = get_associated_token_address(&pubkey, &get_ore_mint());
+
+                // stake the full wallet balance
+                let amount = match client.get_token_account_balance(&token_account).await {
+                    Ok(balance) => balance.amount.parse::<u64>().map(OreAmount).unwrap_or(OreAmount::ZERO),
+                    Err(_) => OreAmount::ZERO,
+                };
+                if amount.is_zero() {
+                    let process_data = TaskProcessTxData {
+                        tx_type: TxType::Stake,
+                        signature: None,
+                        signed_tx: None,
+                        hash_time: None,
+                        priority_fee: None,
+                        last_valid_block_height: None,
+                    };
+                    return Err((process_data, "No ORE in wallet to stake".to_string()));
+                }
+                let ix = ore_api::instruction::stake(pubkey, token_account, amount.0);
+
+                send_tx_with_priority_fee(&client, TxType::Stake, &wallet, vec![ix], &fee_config).await
+            }));
+
+            commands
+                .entity(task_handler_entity)
+                .insert(TaskProcessTx { task });
+        } else {
+            error!("Failed to get task_handler_entity. handle_event_stake_ore.");
+        }
+    }
+}
+
+/// Builds the transaction with a priority fee from the configured strategy and sends it.
+/// Preflight is skipped since the transaction was already simulated to size the compute units.
+pub async fn send_tx_with_priority_fee(
+    client: &RpcClient,
+    tx_type: TxType,
+    signer: &Keypair,
+    ixs: Vec<Instruction>,
+    fee_config: &PriorityFeeConfig,
+) -> Result<TaskProcessTxData, (TaskProcessTxData, String)> {
+    let (tx, priority_fee, last_valid_block_height) = match build_tx_with_priority_fee(client, signer, &ixs, fee_config).await {
+        Ok(result) => result,
+        Err(e) => {
+            let process_data = TaskProcessTxData {
+                tx_type,
+                signature: None,
+                signed_tx: None,
+                hash_time: None,
+                priority_fee: None,
+                last_valid_block_height: None,
+            };
+            return Err((process_data, e));
+        }
+    };
+
+    send_signed_tx(client, tx_type, tx, priority_fee, last_valid_block_height).await
+}
+
+/// Sends a transaction that has already been built and signed, e.g. one the
+/// user reviewed before confirming.
+pub async fn send_signed_tx(
+    client: &RpcClient,
+    tx_type: TxType,
+    tx: Transaction,
+    priority_fee: PriorityFeeEstimate,
+    last_valid_block_height: u64,
+) -> Result<TaskProcessTxData, (TaskProcessTxData, String)> {
+    let mut process_data = TaskProcessTxData {
+        tx_type,
+        signature: None,
+        signed_tx: None,
+        hash_time: None,
+        priority_fee: Some(priority_fee),
+        last_valid_block_height: Some(last_valid_block_height),
+    };
+
+    let send_config = RpcSendTransactionConfig {
+        skip_preflight: true,
+        preflight_commitment: Some(CommitmentLevel::Confirmed),
+        ..Default::default()
+    };
+
+    match client.send_transaction_with_config(&tx, send_config).await {
+        Ok(sig) => {
+            process_data.signature = Some(sig);
+            process_data.signed_tx = Some(tx);
+            Ok(process_data)
+        },
+        Err(e) => {
+            process_data.signed_tx = Some(tx);
+            Err((process_data, e.to_string()))
+        }
+    }
+}
+
+/// Drops the UI's copy of the wallet. Mining keeps going with the session
+/// signer until a different wallet is unlocked or the profile is switched.
+pub fn handle_event_lock(
     mut event_reader: EventReader<EventLock>,
+    mut app_wallet: ResMut<AppWallet>,
+    mut next_state: ResMut<NextState<AppScreenState>>,
+) {
+    for _ev in event_reader.read() {
+        app_wallet.wallet = None;
+        app_wallet.watch_only = None;
+        next_state.set(AppScreenState::Unlock);
+    }
+}
+
+pub fn handle_event_select_profile(
+    mut event_reader: EventReader<EventSelectProfile>,
+    mut profiles_res: ResMut<ProfilesResource>,
+    mut unlock_throttle: ResMut<UnlockThrottleResource>,
+    mut set: ParamSet<(
+        Query<&mut Text, With<TextLockedWalletPubkey>>,
+        Query<&mut Text, With<TextLockedWalletLabel>>,
+    )>,
+) {
+    for ev in event_reader.read() {
+        if let Err(e) = profiles_res.profiles.set_active(&ev.0) {
+            error!("Failed to select profile: {}", e);
+            continue;
+        }
+        unlock_throttle.error = None;
+        if let Err(e) = profiles_res.profiles.save() {
+            error!("Failed to save profiles: {}", e);
+        }
+
+        let (pubkey, label) = locked_wallet_info(profiles_res.profiles.active_profile());
+        for mut text in set.p0().iter_mut() {
+            text.sections[0].value = pubkey.clone();
+        }
+        for mut text in set.p1().iter_mut() {
+            text.sections[0].value = label.clone();
+        }
+    }
+}
+
+/// Everything that belongs to the wallet currently mining, torn down when
+/// another wallet takes over.
+#[derive(SystemParam)]
+pub struct WalletSessionReset<'w, 's> {
+    commands: Commands<'w, 's>,
+    event_writer_cancel_mining: EventWriter<'w, EventCancelMining>,
+    miner_status: ResMut<'w, MinerStatusResource>,
+    proof_account: ResMut<'w, ProofAccountResource>,
+    tx_history_res: ResMut<'w, TxHistoryResource>,
+    mining_session: ResMut<'w, MiningSessionResource>,
+    instance_lock: ResMut<'w, InstanceLockResource>,
+    asset_server: Res<'w, AssetServer>,
+    query_toggle: Query<'w, 's, (&'static mut UiImage, &'static mut ToggleAutoMine)>,
+    query_mining_screen: Query<'w, 's, Entity, With<MiningScreenNode>>,
+}
+
+impl<'w, 's> WalletSessionReset<'w, 's> {
+    pub fn reset(&mut self) {
+        if self.miner_status.miner_status != "STOPPED" {
+            self.miner_status.miner_status = "STOPPED".to_string();
+            if let Ok((mut btn, mut toggle)) = self.query_toggle.get_single_mut() {
+                toggle.0 = false;
+                *btn = UiImage::new(self.asset_server.load(MINE_TOGGLE_OFF));
+            }
+            self.event_writer_cancel_mining.send(EventCancelMining);
+        }
+
+        // the mining screen is respawned for the next wallet
+        for entity in self.query_mining_screen.iter() {
+            self.commands.entity(entity).despawn_recursive();
+        }
+
+        *self.proof_account = ProofAccountResource::default();
+        *self.tx_history_res = TxHistoryResource::default();
+        self.mining_session.signer = None;
+        self.instance_lock.wallet = None;
+    }
+}
+
+/// Locks the current wallet without tearing down the app, then returns to
+/// the lock screen where another profile can be picked and unlocked.
+pub fn handle_event_switch_profile(
+    mut event_reader: EventReader<EventSwitchProfile>,
+    mut app_wallet: ResMut<AppWallet>,
+    mut session_reset: WalletSessionReset,
     mut next_state: ResMut<NextState<AppScreenState>>,
 ) {
     for _ev in event_reader.read() {
-        commands.remove_resource::<AppWallet>();
+        session_reset.reset();
+        app_wallet.wallet = None;
+        app_wallet.watch_only = None;
+        app_wallet.sol_balance = Lamports::ZERO;
+        app_wallet.ore_balance = OreAmount::ZERO;
         next_state.set(AppScreenState::Unlock);
     }
 }
@@ -386,31 +670,131 @@ pub fn handle_event_lock(
 pub fn handle_event_unlock(
     mut event_reader: EventReader<EventUnlock>,
     mut app_wallet: ResMut<AppWallet>,
-    query: Query<&TextInput, With<TextPasswordInput>>,
+    mut ore_app_state: ResMut<OreAppState>,
+    profiles_res: Res<ProfilesResource>,
+    mut launch: ResMut<LaunchResource>,
+    mut unlock_throttle: ResMut<UnlockThrottleResource>,
+    mut session_reset: WalletSessionReset,
+    mut query: Query<&mut TextInput, With<TextPasswordInput>>,
     mut next_state: ResMut<NextState<AppScreenState>>,
 ) {
     for _ev in event_reader.read() {
-        let text = query.get_single();
-        if let Ok(text_input) = text {
-            let password = text_input.text.clone();
-
-            // TODO: use const path?
-            let wallet_path = Path::new("save.data");
-
-            let cocoon = Cocoon::new(password.as_bytes());
-            let mut file = File::open(wallet_path).unwrap();
-            let encoded = cocoon.parse(&mut file);
-            if let Ok(encoded) = encoded {
-                let wallet = Keypair::from_bytes(&encoded);
-                if let Ok(wallet) = wallet {
+        let text = query.get_single_mut();
+        if let Ok(mut text_input) = text {
+            let password = SecretString::copy_from(text_input.value());
+            text_input.clear();
+
+            let profile = if let Some(profile) = profiles_res.profiles.active_profile() {
+                profile
+            } else {
+                error!("No wallet profile to unlock. (events.rs: handle_event_unlock)");
+                continue;
+            };
+
+            if profile.is_watch_only() {
+                if let Some(pubkey) = profile.watch_pubkey() {
+                    session_reset.reset();
+                    app_wallet.sol_balance = Lamports::ZERO;
+                    app_wallet.ore_balance = OreAmount::ZERO;
+                    app_wallet.watch_only = Some(pubkey);
+                    ore_app_state.config = profiles_res.effective_config();
+                    // watch-only wallets can't send
+                    let start_screen = launch
+                        .start_screen
+                        .take()
+                        .filter(|screen| *screen != AppScreenState::Send);
+                    next_state.set(start_screen.unwrap_or(AppScreenState::Mining));
+                } else {
+                    error!("Profile {} has an invalid watch pubkey. (events.rs: handle_event_unlock)", profile.name);
+                }
+                continue;
+            }
+
+            let now = get_unix_timestamp();
+            if let Err(e) = unlock_throttle.check(&profile.name, now) {
+                unlock_throttle.error = Some(e);
+                continue;
+            }
+
+            let wallet_path = profile.wallet_file();
+
+            // a missing or corrupt file isn't a wrong password, don't count it
+            if let Err(e) = read_wallet_header(&wallet_path) {
+                error!("{} (events.rs: handle_event_unlock)", e);
+                unlock_throttle.error = Some(e);
+                continue;
+            }
+
+            match read_wallet_file(&wallet_path, password.as_str()) {
+                Ok(wallet) => {
+                    unlock_throttle.error = None;
+                    unlock_throttle.record_success(&profile.name);
+                    match migrate_wallet_file(&wallet_path, password.as_str(), &wallet, profile.name.clone()) {
+                        Ok(true) => info!("Migrated wallet file {} to the current format.", wallet_path.display()),
+                        Ok(false) => {},
+                        Err(e) => error!("Failed to migrate wallet file: {}", e),
+                    }
                     let wallet = Arc::new(wallet);
+                    // a session left running from before the lock only carries
+                    // on if the same wallet was unlocked again
+                    let same_session = session_reset
+                        .mining_session
+                        .signer
+                        .as_ref()
+                        .is_some_and(|signer| signer.pubkey() == wallet.pubkey());
+                    if !same_session {
+                        // another instance mining this wallet would split the
+                        // CPU and submit duplicate work
+                        let wallet_lock = if session_reset.instance_lock.allow_multiple {
+                            None
+                        } else {
+                            match InstanceLock::acquire(&wallet_lock_path(&wallet.pubkey().to_string()), None) {
+                                Ok(lock) => Some(lock),
+                                Err(LockError::Held(info)) => {
+                                    unlock_throttle.error = Some(format!(
+                                        "This wallet is open in another instance (pid {}).",
+                                        info.pid
+                                    ));
+                                    continue;
+                                },
+                                Err(LockError::Io(e)) => {
+                                    error!("{}, skipping the wallet lock. (events.rs: handle_event_unlock)", e);
+                                    None
+                                },
+                            }
+                        };
+                        session_reset.reset();
+                        session_reset.instance_lock.wallet = wallet_lock;
+                        app_wallet.sol_balance = Lamports::ZERO;
+                        app_wallet.ore_balance = OreAmount::ZERO;
+                        session_reset.mining_session.signer = Some(SessionSigner::new(wallet.clone()));
+                    }
                     app_wallet.wallet = Some(wallet);
-                    next_state.set(AppScreenState::Mining);
-                } else {
-                    error!("Failed to parse keypair from bytes. (events.rs: handle_event_unlock)");
+                    ore_app_state.config = profiles_res.effective_config();
+                    let start_screen = launch.start_screen.take();
+                    // mining runs from the mining screen, so --start-mining wins over --screen
+                    if launch.start_mining {
+                        next_state.set(AppScreenState::Mining);
+                    } else {
+                        next_state.set(start_screen.unwrap_or(AppScreenState::Mining));
+                    }
+                },
+                Err(e) => {
+                    error!("{} (events.rs: handle_event_unlock)", e);
+                    let failures = unlock_throttle.record_failure(&profile.name, now);
+                    unlock_throttle.error = Some(format!("Wrong password, {} failed attempts.", failures));
+
+                    let wipe_after = ore_app_state.config.unlock_wipe_after_failures;
+                    if wipe_after > 0
+                        && failures >= wipe_after
+                        && session_reset.mining_session.signer.is_some()
+                    {
+                        info!("{} failed unlock attempts, wiping the mining session.", failures);
+                        session_reset.reset();
+                        app_wallet.sol_balance = Lamports::ZERO;
+                        app_wallet.ore_balance = OreAmount::ZERO;
+                    }
                 }
-            } else {
-                error!("Failed to decrypt file. (events.rs: handle_event_unlock)");
             }
         } else {
             error!("Failed to get_single on TextPasswordInput (events.rs: handle_event_unlock)");
@@ -421,33 +805,113 @@ pub fn handle_event_unlock(
 pub fn handle_event_save_config(
     mut event_reader: EventReader<EventSaveConfig>,
     mut ore_app_state: ResMut<OreAppState>,
+    mut profiles_res: ResMut<ProfilesResource>,
+    mut config_status: ResMut<ConfigStatusResource>,
+    mut config_watch: ResMut<ConfigWatchResource>,
+    mut miner_status: ResMut<MinerStatusResource>,
     mut next_state: ResMut<NextState<AppScreenState>>,
+    mut set: ParamSet<(
+        Query<(&mut Text, &TextConfigFieldError)>,
+        Query<&mut Text, With<TextConfigStatus>>,
+    )>,
 ) {
     for ev in event_reader.read() {
-        let new_config = ev.0.clone();
-        let toml_string = toml::to_string(&new_config).unwrap();
-        let data = toml_string.into_bytes();
+        let result = parse_config_inputs(&profiles_res.base_config, &ev.0);
+
+        for (mut text, field) in set.p0().iter_mut() {
+            text.sections[0].value = match &result {
+                Err(errors) => errors
+                    .iter()
+                    .find(|(f, _)| *f == field.0)
+                    .map(|(_, e)| e.clone())
+                    .unwrap_or_default(),
+                Ok(_) => String::new(),
+            };
+        }
 
-        let mut f = File::create("config.toml").expect("Unable to create file");
-        f.write_all(&data).expect("Unable to write data");
+        let new_config = match result {
+            Ok(config) => config,
+            Err(_) => {
+                if let Ok(mut text) = set.p1().get_single_mut() {
+                    text.sections[0].value = "Fix the highlighted fields to save.".to_string();
+                }
+                continue;
+            }
+        };
+
+        let config_path = config_file();
+        if config_status.error.is_some() && config_path.exists() {
+            // keep the file that failed to load, it may hold hand edits
+            let backup_path = config_path.with_extension("toml.bak");
+            if let Err(e) = fs::copy(&config_path, &backup_path) {
+                error!("Failed to back up {} to {}: {}", config_path.display(), backup_path.display(), e);
+            }
+        }
 
+        let saved = toml::to_string(&new_config)
+            .map_err(|e| e.to_string())
+            .and_then(|toml_string| fs::write(&config_path, toml_string).map_err(|e| e.to_string()));
+        if let Err(e) = saved {
+            error!("Failed to save {}: {}", CONFIG_PATH, e);
+            if let Ok(mut text) = set.p1().get_single_mut() {
+                text.sections[0].value = format!("Failed to save {}: {}", CONFIG_PATH, e);
+            }
+            continue;
+        }
+        config_status.error = None;
 
-        let new_state;
-        let wallet_path = Path::new("save.data");
-        if wallet_path.exists() {
-            new_state = AppScreenState::Mining;
+        let new_state = if profiles_res.profiles.has_wallets() {
+            AppScreenState::Mining
         } else {
-            new_state = AppScreenState::WalletSetup;
-        }
+            AppScreenState::WalletSetup
+        };
 
-        //miner_status.miner_threads = new_config.threads;
-        ore_app_state.config = new_config;
+        apply_config(new_config, &mut profiles_res, &mut ore_app_state, &mut miner_status, &mut config_watch);
         next_state.set(new_state);
     }
 }
 
+/// Swaps in a new `config.toml`. Everything but the settings listed by
+/// `restart_required_changes` is read where it's used, so this is enough
+/// to apply them.
+fn apply_config(
+    config: AppConfig,
+    profiles_res: &mut ProfilesResource,
+    ore_app_state: &mut OreAppState,
+    miner_status: &mut MinerStatusResource,
+    config_watch: &mut ConfigWatchResource,
+) {
+    config_watch.restart_required = restart_required_changes(&config_watch.started_with, &config);
+    profiles_res.base_config = config;
+    ore_app_state.config = profiles_res.effective_config();
+    // picked up by the next hash, mining doesn't need to be restarted
+    miner_status.miner_threads = ore_app_state.config.threads;
+}
+
+pub fn handle_event_config_changed(
+    mut event_reader: EventReader<EventConfigChanged>,
+    mut ore_app_state: ResMut<OreAppState>,
+    mut profiles_res: ResMut<ProfilesResource>,
+    mut config_status: ResMut<ConfigStatusResource>,
+    mut config_watch: ResMut<ConfigWatchResource>,
+    mut miner_status: ResMut<MinerStatusResource>,
+) {
+    for ev in event_reader.read() {
+        apply_config(ev.0.clone(), &mut profiles_res, &mut ore_app_state, &mut miner_status, &mut config_watch);
+        // a fixed file replaces the one that failed to load
+        config_status.error = None;
+        if !config_watch.restart_required.is_empty() {
+            info!("Restart to apply: {}", config_watch.restart_required.join(", "));
+        }
+    }
+}
+
 pub fn handle_event_generate_wallet(
+    mut commands: Commands,
     mut event_reader: EventReader<EventGenerateWallet>,
+    asset_server: Res<AssetServer>,
+    mut backup: ResMut<BackupVerificationResource>,
+    query_word_grid: Query<Entity, With<BackupWordGrid>>,
     // mut text_query: Query<&mut Text, With<TextGeneratedPubkey>>,
     // mut ore_app_state: ResMut<OreAppState>,
     // mut next_state: ResMut<NextState<GameState>>,
@@ -461,13 +925,13 @@ pub fn handle_event_generate_wallet(
     for _ev in event_reader.read() {
         let new_mnemonic = Mnemonic::new(MnemonicType::Words12, Language::English);
 
-        let phrase = new_mnemonic.clone().into_phrase();
+        let mut phrase = new_mnemonic.clone().into_phrase();
 
         let words: Vec<&str> = phrase.split(" ").collect();
 
         let seed = Seed::new(&new_mnemonic, "");
 
-        let derivation_path = DerivationPath::from_absolute_path_str("m/44'/501'/0'/0'").unwrap();
+        let derivation_path = DerivationPath::from_absolute_path_str(DEFAULT_DERIVATION_PATH).unwrap();
 
         let new_key = Keypair::from_seed_and_derivation_path(seed.as_bytes(), Some(derivation_path));
         if let Ok(new_key) = new_key {
@@ -476,6 +940,7 @@ pub fn handle_event_generate_wallet(
             for (mut text, mut text_keypair) in set.p0().iter_mut() {
                 text.sections[0].value = pubkey.clone();
                 text_keypair.0 = new_key.clone();
+                text_keypair.1 = Some(SecretString::copy_from(&phrase));
             }
             for mut text in set.p1().iter_mut() {
                 let mut value = String::new();
@@ -501,14 +966,97 @@ pub fn handle_event_generate_wallet(
                 }
                 text.sections[0].value = value;
             }
+
+            let challenge = BackupChallenge::new(&phrase);
+            if let Ok(grid) = query_word_grid.get_single() {
+                let shuffled = challenge.shuffled_words();
+                commands.entity(grid).despawn_descendants().with_children(|parent| {
+                    for word in shuffled.iter() {
+                        spawn_backup_word_button(parent, &asset_server, word);
+                    }
+                });
+            }
+            backup.challenge = Some(challenge);
+            backup.accept_risk = false;
         } else {
             error!("Failed to generate keypair from seed as bytes");
         }
+        phrase.zeroize();
+    }
+}
+
+pub fn handle_event_restore_mnemonic(
+    mut commands: Commands,
+    mut event_reader: EventReader<EventRestoreMnemonic>,
+    asset_server: Res<AssetServer>,
+    mut backup: ResMut<BackupVerificationResource>,
+    query_mnemonic: Query<&TextInput, With<TextMnemonicInput>>,
+    query_passphrase: Query<&TextInput, With<TextMnemonicPassphraseInput>>,
+    query_derivation_path: Query<&TextInput, With<TextDerivationPathInput>>,
+    query_preview_list: Query<Entity, With<RestorePreviewList>>,
+    mut set: ParamSet<(
+        Query<(&mut Text, &mut TextGeneratedKeypair)>,
+        Query<&mut Text, With<TextRestoreStatus>>,
+        Query<&mut Text, Or<(With<TextMnemonicLine1>, With<TextMnemonicLine2>, With<TextMnemonicLine3>)>>,
+    )>,
+) {
+    for _ev in event_reader.read() {
+        let phrase = SecretString::copy_from(query_mnemonic.single().value());
+        let passphrase = SecretString::copy_from(query_passphrase.single().value());
+        let derivation_path = query_derivation_path.single().text.trim().to_string();
+
+        let mnemonic = match parse_mnemonic(phrase.as_str()) {
+            Ok(mnemonic) => mnemonic,
+            Err(e) => {
+                set.p1().single_mut().sections[0].value = e;
+                continue;
+            }
+        };
+        let keypair = match derive_keypair(&mnemonic, passphrase.as_str(), &derivation_path) {
+            Ok(keypair) => Arc::new(keypair),
+            Err(e) => {
+                set.p1().single_mut().sections[0].value = e;
+                continue;
+            }
+        };
+
+        let pubkey = keypair.pubkey();
+        for (mut text, mut text_keypair) in set.p0().iter_mut() {
+            text.sections[0].value = pubkey.to_string();
+            text_keypair.0 = keypair.clone();
+            text_keypair.1 = Some(SecretString::copy_from(mnemonic.phrase()));
+        }
+        // the restored phrase is already known to the user, don't echo it back
+        for mut text in set.p2().iter_mut() {
+            text.sections[0].value = String::new();
+        }
+        *backup = BackupVerificationResource::default();
+        set.p1().single_mut().sections[0].value = format!(
+            "Restored {} at {}. Pick another account below if this isn't yours.",
+            shorten_string(pubkey.to_string(), 10),
+            derivation_path,
+        );
+
+        if let Ok(list) = query_preview_list.get_single() {
+            let accounts = preview_accounts(&mnemonic, passphrase.as_str(), RESTORE_PREVIEW_ACCOUNTS);
+            commands.entity(list).despawn_descendants().with_children(|parent| {
+                for (path, account_pubkey) in accounts.iter() {
+                    spawn_restore_account_item(
+                        parent,
+                        &asset_server,
+                        path,
+                        account_pubkey,
+                        *account_pubkey == pubkey,
+                    );
+                }
+            });
+        }
     }
 }
 
 pub fn handle_event_load_keypair_file(
     mut event_reader: EventReader<EventLoadKeypairFile>,
+    mut backup: ResMut<BackupVerificationResource>,
     // mut text_query: Query<&mut Text, With<TextGeneratedPubkey>>,
     // mut ore_app_state: ResMut<OreAppState>,
     // mut next_state: ResMut<NextState<GameState>>,
@@ -527,6 +1075,7 @@ pub fn handle_event_load_keypair_file(
             for (mut text, mut text_keypair) in set.p0().iter_mut() {
                 text.sections[0].value = pubkey.clone();
                 text_keypair.0 = keypair.clone();
+                text_keypair.1 = None;
             }
             for mut text in set.p1().iter_mut() {
                 let value = String::new();
@@ -540,6 +1089,7 @@ pub fn handle_event_load_keypair_file(
                 let value = String::new();
                 text.sections[0].value = value;
             }
+            *backup = BackupVerificationResource::default();
         } else {
             error!("Error: Failed to load keypair file from path: {}", path.display());
         }
@@ -550,55 +1100,369 @@ pub fn handle_event_load_keypair_file(
 pub fn handle_event_save_wallet(
     mut event_reader: EventReader<EventSaveWallet>,
     mut set: ParamSet<(
-        Query<&TextGeneratedKeypair>,
-        Query<&TextInput, With<TextPasswordInput>>,
+        Query<&mut TextGeneratedKeypair>,
+        Query<&mut TextInput, With<TextPasswordInput>>,
+        Query<&TextInput, With<TextProfileNameInput>>,
+        Query<&mut Text, Or<(With<TextMnemonicLine1>, With<TextMnemonicLine2>, With<TextMnemonicLine3>)>>,
+        Query<&mut TextInput, Or<(With<TextMnemonicInput>, With<TextMnemonicPassphraseInput>)>>,
     )>,
+    mut profiles_res: ResMut<ProfilesResource>,
+    mut backup: ResMut<BackupVerificationResource>,
     mut next_state: ResMut<NextState<AppScreenState>>,
 ) {
     for _ev in event_reader.read() {
+        if !backup.can_save() {
+            error!("Error: Verify the seed phrase backup or accept the risk before saving.");
+            continue;
+        }
         let generated_keypair = set.p0().single().0.clone();
+        let generated_mnemonic = set.p0()
+            .single()
+            .1
+            .as_ref()
+            .map(|mnemonic| SecretString::copy_from(mnemonic.as_str()));
+
+        let password = SecretString::copy_from(set.p1().single().value());
+
+        let profile_name = set.p2().single().text.trim().to_string();
+        let mut profiles = profiles_res.profiles.clone();
+        if let Err(e) = profiles.add(WalletProfile::new(profile_name.clone())) {
+            error!("Error: Failed to add wallet profile: {}", e);
+            continue;
+        }
+        let profile = profiles.get(&profile_name).unwrap().clone();
+
+        let wallet_path = profile.wallet_file();
+        let mut header = WalletHeader::new(&generated_keypair, profile.name.clone());
+        header.backup = backup.backup_status();
+        if let Err(e) = write_wallet_file(
+            &wallet_path,
+            password.as_str(),
+            &generated_keypair,
+            generated_mnemonic.as_ref().map(|mnemonic| mnemonic.as_str()),
+            &header,
+        ) {
+            error!("Error: Failed to save wallet file: {}", e);
+            continue;
+        }
 
-        let password = set.p1().single().text.clone();
-
-        let wallet_path = Path::new("save.data");
+        if let Err(e) = profiles.save() {
+            error!("Error: Failed to save profiles: {}", e);
+        }
+        profiles_res.profiles = profiles;
+        *backup = BackupVerificationResource::default();
+
+        // the wallet is on disk now, wipe the secrets from the setup screen
+        drop(generated_keypair);
+        for mut generated in set.p0().iter_mut() {
+            generated.0 = Arc::new(Keypair::new());
+            generated.1 = None;
+        }
+        for mut text_input in set.p1().iter_mut() {
+            text_input.clear();
+        }
+        for mut text in set.p3().iter_mut() {
+            text.sections[0].value.zeroize();
+        }
+        for mut text_input in set.p4().iter_mut() {
+            text_input.clear();
+        }
 
-        let cocoon = Cocoon::new(password.as_bytes());
-        let wallet_bytes = generated_keypair.to_bytes();
-        let file = File::create(wallet_path);
+        // go to locked screen
+        next_state.set(AppScreenState::Unlock);
+    }
+}
 
-        if let Ok(mut file) = file {
-            let container = cocoon.dump(wallet_bytes.to_vec(), &mut file);
+pub fn handle_event_backup_word_picked(
+    mut event_reader: EventReader<EventBackupWordPicked>,
+    mut backup: ResMut<BackupVerificationResource>,
+) {
+    for ev in event_reader.read() {
+        if let Some(challenge) = backup.challenge.as_mut() {
+            challenge.pick(&ev.0);
+        }
+    }
+}
 
-            if let Ok(_) = container {
-                // go to locked screen
+/// Adds a profile that only watches a pubkey, no keypair is stored for it.
+pub fn handle_event_add_watch_only(
+    mut event_reader: EventReader<EventAddWatchOnly>,
+    mut set: ParamSet<(
+        Query<&TextInput, With<TextWatchPubkeyInput>>,
+        Query<&TextInput, With<TextProfileNameInput>>,
+        Query<&mut Text, With<TextRestoreStatus>>,
+    )>,
+    mut profiles_res: ResMut<ProfilesResource>,
+    mut next_state: ResMut<NextState<AppScreenState>>,
+) {
+    for _ev in event_reader.read() {
+        let pubkey_str = set.p0().single().text.trim().to_string();
+        let profile_name = set.p1().single().text.trim().to_string();
+
+        let result = Pubkey::from_str(&pubkey_str)
+            .map_err(|_| "Invalid pubkey".to_string())
+            .and_then(|pubkey| {
+                let mut profiles = profiles_res.profiles.clone();
+                profiles.add(WalletProfile::new_watch_only(profile_name.clone(), &pubkey))?;
+                profiles.save()?;
+                Ok(profiles)
+            });
+
+        match result {
+            Ok(profiles) => {
+                profiles_res.profiles = profiles;
                 next_state.set(AppScreenState::Unlock);
-            } else {
-                error!("Error: Failed to save wallet file.");
+            },
+            Err(e) => {
+                error!("Failed to add watch-only profile: {}", e);
+                if let Ok(mut text) = set.p2().get_single_mut() {
+                    text.sections[0].value = format!("Error: {}", e);
+                }
             }
-        } else {
-            error!("Error: failed to create file at path: {}", wallet_path.display());
         }
     }
 }
 
-pub fn handle_event_request_airdrop(
-    mut commands: Commands,
-    mut event_reader: EventReader<EventRequestAirdrop>,
-    app_wallet: Res<AppWallet>,
-    query_task_handler: Query<Entity, With<EntityTaskHandler>>,
-    mut next_state: ResMut<NextState<AppScreenState>>,
+pub fn handle_event_change_password(
+    mut event_reader: EventReader<EventChangePassword>,
+    ore_app_state: Res<OreAppState>,
+    profiles_res: Res<ProfilesResource>,
+    mut unlock_throttle: ResMut<UnlockThrottleResource>,
+    mut query_inputs: Query<(
+        &mut TextInput,
+        Option<&TextChangePasswordOld>,
+        Option<&TextChangePasswordNew>,
+        Option<&TextChangePasswordConfirm>,
+    )>,
+    mut query_status: Query<&mut Text, With<TextChangePasswordStatus>>,
 ) {
     for _ev in event_reader.read() {
-        let wallet = if let Some(wallet) =  &app_wallet.wallet {
-            wallet.clone()
-        } else {
-            next_state.set(AppScreenState::Unlock);
-            error!("wallet is None, switching to wallet unlock screen");
-            continue;
-        }; 
-        if let Ok(task_handler_entity) = query_task_handler.get_single() {
-            let pool = IoTaskPool::get();
-            let task = pool.spawn(Compat::new(async move {
+        let mut old_password = SecretString::new();
+        let mut new_password = SecretString::new();
+        let mut confirm_password = SecretString::new();
+        for (text_input, old, new, confirm) in query_inputs.iter() {
+            if old.is_some() {
+                old_password = SecretString::copy_from(text_input.value());
+            } else if new.is_some() {
+                new_password = SecretString::copy_from(text_input.value());
+            } else if confirm.is_some() {
+                confirm_password = SecretString::copy_from(text_input.value());
+            }
+        }
+
+        let result = validate_new_password(
+            new_password.as_str(),
+            confirm_password.as_str(),
+            ore_app_state.config.min_password_strength,
+        )
+        .and_then(|_| {
+            let profile = profiles_res
+                .profiles
+                .active_profile()
+                .ok_or("No wallet profile".to_string())?;
+            let wallet_path = profile.wallet_file();
+            let now = get_unix_timestamp();
+            unlock_throttle.check(&profile.name, now)?;
+            // a missing or corrupt file isn't a wrong password, don't count it
+            read_wallet_header(&wallet_path)?;
+            let secrets = match read_wallet_secrets(&wallet_path, old_password.as_str()) {
+                Ok(secrets) => secrets,
+                Err(_) => {
+                    let failures = unlock_throttle.record_failure(&profile.name, now);
+                    return Err(format!("Old password is incorrect, {} failed attempts.", failures));
+                },
+            };
+            unlock_throttle.record_success(&profile.name);
+            change_wallet_password(
+                &wallet_path,
+                &secrets,
+                new_password.as_str(),
+                profile.name.clone(),
+            )
+        });
+
+        let status = match result {
+            Ok(backup_path) => {
+                // clear the fields so the passwords don't linger on screen
+                for (mut text_input, old, new, confirm) in query_inputs.iter_mut() {
+                    if old.is_some() || new.is_some() || confirm.is_some() {
+                        text_input.clear();
+                    }
+                }
+                format!("Password changed. Previous file kept at {}", backup_path.display())
+            },
+            Err(e) => {
+                error!("Failed to change password: {}", e);
+                e
+            }
+        };
+        if let Ok(mut text) = query_status.get_single_mut() {
+            text.sections[0].value = status;
+        }
+    }
+}
+
+pub fn handle_event_reveal_secret(
+    mut event_reader: EventReader<EventRevealSecret>,
+    profiles_res: Res<ProfilesResource>,
+    mut secret_clipboard: ResMut<SecretClipboardResource>,
+    mut unlock_throttle: ResMut<UnlockThrottleResource>,
+    mut query_inputs: Query<(
+        &mut TextInput,
+        Option<&TextRevealPassword>,
+        Option<&TextExportPath>,
+    )>,
+    mut set: ParamSet<(
+        Query<&mut Text, With<TextRevealStatus>>,
+        Query<&mut Text, With<TextRevealedMnemonic>>,
+    )>,
+) {
+    for ev in event_reader.read() {
+        let mut password = SecretString::new();
+        let mut export_path = String::new();
+        for (mut text_input, reveal_password, path) in query_inputs.iter_mut() {
+            if reveal_password.is_some() {
+                password = SecretString::copy_from(text_input.value());
+                // the password is asked again for every reveal
+                text_input.clear();
+            } else if path.is_some() {
+                export_path = text_input.text.trim().to_string();
+            }
+        }
+
+        let profile = if let Some(profile) = profiles_res.profiles.active_profile() {
+            profile
+        } else {
+            error!("No wallet profile. (events.rs: handle_event_reveal_secret)");
+            continue;
+        };
+        let wallet_path = profile.wallet_file();
+
+        let now = get_unix_timestamp();
+        if let Err(e) = unlock_throttle.check(&profile.name, now) {
+            if let Ok(mut text) = set.p0().get_single_mut() {
+                text.sections[0].value = e;
+            }
+            continue;
+        }
+        // a missing or corrupt file isn't a wrong password, don't count it
+        if let Err(e) = read_wallet_header(&wallet_path) {
+            error!("{} (events.rs: handle_event_reveal_secret)", e);
+            if let Ok(mut text) = set.p0().get_single_mut() {
+                text.sections[0].value = e;
+            }
+            continue;
+        }
+
+        let secrets = read_wallet_secrets(&wallet_path, password.as_str());
+        let pubkey = match &secrets {
+            Ok(secrets) => secrets.keypair.pubkey().to_string(),
+            Err(_) => read_wallet_header(&wallet_path)
+                .ok()
+                .flatten()
+                .map(|header| header.pubkey)
+                .unwrap_or_else(|| "unknown".to_string()),
+        };
+        let password_ok = secrets.is_ok();
+        let failures = if password_ok {
+            unlock_throttle.record_success(&profile.name);
+            0
+        } else {
+            unlock_throttle.record_failure(&profile.name, now)
+        };
+
+        let result = match secrets {
+            Ok(secrets) => match ev.0 {
+                SecretAction::ShowMnemonic => {
+                    if let Some(mnemonic) = secrets.mnemonic {
+                        let mut words: Vec<String> = mnemonic
+                            .as_str()
+                            .split_whitespace()
+                            .enumerate()
+                            .map(|(i, word)| format!("{}. {}", i + 1, word))
+                            .collect();
+                        for mut text in set.p1().iter_mut() {
+                            text.sections[0].value = words.join("   ");
+                        }
+                        words.iter_mut().for_each(|word| word.zeroize());
+                        secret_clipboard.mnemonic_hide_at = Some(Instant::now() + Duration::from_secs(MNEMONIC_REVEAL_SECONDS));
+                        Ok(format!(
+                            "Seed phrase shown below for {} seconds, it is hidden when you leave this screen.",
+                            MNEMONIC_REVEAL_SECONDS
+                        ))
+                    } else {
+                        Err("No seed phrase is stored for this wallet, it was imported from a keypair.".to_string())
+                    }
+                },
+                SecretAction::ExportKeypair => {
+                    if export_path.is_empty() {
+                        Err("Enter a path to export the keypair to.".to_string())
+                    } else if Path::new(&export_path).exists() {
+                        Err(format!("{} already exists.", export_path))
+                    } else {
+                        write_keypair_file(&secrets.keypair, &export_path)
+                            .map(|_| format!("Keypair exported to {}", export_path))
+                            .map_err(|e| format!("Failed to export keypair: {}", e))
+                    }
+                },
+                SecretAction::CopySecret => {
+                    // the encoded key is handed to the clipboard, only a wiped copy is kept
+                    let encoded = secrets.keypair.to_base58_string();
+                    let secret = SecretString::copy_from(&encoded);
+                    match ClipboardContext::new().and_then(|mut ctx| ctx.set_contents(encoded)) {
+                        Ok(_) => {
+                            secret_clipboard.clear_at = Some(Instant::now() + Duration::from_secs(SECRET_CLIPBOARD_SECONDS));
+                            secret_clipboard.secret = Some(secret);
+                            Ok(format!("Secret key copied, the clipboard is cleared in {} seconds.", SECRET_CLIPBOARD_SECONDS))
+                        },
+                        Err(e) => Err(format!("Failed to copy to the clipboard: {}", e)),
+                    }
+                },
+            },
+            Err(_) => Err(format!("Password is incorrect, {} failed attempts.", failures)),
+        };
+
+        // only a secret that was actually shown, exported or copied counts as revealed
+        let outcome = match (&result, password_ok) {
+            (Ok(_), _) => SecretAccessOutcome::Revealed,
+            (Err(_), true) => SecretAccessOutcome::Failed,
+            (Err(_), false) => SecretAccessOutcome::Denied,
+        };
+        if let Err(e) = record_secret_access(ev.0.audit_name(), &profile.name, &pubkey, outcome) {
+            error!("Failed to write audit log: {}", e);
+        }
+
+        let status = match result {
+            Ok(status) => status,
+            Err(e) => {
+                error!("Secret reveal failed: {}", e);
+                e
+            }
+        };
+        if let Ok(mut text) = set.p0().get_single_mut() {
+            text.sections[0].value = status;
+        }
+    }
+}
+
+pub fn handle_event_request_airdrop(
+    mut commands: Commands,
+    mut event_reader: EventReader<EventRequestAirdrop>,
+    app_wallet: Res<AppWallet>,
+    query_task_handler: Query<Entity, With<EntityTaskHandler>>,
+    mut next_state: ResMut<NextState<AppScreenState>>,
+) {
+    for _ev in event_reader.read() {
+        let wallet = if let Some(wallet) =  &app_wallet.wallet {
+            wallet.clone()
+        } else {
+            next_state.set(AppScreenState::Unlock);
+            error!("wallet is None, switching to wallet unlock screen");
+            continue;
+        }; 
+        if let Ok(task_handler_entity) = query_task_handler.get_single() {
+            let pool = IoTaskPool::get();
+            let task = pool.spawn(Compat::new(async move {
                 let devnet_url = "https://api.devnet.solana.com".to_string();
                 let client = RpcClient::new(devnet_url);
 
@@ -607,28 +1471,32 @@ pub fn handle_event_request_airdrop(
                 match airdrop_request {
                     Ok(sig) => {
                         let process_data = TaskProcessTxData {
-                            tx_type: "Airdrop".to_string(),
+                            tx_type: TxType::Airdrop,
                             signature: Some(sig),
                             signed_tx: None,
                             hash_time: None,
+                            priority_fee: None,
+                            last_valid_block_height: None,
                         };
 
-                        return Ok(process_data);
+                        Ok(process_data)
                     },
                     Err(e) => {
                         // error!("Failed to request airdrop. handle_event_request_airdrop");
                         // error!("Error: {}", e.to_string());
                         let process_data = TaskProcessTxData {
-                            tx_type: "Airdrop".to_string(),
+                            tx_type: TxType::Airdrop,
                             signature: None,
                             signed_tx: None,
                             hash_time: None,
+                            priority_fee: None,
+                            last_valid_block_height: None,
                         };
 
-                        return Err((
+                        Err((
                             process_data,
                             e.to_string(),
-                        ));
+                        ))
                     }
                 }
             }));
@@ -643,6 +1511,635 @@ pub fn handle_event_request_airdrop(
 }
 
 
+pub fn handle_event_tx_result(
+    mut commands: Commands,
+    mut event_reader: EventReader<EventTxResult>,
+    asset_server: Res<AssetServer>,
+    app_wallet: Res<AppWallet>,
+    proof_account: Res<ProofAccountResource>,
+    query_tx_result_list: Query<(Entity, Option<&Children>), With<MiningScreenTxResultList>>,
+) {
+    for ev in event_reader.read() {
+        if !ev.tx_status.error.is_empty() {
+            error!("{} tx {}: {}", ev.tx_type.to_string(), ev.tx_status.status, ev.tx_status.error);
+        }
+        info!(
+            "{} tx {} {}. processed: {:?}ms, confirmed: {:?}ms, finalized: {:?}ms",
+            ev.tx_type.to_string(),
+            ev.sig,
+            ev.tx_status.status,
+            ev.commitment_times.processed,
+            ev.commitment_times.confirmed,
+            ev.commitment_times.finalized,
+        );
+
+        let record = MiningRecord::Tx {
+            timestamp: get_unix_timestamp() as i64,
+            wallet: app_wallet.pubkey().map(|p| p.to_string()).unwrap_or_default(),
+            tx_type: ev.tx_type.to_string(),
+            signature: ev.sig.clone(),
+            status: ev.tx_status.status.clone(),
+            error: ev.tx_status.error.clone(),
+            challenge: proof_account.challenge.clone(),
+            difficulty: ev.hash_status.map(|h| h.hash_difficulty),
+            hash_time: ev.hash_status.map(|h| h.hash_time),
+            tx_time: ev.tx_time,
+            priority_fee_lamports: ev.priority_fee.map(|fee| fee.fee_lamports().0),
+        };
+        if let Err(e) = append_record(&record) {
+            error!("Failed to save tx result to mining history: {}", e);
+        }
+
+        if let Ok((list_entity, children)) = query_tx_result_list.get_single() {
+            let item_count = children.map(|c| c.len()).unwrap_or(0);
+            let hash_time = if let Some(hash_status) = ev.hash_status {
+                format!("{}s", hash_status.hash_time)
+            } else {
+                "-".to_string()
+            };
+
+            let item = UiListItem {
+                id: ev.tx_type.to_string(),
+                landed_at: chrono::Local::now().format("%H:%M:%S").to_string(),
+                sig: ev.sig.clone(),
+                tx_time: format!("{}s", ev.tx_time),
+                hash_time,
+                status: ev.tx_status.status.clone(),
+            };
+
+            spawn_new_list_item(&mut commands, &asset_server, list_entity, item, item_count % 2 == 0);
+        }
+    }
+}
+
+#[derive(Event)]
+/// The pool's result for a submitted round, sent by the pool connection once
+/// it decodes a `ServerMessage::PoolSubmissionResult`.
+pub struct EventPoolSubmissionResult(pub ServerMessagePoolSubmissionResult);
+
+/// Appends pool rounds to the mining history, amounts in base units.
+pub fn handle_event_pool_submission_result(
+    mut event_reader: EventReader<EventPoolSubmissionResult>,
+    app_wallet: Res<AppWallet>,
+) {
+    for ev in event_reader.read() {
+        let wallet = app_wallet.pubkey().map(|p| p.to_string()).unwrap_or_default();
+        let record = ev.0.to_record(wallet, get_unix_timestamp() as i64);
+        if let Err(e) = append_record(&record) {
+            error!("Failed to save pool round to mining history: {}", e);
+        }
+    }
+}
+
+#[derive(Event)]
+pub struct EventExportMiningHistory {
+    pub from: String,
+    pub to: String,
+    pub format: ExportFormat,
+}
+
+/// Exports on the IO pool, the history file can be large.
+pub fn handle_event_export_mining_history(
+    mut commands: Commands,
+    mut event_reader: EventReader<EventExportMiningHistory>,
+    query_task_handler: Query<Entity, (With<EntityTaskHandler>, Without<TaskExportMiningHistory>)>,
+    mut query_status_text: Query<&mut Text, With<TextMiningExportStatus>>,
+) {
+    for ev in event_reader.read() {
+        let range = parse_export_date(&ev.from, false)
+            .and_then(|from| parse_export_date(&ev.to, true).map(|to| (from, to)))
+            .and_then(|(from, to)| {
+                if from > to {
+                    Err("The start date is after the end date".to_string())
+                } else {
+                    Ok((from, to))
+                }
+            });
+        let (from, to) = match range {
+            Ok(range) => range,
+            Err(e) => {
+                if let Ok(mut text) = query_status_text.get_single_mut() {
+                    text.sections[0].value = e;
+                }
+                continue;
+            }
+        };
+
+        let task_handler_entity = if let Ok(entity) = query_task_handler.get_single() {
+            entity
+        } else {
+            if let Ok(mut text) = query_status_text.get_single_mut() {
+                text.sections[0].value = "An export is already running".to_string();
+            }
+            continue;
+        };
+
+        let history = data_path(MINING_HISTORY_PATH);
+        let output = export_path(&ev.from, &ev.to, ev.format);
+        let format = ev.format;
+        let task = IoTaskPool::get().spawn(async move {
+            export_records(&history, &output, from, to, format).map(|rows| (rows, output))
+        });
+        commands
+            .entity(task_handler_entity)
+            .insert(TaskExportMiningHistory { task });
+        if let Ok(mut text) = query_status_text.get_single_mut() {
+            text.sections[0].value = "Exporting...".to_string();
+        }
+    }
+}
+
+#[derive(Event)]
+pub struct EventCreateAppBackup;
+
+/// Encrypts on the IO pool, the mining history can be large.
+pub fn handle_event_create_app_backup(
+    mut commands: Commands,
+    mut event_reader: EventReader<EventCreateAppBackup>,
+    ore_app_state: Res<OreAppState>,
+    query_task_handler: Query<Entity, (With<EntityTaskHandler>, Without<TaskCreateAppBackup>)>,
+    mut query_inputs: Query<(
+        &mut TextInput,
+        Option<&TextAppBackupPasswordInput>,
+        Option<&TextAppBackupConfirmInput>,
+    )>,
+    mut query_status_text: Query<&mut Text, With<TextAppBackupStatus>>,
+) {
+    for _ev in event_reader.read() {
+        let mut password = SecretString::new();
+        let mut confirm_password = SecretString::new();
+        for (text_input, backup_password, confirm) in query_inputs.iter() {
+            if backup_password.is_some() {
+                password = SecretString::copy_from(text_input.value());
+            } else if confirm.is_some() {
+                confirm_password = SecretString::copy_from(text_input.value());
+            }
+        }
+
+        let status = if let Err(e) = validate_new_password(
+            password.as_str(),
+            confirm_password.as_str(),
+            ore_app_state.config.min_password_strength,
+        ) {
+            e
+        } else if let Ok(task_handler_entity) = query_task_handler.get_single() {
+            let output = backup_file_path(get_unix_timestamp() as i64);
+            let task = IoTaskPool::get().spawn(async move {
+                create_backup(&output, password.as_str()).map(|files| (files, output))
+            });
+            commands
+                .entity(task_handler_entity)
+                .insert(TaskCreateAppBackup { task });
+            for (mut text_input, backup_password, confirm) in query_inputs.iter_mut() {
+                if backup_password.is_some() || confirm.is_some() {
+                    text_input.clear();
+                }
+            }
+            "Creating backup...".to_string()
+        } else {
+            "A backup is already running".to_string()
+        };
+        if let Ok(mut text) = query_status_text.get_single_mut() {
+            text.sections[0].value = status;
+        }
+    }
+}
+
+#[derive(Event)]
+pub struct EventOpenAppBackup;
+
+/// Decrypts the backup and checks it against its manifest, nothing is
+/// restored until Merge or Replace is clicked.
+pub fn handle_event_open_app_backup(
+    mut event_reader: EventReader<EventOpenAppBackup>,
+    query_inputs: Query<(
+        &TextInput,
+        Option<&TextAppRestorePathInput>,
+        Option<&TextAppRestorePasswordInput>,
+    )>,
+    mut app_restore: ResMut<AppRestoreResource>,
+    mut query_status_text: Query<&mut Text, With<TextAppRestoreStatus>>,
+) {
+    for _ev in event_reader.read() {
+        let mut path = String::new();
+        let mut password = SecretString::new();
+        for (text_input, path_input, password_input) in query_inputs.iter() {
+            if path_input.is_some() {
+                path = text_input.text.trim().to_string();
+            } else if password_input.is_some() {
+                password = SecretString::copy_from(text_input.value());
+            }
+        }
+
+        let path = PathBuf::from(path);
+        let status = match open_backup(&path, password.as_str()) {
+            Ok(archive) => {
+                let mut lines = archive.summary();
+                lines.push("Merge adds these next to your profiles, Replace swaps everything for them.".to_string());
+                app_restore.archive = Some(archive);
+                app_restore.path = Some(path);
+                lines.join("\n")
+            },
+            Err(e) => {
+                error!("Failed to open backup {}: {}", path.display(), e);
+                app_restore.archive = None;
+                app_restore.path = None;
+                format!("Error: {}", e)
+            }
+        };
+        if let Ok(mut text) = query_status_text.get_single_mut() {
+            text.sections[0].value = status;
+        }
+    }
+}
+
+#[derive(Event)]
+pub struct EventRestoreAppBackup(pub RestoreMode);
+
+/// Restores the opened backup. A restored `config.toml` is picked up by
+/// `watch_config_file` like any other edit.
+pub fn handle_event_restore_app_backup(
+    mut event_reader: EventReader<EventRestoreAppBackup>,
+    mut app_restore: ResMut<AppRestoreResource>,
+    mut profiles_res: ResMut<ProfilesResource>,
+    mut send_res: ResMut<SendResource>,
+    mut query_status_text: Query<&mut Text, With<TextAppRestoreStatus>>,
+    mut next_state: ResMut<NextState<AppScreenState>>,
+) {
+    for ev in event_reader.read() {
+        let archive = if let Some(archive) = &app_restore.archive {
+            archive
+        } else {
+            if let Ok(mut text) = query_status_text.get_single_mut() {
+                text.sections[0].value = "Open a backup first".to_string();
+            }
+            continue;
+        };
+
+        match archive.restore(ev.0) {
+            Ok(report) => {
+                let path = app_restore.path.as_ref().map(|p| p.display().to_string()).unwrap_or_default();
+                info!("Restored backup {} ({:?}): {}", path, ev.0, report.join(", "));
+                profiles_res.profiles = WalletProfiles::load();
+                send_res.address_book = AddressBook::load();
+                app_restore.archive = None;
+                if profiles_res.profiles.has_wallets() {
+                    next_state.set(AppScreenState::Unlock);
+                } else if let Ok(mut text) = query_status_text.get_single_mut() {
+                    text.sections[0].value = report.join("\n");
+                }
+            },
+            Err(e) => {
+                error!("Failed to restore backup: {}", e);
+                if let Ok(mut text) = query_status_text.get_single_mut() {
+                    text.sections[0].value = format!("Restore failed: {}", e);
+                }
+            }
+        }
+    }
+}
+
+#[derive(Event)]
+pub struct EventCreateSupportBundle;
+
+/// Gathering system info and zipping the log runs on the IO pool.
+pub fn handle_event_create_support_bundle(
+    mut commands: Commands,
+    mut event_reader: EventReader<EventCreateSupportBundle>,
+    query_task_handler: Query<Entity, (With<EntityTaskHandler>, Without<TaskCreateSupportBundle>)>,
+    mut query_status_text: Query<&mut Text, With<TextSupportBundleStatus>>,
+) {
+    for _ev in event_reader.read() {
+        let status = if let Ok(task_handler_entity) = query_task_handler.get_single() {
+            let output = support_bundle_path();
+            let config = redacted_config();
+            let task = IoTaskPool::get().spawn(async move {
+                create_support_bundle(&output, config).map(|files| (files, output))
+            });
+            commands
+                .entity(task_handler_entity)
+                .insert(TaskCreateSupportBundle { task });
+            "Creating support bundle...".to_string()
+        } else {
+            "A support bundle is already being created".to_string()
+        };
+        if let Ok(mut text) = query_status_text.get_single_mut() {
+            text.sections[0].value = status;
+        }
+    }
+}
+
+#[derive(Event)]
+pub struct EventCrashRecovery {
+    pub resume: bool,
+}
+
+/// Resuming reuses `--start-mining`, a locked wallet goes to the mining
+/// screen once it's unlocked.
+pub fn handle_event_crash_recovery(
+    mut event_reader: EventReader<EventCrashRecovery>,
+    mut crash_recovery: ResMut<CrashRecoveryResource>,
+    mut launch: ResMut<LaunchResource>,
+    mut profiles_res: ResMut<ProfilesResource>,
+    app_wallet: Res<AppWallet>,
+    mut next_state: ResMut<NextState<AppScreenState>>,
+) {
+    for ev in event_reader.read() {
+        let pending = if let Some(pending) = crash_recovery.pending.take() {
+            pending
+        } else {
+            continue;
+        };
+        if !ev.resume || !pending.mining {
+            continue;
+        }
+
+        info!("Resuming mining after the crash at {}", pending.time);
+        launch.start_mining = true;
+        if app_wallet.pubkey().is_some() {
+            next_state.set(AppScreenState::Mining);
+        } else if let Some(profile) = &pending.profile {
+            // only for this run, like --profile
+            if let Err(e) = profiles_res.profiles.set_active(profile) {
+                error!("Not switching to the crashed profile: {}", e);
+            }
+        }
+    }
+}
+
+#[derive(Event)]
+pub struct EventFetchTxHistory {
+    pub reset: bool,
+}
+
+pub fn handle_event_fetch_tx_history(
+    mut commands: Commands,
+    mut event_reader: EventReader<EventFetchTxHistory>,
+    app_wallet: Res<AppWallet>,
+    ore_app_state: Res<OreAppState>,
+    mut tx_history: ResMut<TxHistoryResource>,
+    query_task_handler: Query<Entity, With<EntityTaskHandler>>,
+    query_history_list: Query<Entity, With<HistoryScreenTxList>>,
+    mut next_state: ResMut<NextState<AppScreenState>>,
+) {
+    for ev in event_reader.read() {
+        let pubkey = if let Some(pubkey) = app_wallet.pubkey() {
+            pubkey
+        } else {
+            next_state.set(AppScreenState::Unlock);
+            error!("wallet is None, switching to wallet unlock screen");
+            continue;
+        };
+        if tx_history.loading {
+            continue;
+        }
+        if ev.reset {
+            if let Ok(list_entity) = query_history_list.get_single() {
+                commands.entity(list_entity).despawn_descendants();
+            }
+            *tx_history = TxHistoryResource::default();
+        } else if tx_history.cursors.is_exhausted() {
+            continue;
+        }
+
+        if let Ok(task_handler_entity) = query_task_handler.get_single() {
+            let pool = IoTaskPool::get();
+            let rpc_url = ore_app_state.config.rpc_url.clone();
+            let cursors = tx_history.cursors;
+            let task = pool.spawn(Compat::new(async move {
+                let client = RpcClient::new(rpc_url);
+                fetch_tx_history_page(&client, pubkey, cursors, HISTORY_PAGE_SIZE).await
+            }));
+
+            tx_history.loading = true;
+            commands
+                .entity(task_handler_entity)
+                .insert(TaskFetchTxHistory { task });
+        } else {
+            error!("Failed to get task_handler_entity. handle_event_fetch_tx_history.");
+        }
+    }
+}
+
+#[derive(Event)]
+pub struct EventPrepareSend {
+    pub recipient: String,
+    pub amount: String,
+}
+
+pub fn handle_event_prepare_send(
+    mut commands: Commands,
+    mut event_reader: EventReader<EventPrepareSend>,
+    app_wallet: Res<AppWallet>,
+    ore_app_state: Res<OreAppState>,
+    mut send_res: ResMut<SendResource>,
+    query_task_handler: Query<Entity, With<EntityTaskHandler>>,
+    mut query_status_text: Query<&mut Text, With<TextSendStatus>>,
+    mut next_state: ResMut<NextState<AppScreenState>>,
+) {
+    for ev in event_reader.read() {
+        let wallet = if let Some(wallet) = &app_wallet.wallet {
+            wallet.clone()
+        } else {
+            next_state.set(AppScreenState::Unlock);
+            error!("wallet is None, switching to wallet unlock screen");
+            continue;
+        };
+        if send_res.preparing || send_res.pending.is_some() {
+            continue;
+        }
+
+        let token = send_res.token;
+        let status = match validate_send(
+            &wallet.pubkey(),
+            &ev.recipient,
+            &ev.amount,
+            token,
+            app_wallet.sol_balance,
+            app_wallet.ore_balance,
+        ) {
+            Ok((recipient, amount)) => {
+                if let Ok(task_handler_entity) = query_task_handler.get_single() {
+                    let pool = IoTaskPool::get();
+                    let rpc_url = ore_app_state.config.rpc_url.clone();
+                    let fee_config = ore_app_state.config.priority_fee.clone();
+                    let sol_balance = app_wallet.sol_balance;
+                    let task = pool.spawn(Compat::new(async move {
+                        let client = RpcClient::new(rpc_url);
+                        prepare_send(&client, &wallet, recipient, token, amount, sol_balance, &fee_config).await
+                    }));
+
+                    send_res.preparing = true;
+                    commands
+                        .entity(task_handler_entity)
+                        .insert(TaskPrepareSend { task });
+                    "Simulating transaction...".to_string()
+                } else {
+                    error!("Failed to get task_handler_entity. handle_event_prepare_send.");
+                    continue;
+                }
+            },
+            Err(e) => e,
+        };
+
+        if let Ok(mut text) = query_status_text.get_single_mut() {
+            text.sections[0].value = status;
+        }
+    }
+}
+
+#[derive(Event)]
+pub struct EventConfirmSend;
+
+pub fn handle_event_confirm_send(
+    mut commands: Commands,
+    mut event_reader: EventReader<EventConfirmSend>,
+    ore_app_state: Res<OreAppState>,
+    mut send_res: ResMut<SendResource>,
+    query_task_handler: Query<Entity, With<EntityTaskHandler>>,
+    query_modal: Query<Entity, With<SendConfirmModal>>,
+    mut query_amount_input: Query<&mut TextInput, With<TextSendAmount>>,
+    mut query_status_text: Query<&mut Text, With<TextSendStatus>>,
+) {
+    for _ev in event_reader.read() {
+        let preview = if let Some(preview) = send_res.pending.take() {
+            preview
+        } else {
+            continue;
+        };
+        for modal in query_modal.iter() {
+            commands.entity(modal).despawn_recursive();
+        }
+
+        if let Ok(task_handler_entity) = query_task_handler.get_single() {
+            let pool = IoTaskPool::get();
+            let rpc_url = ore_app_state.config.rpc_url.clone();
+            let task = pool.spawn(Compat::new(async move {
+                let client = RpcClient::new(rpc_url);
+                send_signed_tx(
+                    &client,
+                    TxType::Transfer,
+                    preview.tx,
+                    preview.priority_fee,
+                    preview.last_valid_block_height,
+                ).await
+            }));
+
+            commands
+                .entity(task_handler_entity)
+                .insert(TaskProcessTx { task });
+
+            if let Ok(mut text_input) = query_amount_input.get_single_mut() {
+                text_input.text = "".to_string();
+            }
+            if let Ok(mut text) = query_status_text.get_single_mut() {
+                text.sections[0].value = "Transfer sent".to_string();
+            }
+        } else {
+            error!("Failed to get task_handler_entity. handle_event_confirm_send.");
+        }
+    }
+}
+
+#[derive(Event)]
+pub struct EventCancelSend;
+
+pub fn handle_event_cancel_send(
+    mut commands: Commands,
+    mut event_reader: EventReader<EventCancelSend>,
+    mut send_res: ResMut<SendResource>,
+    query_modal: Query<Entity, With<SendConfirmModal>>,
+    mut query_status_text: Query<&mut Text, With<TextSendStatus>>,
+) {
+    for _ev in event_reader.read() {
+        send_res.pending = None;
+        for modal in query_modal.iter() {
+            commands.entity(modal).despawn_recursive();
+        }
+        if let Ok(mut text) = query_status_text.get_single_mut() {
+            text.sections[0].value = "Transfer cancelled".to_string();
+        }
+    }
+}
+
+#[derive(Event)]
+pub struct EventSaveRecipient {
+    pub name: String,
+    pub address: String,
+}
+
+pub fn handle_event_save_recipient(
+    mut commands: Commands,
+    mut event_reader: EventReader<EventSaveRecipient>,
+    asset_server: Res<AssetServer>,
+    mut send_res: ResMut<SendResource>,
+    query_address_book_list: Query<Entity, With<AddressBookList>>,
+    mut query_status_text: Query<&mut Text, With<TextSendStatus>>,
+) {
+    for ev in event_reader.read() {
+        let address = ev.address.trim().to_string();
+        let status = if Pubkey::from_str(&address).is_err() {
+            "Invalid recipient address".to_string()
+        } else {
+            let name = if ev.name.trim().is_empty() {
+                shorten_string(address.clone(), 10)
+            } else {
+                ev.name.trim().to_string()
+            };
+            send_res.address_book.upsert(name, address);
+            if let Ok(list_entity) = query_address_book_list.get_single() {
+                respawn_address_book_list(&mut commands, &asset_server, list_entity, &send_res);
+            }
+            match send_res.address_book.save() {
+                Ok(()) => "Recipient saved".to_string(),
+                Err(e) => {
+                    error!("Failed to save address book: {}", e);
+                    format!("Failed to save address book: {}", e)
+                }
+            }
+        };
+
+        if let Ok(mut text) = query_status_text.get_single_mut() {
+            text.sections[0].value = status;
+        }
+    }
+}
+
+#[derive(Event)]
+pub struct EventRemoveRecipient(pub String);
+
+pub fn handle_event_remove_recipient(
+    mut commands: Commands,
+    mut event_reader: EventReader<EventRemoveRecipient>,
+    asset_server: Res<AssetServer>,
+    mut send_res: ResMut<SendResource>,
+    query_address_book_list: Query<Entity, With<AddressBookList>>,
+) {
+    for ev in event_reader.read() {
+        send_res.address_book.remove(&ev.0);
+        if let Ok(list_entity) = query_address_book_list.get_single() {
+            respawn_address_book_list(&mut commands, &asset_server, list_entity, &send_res);
+        }
+        if let Err(e) = send_res.address_book.save() {
+            error!("Failed to save address book: {}", e);
+        }
+    }
+}
+
+fn respawn_address_book_list(
+    commands: &mut Commands,
+    asset_server: &AssetServer,
+    list_entity: Entity,
+    send_res: &SendResource,
+) {
+    commands.entity(list_entity).despawn_descendants();
+    commands.entity(list_entity).with_children(|parent| {
+        for entry in send_res.address_book.entries.iter() {
+            spawn_address_book_entry(parent, asset_server, entry);
+        }
+    });
+}
+
 #[derive(Event)]
 pub struct EventCancelMining;
 
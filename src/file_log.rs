@@ -0,0 +1,397 @@
+use std::{
+    collections::{BTreeMap, VecDeque},
+    fmt,
+    fs::{self, File, OpenOptions},
+    io::Write,
+    panic,
+    path::{Path, PathBuf},
+    str::FromStr,
+    sync::{Mutex, OnceLock},
+    time::{Duration, SystemTime},
+};
+
+use bevy::{
+    log::{
+        error,
+        tracing_subscriber::{layer::{Context, SubscriberExt}, Layer},
+        BoxedSubscriber,
+    },
+    utils::tracing::{
+        field::{Field, Visit},
+        Event, Level, Subscriber,
+    },
+};
+use chrono::{SecondsFormat, Utc};
+use serde::{Deserialize, Serialize};
+
+pub const LOGS_DIR: &str = "logs";
+pub const LOG_FILE_NAME: &str = "ore-hq-desktop.log";
+/// Records kept in memory for the log viewer.
+pub const RECENT_RECORDS: usize = 2000;
+
+/// Bevy's own default, keeps the render backends quiet.
+const BASE_FILTER: &str = "wgpu=error,naga=warn";
+
+static FILE_LOG: OnceLock<FileLog> = OnceLock::new();
+
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq, Default)]
+#[serde(rename_all = "lowercase")]
+pub enum LogFormat {
+    #[default]
+    Logfmt,
+    Json,
+}
+
+/// `[log]` in `config.toml`. Read at startup.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+#[serde(default)]
+pub struct LogConfig {
+    pub format: LogFormat,
+    /// Level per module path, e.g. `"ore_hq_desktop::tx_engine" = "debug"`.
+    pub modules: BTreeMap<String, String>,
+    /// The log file is rotated once it reaches this size, 0 disables it.
+    pub max_file_mb: u64,
+    /// The log file is rotated once it's this old, and rotated files older
+    /// than this are deleted. 0 disables both.
+    pub max_age_days: u64,
+    /// Rotated files kept next to the current one.
+    pub max_files: u32,
+}
+
+impl Default for LogConfig {
+    fn default() -> Self {
+        Self {
+            format: LogFormat::Logfmt,
+            modules: BTreeMap::new(),
+            max_file_mb: 10,
+            max_age_days: 7,
+            max_files: 5,
+        }
+    }
+}
+
+impl LogConfig {
+    /// `LogPlugin` filter with the per-module levels, skipping invalid ones.
+    pub fn filter(&self) -> String {
+        let mut filter = BASE_FILTER.to_string();
+        for (module, level) in &self.modules {
+            if valid_module_level(module, level) {
+                filter.push_str(&format!(",{}={}", module, level.to_lowercase()));
+            }
+        }
+        filter
+    }
+
+    /// Module levels `filter` skips, as `module = level`.
+    pub fn invalid_module_levels(&self) -> Vec<String> {
+        self.modules
+            .iter()
+            .filter(|(module, level)| !valid_module_level(module, level))
+            .map(|(module, level)| format!("{} = {}", module, level))
+            .collect()
+    }
+}
+
+fn valid_module_level(module: &str, level: &str) -> bool {
+    !module.is_empty() && !module.contains([',', '=', ' ']) && Level::from_str(level).is_ok()
+}
+
+/// One log line, as written to the file and shown in the log viewer.
+#[derive(Serialize, Clone, Debug)]
+pub struct LogRecord {
+    pub timestamp: String,
+    pub level: String,
+    pub target: String,
+    pub message: String,
+    #[serde(skip_serializing_if = "BTreeMap::is_empty")]
+    pub fields: BTreeMap<String, String>,
+}
+
+impl LogRecord {
+    pub fn level(&self) -> Level {
+        Level::from_str(&self.level).unwrap_or(Level::INFO)
+    }
+
+    fn to_line(&self, format: LogFormat) -> String {
+        match format {
+            LogFormat::Json => serde_json::to_string(self).unwrap_or_default(),
+            LogFormat::Logfmt => {
+                let mut line = format!(
+                    "time={} level={} target={} msg={}",
+                    self.timestamp,
+                    self.level,
+                    logfmt_value(&self.target),
+                    logfmt_value(&self.message),
+                );
+                for (key, value) in &self.fields {
+                    line.push_str(&format!(" {}={}", key, logfmt_value(value)));
+                }
+                line
+            },
+        }
+    }
+}
+
+fn logfmt_value(value: &str) -> String {
+    if !value.is_empty() && !value.contains([' ', '"', '=']) {
+        return value.to_string();
+    }
+    format!("\"{}\"", value.replace('\\', "\\\\").replace('"', "\\\"").replace('\n', "\\n"))
+}
+
+/// The file sink and the records kept for the log viewer.
+struct FileLog {
+    writer: Mutex<LogWriter>,
+    recent: Mutex<RecentRecords>,
+}
+
+#[derive(Default)]
+struct RecentRecords {
+    records: VecDeque<LogRecord>,
+    /// Records seen since startup, including ones dropped from `records`.
+    total: u64,
+}
+
+struct LogWriter {
+    config: LogConfig,
+    path: PathBuf,
+    file: Option<File>,
+    size: u64,
+    opened_at: SystemTime,
+}
+
+impl LogWriter {
+    fn open(config: LogConfig, dir: &Path) -> Result<Self, String> {
+        fs::create_dir_all(dir)
+            .map_err(|e| format!("Failed to create {}: {}", dir.display(), e))?;
+        let mut writer = Self {
+            config,
+            path: dir.join(LOG_FILE_NAME),
+            file: None,
+            size: 0,
+            opened_at: SystemTime::now(),
+        };
+        writer.open_file()?;
+        if writer.due_for_rotation(0) {
+            writer.rotate()?;
+        }
+        writer.prune();
+        Ok(writer)
+    }
+
+    fn open_file(&mut self) -> Result<(), String> {
+        let file = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&self.path)
+            .map_err(|e| format!("Failed to open {}: {}", self.path.display(), e))?;
+        let metadata = file.metadata().ok();
+        self.size = metadata.as_ref().map_or(0, |m| m.len());
+        // an existing file keeps its age across restarts
+        self.opened_at = metadata
+            .and_then(|m| m.created().ok())
+            .unwrap_or_else(SystemTime::now);
+        self.file = Some(file);
+        Ok(())
+    }
+
+    fn max_age(&self) -> Duration {
+        Duration::from_secs(self.config.max_age_days * 24 * 60 * 60)
+    }
+
+    fn due_for_rotation(&self, next_line_len: u64) -> bool {
+        let too_big = self.config.max_file_mb > 0
+            && self.size > 0
+            && self.size + next_line_len > self.config.max_file_mb * 1024 * 1024;
+        let too_old = self.config.max_age_days > 0
+            && self.size > 0
+            && self.opened_at.elapsed().is_ok_and(|age| age >= self.max_age());
+        too_big || too_old
+    }
+
+    fn rotated_path(&self, n: u32) -> PathBuf {
+        self.path.with_file_name(format!("{}.{}", LOG_FILE_NAME, n))
+    }
+
+    /// `ore-hq-desktop.log` becomes `.1`, `.1` becomes `.2` and so on.
+    fn rotate(&mut self) -> Result<(), String> {
+        self.file = None;
+        let _ = fs::remove_file(self.rotated_path(self.config.max_files.max(1)));
+        for n in (1..self.config.max_files.max(1)).rev() {
+            let from = self.rotated_path(n);
+            if from.exists() {
+                let _ = fs::rename(&from, self.rotated_path(n + 1));
+            }
+        }
+        if self.config.max_files == 0 {
+            let _ = fs::remove_file(&self.path);
+        } else {
+            fs::rename(&self.path, self.rotated_path(1))
+                .map_err(|e| format!("Failed to rotate {}: {}", self.path.display(), e))?;
+        }
+        self.open_file()?;
+        // created() isn't reset by every filesystem
+        self.opened_at = SystemTime::now();
+        self.prune();
+        Ok(())
+    }
+
+    /// Deletes rotated files older than `max_age_days`.
+    fn prune(&self) {
+        if self.config.max_age_days == 0 {
+            return;
+        }
+        for n in 1..=self.config.max_files {
+            let path = self.rotated_path(n);
+            let expired = fs::metadata(&path)
+                .and_then(|m| m.modified())
+                .ok()
+                .and_then(|modified| modified.elapsed().ok())
+                .is_some_and(|age| age >= self.max_age());
+            if expired {
+                let _ = fs::remove_file(path);
+            }
+        }
+    }
+
+    /// Writes straight to the file, unbuffered, so a crash loses nothing.
+    fn write_line(&mut self, line: &str) {
+        let len = line.len() as u64 + 1;
+        if self.due_for_rotation(len) {
+            if let Err(e) = self.rotate() {
+                eprintln!("{}", e);
+            }
+        }
+        if let Some(file) = &mut self.file {
+            if writeln!(file, "{}", line).is_ok() {
+                self.size += len;
+            }
+        }
+    }
+}
+
+/// Opens the log file in `dir`. Call before the `LogPlugin` is built,
+/// records logged before this only go to the console.
+pub fn init(config: &LogConfig, dir: &Path) -> Result<(), String> {
+    let writer = LogWriter::open(config.clone(), dir)?;
+    let _ = FILE_LOG.set(FileLog {
+        writer: Mutex::new(writer),
+        recent: Mutex::new(RecentRecords::default()),
+    });
+    Ok(())
+}
+
+/// Logs panics before the default hook prints them, so the log file
+/// shows what happened after a crash.
+pub fn log_panics() {
+    let default_hook = panic::take_hook();
+    panic::set_hook(Box::new(move |info| {
+        error!(target: "panic", "{}", info);
+        default_hook(info);
+    }));
+}
+
+/// For `LogPlugin::update_subscriber`.
+pub fn add_file_layer(subscriber: BoxedSubscriber) -> BoxedSubscriber {
+    Box::new(subscriber.with(FileLogLayer))
+}
+
+/// The in-memory records, oldest first, and how many were logged in total.
+pub fn recent_records() -> (Vec<LogRecord>, u64) {
+    match FILE_LOG.get().and_then(|log| log.recent.lock().ok()) {
+        Some(recent) => (recent.records.iter().cloned().collect(), recent.total),
+        None => (Vec::new(), 0),
+    }
+}
+
+/// Records logged so far, cheap enough to poll every frame.
+pub fn records_logged() -> u64 {
+    FILE_LOG
+        .get()
+        .and_then(|log| log.recent.lock().ok())
+        .map_or(0, |recent| recent.total)
+}
+
+/// The last `count` records as log file lines. Uses `try_lock`, it's called
+/// from the panic hook and the panic may have happened mid-write.
+pub fn last_lines(count: usize) -> Vec<String> {
+    let log = match FILE_LOG.get() {
+        Some(log) => log,
+        None => return Vec::new(),
+    };
+    let format = log.writer.try_lock().map_or(LogFormat::Logfmt, |writer| writer.config.format);
+    match log.recent.try_lock() {
+        Ok(recent) => {
+            let skip = recent.records.len().saturating_sub(count);
+            recent.records.iter().skip(skip).map(|record| record.to_line(format)).collect()
+        },
+        Err(_) => Vec::new(),
+    }
+}
+
+/// Path of the current log file.
+pub fn log_file_path() -> Option<PathBuf> {
+    FILE_LOG
+        .get()
+        .and_then(|log| log.writer.lock().ok())
+        .map(|writer| writer.path.clone())
+}
+
+struct FileLogLayer;
+
+impl<S: Subscriber> Layer<S> for FileLogLayer {
+    fn on_event(&self, event: &Event<'_>, _ctx: Context<'_, S>) {
+        let file_log = if let Some(file_log) = FILE_LOG.get() {
+            file_log
+        } else {
+            return;
+        };
+
+        let mut visitor = RecordVisitor::default();
+        event.record(&mut visitor);
+        let metadata = event.metadata();
+        let record = LogRecord {
+            timestamp: Utc::now().to_rfc3339_opts(SecondsFormat::Millis, true),
+            level: metadata.level().to_string(),
+            target: metadata.target().to_string(),
+            message: visitor.message,
+            fields: visitor.fields,
+        };
+
+        if let Ok(mut writer) = file_log.writer.lock() {
+            let format = writer.config.format;
+            writer.write_line(&record.to_line(format));
+        }
+        if let Ok(mut recent) = file_log.recent.lock() {
+            if recent.records.len() >= RECENT_RECORDS {
+                recent.records.pop_front();
+            }
+            recent.records.push_back(record);
+            recent.total += 1;
+        }
+    }
+}
+
+#[derive(Default)]
+struct RecordVisitor {
+    message: String,
+    fields: BTreeMap<String, String>,
+}
+
+impl Visit for RecordVisitor {
+    fn record_str(&mut self, field: &Field, value: &str) {
+        if field.name() == "message" {
+            self.message = value.to_string();
+        } else {
+            self.fields.insert(field.name().to_string(), value.to_string());
+        }
+    }
+
+    fn record_debug(&mut self, field: &Field, value: &dyn fmt::Debug) {
+        if field.name() == "message" {
+            self.message = format!("{:?}", value);
+        } else {
+            self.fields.insert(field.name().to_string(), format!("{:?}", value));
+        }
+    }
+}
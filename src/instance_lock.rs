@@ -0,0 +1,262 @@
+use std::{
+    fmt,
+    fs,
+    io::{BufRead, BufReader, ErrorKind, Write},
+    net::{Ipv4Addr, TcpListener, TcpStream},
+    path::{Path, PathBuf},
+    process,
+    thread,
+    time::Duration,
+};
+
+use bevy::log::{error, info};
+use crossbeam_channel::{unbounded, Receiver};
+use serde::{Deserialize, Serialize};
+use sysinfo::{Pid, System};
+
+use crate::utils::get_unix_timestamp;
+
+pub const DATA_DIR_LOCK_PATH: &str = "ore-hq-desktop.lock";
+/// Wallet locks live outside any data directory, so two portable installs
+/// with a copy of the same wallet still see each other.
+pub const WALLET_LOCKS_DIR: &str = "ore-hq-desktop-wallets";
+const FOCUS_MESSAGE: &str = "focus";
+const FOCUS_TIMEOUT: Duration = Duration::from_secs(2);
+/// Start times are whole seconds and may be read through different clocks.
+const START_TIME_TOLERANCE_SECONDS: u64 = 2;
+
+/// Contents of a lock file.
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct LockInfo {
+    pub pid: u32,
+    /// When the process started, unix timestamp in seconds. Tells a live
+    /// holder from another process that was given the same pid later.
+    pub started_at: u64,
+    /// Port the instance listens on for focus requests, data dir locks only.
+    #[serde(default)]
+    pub focus_port: Option<u16>,
+}
+
+#[derive(Debug)]
+pub enum LockError {
+    /// Another running process holds it.
+    Held(LockInfo),
+    Io(String),
+}
+
+impl fmt::Display for LockError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            LockError::Held(info) => write!(f, "in use by another instance (pid {})", info.pid),
+            LockError::Io(e) => write!(f, "{}", e),
+        }
+    }
+}
+
+/// A held lock file, removed again when dropped.
+#[derive(Debug)]
+pub struct InstanceLock {
+    path: PathBuf,
+}
+
+impl InstanceLock {
+    /// Creates `path` for this process. A lock left by a process that is no
+    /// longer running, or whose pid now belongs to a process started at
+    /// another time, is stale and taken over.
+    pub fn acquire(path: &Path, focus_port: Option<u16>) -> Result<Self, LockError> {
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir)
+                .map_err(|e| LockError::Io(format!("Failed to create {}: {}", dir.display(), e)))?;
+        }
+        let pid = process::id();
+        let info = LockInfo {
+            pid,
+            started_at: process_start_time(pid).unwrap_or_else(get_unix_timestamp),
+            focus_port,
+        };
+        let contents = toml::to_string(&info).map_err(|e| LockError::Io(e.to_string()))?;
+
+        // written in full under a name of its own and then linked into place,
+        // so another instance never reads a lock that has no contents yet
+        let tmp_path = tmp_lock_path(path, pid);
+        fs::write(&tmp_path, contents)
+            .map_err(|e| LockError::Io(format!("Failed to write {}: {}", tmp_path.display(), e)))?;
+        let lock = Self::link(path, &tmp_path, &info);
+        let _ = fs::remove_file(&tmp_path);
+        lock
+    }
+
+    /// Links the written `tmp_path` to `path`, which fails if `path` exists.
+    fn link(path: &Path, tmp_path: &Path, info: &LockInfo) -> Result<Self, LockError> {
+        // one retry, after removing a stale lock
+        for _ in 0..2 {
+            match fs::hard_link(tmp_path, path) {
+                Ok(()) => return Ok(Self { path: path.to_path_buf() }),
+                Err(e) if e.kind() == ErrorKind::AlreadyExists => {
+                    match read_lock(path) {
+                        Some(held) if held.pid != info.pid && holder_running(&held) => {
+                            return Err(LockError::Held(held));
+                        },
+                        Some(held) => info!("Removing stale lock {} (pid {})", path.display(), held.pid),
+                        None => info!("Removing unreadable lock {}", path.display()),
+                    }
+                    let _ = fs::remove_file(path);
+                },
+                Err(e) => {
+                    return Err(LockError::Io(format!("Failed to create {}: {}", path.display(), e)));
+                },
+            }
+        }
+        Err(LockError::Io(format!("Failed to take over {}", path.display())))
+    }
+}
+
+impl Drop for InstanceLock {
+    fn drop(&mut self) {
+        // only remove it if it's still ours
+        if read_lock(&self.path).is_some_and(|info| info.pid == process::id()) {
+            let _ = fs::remove_file(&self.path);
+        }
+    }
+}
+
+fn tmp_lock_path(path: &Path, pid: u32) -> PathBuf {
+    let mut file_name = path.file_name().unwrap_or_default().to_os_string();
+    file_name.push(format!(".{}.tmp", pid));
+    path.with_file_name(file_name)
+}
+
+fn read_lock(path: &Path) -> Option<LockInfo> {
+    fs::read_to_string(path).ok().and_then(|s| toml::from_str(&s).ok())
+}
+
+/// Unix timestamp in seconds, `None` when no such process is running.
+fn process_start_time(pid: u32) -> Option<u64> {
+    let pid = Pid::from_u32(pid);
+    let mut system = System::new();
+    if !system.refresh_process(pid) {
+        return None;
+    }
+    system.process(pid).map(|process| process.start_time())
+}
+
+/// Whether the process that wrote `held` is still the one running under its pid.
+fn holder_running(held: &LockInfo) -> bool {
+    process_start_time(held.pid)
+        .is_some_and(|start_time| start_time.abs_diff(held.started_at) <= START_TIME_TOLERANCE_SECONDS)
+}
+
+/// `<temp dir>/ore-hq-desktop-wallets/<pubkey>.lock`.
+pub fn wallet_lock_path(pubkey: &str) -> PathBuf {
+    std::env::temp_dir().join(WALLET_LOCKS_DIR).join(format!("{}.lock", pubkey))
+}
+
+/// Listens on localhost for a second launch asking this window to come to
+/// the front. Returns the port and a receiver that gets a message per request.
+pub fn start_focus_listener() -> Result<(u16, Receiver<()>), String> {
+    let listener = TcpListener::bind((Ipv4Addr::LOCALHOST, 0))
+        .map_err(|e| format!("Failed to open the focus socket: {}", e))?;
+    let port = listener.local_addr().map_err(|e| e.to_string())?.port();
+    let (sender, receiver) = unbounded();
+    thread::spawn(move || {
+        for stream in listener.incoming() {
+            let stream = match stream {
+                Ok(stream) => stream,
+                Err(e) => {
+                    error!("Focus socket: {}", e);
+                    continue;
+                },
+            };
+            let _ = stream.set_read_timeout(Some(FOCUS_TIMEOUT));
+            let mut line = String::new();
+            if BufReader::new(stream).read_line(&mut line).is_ok() && line.trim() == FOCUS_MESSAGE
+                && sender.send(()).is_err() {
+                    return;
+                }
+        }
+    });
+    Ok((port, receiver))
+}
+
+/// Asks the instance holding `info` to bring its window to the front.
+pub fn request_focus(info: &LockInfo) -> Result<(), String> {
+    let port = info.focus_port.ok_or("The running instance has no focus socket")?;
+    let mut stream = TcpStream::connect_timeout(&(Ipv4Addr::LOCALHOST, port).into(), FOCUS_TIMEOUT)
+        .map_err(|e| format!("Failed to reach the running instance: {}", e))?;
+    writeln!(stream, "{}", FOCUS_MESSAGE).map_err(|e| e.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn own_lock_info() -> LockInfo {
+        let pid = process::id();
+        LockInfo {
+            pid,
+            started_at: process_start_time(pid).unwrap(),
+            focus_port: None,
+        }
+    }
+
+    #[test]
+    fn live_holder_is_running() {
+        assert!(holder_running(&own_lock_info()));
+    }
+
+    #[test]
+    fn exited_holder_is_not_running() {
+        let mut child = process::Command::new(std::env::current_exe().unwrap())
+            .arg("--list")
+            .stdout(process::Stdio::null())
+            .spawn()
+            .unwrap();
+        let pid = child.id();
+        child.wait().unwrap();
+
+        let held = LockInfo { pid, started_at: get_unix_timestamp(), focus_port: None };
+        assert!(!holder_running(&held));
+    }
+
+    #[test]
+    fn reused_pid_is_not_the_holder() {
+        let mut held = own_lock_info();
+        held.started_at -= 60 * 60;
+        assert!(!holder_running(&held));
+
+        held.started_at += 60 * 60 + START_TIME_TOLERANCE_SECONDS;
+        assert!(holder_running(&held));
+    }
+
+    #[test]
+    fn lock_is_written_before_it_appears_and_released_on_drop() {
+        let path = std::env::temp_dir().join(format!("ore-hq-lock-test-{}.lock", process::id()));
+        let _ = fs::remove_file(&path);
+
+        let lock = InstanceLock::acquire(&path, Some(4242)).unwrap();
+        let held = read_lock(&path).unwrap();
+        let tmp_left = tmp_lock_path(&path, process::id()).exists();
+        drop(lock);
+
+        assert_eq!(held.pid, process::id());
+        assert_eq!(held.focus_port, Some(4242));
+        assert!(!tmp_left);
+        assert!(!path.exists());
+    }
+
+    #[test]
+    fn stale_and_empty_locks_are_taken_over() {
+        let path = std::env::temp_dir().join(format!("ore-hq-lock-test-{}-stale.lock", process::id()));
+        let mut stale = own_lock_info();
+        stale.started_at -= 60 * 60;
+        fs::write(&path, toml::to_string(&stale).unwrap()).unwrap();
+        let taken_over = InstanceLock::acquire(&path, None).map(drop);
+
+        fs::write(&path, "").unwrap();
+        let empty = InstanceLock::acquire(&path, None).map(drop);
+        let _ = fs::remove_file(&path);
+
+        assert!(taken_over.is_ok());
+        assert!(empty.is_ok());
+    }
+}
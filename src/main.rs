@@ -1,26 +1,52 @@
+// bevy systems take their data as parameters, long queries and argument lists are expected
+#![allow(clippy::type_complexity, clippy::too_many_arguments)]
+
 use std::{
-    fs, path::Path, sync::Arc, time::{Duration, Instant}
+    path::PathBuf, process, sync::Arc, time::{Duration, Instant, SystemTime}
 };
 
-use bevy::{prelude::*, utils::HashMap, winit::{UpdateMode, WinitSettings}};
-use bevy_inspector_egui::{inspector_options::ReflectInspectorOptions, quick::WorldInspectorPlugin, InspectorOptions};
+use bevy::{input::mouse::MouseWheel, log::{Level, LogPlugin}, prelude::*, utils::HashMap, window::PrimaryWindow, winit::{UpdateMode, WinitSettings}};
+use bevy_inspector_egui::{inspector_options::ReflectInspectorOptions, InspectorOptions};
 use copypasta::{ClipboardContext, ClipboardProvider};
+use zeroize::Zeroize;
 use crossbeam_channel::{Receiver, Sender};
+use amount::{Lamports, OreAmount};
 use events::*;
 use ore_api::state::{Bus, Proof,};
-use ore_utils::ORE_TOKEN_DECIMALS;
+use priority_fees::{PriorityFeeConfig, PriorityFeeEstimate};
 use serde::{Deserialize, Serialize};
 use solana_sdk::{
-    keccak::Hash as KeccakHash, pubkey::Pubkey, signature::{Keypair, Signature}, signer::Signer, transaction::Transaction
+    pubkey::Pubkey, signature::{Keypair, Signature}, signer::Signer, transaction::Transaction
 };
 use tasks::{
-    handle_task_got_sig_checks, handle_task_process_tx_result, handle_task_send_tx_result, handle_task_tx_sig_check_results, task_generate_hash, task_register_wallet, task_update_app_wallet_sol_balance
+    handle_task_fetch_mining_proofs_result, handle_task_fetch_tx_history_result, handle_task_export_mining_history_result, handle_task_create_app_backup_result, handle_task_create_support_bundle_result, handle_task_got_sig_checks, handle_task_prepare_send_result, handle_task_process_tx_result, handle_task_resign_tx_result, handle_task_send_tx_result, task_generate_hash, task_register_wallet, task_update_app_wallet_sol_balance
 };
+use tx_engine::{handle_event_check_sigs, tx_processor_rebroadcast, tx_processor_result_checks, tx_processors_sigs_check};
+use tx_history::TxHistoryCursors;
+use transfer::{SendPreview, SendToken};
+use address_book::{AddressBook, ADDRESS_BOOK_PATH};
+use network_stats::{mining_proofs_refresh, StakeDistribution};
+use config_watch::{config_modified_at, watch_config_file};
+use file_log::{add_file_layer, LogConfig, LOGS_DIR};
+use mining_history::ExportFormat;
+use app_backup::BackupArchive;
+use crash_report::PendingCrash;
+use instance_lock::{InstanceLock, LockError, DATA_DIR_LOCK_PATH};
+use wallet_profiles::{WalletProfiles, LEGACY_WALLET_PATH, PROFILES_PATH, WALLETS_DIR};
+use session_signer::SessionSigner;
+use backup_verification::BackupChallenge;
+use secret_string::SecretString;
+use wallet_file::BackupStatus;
+use unlock_throttle::{UnlockAttempts, UNLOCK_ATTEMPTS_PATH};
+use audit_log::AUDIT_LOG_PATH;
+use app_dirs::{app_dirs, data_path, default_config_dir, default_data_dir, migrate_legacy_files, AppDirs};
+use cli::LaunchOptions;
+use config::{config_file, load_config, validate_config, CONFIG_PATH, CONFIG_VERSION};
 use ui::{
-    components::{AppScreenParent, ButtonCaptureTextInput, DashboardProofUpdatesLogsList, DashboardScreenNode, MiningScreenNode, NavItemArrow, NavItemIcon, NavItemText, NavItemWhiteSelectedBar, SpinnerIcon, TextInput, TextPasswordInput}, nav_item_systems::nav_item_interactions, screens::{screen_base::spawn_base_screen, screen_dashboard::spawn_dashboard_screen, screen_locked::{despawn_locked_screen, spawn_locked_screen}, screen_mining::spawn_app_screen_mining, screen_settings_config::{despawn_settings_config_screen, spawn_settings_config_screen}, screen_settings_general::{despawn_settings_general_screen, spawn_settings_general_screen}, screen_settings_wallet::{despawn_settings_wallet_screen, spawn_settings_wallet_screen}, screen_setup_wallet::{despawn_wallet_create_screen, spawn_wallet_setup_screen}}, ui_button_systems::{
-        button_auto_scroll, button_capture_text, button_claim_ore_rewards, button_copy_text, button_generate_wallet, button_lock, button_open_web_tx_explorer, button_request_airdrop, button_save_config, button_save_wallet, button_stake_ore, button_start_stop_mining, button_unlock, tick_button_cooldowns
+    components::{AppScreenParent, ButtonCaptureTextInput, DashboardProofUpdatesLogsList, DashboardScreenNode, MiningScreenNode, NavItemArrow, NavItemIcon, NavItemText, NavItemWhiteSelectedBar, SpinnerIcon, TextAppRestorePathInput, TextInput, TextPasswordInput, TextRevealedMnemonic}, nav_item_systems::nav_item_interactions, screens::{screen_base::spawn_base_screen, screen_dashboard::spawn_dashboard_screen, screen_history::{despawn_history_screen, spawn_history_screen}, screen_logs::{despawn_logs_screen, spawn_logs_screen}, screen_send::{despawn_send_screen, spawn_send_screen}, screen_locked::{despawn_locked_screen, spawn_locked_screen}, screen_mining::spawn_app_screen_mining, screen_settings_config::{despawn_settings_config_screen, spawn_settings_config_screen}, screen_settings_general::{despawn_settings_general_screen, spawn_settings_general_screen}, screen_settings_wallet::{despawn_settings_wallet_screen, spawn_settings_wallet_screen}, screen_setup_wallet::{despawn_wallet_create_screen, spawn_wallet_setup_screen}}, ui_button_systems::{
+        button_accept_backup_risk, button_add_watch_only, button_auto_scroll, button_backup_word, button_capture_text, button_change_password, button_claim_ore_rewards, button_copy_text, button_crash_recovery, button_create_app_backup, button_create_support_bundle, button_generate_wallet, button_address_book_entry, button_address_book_remove, button_history_load_more, button_history_refresh, button_lock, button_log_level_filter, button_export_format, button_export_mining_history, button_open_app_backup, button_open_web_tx_explorer, button_request_airdrop, button_restore_account, button_restore_app_backup, button_reveal_secret, button_restore_mnemonic, button_save_config, button_save_wallet, button_send_cancel, button_send_confirm, button_send_review, button_send_save_recipient, button_send_toggle_token, button_stake_ore, button_start_stop_mining, button_new_profile, button_select_profile, button_switch_profile, button_unlock, tick_button_cooldowns
     }, ui_sync_systems::{
-        fps_counter_showhide, fps_text_update_system, mouse_scroll, update_active_miners_ui, update_active_text_input_cursor_vis, update_app_wallet_ui, update_busses_ui, update_hash_rate_ui, update_miner_status_ui, update_proof_account_ui, update_text_input_ui, update_treasury_account_ui
+        fps_counter_showhide, fps_text_update_system, mouse_scroll, update_active_miners_ui, update_active_text_input_cursor_vis, update_app_wallet_ui, update_backup_verification_ui, update_hash_rate_ui, update_miner_status_ui, update_password_strength_ui, update_active_profile_ui, update_profile_picker_ui, update_proof_account_ui, update_text_input_ui, update_tx_processor_status_ui, update_unlock_status_ui, update_config_banner_ui, update_crash_recovery_ui, update_log_viewer_ui, update_watch_only_ui
     }
 };
 
@@ -28,75 +54,286 @@ use ui::{
 pub const FAST_DURATION: Duration = Duration::from_millis(30);
 pub const REGULAR_DURATION: Duration = Duration::from_millis(100);
 pub const SLOW_DURATION: Duration = Duration::from_millis(1000);
+/// How long a copied secret key stays on the clipboard.
+pub const SECRET_CLIPBOARD_SECONDS: u64 = 30;
+/// How long a revealed seed phrase stays on the Wallet settings screen.
+pub const MNEMONIC_REVEAL_SECONDS: u64 = 60;
 
+pub mod amount;
 pub mod events;
 pub mod ore_utils;
 pub mod tasks;
 pub mod ui;
 pub mod utils;
 pub mod ore_hq_api;
-
-#[derive(Serialize, Deserialize, Clone)]
+pub mod priority_fees;
+pub mod tx_engine;
+pub mod tx_history;
+pub mod transfer;
+pub mod address_book;
+pub mod network_stats;
+pub mod wallet_profiles;
+pub mod wallet_restore;
+pub mod wallet_file;
+pub mod password_strength;
+pub mod session_signer;
+pub mod audit_log;
+pub mod backup_verification;
+pub mod secret_string;
+pub mod unlock_throttle;
+pub mod config;
+pub mod app_dirs;
+pub mod cli;
+pub mod config_watch;
+pub mod file_log;
+pub mod mining_history;
+pub mod app_backup;
+pub mod crash_report;
+pub mod instance_lock;
+
+#[derive(Serialize, Deserialize, Clone, PartialEq)]
 pub struct AppConfig {
+    /// Schema version, see `config::CONFIG_VERSION`.
+    #[serde(default)]
+    pub version: u32,
+    #[serde(default = "default_server_url")]
     pub server_url: String,
+    /// Pools tried after `server_url`, in order.
+    #[serde(default)]
+    pub fallback_server_urls: Vec<String>,
+    #[serde(default = "default_rpc_url")]
+    pub rpc_url: String,
+    #[serde(default = "default_threads")]
+    pub threads: u64,
+    #[serde(default = "default_ui_fetch_interval")]
     pub ui_fetch_interval: u64,
+    #[serde(default)]
+    pub priority_fee: PriorityFeeConfig,
+    #[serde(default = "default_tx_send_interval")]
+    pub tx_send_interval: u64,
+    #[serde(default = "default_tx_resign_on_expiry")]
+    pub tx_resign_on_expiry: bool,
+    #[serde(default = "default_tx_max_resigns")]
+    pub tx_max_resigns: u32,
+    /// Minimum `password_strength` score accepted when changing the wallet password.
+    #[serde(default = "default_min_password_strength")]
+    pub min_password_strength: u8,
+    /// Minutes without input before the wallet locks, 0 disables auto-lock.
+    #[serde(default = "default_auto_lock_minutes")]
+    pub auto_lock_minutes: u64,
+    /// Failed unlock attempts after which a mining session left running
+    /// behind the lock is stopped and its signer dropped, 0 disables it.
+    #[serde(default)]
+    pub unlock_wipe_after_failures: u32,
+    /// Directory for wallets and other app data, unset keeps them in the
+    /// working directory.
+    #[serde(default)]
+    pub data_dir: Option<String>,
+    #[serde(default)]
+    pub log: LogConfig,
 }
 
 impl Default for AppConfig {
     fn default() -> Self {
         Self {
-            server_url: "ec1ipse.me".to_string(),
-            ui_fetch_interval: 1000,
+            version: CONFIG_VERSION,
+            server_url: default_server_url(),
+            fallback_server_urls: Vec::new(),
+            rpc_url: default_rpc_url(),
+            threads: default_threads(),
+            ui_fetch_interval: default_ui_fetch_interval(),
+            priority_fee: PriorityFeeConfig::default(),
+            tx_send_interval: default_tx_send_interval(),
+            tx_resign_on_expiry: default_tx_resign_on_expiry(),
+            tx_max_resigns: default_tx_max_resigns(),
+            min_password_strength: default_min_password_strength(),
+            auto_lock_minutes: default_auto_lock_minutes(),
+            unlock_wipe_after_failures: 0,
+            data_dir: None,
+            log: LogConfig::default(),
         }
     }
 }
 
+fn default_server_url() -> String {
+    "ec1ipse.me".to_string()
+}
+
+fn default_threads() -> u64 {
+    1
+}
+
+fn default_ui_fetch_interval() -> u64 {
+    1000
+}
+
+fn default_rpc_url() -> String {
+    "https://api.mainnet-beta.solana.com".to_string()
+}
+
+fn default_tx_send_interval() -> u64 {
+    2000
+}
+
+fn default_tx_resign_on_expiry() -> bool {
+    true
+}
+
+fn default_tx_max_resigns() -> u32 {
+    2
+}
+
+fn default_min_password_strength() -> u8 {
+    2
+}
+
+fn default_auto_lock_minutes() -> u64 {
+    15
+}
+
 #[derive(States, Debug, Clone, PartialEq, Eq, Hash, Copy)]
 pub enum AppScreenState {
     WalletSetup,
     Unlock,
     Dashboard,
     Mining,
+    History,
+    Send,
     SettingsConfig,
     SettingsWallet,
     SettingsGeneral,
+    Logs,
 }
 
 #[derive(PartialEq)]
 pub enum NavItemScreen {
     Dashboard,
     Mining,
+    History,
+    Send,
     SettingsConfig,
     SettingsWallet,
     SettingsGeneral,
+    Logs,
 }
 
 fn main() {
+    let launch = LaunchOptions::parse();
+
+    // older versions kept everything in the working directory, move those
+    // files into the config and data directories the first time
+    let dir_override = launch.data_dir.as_ref().map(|o| o.value.clone());
+    let config_dir = dir_override.clone().unwrap_or_else(default_config_dir);
+    let config_file = launch
+        .config
+        .as_ref()
+        .map(|o| o.value.clone())
+        .unwrap_or_else(|| config_dir.join(CONFIG_PATH));
+    match migrate_legacy_files(&[CONFIG_PATH], &config_dir) {
+        Ok(moved) => moved.iter().for_each(|line| println!("{}", line)),
+        Err(e) => eprintln!("Failed to migrate {}: {}", CONFIG_PATH, e),
+    }
+
     let mut starting_state = AppScreenState::SettingsConfig;
-    let config_path = Path::new("config.toml");
-    let config: AppConfig = if config_path.exists() {
-        let config_string = fs::read_to_string(config_path).unwrap();
-        let config = match toml::from_str(&config_string) {
-            Ok(d) => {
+    let mut config_error = None;
+    let config = match load_config(&config_file) {
+        Ok(Some(config)) => {
+            if validate_config(&config).is_empty() {
                 starting_state = AppScreenState::WalletSetup;
-                Some(d)
+            } else {
+                config_error = Some(format!("{} has invalid values, fix them below.", CONFIG_PATH));
             }
-            Err(_) => None,
-        };
-        config.unwrap_or(AppConfig::default())
-    } else {
-        AppConfig::default()
+            config
+        },
+        Ok(None) => AppConfig::default(),
+        Err(e) => {
+            // don't quietly fall back to defaults, the config screen shows
+            // the error and saving keeps the broken file as a backup
+            eprintln!("{}", e);
+            config_error = Some(format!("{}. Saving keeps the old file as {}.bak.", e, CONFIG_PATH));
+            AppConfig::default()
+        }
     };
 
-    if starting_state == AppScreenState::WalletSetup {
-        let wallet_path = Path::new("save.data");
-        if wallet_path.exists() {
-            starting_state = AppScreenState::Unlock;
+    let data_dir = dir_override
+        .or_else(|| config.data_dir.as_ref().map(PathBuf::from))
+        .unwrap_or_else(default_data_dir);
+    let data_files = [
+        PROFILES_PATH,
+        LEGACY_WALLET_PATH,
+        WALLETS_DIR,
+        ADDRESS_BOOK_PATH,
+        AUDIT_LOG_PATH,
+        UNLOCK_ATTEMPTS_PATH,
+    ];
+    match migrate_legacy_files(&data_files, &data_dir) {
+        Ok(moved) => moved.iter().for_each(|line| println!("{}", line)),
+        Err(e) => eprintln!("Failed to migrate app data: {}", e),
+    }
+    if let Err(e) = app_dirs::init(AppDirs { config_file, data_dir }) {
+        eprintln!("{}, using the working directory.", e);
+    }
+
+    // two instances on one data directory would mine the same wallets and
+    // submit duplicate work, a second launch brings the first one forward
+    let allow_multiple_instances = launch.allow_multiple_instances.value;
+    let mut focus_requests = None;
+    let mut _data_dir_lock = None;
+    if !allow_multiple_instances {
+        let focus_port = match instance_lock::start_focus_listener() {
+            Ok((port, receiver)) => {
+                focus_requests = Some(receiver);
+                Some(port)
+            },
+            Err(e) => {
+                eprintln!("{}", e);
+                None
+            },
+        };
+        match InstanceLock::acquire(&data_path(DATA_DIR_LOCK_PATH), focus_port) {
+            Ok(lock) => _data_dir_lock = Some(lock),
+            Err(LockError::Held(info)) => {
+                eprintln!(
+                    "Ore HQ Desktop is already running with this data directory (pid {}). Pass --allow-multiple-instances to start another.",
+                    info.pid
+                );
+                match instance_lock::request_focus(&info) {
+                    Ok(()) => process::exit(0),
+                    Err(e) => {
+                        eprintln!("{}", e);
+                        process::exit(1);
+                    },
+                }
+            },
+            Err(LockError::Io(e)) => eprintln!("{}, skipping the single instance check.", e),
         }
     }
+    if let Err(e) = file_log::init(&config.log, &data_path(LOGS_DIR)) {
+        eprintln!("{}, logging to the console only.", e);
+    }
+    file_log::log_panics();
+    crash_report::install_panic_hook();
+    let pending_crash = crash_report::take_pending_crash();
+    for invalid in config.log.invalid_module_levels() {
+        eprintln!("Ignoring invalid log level in {}: {}", CONFIG_PATH, invalid);
+    }
+
+    let mut profiles = WalletProfiles::load();
+    if let Some(profile) = &launch.profile {
+        // only for this run, profiles.toml keeps the profile picked last
+        if let Err(e) = profiles.set_active(&profile.value) {
+            eprintln!("{}", e);
+        }
+    }
+    let effective_config = launch.apply(&config);
+    crash_report::set_config(&effective_config);
+    if starting_state == AppScreenState::WalletSetup
+        && profiles.has_wallets() {
+            starting_state = AppScreenState::Unlock;
+        }
 
     // let tx_send_interval = config.tx_send_interval;
-    let threads = 1;
+    let threads = effective_config.threads;
     App::new()
         .insert_state(starting_state)
         .add_plugins(
@@ -111,6 +348,11 @@ fn main() {
                     }),
                     ..Default::default()
                 })
+                .set(LogPlugin {
+                    level: launch.log_level.value.level(),
+                    filter: config.log.filter(),
+                    update_subscriber: Some(add_file_layer),
+                })
         )
         // .add_plugins(WorldInspectorPlugin::new())
         //.add_plugins(FrameTimeDiagnosticsPlugin::default())
@@ -119,17 +361,37 @@ fn main() {
             unfocused_mode: bevy::winit::UpdateMode::ReactiveLowPower { wait: REGULAR_DURATION },
         })
         .insert_resource(OreAppState {
-            config: config.clone(),
+            config: effective_config,
             active_input_node: None,
         })
+        .insert_resource(ConfigStatusResource {
+            error: config_error,
+        })
+        .insert_resource(LogViewerResource::default())
+        .insert_resource(CrashRecoveryResource {
+            pending: pending_crash,
+        })
+        .insert_resource(MiningExportResource::default())
+        .insert_resource(InstanceLockResource {
+            wallet: None,
+            allow_multiple: allow_multiple_instances,
+            focus_requests,
+        })
+        .insert_resource(ConfigWatchResource {
+            modified: config_modified_at(),
+            started_with: config.clone(),
+            restart_required: Vec::new(),
+            reload_error: None,
+        })
         .insert_resource(MinerStatusResource {
             miner_threads: threads,
             ..Default::default()
         })
         .insert_resource(AppWallet {
             wallet: None,
-            sol_balance: 0.0,
-            ore_balance: 0.0,
+            watch_only: None,
+            sol_balance: Lamports::ZERO,
+            ore_balance: OreAmount::ZERO,
         })
         .insert_resource(BussesResource {
             busses: vec![],
@@ -143,11 +405,39 @@ fn main() {
             largest_difficulty_seen: 0,
             miners_last_epoch: 0,
             miners_this_epoch: 0,
+            top_staker: None,
+            total_stake: OreAmount::ZERO,
+            stake_distribution: StakeDistribution::default(),
+            last_full_reload: None,
         })
         .insert_resource(MiningDataChannelResource {
             receiver: None,
             sender: None,
         })
+        .init_resource::<TxHistoryResource>()
+        .init_resource::<MiningSessionResource>()
+        .init_resource::<SecretClipboardResource>()
+        .init_resource::<BackupVerificationResource>()
+        .init_resource::<AppRestoreResource>()
+        .insert_resource(UnlockThrottleResource {
+            attempts: UnlockAttempts::load(),
+            error: None,
+        })
+        .insert_resource(SendResource {
+            token: SendToken::Sol,
+            address_book: AddressBook::load(),
+            pending: None,
+            preparing: false,
+        })
+        .insert_resource(LaunchResource {
+            start_screen: launch.screen.as_ref().map(|o| o.value.state()),
+            start_mining: launch.start_mining.value,
+        })
+        .insert_resource(ProfilesResource {
+            profiles,
+            base_config: config.clone(),
+            launch,
+        })
         .init_resource::<ProofAccountResource>()
         .register_type::<ProofAccountResource>()
         .init_resource::<TreasuryAccountResource>()
@@ -155,6 +445,13 @@ fn main() {
         .add_event::<EventStartStopMining>()
         .add_event::<EventSubmitHashTx>()
         .add_event::<EventTxResult>()
+        .add_event::<EventPoolSubmissionResult>()
+        .add_event::<EventExportMiningHistory>()
+        .add_event::<EventCreateAppBackup>()
+        .add_event::<EventOpenAppBackup>()
+        .add_event::<EventRestoreAppBackup>()
+        .add_event::<EventCreateSupportBundle>()
+        .add_event::<EventCrashRecovery>()
         .add_event::<EventFetchUiDataFromRpc>()
         .add_event::<EventMineForHash>()
         .add_event::<EventRegisterWallet>()
@@ -164,12 +461,26 @@ fn main() {
         .add_event::<EventUnlock>()
         .add_event::<EventLock>()
         .add_event::<EventSaveConfig>()
+        .add_event::<EventConfigChanged>()
         .add_event::<EventGenerateWallet>()
         .add_event::<EventSaveWallet>()
+        .add_event::<EventRestoreMnemonic>()
+        .add_event::<EventAddWatchOnly>()
+        .add_event::<EventRevealSecret>()
+        .add_event::<EventBackupWordPicked>()
+        .add_event::<EventChangePassword>()
         .add_event::<EventLoadKeypairFile>()
         .add_event::<EventRequestAirdrop>()
         .add_event::<EventCheckSigs>()
         .add_event::<EventCancelMining>()
+        .add_event::<EventFetchTxHistory>()
+        .add_event::<EventPrepareSend>()
+        .add_event::<EventConfirmSend>()
+        .add_event::<EventCancelSend>()
+        .add_event::<EventSaveRecipient>()
+        .add_event::<EventRemoveRecipient>()
+        .add_event::<EventSelectProfile>()
+        .add_event::<EventSwitchProfile>()
         .add_systems(Startup, setup_base_screen)
         .add_systems(Update, fps_text_update_system)
         .add_systems(Update, fps_counter_showhide)
@@ -180,6 +491,15 @@ fn main() {
         .add_systems(Update, tick_button_cooldowns)
         .add_systems(Update, nav_item_interactions)
         .add_systems(Update, update_app_wallet_ui)
+        .add_systems(Update, update_active_profile_ui)
+        .add_systems(Update, update_watch_only_ui)
+        .add_systems(Update, auto_lock_on_inactivity)
+        .add_systems(Update, (handle_event_pool_submission_result, handle_task_export_mining_history_result, handle_task_create_app_backup_result, handle_task_create_support_bundle_result))
+        .add_systems(Update, (button_crash_recovery, handle_event_crash_recovery, update_crash_recovery_ui, track_crash_session))
+        .add_systems(Update, (watch_config_file, handle_event_config_changed, update_config_banner_ui).chain())
+        .add_systems(Update, start_mining_on_launch.run_if(is_mining_screen_with_some_wallet))
+        .add_systems(Update, (clear_secret_clipboard, hide_revealed_mnemonic))
+        .add_systems(Update, focus_window_on_request)
         .add_systems(Update, mouse_scroll)
         .add_systems(Update, dashboard_list_cleanup_system)
         .add_systems(Update, 
@@ -199,8 +519,29 @@ fn main() {
                     task_update_app_wallet_sol_balance,
                     task_generate_hash,
                     task_register_wallet,
+                    handle_task_process_tx_result,
+                ),
+                (
+                    tx_processor_rebroadcast,
+                    tx_processors_sigs_check,
+                    handle_event_check_sigs,
+                    handle_task_send_tx_result,
+                    handle_task_got_sig_checks,
+                    handle_task_resign_tx_result,
+                    tx_processor_result_checks,
+                    handle_event_tx_result,
+                    update_tx_processor_status_ui,
                 ),
-            ).run_if(run_if_has_some_wallet)
+                (
+                    mining_proofs_refresh,
+                    handle_task_fetch_mining_proofs_result,
+                ),
+                (
+                    button_switch_profile,
+                    handle_event_switch_profile,
+                    handle_event_lock,
+                ),
+            ).run_if(run_if_has_mining_session)
         )
         .add_systems(OnEnter(AppScreenState::SettingsConfig), setup_settings_config_screen)
         .add_systems(
@@ -209,7 +550,27 @@ fn main() {
                 despawn_settings_config_screen,
             )
         )
+        .add_systems(OnEnter(AppScreenState::Logs), setup_logs_screen)
+        .add_systems(OnExit(AppScreenState::Logs), despawn_logs_screen)
+        .add_systems(
+            Update,
+            (
+                button_log_level_filter,
+                button_create_support_bundle,
+                handle_event_create_support_bundle,
+                update_log_viewer_ui,
+            )
+                .run_if(in_state(AppScreenState::Logs)),
+        )
         .add_systems(OnEnter(AppScreenState::SettingsGeneral), setup_settings_general_screen)
+        .add_systems(
+            Update,
+            (
+                button_create_app_backup,
+                handle_event_create_app_backup,
+            )
+                .run_if(in_state(AppScreenState::SettingsGeneral)),
+        )
         .add_systems(
             OnExit(AppScreenState::SettingsGeneral),
             (
@@ -220,6 +581,8 @@ fn main() {
         .add_systems(
             OnExit(AppScreenState::SettingsWallet),
             (
+                clear_secret_inputs,
+                clear_revealed_mnemonic,
                 despawn_settings_wallet_screen,
             )
         )
@@ -227,16 +590,21 @@ fn main() {
         .add_systems(
             OnExit(AppScreenState::WalletSetup),
             (
+                clear_secret_inputs,
                 despawn_wallet_create_screen,
             )
         )
         // .add_systems(OnExit(GameState::WalletSetup), despawn_wallet_setup_screen)
         .add_systems(OnEnter(AppScreenState::Dashboard), setup_dashboard_screen)
         .add_systems(OnExit(AppScreenState::Dashboard), hide_dashboard_screen)
-        .add_systems(OnEnter(AppScreenState::Unlock), setup_locked_screen)
-        .add_systems(OnExit(AppScreenState::Unlock), despawn_locked_screen)
+        .add_systems(OnEnter(AppScreenState::Unlock), (clear_secret_clipboard_on_lock, setup_locked_screen))
+        .add_systems(OnExit(AppScreenState::Unlock), (clear_secret_inputs, despawn_locked_screen))
         .add_systems(OnEnter(AppScreenState::Mining), setup_mining_screen)
         .add_systems(OnExit(AppScreenState::Mining), hide_mining_screen)
+        .add_systems(OnEnter(AppScreenState::History), setup_history_screen)
+        .add_systems(OnExit(AppScreenState::History), despawn_history_screen)
+        .add_systems(OnEnter(AppScreenState::Send), setup_send_screen)
+        .add_systems(OnExit(AppScreenState::Send), despawn_send_screen)
         .add_systems(
             Update,
             (
@@ -251,22 +619,44 @@ fn main() {
                 (
                     button_generate_wallet,
                     button_save_wallet,
+                    button_restore_mnemonic,
+                    button_restore_account,
+                    button_add_watch_only,
+                    button_backup_word,
+                    button_accept_backup_risk,
+                    button_open_app_backup,
+                    button_restore_app_backup,
                 ),
                 (
                     handle_event_generate_wallet,
                     handle_event_save_wallet,
                     handle_event_load_keypair_file,
+                    handle_event_restore_mnemonic,
+                    handle_event_add_watch_only,
+                    handle_event_backup_word_picked,
+                    handle_event_open_app_backup,
+                    handle_event_restore_app_backup,
                 ),
                 (
                     text_password_input,
                     file_drop,
+                    update_backup_verification_ui,
                 ),
             )
                 .run_if(in_state(AppScreenState::WalletSetup)),
         )
         .add_systems(
             Update,
-            (button_unlock, handle_event_unlock, text_password_input)
+            (
+                button_unlock,
+                button_select_profile,
+                button_new_profile,
+                handle_event_unlock,
+                handle_event_select_profile,
+                text_password_input,
+                update_profile_picker_ui,
+                update_unlock_status_ui,
+            )
                 .run_if(in_state(AppScreenState::Unlock)),
         )
         .add_systems(
@@ -274,6 +664,53 @@ fn main() {
             (update_active_miners_ui)
                 .run_if(in_state(AppScreenState::Dashboard)),
         )
+        .add_systems(
+            Update,
+            (
+                button_change_password,
+                button_reveal_secret,
+                handle_event_change_password,
+                handle_event_reveal_secret,
+                update_password_strength_ui,
+            )
+                .run_if(in_state(AppScreenState::SettingsWallet)),
+        )
+        .add_systems(
+            Update,
+            (
+                button_history_refresh,
+                button_history_load_more,
+                button_export_format,
+                button_export_mining_history,
+                handle_event_fetch_tx_history,
+                handle_task_fetch_tx_history_result,
+                handle_event_export_mining_history,
+            )
+                .run_if(in_state(AppScreenState::History)),
+        )
+        .add_systems(
+            Update,
+            (
+                (
+                    button_send_toggle_token,
+                    button_send_review,
+                    button_send_save_recipient,
+                    button_send_confirm,
+                    button_send_cancel,
+                    button_address_book_entry,
+                    button_address_book_remove,
+                ),
+                (
+                    handle_event_prepare_send,
+                    handle_event_confirm_send,
+                    handle_event_cancel_send,
+                    handle_event_save_recipient,
+                    handle_event_remove_recipient,
+                    handle_task_prepare_send_result,
+                ),
+            )
+                .run_if(in_state(AppScreenState::Send)),
+        )
         .add_systems(
             Update,
             (
@@ -289,7 +726,7 @@ fn main() {
                 ),
                 (
                     handle_event_claim_ore_rewards,
-                    handle_event_lock,
+                    handle_event_stake_ore,
                     handle_event_request_airdrop,
                 ),
                 (
@@ -307,7 +744,7 @@ fn setup_base_screen(
     mut commands: Commands,
     asset_server: Res<AssetServer>,
     app_state: Res<OreAppState>,
-    mut event_writer: EventWriter<EventFetchUiDataFromRpc>,
+    _event_writer: EventWriter<EventFetchUiDataFromRpc>,
 ) {
     // Spawn Camera
     commands.spawn(Camera2dBundle::default());
@@ -317,17 +754,18 @@ fn setup_base_screen(
     commands.spawn((EntityTaskFetchUiData, Name::new("EntityFetchUiData")));
 
     // Setup the base screen
-    spawn_base_screen(commands.reborrow(), asset_server, "Locked".to_string(), 0.0, 0.0, app_state.config.clone());
+    spawn_base_screen(commands.reborrow(), asset_server, "Locked".to_string(), Lamports::ZERO, OreAmount::ZERO, app_state.config.clone());
 }
 
 fn setup_mining_screen(
     mut commands: Commands,
     asset_server: Res<AssetServer>,
-    app_state: Res<OreAppState>,
+    _app_state: Res<OreAppState>,
     app_wallet: Res<AppWallet>,
+    profiles_res: Res<ProfilesResource>,
     query: Query<Entity, With<AppScreenParent>>,
     mut query_mining_screen: Query<(Entity, &mut Visibility), (With<MiningScreenNode>, Without<AppScreenParent>)>,
-    mut event_writer: EventWriter<EventFetchUiDataFromRpc>,
+    _event_writer: EventWriter<EventFetchUiDataFromRpc>,
     mut next_state: ResMut<NextState<AppScreenState>>,
     mut set: ParamSet<(
         Query<(&mut Visibility, &NavItemWhiteSelectedBar), Without<MiningScreenNode>>,
@@ -341,14 +779,13 @@ fn setup_mining_screen(
         *visibility = Visibility::Visible;
     } else {
 
-        if let Some(wallet) = &app_wallet.wallet {
+        if app_wallet.pubkey().is_some() {
             let mut parent = commands.get_entity(base_screen_entity_id).unwrap();
             parent.with_children(|parent| {
                 spawn_app_screen_mining(parent, &asset_server);
             });
         } else {
-            let wallet_path = Path::new("save.data");
-            if wallet_path.exists() {
+            if profiles_res.profiles.has_wallets() {
                 next_state.set(AppScreenState::Unlock);
             } else {
                 next_state.set(AppScreenState::WalletSetup);
@@ -459,10 +896,172 @@ fn hide_dashboard_screen(
     }
 }
 
+fn setup_history_screen(
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+    query: Query<Entity, With<AppScreenParent>>,
+    mut event_writer: EventWriter<EventFetchTxHistory>,
+    mining_export: Res<MiningExportResource>,
+    mut set: ParamSet<(
+        Query<(&mut Visibility, &NavItemWhiteSelectedBar)>,
+        Query<(&mut BackgroundColor, &NavItemIcon)>,
+        Query<(&mut Text, &NavItemText)>,
+        Query<(&mut BackgroundColor, &NavItemArrow)>,
+    )>,
+) {
+    let base_screen_entity_id = query.get_single().unwrap();
+
+    let mut parent = commands.get_entity(base_screen_entity_id).unwrap();
+
+    parent.with_children(|parent| {
+        spawn_history_screen(parent, &asset_server, mining_export.format);
+    });
+
+    event_writer.send(EventFetchTxHistory { reset: true });
+
+    let this_nav_screen = NavItemScreen::History;
+    for (mut visibility, nav_item_screen) in set.p0().iter_mut() {
+        if nav_item_screen.0 == this_nav_screen {
+            *visibility = Visibility::Visible;
+        } else {
+            *visibility = Visibility::Hidden;
+        }
+    }
+    for (mut background_color, nav_item_screen) in set.p1().iter_mut() {
+        if nav_item_screen.0 == this_nav_screen {
+            *background_color = Color::WHITE.into();
+        } else {
+            *background_color = Color::GRAY.into();
+        }
+    }
+    for (mut text, nav_item_screen) in set.p2().iter_mut() {
+        if nav_item_screen.0 == this_nav_screen {
+            text.sections[0].style.color = Color::WHITE;
+        } else {
+            text.sections[0].style.color = Color::GRAY;
+        }
+    }
+    for (mut background_color, nav_item_screen) in set.p3().iter_mut() {
+        if nav_item_screen.0 == this_nav_screen {
+            *background_color = Color::WHITE.into();
+        } else {
+            *background_color = Color::GRAY.into();
+        }
+    }
+
+}
+
+fn setup_logs_screen(
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+    query: Query<Entity, With<AppScreenParent>>,
+    mut log_viewer: ResMut<LogViewerResource>,
+    mut set: ParamSet<(
+        Query<(&mut Visibility, &NavItemWhiteSelectedBar)>,
+        Query<(&mut BackgroundColor, &NavItemIcon)>,
+        Query<(&mut Text, &NavItemText)>,
+        Query<(&mut BackgroundColor, &NavItemArrow)>,
+    )>,
+) {
+    let base_screen_entity_id = query.get_single().unwrap();
+
+    let mut parent = commands.get_entity(base_screen_entity_id).unwrap();
+
+    parent.with_children(|parent| {
+        spawn_logs_screen(parent, &asset_server);
+    });
+
+    *log_viewer = LogViewerResource::default();
+
+    let this_nav_screen = NavItemScreen::Logs;
+    for (mut visibility, nav_item_screen) in set.p0().iter_mut() {
+        if nav_item_screen.0 == this_nav_screen {
+            *visibility = Visibility::Visible;
+        } else {
+            *visibility = Visibility::Hidden;
+        }
+    }
+    for (mut background_color, nav_item_screen) in set.p1().iter_mut() {
+        if nav_item_screen.0 == this_nav_screen {
+            *background_color = Color::WHITE.into();
+        } else {
+            *background_color = Color::GRAY.into();
+        }
+    }
+    for (mut text, nav_item_screen) in set.p2().iter_mut() {
+        if nav_item_screen.0 == this_nav_screen {
+            text.sections[0].style.color = Color::WHITE;
+        } else {
+            text.sections[0].style.color = Color::GRAY;
+        }
+    }
+    for (mut background_color, nav_item_screen) in set.p3().iter_mut() {
+        if nav_item_screen.0 == this_nav_screen {
+            *background_color = Color::WHITE.into();
+        } else {
+            *background_color = Color::GRAY.into();
+        }
+    }
+
+}
+
+fn setup_send_screen(
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+    send_res: Res<SendResource>,
+    query: Query<Entity, With<AppScreenParent>>,
+    mut set: ParamSet<(
+        Query<(&mut Visibility, &NavItemWhiteSelectedBar)>,
+        Query<(&mut BackgroundColor, &NavItemIcon)>,
+        Query<(&mut Text, &NavItemText)>,
+        Query<(&mut BackgroundColor, &NavItemArrow)>,
+    )>,
+) {
+    let base_screen_entity_id = query.get_single().unwrap();
+
+    let mut parent = commands.get_entity(base_screen_entity_id).unwrap();
+
+    parent.with_children(|parent| {
+        spawn_send_screen(parent, &asset_server, send_res.token, &send_res.address_book.entries);
+    });
+
+    let this_nav_screen = NavItemScreen::Send;
+    for (mut visibility, nav_item_screen) in set.p0().iter_mut() {
+        if nav_item_screen.0 == this_nav_screen {
+            *visibility = Visibility::Visible;
+        } else {
+            *visibility = Visibility::Hidden;
+        }
+    }
+    for (mut background_color, nav_item_screen) in set.p1().iter_mut() {
+        if nav_item_screen.0 == this_nav_screen {
+            *background_color = Color::WHITE.into();
+        } else {
+            *background_color = Color::GRAY.into();
+        }
+    }
+    for (mut text, nav_item_screen) in set.p2().iter_mut() {
+        if nav_item_screen.0 == this_nav_screen {
+            text.sections[0].style.color = Color::WHITE;
+        } else {
+            text.sections[0].style.color = Color::GRAY;
+        }
+    }
+    for (mut background_color, nav_item_screen) in set.p3().iter_mut() {
+        if nav_item_screen.0 == this_nav_screen {
+            *background_color = Color::WHITE.into();
+        } else {
+            *background_color = Color::GRAY.into();
+        }
+    }
+
+}
+
 fn setup_settings_config_screen(
     mut commands: Commands,
     asset_server: Res<AssetServer>,
-    app_state: Res<OreAppState>,
+    profiles_res: Res<ProfilesResource>,
+    config_status: Res<ConfigStatusResource>,
     query: Query<Entity, With<AppScreenParent>>,
     mut set: ParamSet<(
         Query<(&mut Visibility, &NavItemWhiteSelectedBar)>,
@@ -475,8 +1074,9 @@ fn setup_settings_config_screen(
 
     let mut parent = commands.get_entity(base_screen_entity_id).unwrap();
 
+    // edit config.toml itself, not the active profile's overrides
     parent.with_children(|parent| {
-        spawn_settings_config_screen(parent, asset_server, app_state.config.clone());
+        spawn_settings_config_screen(parent, asset_server, &profiles_res.base_config, config_status.error.clone());
     });
 
     // Update Nav Items Highlights
@@ -515,6 +1115,7 @@ fn setup_settings_config_screen(
 fn setup_settings_general_screen(
     mut commands: Commands,
     asset_server: Res<AssetServer>,
+    profiles_res: Res<ProfilesResource>,
     query: Query<Entity, With<AppScreenParent>>,
     mut set: ParamSet<(
         Query<(&mut Visibility, &NavItemWhiteSelectedBar)>,
@@ -527,8 +1128,14 @@ fn setup_settings_general_screen(
 
     let mut parent = commands.get_entity(base_screen_entity_id).unwrap();
 
+    let effective_config = profiles_res.launch.describe(
+        &profiles_res.effective_config(),
+        profiles_res.profiles.active_profile(),
+        &config_file(),
+        &app_dirs().data_dir,
+    );
     parent.with_children(|parent| {
-        spawn_settings_general_screen(parent, asset_server);
+        spawn_settings_general_screen(parent, asset_server, effective_config);
     });
 
     let this_nav_screen = NavItemScreen::SettingsGeneral;
@@ -566,20 +1173,29 @@ fn setup_settings_general_screen(
 fn setup_wallet_create_screen(
     mut commands: Commands,
     asset_server: Res<AssetServer>,
+    profiles_res: Res<ProfilesResource>,
+    mut backup: ResMut<BackupVerificationResource>,
+    mut app_restore: ResMut<AppRestoreResource>,
     query: Query<Entity, With<AppScreenParent>>,
 ) {
     let base_screen_entity_id = query.get_single().unwrap();
 
     let mut parent = commands.get_entity(base_screen_entity_id).unwrap();
 
+    // a phrase generated on an earlier visit is gone with its screen
+    *backup = BackupVerificationResource::default();
+    *app_restore = AppRestoreResource::default();
+    let profile_name = profiles_res.profiles.next_profile_name();
     parent.with_children(|parent| {
-        spawn_wallet_setup_screen(parent, asset_server);
+        spawn_wallet_setup_screen(parent, asset_server, profile_name);
     });
 }
 
 fn setup_settings_wallet_screen(
     mut commands: Commands,
     asset_server: Res<AssetServer>,
+    app_state: Res<OreAppState>,
+    profiles_res: Res<ProfilesResource>,
     query: Query<Entity, With<AppScreenParent>>,
     mut set: ParamSet<(
         Query<(&mut Visibility, &NavItemWhiteSelectedBar)>,
@@ -592,8 +1208,13 @@ fn setup_settings_wallet_screen(
 
     let mut parent = commands.get_entity(base_screen_entity_id).unwrap();
 
+    let profile_name = profiles_res
+        .profiles
+        .active_profile()
+        .map(|p| p.name.clone())
+        .unwrap_or_default();
     parent.with_children(|parent| {
-        spawn_settings_wallet_screen(parent, asset_server);
+        spawn_settings_wallet_screen(parent, asset_server, profile_name, app_state.config.min_password_strength);
     });
 
     let this_nav_screen = NavItemScreen::SettingsWallet;
@@ -631,8 +1252,9 @@ fn setup_settings_wallet_screen(
 fn setup_locked_screen(
     mut commands: Commands,
     asset_server: Res<AssetServer>,
-    app_state: Res<OreAppState>,
-    mut event_writer: EventWriter<EventFetchUiDataFromRpc>,
+    _app_state: Res<OreAppState>,
+    profiles_res: Res<ProfilesResource>,
+    _event_writer: EventWriter<EventFetchUiDataFromRpc>,
     query: Query<Entity, With<AppScreenParent>>,
 ) {
 
@@ -641,7 +1263,7 @@ fn setup_locked_screen(
     let mut parent = commands.get_entity(base_screen_entity_id).unwrap();
 
     parent.with_children(|parent| {
-        spawn_locked_screen(parent, asset_server);
+        spawn_locked_screen(parent, asset_server, &profiles_res.profiles);
     });
 }
 
@@ -649,20 +1271,22 @@ fn is_mining_screen_with_some_wallet(
     app_wallet: Res<AppWallet>,
     app_screen_state: Res<State<AppScreenState>>,
 ) -> bool {
-    *app_screen_state == AppScreenState::Mining && app_wallet.wallet.is_some()
+    *app_screen_state == AppScreenState::Mining && app_wallet.pubkey().is_some()
 }
 
-fn run_if_has_some_wallet(
+/// Mining keeps running while the UI is locked, as long as the session signer is alive.
+fn run_if_has_mining_session(
     app_wallet: Res<AppWallet>,
+    mining_session: Res<MiningSessionResource>,
 ) -> bool {
-    app_wallet.wallet.is_some()
+    app_wallet.pubkey().is_some() || mining_session.signer.is_some()
 }
 
 // Components
 #[derive(Component)]
 pub struct EntityTaskHandler;
 
-#[derive(Clone, PartialEq, Eq)]
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
 pub enum TxType {
     Mine,
     Register,
@@ -670,32 +1294,36 @@ pub enum TxType {
     CreateAta,
     Stake,
     Claim,
-    Airdrop
+    Airdrop,
+    Transfer,
 }
 
-impl ToString for TxType {
-    fn to_string(&self) -> String {
+impl std::fmt::Display for TxType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
             TxType::Mine => {
-                "Mine".to_string()
+                write!(f, "Mine")
             },
             TxType::Register => {
-                "Register".to_string()
+                write!(f, "Register")
             },
             TxType::ResetEpoch => {
-                "Reset".to_string()
+                write!(f, "Reset")
             },
             TxType::CreateAta =>  {
-                "Create Ata".to_string()
+                write!(f, "Create Ata")
             },
             TxType::Stake =>  {
-                "Stake".to_string()
+                write!(f, "Stake")
             },
             TxType::Claim => {
-                "Claim".to_string()
+                write!(f, "Claim")
             },
             TxType::Airdrop => {
-                "Airdrop".to_string()
+                write!(f, "Airdrop")
+            },
+            TxType::Transfer => {
+                write!(f, "Transfer")
             },
         }
     }
@@ -707,17 +1335,72 @@ pub struct HashStatus {
     pub hash_difficulty: u32,
 }
 
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum TxProcessorStatus {
+    Sending,
+    Processed,
+    Confirmed,
+    Finalized,
+    Failed,
+    Expired,
+}
+
+impl TxProcessorStatus {
+    pub fn is_landed(&self) -> bool {
+        matches!(
+            self,
+            TxProcessorStatus::Processed | TxProcessorStatus::Confirmed | TxProcessorStatus::Finalized
+        )
+    }
+
+    pub fn is_final(&self) -> bool {
+        !matches!(self, TxProcessorStatus::Sending | TxProcessorStatus::Processed)
+    }
+
+    /// Confirmed txs are still polled so a waiting Mine tx can reach Finalized.
+    pub fn needs_status_check(&self) -> bool {
+        matches!(
+            self,
+            TxProcessorStatus::Sending | TxProcessorStatus::Processed | TxProcessorStatus::Confirmed
+        )
+    }
+}
+
+impl std::fmt::Display for TxProcessorStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            TxProcessorStatus::Sending => write!(f, "SENDING"),
+            TxProcessorStatus::Processed => write!(f, "PROCESSED"),
+            TxProcessorStatus::Confirmed => write!(f, "CONFIRMED"),
+            TxProcessorStatus::Finalized => write!(f, "FINALIZED"),
+            TxProcessorStatus::Failed => write!(f, "FAILED"),
+            TxProcessorStatus::Expired => write!(f, "EXPIRED"),
+        }
+    }
+}
+
+/// Milliseconds from the first send until each commitment level was seen.
+#[derive(Clone, Copy, Default, Debug, PartialEq)]
+pub struct TxCommitmentTimes {
+    pub processed: Option<u64>,
+    pub confirmed: Option<u64>,
+    pub finalized: Option<u64>,
+}
+
 #[derive(Component)]
 pub struct TxProcessor {
     tx_type: TxType,
-    status: String,
+    status: TxProcessorStatus,
     error: String,
-    sol_balance: f64,
-    staked_balance: Option<u64>,
+    staked_balance: Option<OreAmount>,
     challenge: String,
     signed_tx: Option<Transaction>,
     signature: Option<Signature>,
     hash_status: Option<HashStatus>,
+    priority_fee: Option<PriorityFeeEstimate>,
+    last_valid_block_height: u64,
+    resign_count: u32,
+    commitment_times: TxCommitmentTimes,
     created_at: Instant,
     send_and_confirm_interval: Timer,
 }
@@ -729,15 +1412,31 @@ pub struct EntityTaskFetchUiData;
 #[derive(Resource)]
 pub struct AppWallet {
     wallet: Option<Arc<Keypair>>,
-    sol_balance: f64,
-    ore_balance: f64,
+    /// Pubkey of an unlocked watch-only profile, `wallet` stays `None` for those.
+    watch_only: Option<Pubkey>,
+    sol_balance: Lamports,
+    ore_balance: OreAmount,
+}
+
+impl AppWallet {
+    /// Pubkey of the unlocked wallet, including watch-only ones.
+    pub fn pubkey(&self) -> Option<Pubkey> {
+        self.wallet
+            .as_ref()
+            .map(|wallet| wallet.pubkey())
+            .or(self.watch_only)
+    }
+
+    pub fn is_watch_only(&self) -> bool {
+        self.wallet.is_none() && self.watch_only.is_some()
+    }
 }
 
 #[derive(Reflect, Resource, InspectorOptions)]
 #[reflect(Resource, InspectorOptions)]
 pub struct ProofAccountResource {
     challenge: String,
-    stake: u64,
+    stake: OreAmount,
     last_hash_at: i64,
     total_hashes: u64,
 }
@@ -758,6 +1457,184 @@ pub struct HashrateResource {
     hashrate: f64,
 }
 
+#[derive(Resource, Default)]
+pub struct TxHistoryResource {
+    cursors: TxHistoryCursors,
+    loading: bool,
+    items_loaded: usize,
+}
+
+#[derive(Resource)]
+pub struct SendResource {
+    token: SendToken,
+    address_book: AddressBook,
+    /// The reviewed transfer waiting on the confirm modal.
+    pending: Option<SendPreview>,
+    preparing: bool,
+}
+
+/// Holds the signer for the wallet that is mining. Unlike `AppWallet` it
+/// survives the UI locking.
+#[derive(Resource, Default)]
+pub struct MiningSessionResource {
+    signer: Option<SessionSigner>,
+}
+
+/// Backup check of a generated seed phrase, it gates saving the wallet.
+#[derive(Resource, Default)]
+pub struct BackupVerificationResource {
+    /// `None` unless the keypair waiting to be saved was generated here.
+    challenge: Option<BackupChallenge>,
+    accept_risk: bool,
+}
+
+impl BackupVerificationResource {
+    pub fn can_save(&self) -> bool {
+        match &self.challenge {
+            Some(challenge) => challenge.is_complete() || self.accept_risk,
+            None => true,
+        }
+    }
+
+    /// What gets recorded in the wallet header.
+    pub fn backup_status(&self) -> Option<BackupStatus> {
+        self.challenge.as_ref().map(|challenge| {
+            if challenge.is_complete() {
+                BackupStatus::Verified
+            } else {
+                BackupStatus::Skipped
+            }
+        })
+    }
+}
+
+/// A crash the last run left behind, until it's resumed or dismissed.
+#[derive(Resource)]
+pub struct CrashRecoveryResource {
+    pending: Option<PendingCrash>,
+}
+
+/// The lock on the unlocked wallet, so another instance can't mine it too.
+/// The data directory lock is held by `main` for the whole run.
+#[derive(Resource)]
+pub struct InstanceLockResource {
+    wallet: Option<InstanceLock>,
+    /// `--allow-multiple-instances`, no locks are taken.
+    allow_multiple: bool,
+    /// Focus requests from later launches.
+    focus_requests: Option<Receiver<()>>,
+}
+
+/// The backup opened on the setup screen, kept until it's restored or the
+/// screen is left.
+#[derive(Resource, Default)]
+pub struct AppRestoreResource {
+    archive: Option<BackupArchive>,
+    /// File the archive was read from.
+    path: Option<PathBuf>,
+}
+
+/// A secret key put on the clipboard, wiped once `clear_at` passes, and
+/// when to hide a revealed seed phrase.
+#[derive(Resource, Default)]
+pub struct SecretClipboardResource {
+    clear_at: Option<Instant>,
+    secret: Option<SecretString>,
+    mnemonic_hide_at: Option<Instant>,
+}
+
+impl SecretClipboardResource {
+    /// Empties the clipboard if it still holds the copied secret, then wipes
+    /// the copy kept to compare against.
+    fn clear_clipboard(&mut self) {
+        if let Some(secret) = &self.secret {
+            if let Ok(mut ctx) = ClipboardContext::new() {
+                // leave the clipboard alone if something else was copied since
+                let mut contents = ctx.get_contents().unwrap_or_default();
+                let still_secret = contents == secret.as_str();
+                contents.zeroize();
+                if still_secret {
+                    if let Err(e) = ctx.set_contents(String::new()) {
+                        error!("Failed to clear the clipboard: {}", e);
+                    }
+                }
+            } else {
+                error!("Failed to create clipboard context.");
+            }
+        }
+        self.clear_at = None;
+        // SecretString zeroizes its buffer when dropped
+        self.secret = None;
+    }
+}
+
+/// Persisted failed unlock attempts, and the last unlock error to show on
+/// the lock screen.
+#[derive(Resource)]
+pub struct UnlockThrottleResource {
+    attempts: UnlockAttempts,
+    error: Option<String>,
+}
+
+impl UnlockThrottleResource {
+    /// The same back-off guards unlocking, changing the password and
+    /// revealing secrets, so none of them can be used to guess it faster.
+    pub fn check(&self, profile: &str, now: u64) -> Result<(), String> {
+        let remaining = self.attempts.remaining_delay(profile, now);
+        if remaining > 0 {
+            Err(format!("Too many failed attempts, try again in {} seconds.", remaining))
+        } else {
+            Ok(())
+        }
+    }
+
+    /// Returns the new failure count.
+    pub fn record_failure(&mut self, profile: &str, now: u64) -> u32 {
+        let failures = self.attempts.record_failure(profile, now);
+        if let Err(e) = self.attempts.save() {
+            error!("Failed to save unlock attempts: {}", e);
+        }
+        failures
+    }
+
+    pub fn record_success(&mut self, profile: &str) {
+        if self.attempts.failures(profile) > 0 {
+            self.attempts.record_success(profile);
+            if let Err(e) = self.attempts.save() {
+                error!("Failed to save unlock attempts: {}", e);
+            }
+        }
+    }
+}
+
+#[derive(Resource)]
+pub struct ProfilesResource {
+    profiles: WalletProfiles,
+    /// `config.toml` as saved, before the active profile's overrides are applied.
+    base_config: AppConfig,
+    /// Command line and environment, applied over the profile's overrides.
+    launch: LaunchOptions,
+}
+
+impl ProfilesResource {
+    /// `config.toml`, then the active profile's overrides, then the command
+    /// line and environment.
+    pub fn effective_config(&self) -> AppConfig {
+        let config = match self.profiles.active_profile() {
+            Some(profile) => profile.apply_overrides(&self.base_config),
+            None => self.base_config.clone(),
+        };
+        self.launch.apply(&config)
+    }
+}
+
+/// What the command line asked for after the first unlock, taken once.
+#[derive(Resource, Default)]
+pub struct LaunchResource {
+    start_screen: Option<AppScreenState>,
+    start_mining: bool,
+}
+
 #[derive(Resource)]
 pub struct BussesResource {
     busses: Vec<ore_api::state::Bus>,
@@ -767,20 +1644,21 @@ pub struct BussesResource {
 #[derive(Reflect, Resource, InspectorOptions)]
 #[reflect(Resource, InspectorOptions)]
 pub struct TreasuryAccountResource {
-    balance: String,
+    /// `None` until the treasury was first fetched.
+    balance: Option<OreAmount>,
     last_reset_at: i64,
     need_epoch_reset: bool,
-    base_reward_rate: f64,
+    base_reward_rate: OreAmount,
     min_difficulty: u64,
 }
 
 impl Default for TreasuryAccountResource {
     fn default() -> Self {
         Self {
-            balance: "loading...".to_string(),
+            balance: None,
             last_reset_at: 0,
             need_epoch_reset: false,
-            base_reward_rate: 0.0,
+            base_reward_rate: OreAmount::ZERO,
             min_difficulty: 0,
         }
     }
@@ -814,6 +1692,10 @@ pub struct MiningProofsResource {
     largest_difficulty_seen: u32,
     miners_this_epoch: u32,
     miners_last_epoch: u32,
+    top_staker: Option<(Pubkey, OreAmount)>,
+    total_stake: OreAmount,
+    stake_distribution: StakeDistribution,
+    last_full_reload: Option<Instant>,
 }
 
 
@@ -853,14 +1735,37 @@ pub struct OreAppState {
     active_input_node: Option<Entity>,
 }
 
-pub struct LocalResetCooldown {
-    reset_timer: Timer
+/// Format picked on the history screen's export row.
+#[derive(Resource, Default)]
+pub struct MiningExportResource {
+    format: ExportFormat,
 }
 
-impl Default for LocalResetCooldown {
-    fn default() -> Self {
-        Self { reset_timer: Timer::new(Duration::from_secs(5), TimerMode::Once) }
-    }
+/// The log viewer's filter and what it last drew.
+#[derive(Resource, Default)]
+pub struct LogViewerResource {
+    /// Most verbose level shown, `None` shows everything.
+    level: Option<Level>,
+    /// Records logged, level and search the list was drawn for.
+    drawn: Option<(u64, Option<Level>, String)>,
+}
+
+/// `config.toml` as last seen by `watch_config_file`.
+#[derive(Resource)]
+pub struct ConfigWatchResource {
+    modified: Option<SystemTime>,
+    /// The config the app started with, for settings only read at startup.
+    started_with: AppConfig,
+    /// Changed settings that only take effect after a restart.
+    restart_required: Vec<&'static str>,
+    reload_error: Option<String>,
+}
+
+/// Why `config.toml` couldn't be used as is, shown on the config screen
+/// until a valid config is saved.
+#[derive(Resource, Default)]
+pub struct ConfigStatusResource {
+    error: Option<String>,
 }
 
 // pub fn mining_screen_hotkeys(
@@ -899,7 +1804,7 @@ pub fn text_password_input(
             for (captured_text_entity, captured_text_children) in captured_text_query.iter() {
                 if captured_text_entity == app_state_active_text_entity {
                     for child in captured_text_children {
-                        for (active_text_entity, mut text_input) in active_text_query.iter_mut() {
+                        for (active_text_entity, _text_input) in active_text_query.iter_mut() {
                             if active_text_entity == *child {
                                 event_writer.send(EventUnlock);
                             }
@@ -927,7 +1832,7 @@ pub fn text_password_input(
                     for child in captured_text_children {
                         for (active_text_entity, mut text_input) in active_text_query.iter_mut() {
                             if active_text_entity == *child {
-                                text_input.text.pop();
+                                text_input.pop();
                                 // reset, to ensure multiple presses aren't going to result in multiple backspaces
                                 backspace_timer.timer.reset();
                             }
@@ -943,7 +1848,7 @@ pub fn text_password_input(
                             if active_text_entity == *child {
                                 backspace_timer.timer.tick(time.delta());
                                 if backspace_timer.timer.just_finished() {
-                                    text_input.text.pop();
+                                    text_input.pop();
                                     backspace_timer.timer.reset();
                                 }
                             }
@@ -963,7 +1868,7 @@ pub fn text_password_input(
                             for child in captured_text_children {
                                 for (active_text_entity, mut text_input) in active_text_query.iter_mut() {
                                     if active_text_entity == *child {
-                                        text_input.text.push_str(ev.char.as_str());
+                                        text_input.push_str(ev.char.as_str());
                                     }
                                 }
                             }
@@ -994,18 +1899,20 @@ pub fn text_input(
         }
         if mouse_input.just_pressed(MouseButton::Right) {
             if let Ok(mut ctx) = ClipboardContext::new() {
-                if let Ok(text) = ctx.get_contents() {
+                if let Ok(mut text) = ctx.get_contents() {
                     for (captured_text_entity, captured_text_children) in captured_text_query.iter() {
                         if captured_text_entity == app_state_active_text_entity {
                             for child in captured_text_children {
                                 for (active_text_entity, mut text_input) in active_text_query.iter_mut() {
                                     if active_text_entity == *child {
-                                        text_input.text = text.clone();
+                                        text_input.set_value(&text);
                                     }
                                 }
                             }
                         }
                     }
+                    // pasted passwords and seed phrases shouldn't linger
+                    text.zeroize();
                 } else {
                     error!("Failed to paste clipboard contents.");
                 }
@@ -1020,7 +1927,7 @@ pub fn text_input(
                     for child in captured_text_children {
                         for (active_text_entity, mut text_input) in active_text_query.iter_mut() {
                             if active_text_entity == *child {
-                                text_input.text.pop();
+                                text_input.pop();
                                 // reset, to ensure multiple presses aren't going to result in multiple backspaces
                                 backspace_timer.timer.reset();
                             }
@@ -1036,7 +1943,7 @@ pub fn text_input(
                             if active_text_entity == *child {
                                 backspace_timer.timer.tick(time.delta());
                                 if backspace_timer.timer.just_finished() {
-                                    text_input.text.pop();
+                                    text_input.pop();
                                     backspace_timer.timer.reset();
                                 }
                             }
@@ -1056,11 +1963,11 @@ pub fn text_input(
                                 for (active_text_entity, mut text_input) in active_text_query.iter_mut() {
                                     if active_text_entity == *child {
                                         if text_input.numbers_only {
-                                            if char.is_numeric() {
-                                                text_input.text.push_str(ev.char.as_str());
+                                            if char.is_numeric() || (char == '.' && !text_input.value().contains('.')) {
+                                                text_input.push_str(ev.char.as_str());
                                             }
                                         } else {
-                                            text_input.text.push_str(ev.char.as_str());
+                                            text_input.push_str(ev.char.as_str());
                                         }
                                     }
                                 }
@@ -1075,13 +1982,21 @@ pub fn text_input(
 
 fn file_drop(
     mut dnd_evr: EventReader<FileDragAndDrop>,
-    mut event_writer: EventWriter<EventLoadKeypairFile>
+    mut event_writer: EventWriter<EventLoadKeypairFile>,
+    mut query_restore_path: Query<&mut TextInput, With<TextAppRestorePathInput>>,
 ) {
     for ev in dnd_evr.read() {
-        println!("{:?}", ev);
+        debug!("{:?}", ev);
         if let FileDragAndDrop::DroppedFile { path_buf, .. } = ev {
-            println!("Dropped file with path: {:?}", path_buf);
+            info!("Dropped file with path: {:?}", path_buf);
 
+            // an app backup goes to the restore field, anything else is a keypair
+            if path_buf.extension().is_some_and(|ext| ext == app_backup::BACKUP_EXTENSION) {
+                if let Ok(mut text_input) = query_restore_path.get_single_mut() {
+                    text_input.text = path_buf.display().to_string();
+                }
+                continue;
+            }
             event_writer.send(EventLoadKeypairFile(path_buf.to_path_buf()));
         }
     }
@@ -1091,7 +2006,7 @@ pub fn dashboard_list_cleanup_system(
     mut commands: Commands,
     mut moving_scroll_panel_query: Query<(Entity, &Children), With<DashboardProofUpdatesLogsList>>,
 ) {
-    if let Ok((entity, children_log_items)) = moving_scroll_panel_query.get_single_mut() {
+    if let Ok((_entity, children_log_items)) = moving_scroll_panel_query.get_single_mut() {
     if children_log_items.len() >= 1000 {
             info!("Cleaning up some log items.");
             let amount = children_log_items.len() - (children_log_items.len() - 500);
@@ -1105,158 +2020,178 @@ pub fn dashboard_list_cleanup_system(
     }
 }
 
-pub struct SigChecksTimer {
-    timer: Timer,
-}
+pub fn auto_lock_on_inactivity(
+    kbd: Res<ButtonInput<KeyCode>>,
+    mouse_input: Res<ButtonInput<MouseButton>>,
+    mut evr_cursor: EventReader<CursorMoved>,
+    mut evr_scroll: EventReader<MouseWheel>,
+    app_state: Res<OreAppState>,
+    app_wallet: Res<AppWallet>,
+    mut last_input: Local<Option<Instant>>,
+    mut event_writer: EventWriter<EventLock>,
+) {
+    let had_input = kbd.get_pressed().next().is_some()
+        || mouse_input.get_pressed().next().is_some()
+        || evr_cursor.read().count() > 0
+        || evr_scroll.read().count() > 0;
+    if had_input || last_input.is_none() || app_wallet.wallet.is_none() {
+        *last_input = Some(Instant::now());
+        return;
+    }
 
-impl Default for SigChecksTimer {
-    fn default() -> Self {
-        Self {
-            timer: Timer::new(Duration::from_millis(1000), TimerMode::Once)
+    let minutes = app_state.config.auto_lock_minutes;
+    if minutes == 0 {
+        return;
+    }
+    if let Some(last) = *last_input {
+        if last.elapsed() >= Duration::from_secs(minutes * 60) {
+            info!("No input for {} minutes, locking wallet.", minutes);
+            *last_input = Some(Instant::now());
+            event_writer.send(EventLock);
         }
     }
 }
 
-pub fn tx_processors_sigs_check(
-    mut event_writer: EventWriter<EventCheckSigs>,
-    mut sig_checks_timer: Local<SigChecksTimer>,
-    time: Res<Time>
+/// Keeps what the panic hook writes to a crash report current.
+pub fn track_crash_session(
+    app_state: Res<OreAppState>,
+    miner_status: Res<MinerStatusResource>,
+    profiles_res: Res<ProfilesResource>,
 ) {
-    sig_checks_timer.timer.tick(time.delta());
-    if sig_checks_timer.timer.just_finished() {
-        event_writer.send(EventCheckSigs);
-        sig_checks_timer.timer.reset();
+    if app_state.is_changed() {
+        crash_report::set_config(&app_state.config);
+    }
+    if miner_status.is_changed() || profiles_res.is_changed() {
+        crash_report::set_session(
+            profiles_res.profiles.active_profile().map(|p| p.name.clone()),
+            miner_status.miner_status != "STOPPED",
+        );
     }
 }
 
-pub fn tx_processor_result_checks(
-    mut commands: Commands,
-    mut event_writer: EventWriter<EventTxResult>,
-    proof_res: Res<ProofAccountResource>,
-    query_tx: Query<(Entity, &TxProcessor)>,
+/// Another launch found this instance running, bring the window forward.
+pub fn focus_window_on_request(
+    instance_lock: Res<InstanceLockResource>,
+    mut query_window: Query<&mut Window, With<PrimaryWindow>>,
 ) {
-    for (entity, tx_processor) in query_tx.iter() {
-        let status = tx_processor.status.clone();
-        let sig = if let Some(s) = tx_processor.signature {
-            s.to_string()
-        } else {
-            "FAILED".to_string()
-        };
-        if status == "SUCCESS" || status == "FAILED" {
-            let sig = if let Some(s) = tx_processor.signature {
-                s.to_string()
-            } else {
-                "FAILED".to_string()
-            };
-
-            match tx_processor.tx_type {
-                TxType::Mine =>  {
-                    if status == "SUCCESS" {
-                        let previous_staked_balance = tx_processor.staked_balance;
-                        if let Some(previous_staked_balance) = previous_staked_balance {
-                            let current_staked_balance = proof_res.stake;
-                            if  tx_processor.challenge.as_str() != proof_res.challenge {
-                                // let sol_diff = current_sol_balance - previous_sol_balance;
-                                let staked_diff = current_staked_balance - previous_staked_balance;
-                                let ore_conversion = staked_diff as f64 / 10f64.powf(ORE_TOKEN_DECIMALS as f64);
-                                let status = format!("{} +{} ORE.", status, ore_conversion.to_string());
-                                
-                                event_writer.send(EventTxResult {
-                                    tx_type: tx_processor.tx_type.to_string(),
-                                    sig,
-                                    hash_status: tx_processor.hash_status,
-                                    tx_time: tx_processor.created_at.elapsed().as_secs(),
-                                    tx_status:  TxStatus {
-                                        status,
-                                        error: tx_processor.error.clone()
-                                    }
-                                });
-
-                                commands.entity(entity).despawn_recursive();
-
-                            }
-                        } else {
-                            event_writer.send(EventTxResult {
-                                tx_type: tx_processor.tx_type.to_string(),
-                                sig,
-                                hash_status: tx_processor.hash_status,
-                                tx_time: tx_processor.created_at.elapsed().as_secs(),
-                                tx_status:  TxStatus {
-                                    status,
-                                    error: tx_processor.error.clone()
-                                }
-                            });
+    let receiver = match &instance_lock.focus_requests {
+        Some(receiver) => receiver,
+        None => return,
+    };
+    if receiver.try_iter().count() == 0 {
+        return;
+    }
+    info!("Another launch asked for focus.");
+    for mut window in query_window.iter_mut() {
+        window.set_minimized(false);
+        window.focused = true;
+    }
+}
 
-                            commands.entity(entity).despawn_recursive();
-                        }
-                    } else if status == "FAILED" {
-                        info!("Found a FAILED tx");
-                            event_writer.send(EventTxResult {
-                                tx_type: tx_processor.tx_type.to_string(),
-                                sig,
-                                hash_status: tx_processor.hash_status,
-                                tx_time: tx_processor.created_at.elapsed().as_secs(),
-                                tx_status:  TxStatus {
-                                    status,
-                                    error: tx_processor.error.clone()
-                                }
-                            });
+/// `--start-mining`: starts once the unlocked wallet's proof has loaded.
+/// Registering costs SOL, so an unregistered wallet is left for the user.
+pub fn start_mining_on_launch(
+    mut launch: ResMut<LaunchResource>,
+    app_wallet: Res<AppWallet>,
+    mining_session: Res<MiningSessionResource>,
+    miner_status: Res<MinerStatusResource>,
+    proof_account: Res<ProofAccountResource>,
+    mut event_writer: EventWriter<EventStartStopMining>,
+) {
+    if !launch.start_mining {
+        return;
+    }
+    if app_wallet.is_watch_only() {
+        launch.start_mining = false;
+        return;
+    }
+    if mining_session.signer.is_none() || miner_status.miner_status != "STOPPED" {
+        return;
+    }
 
-                            commands.entity(entity).despawn_recursive();
-                    }
-                }
-                TxType::Airdrop => {
-                    event_writer.send(EventTxResult {
-                        tx_type: tx_processor.tx_type.to_string(),
-                        sig: tx_processor.signature.unwrap().to_string(),
-                        hash_status: tx_processor.hash_status,
-                        tx_time: tx_processor.created_at.elapsed().as_secs(),
-                        tx_status:  TxStatus {
-                            status,
-                            error: tx_processor.error.clone()
-                        }
-                    });
-
-                    commands.entity(entity).despawn_recursive();
-                },
-                TxType::Register |
-                TxType::ResetEpoch |
-                TxType::Stake |
-                TxType::Claim |
-                TxType::CreateAta =>  {
-                    event_writer.send(EventTxResult {
-                        tx_type: tx_processor.tx_type.to_string(),
-                        sig,
-                        hash_status: tx_processor.hash_status,
-                        tx_time: tx_processor.created_at.elapsed().as_secs(),
-                        tx_status:  TxStatus {
-                            status,
-                            error: tx_processor.error.clone()
-                        }
-                    });
+    match proof_account.challenge.as_str() {
+        "loading..." => {},
+        "Not Found" => {
+            info!("Wallet is not registered, not starting mining on launch.");
+            launch.start_mining = false;
+        },
+        _ => {
+            event_writer.send(EventStartStopMining);
+            launch.start_mining = false;
+        },
+    }
+}
 
-                    commands.entity(entity).despawn_recursive();
+/// Wipes secret inputs when their screen closes, before the entities are
+/// despawned. The seed phrase input is shown unmasked, so its text goes too.
+pub fn clear_secret_inputs(
+    mut query: Query<(&mut TextInput, Option<&mut Text>)>,
+) {
+    for (mut text_input, text) in query.iter_mut() {
+        if text_input.secret.is_some() {
+            text_input.clear();
+            if let Some(mut text) = text {
+                for section in text.sections.iter_mut() {
+                    section.value.zeroize();
                 }
             }
-        } else {
-            if tx_processor.created_at.elapsed().as_secs() >= 80 {
-                event_writer.send(EventTxResult {
-                    tx_type: tx_processor.tx_type.to_string(),
-                    sig,
-                    hash_status: tx_processor.hash_status,
-                    tx_time: tx_processor.created_at.elapsed().as_secs(),
-                    tx_status:  TxStatus {
-                        status,
-                        error: "Expired: ".to_string() + &tx_processor.error,
-                    }
-                });
+        }
+    }
+}
 
-                commands.entity(entity).despawn_recursive();
-            }
+/// Wipes a revealed seed phrase once `MNEMONIC_REVEAL_SECONDS` pass.
+pub fn hide_revealed_mnemonic(
+    mut secret_clipboard: ResMut<SecretClipboardResource>,
+    query: Query<&mut Text, With<TextRevealedMnemonic>>,
+) {
+    match secret_clipboard.mnemonic_hide_at {
+        Some(hide_at) if Instant::now() >= hide_at => {},
+        _ => return,
+    }
+    secret_clipboard.mnemonic_hide_at = None;
+    wipe_revealed_mnemonic(query);
+}
+
+/// Wipes a revealed seed phrase when the Wallet settings screen closes.
+pub fn clear_revealed_mnemonic(
+    mut secret_clipboard: ResMut<SecretClipboardResource>,
+    query: Query<&mut Text, With<TextRevealedMnemonic>>,
+) {
+    secret_clipboard.mnemonic_hide_at = None;
+    wipe_revealed_mnemonic(query);
+}
+
+fn wipe_revealed_mnemonic(mut query: Query<&mut Text, With<TextRevealedMnemonic>>) {
+    for mut text in query.iter_mut() {
+        for section in text.sections.iter_mut() {
+            section.value.zeroize();
         }
     }
 }
 
+pub fn clear_secret_clipboard(
+    mut secret_clipboard: ResMut<SecretClipboardResource>,
+) {
+    let clear_at = if let Some(clear_at) = secret_clipboard.clear_at {
+        clear_at
+    } else {
+        return;
+    };
+    if Instant::now() < clear_at {
+        return;
+    }
+    secret_clipboard.clear_clipboard();
+}
+
+/// Locking doesn't wait for the timeout, a copied secret key goes right away.
+pub fn clear_secret_clipboard_on_lock(
+    mut secret_clipboard: ResMut<SecretClipboardResource>,
+) {
+    if secret_clipboard.secret.is_some() {
+        secret_clipboard.clear_clipboard();
+    }
+}
+
 pub fn spin_spinner_icons(
     mut query: Query<(&mut Transform, &Visibility), With<SpinnerIcon>>,
     mut winit_setting: ResMut<WinitSettings>,
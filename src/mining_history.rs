@@ -0,0 +1,307 @@
+use std::{
+    fmt,
+    fs::{self, File, OpenOptions},
+    io::{BufRead, BufReader, BufWriter, Write},
+    path::{Path, PathBuf},
+};
+
+use chrono::{DateTime, NaiveDate, SecondsFormat};
+use serde::{Deserialize, Serialize};
+
+use crate::{amount::OreAmount, app_dirs::data_path};
+
+pub const MINING_HISTORY_PATH: &str = "mining_history.jsonl";
+pub const EXPORTS_DIR: &str = "exports";
+
+/// One line of `mining_history.jsonl`.
+#[derive(Serialize, Deserialize, Clone, Debug)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum MiningRecord {
+    /// A transaction that reached a final status.
+    Tx {
+        timestamp: i64,
+        wallet: String,
+        tx_type: String,
+        signature: String,
+        status: String,
+        #[serde(default)]
+        error: String,
+        /// Proof challenge when the result came in.
+        challenge: String,
+        difficulty: Option<u32>,
+        /// Seconds spent hashing, mine transactions only.
+        hash_time: Option<u64>,
+        /// Seconds from first send to the final status.
+        tx_time: u64,
+        priority_fee_lamports: Option<u64>,
+    },
+    /// The pool's result for a round this miner submitted to.
+    PoolRound {
+        timestamp: i64,
+        wallet: String,
+        challenge: String,
+        /// Best difficulty the pool submitted for the round.
+        pool_difficulty: u32,
+        /// Difficulty this miner supplied.
+        difficulty: u32,
+        earned_ore: OreAmount,
+        miner_percentage: f64,
+        active_miners: u32,
+        total_rewards: OreAmount,
+        /// Stake multiplier in basis points, 10000 is 1x.
+        multiplier_bps: u64,
+    },
+}
+
+impl MiningRecord {
+    pub fn timestamp(&self) -> i64 {
+        match self {
+            MiningRecord::Tx { timestamp, .. } => *timestamp,
+            MiningRecord::PoolRound { timestamp, .. } => *timestamp,
+        }
+    }
+
+    fn to_row(&self) -> ExportRow {
+        let time = |timestamp: i64| {
+            DateTime::from_timestamp(timestamp, 0)
+                .map(|dt| dt.to_rfc3339_opts(SecondsFormat::Secs, true))
+                .unwrap_or_else(|| timestamp.to_string())
+        };
+        match self.clone() {
+            MiningRecord::Tx {
+                timestamp,
+                wallet,
+                tx_type,
+                signature,
+                status,
+                error,
+                challenge,
+                difficulty,
+                hash_time,
+                tx_time,
+                priority_fee_lamports,
+            } => ExportRow {
+                timestamp: time(timestamp),
+                kind: "tx",
+                wallet,
+                tx_type,
+                challenge,
+                difficulty,
+                pool_difficulty: None,
+                hash_time,
+                tx_time: Some(tx_time),
+                earned_ore: None,
+                miner_percentage: None,
+                priority_fee_lamports,
+                signature,
+                status,
+                error,
+            },
+            MiningRecord::PoolRound {
+                timestamp,
+                wallet,
+                challenge,
+                pool_difficulty,
+                difficulty,
+                earned_ore,
+                miner_percentage,
+                ..
+            } => ExportRow {
+                timestamp: time(timestamp),
+                kind: "pool_round",
+                wallet,
+                tx_type: String::new(),
+                challenge,
+                difficulty: Some(difficulty),
+                pool_difficulty: Some(pool_difficulty),
+                hash_time: None,
+                tx_time: None,
+                earned_ore: Some(earned_ore.to_string()),
+                miner_percentage: Some(miner_percentage),
+                priority_fee_lamports: None,
+                signature: String::new(),
+                status: String::new(),
+                error: String::new(),
+            },
+        }
+    }
+}
+
+/// Appends `record` to `mining_history.jsonl`.
+pub fn append_record(record: &MiningRecord) -> Result<(), String> {
+    let path = data_path(MINING_HISTORY_PATH);
+    let line = serde_json::to_string(record).map_err(|e| e.to_string())?;
+    let mut file = OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(&path)
+        .map_err(|e| format!("Failed to open {}: {}", path.display(), e))?;
+    writeln!(file, "{}", line).map_err(|e| format!("Failed to write {}: {}", path.display(), e))
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Default)]
+pub enum ExportFormat {
+    #[default]
+    Csv,
+    Json,
+}
+
+impl ExportFormat {
+    pub fn extension(&self) -> &'static str {
+        match self {
+            ExportFormat::Csv => "csv",
+            ExportFormat::Json => "json",
+        }
+    }
+
+    pub fn toggled(&self) -> Self {
+        match self {
+            ExportFormat::Csv => ExportFormat::Json,
+            ExportFormat::Json => ExportFormat::Csv,
+        }
+    }
+}
+
+impl fmt::Display for ExportFormat {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ExportFormat::Csv => write!(f, "CSV"),
+            ExportFormat::Json => write!(f, "JSON"),
+        }
+    }
+}
+
+/// An export row, the same columns for both record kinds.
+#[derive(Serialize)]
+struct ExportRow {
+    timestamp: String,
+    kind: &'static str,
+    wallet: String,
+    tx_type: String,
+    challenge: String,
+    difficulty: Option<u32>,
+    pool_difficulty: Option<u32>,
+    hash_time: Option<u64>,
+    tx_time: Option<u64>,
+    /// Exact decimal, so JSON readers don't round it through a float.
+    earned_ore: Option<String>,
+    miner_percentage: Option<f64>,
+    priority_fee_lamports: Option<u64>,
+    signature: String,
+    status: String,
+    error: String,
+}
+
+const CSV_HEADER: &str = "timestamp,kind,wallet,tx_type,challenge,difficulty,pool_difficulty,hash_time,tx_time,earned_ore,miner_percentage,priority_fee_lamports,signature,status,error";
+
+impl ExportRow {
+    fn to_csv(&self) -> String {
+        fn opt<T: ToString>(value: &Option<T>) -> String {
+            value.as_ref().map(|v| v.to_string()).unwrap_or_default()
+        }
+        [
+            csv_field(&self.timestamp),
+            self.kind.to_string(),
+            csv_field(&self.wallet),
+            csv_field(&self.tx_type),
+            csv_field(&self.challenge),
+            opt(&self.difficulty),
+            opt(&self.pool_difficulty),
+            opt(&self.hash_time),
+            opt(&self.tx_time),
+            opt(&self.earned_ore),
+            opt(&self.miner_percentage),
+            opt(&self.priority_fee_lamports),
+            csv_field(&self.signature),
+            csv_field(&self.status),
+            csv_field(&self.error),
+        ]
+        .join(",")
+    }
+}
+
+fn csv_field(value: &str) -> String {
+    if value.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
+
+/// Parses a `YYYY-MM-DD` input. `end_of_day` gives the last second of the
+/// day so a range includes both its first and last day.
+pub fn parse_export_date(input: &str, end_of_day: bool) -> Result<i64, String> {
+    let date = NaiveDate::parse_from_str(input.trim(), "%Y-%m-%d")
+        .map_err(|_| format!("{} is not a YYYY-MM-DD date", input.trim()))?;
+    let time = if end_of_day {
+        date.and_hms_opt(23, 59, 59)
+    } else {
+        date.and_hms_opt(0, 0, 0)
+    };
+    time.map(|t| t.and_utc().timestamp())
+        .ok_or_else(|| format!("{} is not a valid date", input.trim()))
+}
+
+/// `exports/mining-<from>-<to>.<ext>` in the data directory.
+pub fn export_path(from: &str, to: &str, format: ExportFormat) -> PathBuf {
+    data_path(EXPORTS_DIR).join(format!("mining-{}-{}.{}", from.trim(), to.trim(), format.extension()))
+}
+
+/// Streams the records between `from` and `to` (unix seconds, inclusive)
+/// from `history` into `output`, a line at a time so the size of the
+/// history doesn't matter. Returns the rows written. Runs off the main
+/// thread, lines that don't parse are skipped.
+pub fn export_records(
+    history: &Path,
+    output: &Path,
+    from: i64,
+    to: i64,
+    format: ExportFormat,
+) -> Result<usize, String> {
+    if let Some(dir) = output.parent() {
+        fs::create_dir_all(dir).map_err(|e| format!("Failed to create {}: {}", dir.display(), e))?;
+    }
+    let file = File::create(output).map_err(|e| format!("Failed to create {}: {}", output.display(), e))?;
+    let mut writer = BufWriter::new(file);
+    let write_err = |e: std::io::Error| format!("Failed to write {}: {}", output.display(), e);
+
+    match format {
+        ExportFormat::Csv => writeln!(writer, "{}", CSV_HEADER).map_err(write_err)?,
+        ExportFormat::Json => write!(writer, "[").map_err(write_err)?,
+    }
+
+    let mut rows = 0;
+    // no history yet is an empty export, not an error
+    if history.exists() {
+        let reader = BufReader::new(
+            File::open(history).map_err(|e| format!("Failed to open {}: {}", history.display(), e))?,
+        );
+        for line in reader.lines() {
+            let line = line.map_err(|e| format!("Failed to read {}: {}", history.display(), e))?;
+            let record: MiningRecord = match serde_json::from_str(&line) {
+                Ok(record) => record,
+                Err(_) => continue,
+            };
+            if record.timestamp() < from || record.timestamp() > to {
+                continue;
+            }
+
+            let row = record.to_row();
+            match format {
+                ExportFormat::Csv => writeln!(writer, "{}", row.to_csv()).map_err(write_err)?,
+                ExportFormat::Json => {
+                    let json = serde_json::to_string(&row).map_err(|e| e.to_string())?;
+                    let separator = if rows == 0 { "\n  " } else { ",\n  " };
+                    write!(writer, "{}{}", separator, json).map_err(write_err)?;
+                },
+            }
+            rows += 1;
+        }
+    }
+
+    if format == ExportFormat::Json {
+        writeln!(writer, "\n]").map_err(write_err)?;
+    }
+    writer.flush().map_err(write_err)?;
+    Ok(rows)
+}
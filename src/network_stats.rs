@@ -0,0 +1,375 @@
+use std::time::{Duration, Instant};
+
+use async_compat::Compat;
+use bevy::{prelude::*, tasks::IoTaskPool, utils::HashMap};
+use ore_api::{state::Proof, ID as ORE_PROGRAM_ID};
+use solana_account_decoder::UiAccountEncoding;
+use solana_client::{
+    nonblocking::rpc_client::RpcClient,
+    rpc_config::{RpcAccountInfoConfig, RpcProgramAccountsConfig},
+    rpc_filter::RpcFilterType,
+};
+use solana_sdk::{account::ReadableAccount, commitment_config::CommitmentConfig, pubkey::Pubkey};
+
+use crate::{
+    amount::OreAmount,
+    ore_utils::AccountDeserialize,
+    tasks::TaskFetchMiningProofs,
+    utils::get_unix_timestamp,
+    EntityTaskHandler, MiningProofsResource, OreAppState,
+};
+
+/// Proof accounts are the 8 byte discriminator followed by the `Proof` struct.
+pub const PROOF_ACCOUNT_SIZE: u64 = 8 + std::mem::size_of::<Proof>() as u64;
+
+/// How often every proof account is reloaded to pick up new miners.
+pub const PROOFS_FULL_RELOAD_INTERVAL: Duration = Duration::from_secs(300);
+/// How often the recently active proofs are refreshed between full reloads.
+pub const PROOFS_REFRESH_INTERVAL: Duration = Duration::from_secs(20);
+/// Proofs that hashed within this many seconds are refreshed incrementally.
+pub const ACTIVE_PROOF_WINDOW: i64 = 600;
+
+/// getMultipleAccounts accepts at most 100 accounts per request.
+pub const MAX_MULTIPLE_ACCOUNTS: usize = 100;
+
+/// Upper bounds in whole ORE for each stake bucket, the last bucket is open ended.
+pub const STAKE_BUCKET_BOUNDS: [u64; 4] = [1, 10, 100, 1000];
+pub const STAKE_BUCKET_LABELS: [&str; 5] = ["<1", "1-10", "10-100", "100-1k", "1k+"];
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct StakeDistribution {
+    /// Number of proofs with a non zero stake in each `STAKE_BUCKET_LABELS` bucket.
+    pub buckets: [u32; 5],
+}
+
+impl StakeDistribution {
+    pub fn to_display_string(&self) -> String {
+        STAKE_BUCKET_LABELS
+            .iter()
+            .zip(self.buckets.iter())
+            .map(|(label, count)| format!("{}: {}", label, count))
+            .collect::<Vec<String>>()
+            .join("  ")
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct NetworkStats {
+    pub miners_this_epoch: u32,
+    pub miners_last_epoch: u32,
+    pub highest_difficulty: u32,
+    pub top_staker: Option<(Pubkey, OreAmount)>,
+    pub total_stake: OreAmount,
+    pub stake_distribution: StakeDistribution,
+}
+
+pub struct MiningProofsUpdate {
+    /// A full reload replaces every known proof, otherwise the proofs are merged.
+    pub full: bool,
+    pub proofs: Vec<(Pubkey, Proof)>,
+}
+
+/// Loads every ORE proof account with a single getProgramAccounts call.
+pub async fn get_all_proofs(client: &RpcClient) -> Result<Vec<(Pubkey, Proof)>, String> {
+    let config = RpcProgramAccountsConfig {
+        filters: Some(vec![RpcFilterType::DataSize(PROOF_ACCOUNT_SIZE)]),
+        account_config: RpcAccountInfoConfig {
+            encoding: Some(UiAccountEncoding::Base64),
+            commitment: Some(CommitmentConfig::confirmed()),
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+
+    let accounts = client
+        .get_program_accounts_with_config(&ORE_PROGRAM_ID, config)
+        .await
+        .map_err(|e| format!("Failed to get proof accounts: {}", e))?;
+
+    Ok(accounts
+        .iter()
+        .filter_map(|(pubkey, account)| {
+            Proof::try_from_bytes(account.data())
+                .ok()
+                .map(|proof| (*pubkey, *proof))
+        })
+        .collect())
+}
+
+/// Refetches the given proof accounts, skipping any that no longer exist.
+pub async fn get_proofs(
+    client: &RpcClient,
+    pubkeys: &[Pubkey],
+) -> Result<Vec<(Pubkey, Proof)>, String> {
+    let mut proofs = vec![];
+    for chunk in pubkeys.chunks(MAX_MULTIPLE_ACCOUNTS) {
+        let accounts = client
+            .get_multiple_accounts(chunk)
+            .await
+            .map_err(|e| format!("Failed to get proof accounts: {}", e))?;
+        for (pubkey, account) in chunk.iter().zip(accounts.iter()) {
+            if let Some(account) = account {
+                if let Ok(proof) = Proof::try_from_bytes(account.data()) {
+                    proofs.push((*pubkey, *proof));
+                }
+            }
+        }
+    }
+
+    Ok(proofs)
+}
+
+/// Proofs worth refreshing between full reloads.
+pub fn recently_active_proofs(proofs: &HashMap<Pubkey, Proof>, now: i64) -> Vec<Pubkey> {
+    proofs
+        .iter()
+        .filter(|(_, proof)| now - proof.last_hash_at <= ACTIVE_PROOF_WINDOW)
+        .map(|(pubkey, _)| *pubkey)
+        .collect()
+}
+
+/// Difficulty of a drillx hash is its number of leading zero bits.
+pub fn hash_difficulty(hash: &[u8; 32]) -> u32 {
+    let mut count = 0;
+    for byte in hash {
+        let lz = byte.leading_zeros();
+        count += lz;
+        if lz < 8 {
+            break;
+        }
+    }
+    count
+}
+
+pub fn compute_network_stats(
+    proofs: &HashMap<Pubkey, Proof>,
+    epoch_start: i64,
+    epoch_duration: i64,
+) -> NetworkStats {
+    let mut stats = NetworkStats::default();
+    let last_epoch_start = epoch_start - epoch_duration;
+
+    for proof in proofs.values() {
+        if proof.last_hash_at >= epoch_start {
+            stats.miners_this_epoch += 1;
+        } else if proof.last_hash_at >= last_epoch_start {
+            stats.miners_last_epoch += 1;
+        }
+
+        stats.highest_difficulty = stats.highest_difficulty.max(hash_difficulty(&proof.last_hash));
+
+        let stake = OreAmount(proof.balance);
+        if stake.is_zero() {
+            continue;
+        }
+        stats.total_stake = stats.total_stake.saturating_add(stake);
+        if stats.top_staker.is_none_or(|(_, top)| stake > top) {
+            stats.top_staker = Some((proof.authority, stake));
+        }
+
+        let whole_ore = stake.whole();
+        let bucket = STAKE_BUCKET_BOUNDS
+            .iter()
+            .position(|bound| whole_ore < *bound)
+            .unwrap_or(STAKE_BUCKET_BOUNDS.len());
+        stats.stake_distribution.buckets[bucket] += 1;
+    }
+
+    stats
+}
+
+pub struct ProofsRefreshTimer {
+    timer: Timer,
+}
+
+impl Default for ProofsRefreshTimer {
+    fn default() -> Self {
+        Self {
+            timer: Timer::new(PROOFS_REFRESH_INTERVAL, TimerMode::Repeating)
+        }
+    }
+}
+
+/// Starts a full reload of the proof accounts when it's due, otherwise
+/// refreshes the recently active ones on the refresh interval.
+pub fn mining_proofs_refresh(
+    mut commands: Commands,
+    time: Res<Time>,
+    ore_app_state: Res<OreAppState>,
+    mut mining_proofs_res: ResMut<MiningProofsResource>,
+    mut refresh_timer: Local<ProofsRefreshTimer>,
+    query_task_handler: Query<Entity, (With<EntityTaskHandler>, Without<TaskFetchMiningProofs>)>,
+) {
+    refresh_timer.timer.tick(time.delta());
+    let task_handler_entity = if let Ok(entity) = query_task_handler.get_single() {
+        entity
+    } else {
+        // previous fetch is still in flight
+        return;
+    };
+
+    let full = mining_proofs_res
+        .last_full_reload
+        .is_none_or(|last| last.elapsed() >= PROOFS_FULL_RELOAD_INTERVAL);
+    if !full && !refresh_timer.timer.just_finished() {
+        return;
+    }
+
+    let pubkeys = if full {
+        vec![]
+    } else {
+        recently_active_proofs(&mining_proofs_res.proofs, get_unix_timestamp() as i64)
+    };
+    if !full && pubkeys.is_empty() {
+        return;
+    }
+
+    let rpc_url = ore_app_state.config.rpc_url.clone();
+    let pool = IoTaskPool::get();
+    let task = pool.spawn(Compat::new(async move {
+        let client = RpcClient::new(rpc_url);
+        let proofs = if full {
+            get_all_proofs(&client).await?
+        } else {
+            get_proofs(&client, &pubkeys).await?
+        };
+
+        Ok(MiningProofsUpdate { full, proofs })
+    }));
+
+    if full {
+        mining_proofs_res.last_full_reload = Some(Instant::now());
+    }
+    commands
+        .entity(task_handler_entity)
+        .insert(TaskFetchMiningProofs { task });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ONE_ORE: u64 = 100_000_000_000;
+
+    fn proof(balance: u64, last_hash_at: i64, last_hash: [u8; 32]) -> (Pubkey, Proof) {
+        let authority = Pubkey::new_unique();
+        let proof = Proof {
+            authority,
+            balance,
+            last_hash,
+            last_hash_at,
+            ..bytemuck::Zeroable::zeroed()
+        };
+        (authority, proof)
+    }
+
+    fn stats_for(proofs: Vec<(Pubkey, Proof)>, epoch_start: i64, epoch_duration: i64) -> NetworkStats {
+        compute_network_stats(&proofs.into_iter().collect(), epoch_start, epoch_duration)
+    }
+
+    #[test]
+    fn hash_difficulty_counts_leading_zero_bits() {
+        assert_eq!(hash_difficulty(&[0; 32]), 256);
+        assert_eq!(hash_difficulty(&[0xff; 32]), 0);
+
+        let mut hash = [0xff; 32];
+        hash[0] = 0x80;
+        assert_eq!(hash_difficulty(&hash), 0);
+        hash[0] = 0x01;
+        assert_eq!(hash_difficulty(&hash), 7);
+    }
+
+    #[test]
+    fn hash_difficulty_across_byte_boundaries() {
+        let mut hash = [0xff; 32];
+        hash[0] = 0;
+        assert_eq!(hash_difficulty(&hash), 8);
+        hash[1] = 0x80;
+        assert_eq!(hash_difficulty(&hash), 8);
+        hash[1] = 0x01;
+        assert_eq!(hash_difficulty(&hash), 15);
+        hash[1] = 0;
+        hash[2] = 0x10;
+        assert_eq!(hash_difficulty(&hash), 19);
+
+        // zero bits after the first set bit don't count
+        let mut hash = [0; 32];
+        hash[3] = 0x20;
+        assert_eq!(hash_difficulty(&hash), 26);
+    }
+
+    #[test]
+    fn miners_are_counted_by_epoch_boundaries() {
+        let epoch_start = 1_000;
+        let duration = 60;
+        let stats = stats_for(
+            vec![
+                proof(0, 1_050, [0xff; 32]),
+                proof(0, 1_000, [0xff; 32]),
+                proof(0, 999, [0xff; 32]),
+                proof(0, 940, [0xff; 32]),
+                proof(0, 939, [0xff; 32]),
+            ],
+            epoch_start,
+            duration,
+        );
+        assert_eq!(stats.miners_this_epoch, 2);
+        assert_eq!(stats.miners_last_epoch, 2);
+    }
+
+    #[test]
+    fn stake_buckets_on_the_bounds() {
+        let stats = stats_for(
+            vec![
+                proof(0, 0, [0xff; 32]),
+                proof(1, 0, [0xff; 32]),
+                proof(ONE_ORE - 1, 0, [0xff; 32]),
+                proof(ONE_ORE, 0, [0xff; 32]),
+                proof(10 * ONE_ORE - 1, 0, [0xff; 32]),
+                proof(10 * ONE_ORE, 0, [0xff; 32]),
+                proof(100 * ONE_ORE, 0, [0xff; 32]),
+                proof(1_000 * ONE_ORE - 1, 0, [0xff; 32]),
+                proof(1_000 * ONE_ORE, 0, [0xff; 32]),
+                proof(50_000 * ONE_ORE, 0, [0xff; 32]),
+            ],
+            1_000,
+            60,
+        );
+        // a zero stake isn't in any bucket
+        assert_eq!(stats.stake_distribution.buckets, [2, 2, 1, 2, 2]);
+    }
+
+    #[test]
+    fn top_staker_total_stake_and_difficulty() {
+        let mut hard = [0xff; 32];
+        hard[0] = 0;
+        hard[1] = 0x01;
+        let top = proof(7 * ONE_ORE, 0, [0xff; 32]);
+        let top_authority = top.0;
+        let stats = stats_for(
+            vec![proof(2 * ONE_ORE, 0, hard), top, proof(0, 0, [0; 32])],
+            1_000,
+            60,
+        );
+        assert_eq!(stats.top_staker, Some((top_authority, OreAmount(7 * ONE_ORE))));
+        assert_eq!(stats.total_stake, OreAmount(9 * ONE_ORE));
+        // difficulty counts for proofs without stake too
+        assert_eq!(stats.highest_difficulty, 256);
+    }
+
+    #[test]
+    fn no_proofs() {
+        let stats = stats_for(vec![], 1_000, 60);
+        assert_eq!(stats, NetworkStats::default());
+        assert_eq!(stats.stake_distribution.to_display_string(), "<1: 0  1-10: 0  10-100: 0  100-1k: 0  1k+: 0");
+    }
+
+    #[test]
+    fn recently_active_window() {
+        let now = 10_000;
+        let (active, active_proof) = proof(0, now - ACTIVE_PROOF_WINDOW, [0xff; 32]);
+        let (stale, stale_proof) = proof(0, now - ACTIVE_PROOF_WINDOW - 1, [0xff; 32]);
+        let proofs: HashMap<Pubkey, Proof> = [(active, active_proof), (stale, stale_proof)].into_iter().collect();
+        assert_eq!(recently_active_proofs(&proofs, now), vec![active]);
+    }
+}
@@ -1,6 +1,8 @@
 use std::{mem::size_of, ops::Range};
 
-use solana_sdk::pubkey::Pubkey;
+use solana_sdk::{bs58, pubkey::Pubkey};
+
+use crate::{amount::OreAmount, mining_history::MiningRecord};
 
 #[derive(Debug)]
 pub enum ServerMessage {
@@ -11,107 +13,102 @@ pub enum ServerMessage {
 #[derive(Debug)]
 pub struct ServerMessagePoolSubmissionResult {
     difficulty: u32,
-    total_balance: f64,
-    total_rewards: f64,
-    top_stake: f64,
+    /// The pool sends ORE amounts as floats, they're converted once here.
+    total_balance: OreAmount,
+    total_rewards: OreAmount,
+    top_stake: OreAmount,
     multiplier: f64,
     active_miners: u32,
     challenge: [u8; 32],
     best_nonce: u64,
     miner_supplied_difficulty: u32,
-    miner_earned_rewards: f64,
+    miner_earned_rewards: OreAmount,
     miner_percentage: f64
 }
 
 impl ServerMessagePoolSubmissionResult {
+    /// The round as stored in the mining history.
+    pub fn to_record(&self, wallet: String, timestamp: i64) -> MiningRecord {
+        MiningRecord::PoolRound {
+            timestamp,
+            wallet,
+            challenge: bs58::encode(self.challenge).into_string(),
+            pool_difficulty: self.difficulty,
+            difficulty: self.miner_supplied_difficulty,
+            earned_ore: self.miner_earned_rewards,
+            miner_percentage: self.miner_percentage,
+            active_miners: self.active_miners,
+            total_rewards: self.total_rewards,
+            multiplier_bps: (self.multiplier * 10_000.0).round() as u64,
+        }
+    }
+
     pub fn new_from_bytes(b: Vec<u8>) -> Self {
         let mut b_index = 1;
 
         let data_size = size_of::<u32>();
         let mut data_bytes = [0u8; size_of::<u32>()];
-        for i in 0..data_size {
-            data_bytes[i] = b[i + b_index];
-        }
+        data_bytes.copy_from_slice(&b[b_index..b_index + data_size]);
         b_index += data_size;
         let difficulty = u32::from_le_bytes(data_bytes);
 
         let data_size = size_of::<f64>();
         let mut data_bytes = [0u8; size_of::<f64>()];
-        for i in 0..data_size {
-            data_bytes[i] = b[i + b_index];
-        }
+        data_bytes.copy_from_slice(&b[b_index..b_index + data_size]);
         b_index += data_size;
-        let total_balance = f64::from_le_bytes(data_bytes);
+        let total_balance = OreAmount::from_f64_lossy(f64::from_le_bytes(data_bytes));
 
         let data_size = size_of::<f64>();
         let mut data_bytes = [0u8; size_of::<f64>()];
-        for i in 0..data_size {
-            data_bytes[i] = b[i + b_index];
-        }
+        data_bytes.copy_from_slice(&b[b_index..b_index + data_size]);
         b_index += data_size;
-        let total_rewards = f64::from_le_bytes(data_bytes);
+        let total_rewards = OreAmount::from_f64_lossy(f64::from_le_bytes(data_bytes));
 
         let data_size = size_of::<f64>();
         let mut data_bytes = [0u8; size_of::<f64>()];
-        for i in 0..data_size {
-            data_bytes[i] = b[i + b_index];
-        }
+        data_bytes.copy_from_slice(&b[b_index..b_index + data_size]);
         b_index += data_size;
-        let top_stake = f64::from_le_bytes(data_bytes);
+        let top_stake = OreAmount::from_f64_lossy(f64::from_le_bytes(data_bytes));
 
         let data_size = size_of::<f64>();
         let mut data_bytes = [0u8; size_of::<f64>()];
-        for i in 0..data_size {
-            data_bytes[i] = b[i + b_index];
-        }
+        data_bytes.copy_from_slice(&b[b_index..b_index + data_size]);
         b_index += data_size;
         let multiplier = f64::from_le_bytes(data_bytes);
 
         let data_size = size_of::<u32>();
         let mut data_bytes = [0u8; size_of::<u32>()];
-        for i in 0..data_size {
-            data_bytes[i] = b[i + b_index];
-        }
+        data_bytes.copy_from_slice(&b[b_index..b_index + data_size]);
         b_index += data_size;
         let active_miners = u32::from_le_bytes(data_bytes);
 
         let data_size = 32;
         let mut data_bytes = [0u8; 32];
-        for i in 0..data_size {
-            data_bytes[i] = b[i + b_index];
-        }
+        data_bytes.copy_from_slice(&b[b_index..b_index + data_size]);
         b_index += data_size;
-        let challenge = data_bytes.clone();
+        let challenge = data_bytes;
 
         let data_size = size_of::<u64>();
         let mut data_bytes = [0u8; size_of::<u64>()];
-        for i in 0..data_size {
-            data_bytes[i] = b[i + b_index];
-        }
+        data_bytes.copy_from_slice(&b[b_index..b_index + data_size]);
         b_index += data_size;
         let best_nonce = u64::from_le_bytes(data_bytes);
 
         let data_size = size_of::<u32>();
         let mut data_bytes = [0u8; size_of::<u32>()];
-        for i in 0..data_size {
-            data_bytes[i] = b[i + b_index];
-        }
+        data_bytes.copy_from_slice(&b[b_index..b_index + data_size]);
         b_index += data_size;
         let miner_supplied_difficulty = u32::from_le_bytes(data_bytes);
 
         let data_size = size_of::<f64>();
         let mut data_bytes = [0u8; size_of::<f64>()];
-        for i in 0..data_size {
-            data_bytes[i] = b[i + b_index];
-        }
+        data_bytes.copy_from_slice(&b[b_index..b_index + data_size]);
         b_index += data_size;
-        let miner_earned_rewards = f64::from_le_bytes(data_bytes);
+        let miner_earned_rewards = OreAmount::from_f64_lossy(f64::from_le_bytes(data_bytes));
 
         let data_size = size_of::<f64>();
         let mut data_bytes = [0u8; size_of::<f64>()];
-        for i in 0..data_size {
-            data_bytes[i] = b[i + b_index];
-        }
+        data_bytes.copy_from_slice(&b[b_index..b_index + data_size]);
         //b_index += data_size;
         let miner_percentage = f64::from_le_bytes(data_bytes);
 
@@ -134,15 +131,15 @@ impl ServerMessagePoolSubmissionResult {
         let mut bin_data = Vec::new();
         bin_data.push(1u8);
         bin_data.extend_from_slice(&self.difficulty.to_le_bytes());
-        bin_data.extend_from_slice(&self.total_balance.to_le_bytes());
-        bin_data.extend_from_slice(&self.total_rewards.to_le_bytes());
-        bin_data.extend_from_slice(&self.top_stake.to_le_bytes());
+        bin_data.extend_from_slice(&self.total_balance.to_f64_lossy().to_le_bytes());
+        bin_data.extend_from_slice(&self.total_rewards.to_f64_lossy().to_le_bytes());
+        bin_data.extend_from_slice(&self.top_stake.to_f64_lossy().to_le_bytes());
         bin_data.extend_from_slice(&self.multiplier.to_le_bytes());
         bin_data.extend_from_slice(&self.active_miners.to_le_bytes());
         bin_data.extend_from_slice(&self.challenge);
         bin_data.extend_from_slice(&self.best_nonce.to_le_bytes());
         bin_data.extend_from_slice(&self.miner_supplied_difficulty.to_le_bytes());
-        bin_data.extend_from_slice(&self.miner_earned_rewards.to_le_bytes());
+        bin_data.extend_from_slice(&self.miner_earned_rewards.to_f64_lossy().to_le_bytes());
         bin_data.extend_from_slice(&self.miner_percentage.to_le_bytes());
 
         bin_data
@@ -158,38 +155,29 @@ pub struct ServerStartMining {
 }
 
 impl ServerStartMining {
-    pub fn new_from_bytes(b: Vec<u8>) -> Result<Self, ()> {
-        if b.len() < 49 {
-            println!("Invalid data for Message StartMining");
-            return Err(())
+    pub fn new_from_bytes(b: Vec<u8>) -> Result<Self, String> {
+        if b.len() < 57 {
+            return Err(format!("Invalid data for Message StartMining, {} bytes", b.len()))
         }
         let mut challenge = [0u8; 32];
         // extract 256 bytes (32 u8's) from data for hash
         let mut b_index = 1;
-        for i in 0..32 {
-            challenge[i] = b[i + b_index];
-        }
+        challenge.copy_from_slice(&b[b_index..b_index + 32]);
         b_index += 32;
 
         // extract 64 bytes (8 u8's)
         let mut cutoff_bytes = [0u8; 8];
-        for i in 0..8 {
-            cutoff_bytes[i] = b[i + b_index];
-        }
+        cutoff_bytes.copy_from_slice(&b[b_index..b_index + 8]);
         b_index += 8;
         let cutoff = u64::from_le_bytes(cutoff_bytes);
 
         let mut nonce_start_bytes = [0u8; 8];
-        for i in 0..8 {
-            nonce_start_bytes[i] = b[i + b_index];
-        }
+        nonce_start_bytes.copy_from_slice(&b[b_index..b_index + 8]);
         b_index += 8;
         let nonce_start = u64::from_le_bytes(nonce_start_bytes);
 
         let mut nonce_end_bytes = [0u8; 8];
-        for i in 0..8 {
-            nonce_end_bytes[i] = b[i + b_index];
-        }
+        nonce_end_bytes.copy_from_slice(&b[b_index..b_index + 8]);
         let nonce_end = u64::from_le_bytes(nonce_end_bytes);
 
         Ok(ServerStartMining {
@@ -221,26 +209,26 @@ pub enum ClientMessage {
 #[derive(Debug)]
 pub struct ClientMessageReady;
 
+impl Default for ClientMessageReady {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl ClientMessageReady {
     pub fn new() -> Self {
         ClientMessageReady
     }
 
-    pub fn new_from_bytes(b: Vec<u8>) -> Result<Self, ()> {
-        if b.len() < 1 {
-            return Err(())
-        }
-        if b[0] != 1 {
-            return Err(())
+    pub fn new_from_bytes(b: Vec<u8>) -> Result<Self, String> {
+        if b.first() != Some(&1) {
+            return Err("Invalid data for Message Ready".to_string())
         }
         Ok(ClientMessageReady)
     }
 
     pub fn to_message_binary(&self) -> Vec<u8> {
-        let mut bin_data = Vec::new();
-        bin_data.push(1u8);
-
-        bin_data
+        vec![1u8]
     }
 }
 
@@ -262,33 +250,24 @@ impl ClientMessageBestSolution {
         }
     }
 
-    pub fn new_from_bytes(b: Vec<u8>) -> Result<Self, ()> {
-        if b.len() < 1 {
-            return Err(())
-        }
-        if b[0] != 2 {
-            return Err(())
+    pub fn new_from_bytes(b: Vec<u8>) -> Result<Self, String> {
+        if b.len() < 57 || b[0] != 2 {
+            return Err(format!("Invalid data for Message BestSolution, {} bytes", b.len()))
         }
 
         let mut solution_bytes = [0u8; 16];
         // extract (16 u8's) from data for hash digest
         let mut b_index = 1;
-        for i in 0..16 {
-            solution_bytes[i] = b[i + b_index];
-        }
+        solution_bytes.copy_from_slice(&b[b_index..b_index + 16]);
         b_index += 16;
 
         // extract 64 bytes (8 u8's)
         let mut nonce = [0u8; 8];
-        for i in 0..8 {
-            nonce[i] = b[i + b_index];
-        }
+        nonce.copy_from_slice(&b[b_index..b_index + 8]);
         b_index += 8;
 
         let mut pubkey = [0u8; 32];
-        for i in 0..32 {
-            pubkey[i] = b[i + b_index];
-        }
+        pubkey.copy_from_slice(&b[b_index..b_index + 32]);
 
         b_index += 32;
 
@@ -313,3 +292,65 @@ impl ClientMessageBestSolution {
         bin_data
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn pool_result() -> ServerMessagePoolSubmissionResult {
+        ServerMessagePoolSubmissionResult {
+            difficulty: 24,
+            total_balance: OreAmount::from_f64_lossy(12.5),
+            total_rewards: OreAmount::from_f64_lossy(0.25),
+            top_stake: OreAmount::from_f64_lossy(3.0),
+            multiplier: 1.5,
+            active_miners: 7,
+            challenge: [9u8; 32],
+            best_nonce: 42,
+            miner_supplied_difficulty: 19,
+            miner_earned_rewards: OreAmount::from_f64_lossy(0.00012345),
+            miner_percentage: 4.2,
+        }
+    }
+
+    #[test]
+    fn pool_result_round_trips_through_bytes() {
+        let result = pool_result();
+        let decoded = ServerMessagePoolSubmissionResult::new_from_bytes(result.to_message_binary());
+
+        assert_eq!(decoded.difficulty, 24);
+        assert_eq!(decoded.total_balance, result.total_balance);
+        assert_eq!(decoded.total_rewards, result.total_rewards);
+        assert_eq!(decoded.top_stake, result.top_stake);
+        assert_eq!(decoded.active_miners, 7);
+        assert_eq!(decoded.challenge, [9u8; 32]);
+        assert_eq!(decoded.best_nonce, 42);
+        assert_eq!(decoded.miner_supplied_difficulty, 19);
+        assert_eq!(decoded.miner_earned_rewards, result.miner_earned_rewards);
+    }
+
+    #[test]
+    fn pool_record_keeps_amounts_in_base_units() {
+        match pool_result().to_record("wallet".to_string(), 1_700_000_000) {
+            MiningRecord::PoolRound {
+                timestamp,
+                pool_difficulty,
+                difficulty,
+                earned_ore,
+                total_rewards,
+                multiplier_bps,
+                active_miners,
+                ..
+            } => {
+                assert_eq!(timestamp, 1_700_000_000);
+                assert_eq!(pool_difficulty, 24);
+                assert_eq!(difficulty, 19);
+                assert_eq!(earned_ore, OreAmount(12_345_000));
+                assert_eq!(total_rewards, OreAmount(25_000_000_000));
+                assert_eq!(multiplier_bps, 15_000);
+                assert_eq!(active_miners, 7);
+            }
+            other => panic!("expected a pool round, got {:?}", other),
+        }
+    }
+}
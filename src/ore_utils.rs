@@ -145,12 +145,12 @@ pub async fn get_proof(client: &RpcClient, authority: Pubkey) -> Result<Proof, S
         Ok(data) => {
             let proof = Proof::try_from_bytes(&data);
             if let Ok(proof) = proof {
-                return Ok(*proof)
+                Ok(*proof)
             } else {
-                return Err("Failed to parse proof account".to_string())
+                Err("Failed to parse proof account".to_string())
             }
         }
-        Err(_) => return Err("Failed to get proof account".to_string()),
+        Err(_) => Err("Failed to get proof account".to_string()),
     }
 }
 
@@ -190,7 +190,6 @@ pub fn find_hash_par(proof: Proof, cutoff_time: u64, threads: u64, min_difficult
     let handles = (0..threads)
         .map(|i| {
             std::thread::spawn({
-                let proof = proof.clone();
                 let message_receiver = mining_messages_reciever.clone();
                 let message_sender = mining_messages_sender.clone();
                 let mut memory = equix::SolverMemory::new();
@@ -232,16 +231,14 @@ pub fn find_hash_par(proof: Proof, cutoff_time: u64, threads: u64, min_difficult
                         }
 
                         // Exit if time has elapsed
-                        if nonce % 100 == 0 {
-                            if timer.elapsed().as_secs().ge(&cutoff_time) {
-                                if best_difficulty.gt(&min_difficulty) {
+                        if nonce % 100 == 0
+                            && timer.elapsed().as_secs().ge(&cutoff_time)
+                                && best_difficulty.gt(&min_difficulty) {
                                     // Mine until min difficulty has been met
                                     // Stop all other threads since time has elapsed and the minimum difficulty has been found
                                     let _ = message_sender.try_send(MiningDataChannelMessage::Stop);
                                     break;
                                 }
-                            } 
-                        }
 
                         // Increment nonce
                         nonce += 1;
@@ -0,0 +1,70 @@
+/// Highest score returned by `password_strength`.
+pub const MAX_PASSWORD_STRENGTH: u8 = 4;
+
+const STRENGTH_LABELS: [&str; 5] = ["Very Weak", "Weak", "Fair", "Strong", "Very Strong"];
+
+const COMMON_PASSWORDS: [&str; 10] = [
+    "password", "123456", "12345678", "qwerty", "letmein",
+    "111111", "abc123", "iloveyou", "admin", "welcome",
+];
+
+/// Scores a password from 0 (very weak) to `MAX_PASSWORD_STRENGTH` based on its
+/// length and the mix of character classes.
+pub fn password_strength(password: &str) -> u8 {
+    let len = password.chars().count();
+    if len == 0 {
+        return 0;
+    }
+    let lower = password.to_lowercase();
+    if COMMON_PASSWORDS.iter().any(|p| lower.contains(p)) && len < 16 {
+        return 0;
+    }
+
+    let classes = [
+        password.chars().any(|c| c.is_ascii_lowercase()),
+        password.chars().any(|c| c.is_ascii_uppercase()),
+        password.chars().any(|c| c.is_ascii_digit()),
+        password.chars().any(|c| !c.is_ascii_alphanumeric()),
+    ]
+    .iter()
+    .filter(|has| **has)
+    .count();
+
+    let mut score: u8 = match len {
+        0..=7 => 0,
+        8..=11 => 1,
+        12..=15 => 2,
+        _ => 3,
+    };
+    if classes >= 3 {
+        score += 1;
+    }
+    if classes == 1 && score > 0 {
+        score -= 1;
+    }
+
+    score.min(MAX_PASSWORD_STRENGTH)
+}
+
+pub fn strength_label(score: u8) -> &'static str {
+    STRENGTH_LABELS[score.min(MAX_PASSWORD_STRENGTH) as usize]
+}
+
+pub fn validate_new_password(
+    new_password: &str,
+    confirm_password: &str,
+    min_strength: u8,
+) -> Result<(), String> {
+    if new_password != confirm_password {
+        return Err("Passwords do not match".to_string());
+    }
+    let score = password_strength(new_password);
+    if score < min_strength {
+        return Err(format!(
+            "Password is {}, at least {} is required",
+            strength_label(score),
+            strength_label(min_strength),
+        ));
+    }
+    Ok(())
+}
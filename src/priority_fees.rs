@@ -0,0 +1,325 @@
+use std::fmt;
+
+use bevy::log::error;
+use serde::{Deserialize, Serialize};
+use solana_client::{
+    nonblocking::rpc_client::RpcClient, rpc_config::RpcSimulateTransactionConfig,
+};
+use solana_sdk::{
+    commitment_config::CommitmentConfig, compute_budget::ComputeBudgetInstruction,
+    instruction::Instruction, pubkey::Pubkey,
+    signature::Keypair, signer::Signer, transaction::Transaction,
+};
+
+use crate::amount::Lamports;
+
+/// Compute unit limit used when simulating, before the real usage is known.
+pub const SIMULATION_COMPUTE_UNIT_LIMIT: u32 = 1_400_000;
+
+/// Extra compute units added on top of the simulated usage.
+pub const COMPUTE_UNIT_MARGIN_PERCENT: u64 = 10;
+pub const MIN_COMPUTE_UNIT_LIMIT: u32 = 1_000;
+
+const MICRO_LAMPORTS_PER_LAMPORT: u64 = 1_000_000;
+
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PriorityFeeStrategy {
+    /// Always use `fixed_micro_lamports` per compute unit.
+    Fixed,
+    /// Use the `percentile` of recent prioritization fees paid for the
+    /// writable accounts of the transaction.
+    Percentile,
+    /// Same as `Percentile`, but the total priority fee never exceeds `max_fee_lamports`.
+    Capped,
+}
+
+impl fmt::Display for PriorityFeeStrategy {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PriorityFeeStrategy::Fixed => write!(f, "Fixed"),
+            PriorityFeeStrategy::Percentile => write!(f, "Percentile"),
+            PriorityFeeStrategy::Capped => write!(f, "Capped"),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+#[serde(default)]
+pub struct PriorityFeeConfig {
+    pub strategy: PriorityFeeStrategy,
+    pub fixed_micro_lamports: u64,
+    pub percentile: u8,
+    pub max_fee_lamports: u64,
+}
+
+impl Default for PriorityFeeConfig {
+    fn default() -> Self {
+        Self {
+            strategy: PriorityFeeStrategy::Capped,
+            fixed_micro_lamports: 10_000,
+            percentile: 75,
+            max_fee_lamports: 100_000,
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct PriorityFeeEstimate {
+    pub compute_unit_limit: u32,
+    /// micro-lamports per compute unit
+    pub compute_unit_price: u64,
+}
+
+impl PriorityFeeEstimate {
+    /// Total priority fee paid on top of the base signature fee.
+    pub fn fee_lamports(&self) -> Lamports {
+        let total = self.compute_unit_limit as u128 * self.compute_unit_price as u128;
+        Lamports(total.div_ceil(MICRO_LAMPORTS_PER_LAMPORT as u128) as u64)
+    }
+
+    pub fn to_display_string(&self) -> String {
+        format!("{:.6} SOL", self.fee_lamports())
+    }
+}
+
+/// Builds a signed transaction with compute budget instructions prepended.
+/// The transaction is simulated first to size the compute unit limit, then
+/// the compute unit price is chosen using the configured strategy.
+/// Also returns the last valid block height of the blockhash used to sign.
+pub async fn build_tx_with_priority_fee(
+    client: &RpcClient,
+    signer: &Keypair,
+    ixs: &[Instruction],
+    fee_config: &PriorityFeeConfig,
+) -> Result<(Transaction, PriorityFeeEstimate, u64), String> {
+    let (blockhash, last_valid_block_height) = client
+        .get_latest_blockhash_with_commitment(CommitmentConfig::confirmed())
+        .await
+        .map_err(|e| format!("Failed to get latest blockhash: {}", e))?;
+
+    let compute_unit_limit = simulate_compute_units(client, signer, ixs).await?;
+    let compute_unit_price =
+        get_compute_unit_price(client, ixs, compute_unit_limit, fee_config).await;
+
+    let estimate = PriorityFeeEstimate {
+        compute_unit_limit,
+        compute_unit_price,
+    };
+
+    let mut final_ixs = compute_budget_ixs(&estimate).to_vec();
+    final_ixs.extend_from_slice(ixs);
+
+    let tx = Transaction::new_signed_with_payer(
+        &final_ixs,
+        Some(&signer.pubkey()),
+        &[signer],
+        blockhash,
+    );
+
+    Ok((tx, estimate, last_valid_block_height))
+}
+
+pub fn compute_budget_ixs(estimate: &PriorityFeeEstimate) -> [Instruction; 2] {
+    [
+        ComputeBudgetInstruction::set_compute_unit_limit(estimate.compute_unit_limit),
+        ComputeBudgetInstruction::set_compute_unit_price(estimate.compute_unit_price),
+    ]
+}
+
+pub async fn simulate_compute_units(
+    client: &RpcClient,
+    signer: &Keypair,
+    ixs: &[Instruction],
+) -> Result<u32, String> {
+    let mut sim_ixs = vec![
+        ComputeBudgetInstruction::set_compute_unit_limit(SIMULATION_COMPUTE_UNIT_LIMIT),
+        ComputeBudgetInstruction::set_compute_unit_price(0),
+    ];
+    sim_ixs.extend_from_slice(ixs);
+
+    let sim_tx = Transaction::new_with_payer(&sim_ixs, Some(&signer.pubkey()));
+    let config = RpcSimulateTransactionConfig {
+        sig_verify: false,
+        replace_recent_blockhash: true,
+        commitment: Some(CommitmentConfig::confirmed()),
+        ..Default::default()
+    };
+
+    let result = client
+        .simulate_transaction_with_config(&sim_tx, config)
+        .await
+        .map_err(|e| format!("Failed to simulate transaction: {}", e))?
+        .value;
+
+    if let Some(err) = result.err {
+        return Err(format!("Simulation failed: {}", err));
+    }
+
+    let units_consumed = result
+        .units_consumed
+        .ok_or("Simulation did not report compute units".to_string())?;
+
+    Ok(compute_unit_limit_with_margin(units_consumed))
+}
+
+/// Simulated usage plus the margin, kept within the limits a transaction accepts.
+pub fn compute_unit_limit_with_margin(units_consumed: u64) -> u32 {
+    let with_margin = units_consumed.saturating_add(units_consumed.saturating_mul(COMPUTE_UNIT_MARGIN_PERCENT) / 100);
+    with_margin.clamp(MIN_COMPUTE_UNIT_LIMIT as u64, SIMULATION_COMPUTE_UNIT_LIMIT as u64) as u32
+}
+
+pub async fn get_compute_unit_price(
+    client: &RpcClient,
+    ixs: &[Instruction],
+    compute_unit_limit: u32,
+    fee_config: &PriorityFeeConfig,
+) -> u64 {
+    match fee_config.strategy {
+        PriorityFeeStrategy::Fixed => fee_config.fixed_micro_lamports,
+        PriorityFeeStrategy::Percentile => recent_fee_or_fixed(client, ixs, fee_config).await,
+        PriorityFeeStrategy::Capped => {
+            let price = recent_fee_or_fixed(client, ixs, fee_config).await;
+            capped_compute_unit_price(price, fee_config.max_fee_lamports, compute_unit_limit)
+        }
+    }
+}
+
+/// Falls back to `fixed_micro_lamports` when the rpc can't provide recent
+/// fees, so a transaction is never blocked on the fee lookup, nor sent
+/// without a priority fee because of it.
+async fn recent_fee_or_fixed(client: &RpcClient, ixs: &[Instruction], fee_config: &PriorityFeeConfig) -> u64 {
+    get_recent_fee_percentile(client, ixs, fee_config.percentile)
+        .await
+        .unwrap_or_else(|e| {
+            error!("{}, using the fixed fee of {} micro-lamports per compute unit", e, fee_config.fixed_micro_lamports);
+            fee_config.fixed_micro_lamports
+        })
+}
+
+/// Lowers `price` so the total priority fee stays within `max_fee_lamports`.
+pub fn capped_compute_unit_price(price: u64, max_fee_lamports: u64, compute_unit_limit: u32) -> u64 {
+    let max_price = max_fee_lamports as u128
+        * MICRO_LAMPORTS_PER_LAMPORT as u128
+        / compute_unit_limit.max(1) as u128;
+
+    price.min(max_price.min(u64::MAX as u128) as u64)
+}
+
+pub async fn get_recent_fee_percentile(
+    client: &RpcClient,
+    ixs: &[Instruction],
+    percentile: u8,
+) -> Result<u64, String> {
+    let accounts = writable_accounts(ixs);
+    let fees = client
+        .get_recent_prioritization_fees(&accounts)
+        .await
+        .map_err(|e| format!("Failed to get recent priority fees: {}", e))?;
+    let mut fees: Vec<u64> = fees.iter().map(|f| f.prioritization_fee).collect();
+    Ok(fee_percentile(&mut fees, percentile))
+}
+
+pub fn fee_percentile(fees: &mut [u64], percentile: u8) -> u64 {
+    if fees.is_empty() {
+        return 0;
+    }
+    fees.sort_unstable();
+    let percentile = percentile.min(100) as usize;
+    let index = (fees.len() - 1) * percentile / 100;
+    fees[index]
+}
+
+pub fn writable_accounts(ixs: &[Instruction]) -> Vec<Pubkey> {
+    let mut accounts: Vec<Pubkey> = vec![];
+    for ix in ixs {
+        for account in ix.accounts.iter() {
+            if account.is_writable && !accounts.contains(&account.pubkey) {
+                accounts.push(account.pubkey);
+            }
+        }
+    }
+    // getRecentPrioritizationFees accepts at most 128 accounts
+    accounts.truncate(128);
+    accounts
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn fee_percentile_of_empty_fees_is_zero() {
+        assert_eq!(fee_percentile(&mut [], 50), 0);
+        assert_eq!(fee_percentile(&mut [], 100), 0);
+    }
+
+    #[test]
+    fn fee_percentile_sorts_and_picks() {
+        let mut fees = [50, 10, 40, 20, 30];
+        assert_eq!(fee_percentile(&mut fees, 0), 10);
+        assert_eq!(fee_percentile(&mut fees, 50), 30);
+        assert_eq!(fee_percentile(&mut fees, 75), 40);
+        assert_eq!(fee_percentile(&mut fees, 100), 50);
+    }
+
+    #[test]
+    fn fee_percentile_rounds_down() {
+        let mut fees = [1, 2, 3, 4];
+        // (4 - 1) * 50 / 100 = 1
+        assert_eq!(fee_percentile(&mut fees, 50), 2);
+        assert_eq!(fee_percentile(&mut fees, 99), 3);
+    }
+
+    #[test]
+    fn fee_percentile_clamps_above_100() {
+        let mut fees = [3, 1, 2];
+        assert_eq!(fee_percentile(&mut fees, 255), 3);
+    }
+
+    #[test]
+    fn fee_percentile_single_fee() {
+        assert_eq!(fee_percentile(&mut [7], 0), 7);
+        assert_eq!(fee_percentile(&mut [7], 100), 7);
+    }
+
+    #[test]
+    fn capped_price_keeps_total_fee_under_max() {
+        // 100_000 lamports over 200_000 units is 500_000 micro-lamports per unit
+        assert_eq!(capped_compute_unit_price(1_000_000, 100_000, 200_000), 500_000);
+        assert_eq!(capped_compute_unit_price(10, 100_000, 200_000), 10);
+        let estimate = PriorityFeeEstimate {
+            compute_unit_limit: 200_000,
+            compute_unit_price: capped_compute_unit_price(u64::MAX, 100_000, 200_000),
+        };
+        assert!(estimate.fee_lamports().0 <= 100_000);
+    }
+
+    #[test]
+    fn capped_price_with_zero_limit_does_not_divide_by_zero() {
+        assert_eq!(capped_compute_unit_price(5, 1, 0), 5);
+        assert_eq!(capped_compute_unit_price(u64::MAX, u64::MAX, 0), u64::MAX);
+    }
+
+    #[test]
+    fn compute_unit_limit_adds_margin_and_clamps() {
+        assert_eq!(compute_unit_limit_with_margin(100_000), 110_000);
+        assert_eq!(compute_unit_limit_with_margin(0), MIN_COMPUTE_UNIT_LIMIT);
+        assert_eq!(compute_unit_limit_with_margin(500), MIN_COMPUTE_UNIT_LIMIT);
+        assert_eq!(compute_unit_limit_with_margin(1_300_000), SIMULATION_COMPUTE_UNIT_LIMIT);
+        assert_eq!(compute_unit_limit_with_margin(u64::MAX), SIMULATION_COMPUTE_UNIT_LIMIT);
+    }
+
+    #[test]
+    fn fee_lamports_rounds_up() {
+        let estimate = PriorityFeeEstimate {
+            compute_unit_limit: 3,
+            compute_unit_price: 1,
+        };
+        assert_eq!(estimate.fee_lamports().0, 1);
+        let estimate = PriorityFeeEstimate {
+            compute_unit_limit: 0,
+            compute_unit_price: 1_000_000,
+        };
+        assert_eq!(estimate.fee_lamports().0, 0);
+    }
+}
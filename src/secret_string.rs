@@ -0,0 +1,151 @@
+use std::fmt;
+
+use zeroize::Zeroize;
+
+/// Room for a typical password, so typing one doesn't reallocate.
+const INITIAL_CAPACITY: usize = 128;
+
+/// String for passwords and other secrets typed into the UI. Its buffer is
+/// zeroed when cleared, when it has to grow and when dropped. It isn't
+/// `Clone`, copies have to be made explicitly with `copy_from`.
+pub struct SecretString {
+    inner: String,
+}
+
+impl SecretString {
+    pub fn new() -> Self {
+        Self {
+            inner: String::with_capacity(INITIAL_CAPACITY),
+        }
+    }
+
+    pub fn copy_from(s: &str) -> Self {
+        let mut secret = Self::new();
+        secret.push_str(s);
+        secret
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.inner
+    }
+
+    pub fn len(&self) -> usize {
+        self.inner.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.inner.is_empty()
+    }
+
+    pub fn push_str(&mut self, s: &str) {
+        let needed = self.inner.len() + s.len();
+        if needed > self.inner.capacity() {
+            // grow into a new buffer here, so the old one can be wiped
+            let mut grown = String::with_capacity(needed.max(self.inner.capacity() * 2));
+            grown.push_str(&self.inner);
+            self.inner.zeroize();
+            self.inner = grown;
+        }
+        self.inner.push_str(s);
+    }
+
+    pub fn pop(&mut self) {
+        if let Some(c) = self.inner.pop() {
+            // overwrite the popped bytes, they are still in the buffer
+            let len = self.inner.len();
+            for _ in 0..c.len_utf8() {
+                self.inner.push('\0');
+            }
+            self.inner.truncate(len);
+        }
+    }
+
+    pub fn clear(&mut self) {
+        self.inner.zeroize();
+    }
+}
+
+impl Default for SecretString {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Drop for SecretString {
+    fn drop(&mut self) {
+        self.clear();
+    }
+}
+
+impl fmt::Debug for SecretString {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("SecretString(***)")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// The whole allocation, spare capacity included.
+    fn buffer(secret: &SecretString) -> &[u8] {
+        unsafe { std::slice::from_raw_parts(secret.inner.as_ptr(), secret.inner.capacity()) }
+    }
+
+    #[test]
+    fn clear_zeroes_the_buffer() {
+        let mut secret = SecretString::copy_from("correct horse battery staple");
+        let capacity = secret.inner.capacity();
+
+        secret.clear();
+
+        assert!(secret.is_empty());
+        // cleared in place, not by moving to a new allocation
+        assert_eq!(secret.inner.capacity(), capacity);
+        assert!(buffer(&secret).iter().all(|&b| b == 0));
+    }
+
+    #[test]
+    fn pop_overwrites_the_removed_char() {
+        let mut secret = SecretString::copy_from("pässword");
+        let full_len = secret.len();
+        secret.pop();
+        secret.pop();
+        secret.pop();
+
+        assert_eq!(secret.as_str(), "pässw");
+        // only the popped bytes, the rest of the spare capacity was never written
+        let len = secret.len();
+        assert!(buffer(&secret)[len..full_len].iter().all(|&b| b == 0));
+    }
+
+    #[test]
+    fn push_str_grows_and_keeps_the_contents() {
+        let long = "x".repeat(INITIAL_CAPACITY + 1);
+        let mut secret = SecretString::copy_from("abc");
+        secret.push_str(&long);
+
+        assert_eq!(secret.len(), 3 + long.len());
+        assert!(secret.as_str().starts_with("abcx"));
+        assert!(secret.inner.capacity() >= INITIAL_CAPACITY * 2);
+
+        secret.clear();
+        assert!(buffer(&secret).iter().all(|&b| b == 0));
+    }
+
+    #[test]
+    fn copy_from_is_a_separate_buffer() {
+        let original = SecretString::copy_from("hunter2");
+        let mut copy = SecretString::copy_from(original.as_str());
+        copy.clear();
+
+        assert_eq!(original.as_str(), "hunter2");
+        assert_ne!(original.inner.as_ptr(), copy.inner.as_ptr());
+    }
+
+    #[test]
+    fn debug_hides_the_value() {
+        let secret = SecretString::copy_from("hunter2");
+        assert_eq!(format!("{:?}", secret), "SecretString(***)");
+    }
+}
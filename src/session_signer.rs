@@ -0,0 +1,45 @@
+use std::sync::Arc;
+
+use solana_sdk::{
+    pubkey::Pubkey,
+    signature::{Keypair, Signature},
+    signer::{Signer, SignerError},
+};
+
+/// Signing capability handed to the mining session. It can sign pool
+/// submissions for its wallet but never hands out the keypair, so it can keep
+/// running after the UI locks and drops its own copy of the wallet.
+#[derive(Clone)]
+pub struct SessionSigner {
+    keypair: Arc<Keypair>,
+}
+
+impl SessionSigner {
+    pub fn new(keypair: Arc<Keypair>) -> Self {
+        Self { keypair }
+    }
+
+    pub fn pubkey(&self) -> Pubkey {
+        self.keypair.pubkey()
+    }
+
+    pub fn sign_message(&self, message: &[u8]) -> Signature {
+        self.keypair.sign_message(message)
+    }
+}
+
+/// Lets the session sign whole transactions, such as a mine tx re-signed
+/// after its blockhash expired.
+impl Signer for SessionSigner {
+    fn try_pubkey(&self) -> Result<Pubkey, SignerError> {
+        Ok(self.keypair.pubkey())
+    }
+
+    fn try_sign_message(&self, message: &[u8]) -> Result<Signature, SignerError> {
+        self.keypair.try_sign_message(message)
+    }
+
+    fn is_interactive(&self) -> bool {
+        false
+    }
+}
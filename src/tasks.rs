@@ -1,23 +1,25 @@
-use std::time::{Duration, Instant};
+use std::{path::PathBuf, time::{Duration, Instant}};
 
+use async_compat::Compat;
 use bevy::{
     prelude::*,
-    tasks::{block_on, futures_lite::future, Task}, winit::{UpdateMode, WinitSettings},
+    tasks::{block_on, futures_lite::future, IoTaskPool, Task}, winit::{UpdateMode, WinitSettings},
 };
 use drillx_2::Solution;
 use ore_api::state::Bus;
-use solana_sdk::{signature::Signature, transaction::Transaction};
-use solana_transaction_status::{TransactionConfirmationStatus, TransactionStatus};
+use solana_client::{nonblocking::rpc_client::RpcClient, rpc_config::RpcSendTransactionConfig};
+use solana_sdk::{commitment_config::CommitmentConfig, signature::Signature, transaction::Transaction};
+use solana_transaction_status::TransactionStatus;
 
 use crate::{
-    ui::{components::{SpinnerIcon, TextTxProcessorTxType, ToggleAutoMineParent, TxPopUpArea}, styles::{hex_black, CURRENT_TX_STATUS_BACKGROUND, FONT_REGULAR, FONT_SIZE_MEDIUM, SPINNER_ICON, TX_POP_UP_BACKGROUND}}, utils::get_unix_timestamp, AppConfig, AppWallet, BussesResource, EventFetchUiDataFromRpc, EventProcessTx, EventSubmitHashTx, EventTxResult, HashStatus, MinerStatusResource, OreAppState, ProofAccountResource, TreasuryAccountResource, TxProcessor, TxStatus, TxType, FAST_DURATION, REGULAR_DURATION
+    amount::{Lamports, OreAmount}, network_stats::{compute_network_stats, MiningProofsUpdate}, ore_utils::get_ore_epoch_duration, priority_fees::PriorityFeeEstimate, tx_engine::{apply_sig_status, expiry_action, ExpiryAction}, transfer::SendPreview, tx_history::TxHistoryPage, ui::{components::{HistoryScreenTxList, SendScreenNode, TextAppBackupStatus, TextHistoryStatus, TextSupportBundleStatus, TextMiningExportStatus, TextSendStatus, SpinnerIcon, TextTxProcessorStatus, TextTxProcessorTxType, ToggleAutoMineParent, TxPopUpArea}, screens::screen_send::spawn_send_confirm_modal, spawn_utils::{spawn_new_list_item, UiListItem}, styles::{FONT_REGULAR, FONT_SIZE_MEDIUM, FONT_SIZE_SMALL, SPINNER_ICON, TX_POP_UP_BACKGROUND}}, utils::get_unix_timestamp, AppWallet, BussesResource, EventFetchUiDataFromRpc, EventProcessTx, EventSubmitHashTx, EventTxResult, HashStatus, MinerStatusResource, MiningProofsResource, MiningSessionResource, OreAppState, ProofAccountResource, SendResource, TreasuryAccountResource, TxCommitmentTimes, TxHistoryResource, TxProcessor, TxProcessorStatus, TxStatus, TxType, FAST_DURATION
 };
 
 // Task Components
 // TODO: tasks should return results so errors can be dealt with by the task handler system
 pub struct TaskUpdateAppWalletSolBalanceData {
-    pub sol_balance: f64,
-    pub ore_balance: f64,
+    pub sol_balance: Lamports,
+    pub ore_balance: OreAmount,
     pub proof_account_data: ProofAccountResource,
     pub treasury_account_data: TreasuryAccountResource,
     pub busses: Vec<Bus>
@@ -51,6 +53,7 @@ pub struct SigCheckResults {
     pub ents: Vec<Entity>,
     pub sigs: Vec<Signature>,
     pub sig_statuses: Vec<Option<TransactionStatus>>,
+    pub block_height: Option<u64>,
 }
 
 #[derive(Component)]
@@ -58,6 +61,45 @@ pub struct TaskSigChecks {
     pub task: Task<Result<SigCheckResults, String>>,
 }
 
+#[derive(Component)]
+pub struct TaskResignTx {
+    // signed tx, signature, last_valid_block_height
+    pub task: Task<Result<(Transaction, Signature, u64), String>>,
+}
+
+#[derive(Component)]
+pub struct TaskFetchMiningProofs {
+    pub task: Task<Result<MiningProofsUpdate, String>>,
+}
+
+#[derive(Component)]
+pub struct TaskPrepareSend {
+    pub task: Task<Result<SendPreview, String>>,
+}
+
+#[derive(Component)]
+pub struct TaskFetchTxHistory {
+    pub task: Task<Result<TxHistoryPage, String>>,
+}
+
+#[derive(Component)]
+pub struct TaskExportMiningHistory {
+    /// Rows written and the file they went to.
+    pub task: Task<Result<(usize, PathBuf), String>>,
+}
+
+#[derive(Component)]
+pub struct TaskCreateAppBackup {
+    /// Files in the backup and where it was written.
+    pub task: Task<Result<(usize, PathBuf), String>>,
+}
+
+#[derive(Component)]
+pub struct TaskCreateSupportBundle {
+    /// Files in the bundle and where it was written.
+    pub task: Task<Result<(usize, PathBuf), String>>,
+}
+
 #[derive(Component)]
 pub struct TaskConfirmTx {
     pub task: Task<Result<Signature, String>>,
@@ -69,11 +111,12 @@ pub struct TaskRegisterWallet {
 }
 
 pub struct TaskProcessTxData {
-    // TODO: change this to enum
-    pub tx_type: String,
+    pub tx_type: TxType,
     pub signature: Option<Signature>,
     pub signed_tx: Option<Transaction>,
-    pub hash_time: Option<(u64, u32)> // hash_time, difficulty
+    pub hash_time: Option<(u64, u32)>, // hash_time, difficulty
+    pub priority_fee: Option<PriorityFeeEstimate>,
+    pub last_valid_block_height: Option<u64>,
 }
 
 #[derive(Component)]
@@ -153,8 +196,7 @@ pub fn task_generate_hash(
                 Ok(result) => {
                     if status == "MINING" {
                         ev_submit_hash_tx.send(EventSubmitHashTx(result));
-                    } else {
-                    }
+                    } 
                 },
                 Err(e) => {
                     error!("Tasks GenerateHash error: {}", e);
@@ -191,13 +233,12 @@ pub fn task_register_wallet(
 pub fn handle_task_process_tx_result(
     mut commands: Commands,
     asset_server: Res<AssetServer>,
-    app_wallet: Res<AppWallet>,
     proof_account: Res<ProofAccountResource>,
     ore_app_state: Res<OreAppState>,
     mut winit_settings: ResMut<WinitSettings>,
     mut query_task_handler: Query<(Entity, &mut TaskProcessTx)>,
     mut event_writer: EventWriter<EventTxResult>,
-    mut query_pop_up: Query<Entity, With<TxPopUpArea>>,
+    query_pop_up: Query<Entity, With<TxPopUpArea>>,
     // mut query: Query<(Entity, &mut TaskProcessTx)>,
 ) {
     for (entity, mut task) in &mut query_task_handler.iter_mut() {
@@ -210,66 +251,38 @@ pub fn handle_task_process_tx_result(
                     //     tx,
                     //     hash_status,
                     // });
-                    let tx_type = task_process_tx_data.tx_type.clone();
+                    let tx_type = task_process_tx_data.tx_type;
                     let tx = task_process_tx_data.signed_tx;
+                    let signature = task_process_tx_data.signature;
                     let hash_status = task_process_tx_data.hash_time;
-                    let tx_type = match tx_type.as_str() {
-                        "Mine" => {
-                            TxType::Mine
-                        },
-                        "Register" => {
-                            TxType::Register
-                        },
-                        "Reset" => {
-                            TxType::ResetEpoch
-                        },
-                        "Claim" => {
-                            TxType::Claim
-                        },
-                        "Stake" => {
-                            TxType::Stake
-                        },
-                        "CreateAta" => {
-                            TxType::CreateAta
-                        },
-                        "Airdrop" => {
-                            let tx_result = EventTxResult {
-                                tx_type: task_process_tx_data.tx_type,
-                                sig: task_process_tx_data.signature.unwrap().to_string(),
-                                tx_time: 0,
-                                hash_status: None,
-                                tx_status: TxStatus {
-                                    status: "SUCCESS".to_string(),
-                                    error: "".to_string(),
-                                }
-
-                            };
-                            event_writer.send(tx_result);
-                            commands.entity(entity).remove::<TaskProcessTx>();
-                            continue;
-                        },
-                        _ => {
-                            error!("Invalid tx_type, stop using strings....");
-                            commands.entity(entity).remove::<TaskProcessTx>();
-                            continue;
-                        }
-                    };
+                    let priority_fee = task_process_tx_data.priority_fee;
+                    let last_valid_block_height = task_process_tx_data.last_valid_block_height.unwrap_or(u64::MAX);
+                    if tx_type == TxType::Airdrop {
+                        let tx_result = EventTxResult {
+                            tx_type,
+                            sig: signature.map(|s| s.to_string()).unwrap_or_default(),
+                            tx_time: 0,
+                            hash_status: None,
+                            tx_status: TxStatus {
+                                status: "SUCCESS".to_string(),
+                                error: "".to_string(),
+                            },
+                            commitment_times: TxCommitmentTimes::default(),
+                            priority_fee: None,
+                        };
+                        event_writer.send(tx_result);
+                        commands.entity(entity).remove::<TaskProcessTx>();
+                        continue;
+                    }
 
-                    let hash_status = if let Some(hash_status) = hash_status {
-                        Some(HashStatus {
+                    let hash_status = hash_status.map(|hash_status| HashStatus {
                             hash_time: hash_status.0,
                             hash_difficulty: hash_status.1,
-                        })
-                    } else {
-                        None
-                    };
+                        });
 
-                    let tx_send_interval = 2000;//ore_app_state.config.tx_send_interval;
-                    let timer = Timer::new(Duration::from_millis(tx_send_interval), TimerMode::Once);
+                    let tx_send_interval = ore_app_state.config.tx_send_interval;
+                    let timer = Timer::new(Duration::from_millis(tx_send_interval), TimerMode::Repeating);
 
-                    let pop_up_area = query_pop_up.single_mut();
-
-                    let sol_balance = app_wallet.sol_balance;
                     let staked_balance = Some(proof_account.stake);
 
                     let new_tx = commands.spawn((
@@ -288,14 +301,17 @@ pub fn handle_task_process_tx_result(
                         },
                         UiImage::new(asset_server.load(TX_POP_UP_BACKGROUND)),
                         TxProcessor {
-                            tx_type: tx_type.clone(),
-                            status: "SENDING".to_string(),
+                            tx_type,
+                            status: TxProcessorStatus::Sending,
                             error: "".to_string(),
-                            sol_balance,
                             staked_balance,
-                            signature: None,
+                            signature,
                             signed_tx: tx,
                             hash_status,
+                            priority_fee,
+                            last_valid_block_height,
+                            resign_count: 0,
+                            commitment_times: TxCommitmentTimes::default(),
                             created_at: Instant::now(),
                             challenge: proof_account.challenge.clone(),
                             send_and_confirm_interval: timer,
@@ -319,12 +335,25 @@ pub fn handle_task_process_tx_result(
                                 TextStyle {
                                     font: asset_server.load(FONT_REGULAR),
                                     font_size: FONT_SIZE_MEDIUM,
-                                    color: Color::ORANGE.into(),
+                                    color: Color::ORANGE,
                                 },
                             ),
-                            Name::new("TextTxProcessorTxType"),
-                            TextTxProcessorTxType,
+                            Name::new("TextTxProcessorStatus"),
+                            TextTxProcessorStatus,
                         ));
+                        if let Some(priority_fee) = priority_fee {
+                            parent.spawn((
+                                TextBundle::from_section(
+                                    priority_fee.to_display_string(),
+                                    TextStyle {
+                                        font: asset_server.load(FONT_REGULAR),
+                                        font_size: FONT_SIZE_SMALL,
+                                        color: Color::hex("#FFFFFF").unwrap(),
+                                    },
+                                ),
+                                Name::new("TextTxProcessorPriorityFee"),
+                            ));
+                        }
                         parent.spawn((
                             NodeBundle {
                                 background_color: Color::WHITE.into(),
@@ -341,7 +370,15 @@ pub fn handle_task_process_tx_result(
                         ));
                     }).id();
 
-                    commands.entity(pop_up_area).add_child(new_tx);
+                    // the tx is still tracked without a popup area to show it in
+                    match query_pop_up.get_single() {
+                        Ok(pop_up_area) => {
+                            commands.entity(pop_up_area).add_child(new_tx);
+                        },
+                        Err(_) => {
+                            commands.entity(new_tx).insert(Visibility::Hidden);
+                        },
+                    }
 
                     winit_settings.focused_mode = UpdateMode::ReactiveLowPower { wait: FAST_DURATION };
                     winit_settings.unfocused_mode = UpdateMode::ReactiveLowPower { wait: FAST_DURATION };
@@ -360,8 +397,9 @@ pub fn handle_task_process_tx_result(
                         tx_status: TxStatus {
                             status: "FAILED".to_string(),
                             error: error_str.clone(),
-                        }
-
+                        },
+                        commitment_times: TxCommitmentTimes::default(),
+                        priority_fee: task_process_tx_data.priority_fee,
                     };
                     event_writer.send(tx_result);
                 }
@@ -379,8 +417,8 @@ pub fn handle_task_send_tx_result(
     for (entity, mut task, mut tx_processor) in &mut query.iter_mut() {
         if let Some(send_tx_result) = block_on(future::poll_once(&mut task.task)) {
             // the txn's are sent on an interval, only successfull sends will 
-            // return the sig.
-            // Txn's will expire after about 80's automatically
+            // return the sig. Expiry is decided from the blockhash's
+            // last valid block height in handle_task_got_sig_checks.
             if let Ok(sig) = send_tx_result {
                 tx_processor.signature = Some(sig);
             } 
@@ -398,38 +436,7 @@ pub fn handle_task_tx_sig_check_results(
             match signature_status {
                 Ok(sig_status) => {
                     if let Some(sig_status) = sig_status {
-                        if let Some(confirmation_status) = &sig_status.confirmation_status {
-                            let current_commitment = confirmation_status;
-                            let mut status;
-                            let mut error = "".to_string();
-                            match current_commitment {
-                                TransactionConfirmationStatus::Processed => {
-                                    match &sig_status.status {
-                                        Ok(_) => {
-                                            status = "PROCESSED".to_string();
-                                        }
-                                        Err(e) => {
-                                            status = "FAILED".to_string();
-                                            error = e.to_string();
-                                        }
-                                    }
-                                }
-                                TransactionConfirmationStatus::Confirmed
-                                | TransactionConfirmationStatus::Finalized => {
-                                    match &sig_status.status {
-                                        Ok(_) => {
-                                            status = "SUCCESS".to_string();
-                                        }
-                                        Err(e) => {
-                                            status = "FAILED".to_string();
-                                            error = e.to_string();
-                                        }
-                                    }
-                                }
-                            }
-                            tx_processor.status = status;
-                            tx_processor.error = error;
-                        }
+                        apply_sig_status(&mut tx_processor, &sig_status);
                     }
                 },
                 Err(e) => {
@@ -443,8 +450,10 @@ pub fn handle_task_tx_sig_check_results(
 
 pub fn handle_task_got_sig_checks(
     mut commands: Commands,
+    mining_session: Res<MiningSessionResource>,
+    ore_app_state: Res<OreAppState>,
     mut query: Query<(Entity, &mut TaskSigChecks)>,
-    mut query_tx_processors: Query<&mut TxProcessor>,
+    mut query_tx_processors: Query<(Entity, &mut TxProcessor)>,
 ) {
     for (entity, mut task) in &mut query.iter_mut() {
         if let Some(signature_status) = block_on(future::poll_once(&mut task.task)) {
@@ -457,43 +466,11 @@ pub fn handle_task_got_sig_checks(
                     if ents.len() == sigs.len() && sigs.len() == sig_statuses.len() {
                         for i in 0..ents.len() {
                             let ent = ents[i];
-                            let sig_status = &sig_statuses[i];
-
-                            if let Some(sig_status) = sig_status {
-                                if let Some(confirmation_status) = &sig_status.confirmation_status {
-                                    let current_commitment = confirmation_status;
-                                    let status;
-                                    let mut error = "".to_string();
-                                    match current_commitment {
-                                        TransactionConfirmationStatus::Processed => {
-                                            match &sig_status.status {
-                                                Ok(_) => {
-                                                    status = "PROCESSED".to_string();
-                                                }
-                                                Err(e) => {
-                                                    status = "FAILED".to_string();
-                                                    error = e.to_string();
-                                                }
-                                            }
-                                        }
-                                        TransactionConfirmationStatus::Confirmed
-                                        | TransactionConfirmationStatus::Finalized => {
-                                            match &sig_status.status {
-                                                Ok(_) => {
-                                                    status = "SUCCESS".to_string();
-                                                }
-                                                Err(e) => {
-                                                    status = "FAILED".to_string();
-                                                    error = e.to_string();
-                                                }
-                                            }
-                                        }
-                                    }
-
-                                    // let tx_processor = query_tx_processors.get_mut(ent);
-                                    if let Ok(mut tx_processor) = query_tx_processors.get_mut(ent) {
-                                        tx_processor.status = status;
-                                        tx_processor.error = error;
+                            if let Some(sig_status) = &sig_statuses[i] {
+                                if let Ok((_, mut tx_processor)) = query_tx_processors.get_mut(ent) {
+                                    // a rebroadcast or re-sign may have replaced the signature
+                                    if tx_processor.signature == Some(sigs[i]) {
+                                        apply_sig_status(&mut tx_processor, sig_status);
                                     }
                                 }
                             }
@@ -501,6 +478,58 @@ pub fn handle_task_got_sig_checks(
                     } else {
                         error!("Error: sigs check ents, sigs, sig_statuses lengths missmatch.");
                     }
+
+                    if let Some(block_height) = sig_check_results.block_height {
+                        // the session signer outlives the UI's wallet, so a
+                        // lock while mining doesn't stop re-signing
+                        let signer = mining_session.signer.clone();
+                        for (tx_entity, mut tx_processor) in query_tx_processors.iter_mut() {
+                            let action = expiry_action(&tx_processor, block_height, &ore_app_state.config, signer.is_some());
+                            if action == ExpiryAction::Wait {
+                                continue;
+                            }
+
+                            if let (ExpiryAction::Resign, Some(signer), Some(tx)) = (action, signer.clone(), tx_processor.signed_tx.clone()) {
+                                tx_processor.resign_count += 1;
+                                // don't trigger again while the re-sign task is running
+                                tx_processor.last_valid_block_height = u64::MAX;
+
+                                let rpc_url = ore_app_state.config.rpc_url.clone();
+                                let pool = IoTaskPool::get();
+                                let task = pool.spawn(Compat::new(async move {
+                                    let client = RpcClient::new(rpc_url);
+                                    let (blockhash, last_valid_block_height) = client
+                                        .get_latest_blockhash_with_commitment(CommitmentConfig::confirmed())
+                                        .await
+                                        .map_err(|e| e.to_string())?;
+
+                                    let mut tx = tx;
+                                    tx.try_sign(&[&signer], blockhash)
+                                        .map_err(|e| e.to_string())?;
+
+                                    let send_config = RpcSendTransactionConfig {
+                                        skip_preflight: true,
+                                        max_retries: Some(0),
+                                        ..Default::default()
+                                    };
+                                    let sig = client
+                                        .send_transaction_with_config(&tx, send_config)
+                                        .await
+                                        .map_err(|e| e.to_string())?;
+
+                                    Ok((tx, sig, last_valid_block_height))
+                                }));
+
+                                commands.entity(tx_entity).insert(TaskResignTx { task });
+                            } else {
+                                tx_processor.status = TxProcessorStatus::Expired;
+                                tx_processor.error = format!(
+                                    "Blockhash expired at block height {}",
+                                    block_height
+                                );
+                            }
+                        }
+                    }
                 },
                 Err(e) => {
                     error!("Error checking tx status: {}", e);
@@ -510,3 +539,243 @@ pub fn handle_task_got_sig_checks(
         }
     }
 }
+
+pub fn handle_task_resign_tx_result(
+    mut commands: Commands,
+    mut query: Query<(Entity, &mut TaskResignTx, &mut TxProcessor)>,
+) {
+    for (entity, mut task, mut tx_processor) in &mut query.iter_mut() {
+        if let Some(result) = block_on(future::poll_once(&mut task.task)) {
+            match result {
+                Ok((tx, sig, last_valid_block_height)) => {
+                    tx_processor.signed_tx = Some(tx);
+                    tx_processor.signature = Some(sig);
+                    tx_processor.last_valid_block_height = last_valid_block_height;
+                    tx_processor.send_and_confirm_interval.reset();
+                },
+                Err(e) => {
+                    tx_processor.status = TxProcessorStatus::Expired;
+                    tx_processor.error = format!("Failed to re-sign expired tx: {}", e);
+                }
+            }
+            commands.entity(entity).remove::<TaskResignTx>();
+        }
+    }
+}
+
+pub fn handle_task_fetch_tx_history_result(
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+    mut tx_history: ResMut<TxHistoryResource>,
+    mut query: Query<(Entity, &mut TaskFetchTxHistory)>,
+    query_history_list: Query<Entity, With<HistoryScreenTxList>>,
+    mut query_status_text: Query<&mut Text, With<TextHistoryStatus>>,
+) {
+    for (entity, mut task) in &mut query.iter_mut() {
+        if let Some(result) = block_on(future::poll_once(&mut task.task)) {
+            tx_history.loading = false;
+            let status = match result {
+                Ok(page) => {
+                    tx_history.cursors = page.cursors;
+                    // the screen may have been closed while loading
+                    if let Ok(list_entity) = query_history_list.get_single() {
+                        for entry in page.entries {
+                            let item = UiListItem {
+                                id: entry.kind.clone(),
+                                landed_at: entry.time_string(),
+                                sig: entry.signature.clone(),
+                                tx_time: entry.amount.clone(),
+                                hash_time: format!("{:.6} - {}", Lamports(entry.fee), entry.slot),
+                                status: entry.status.clone(),
+                            };
+                            let use_light_background = tx_history.items_loaded.is_multiple_of(2);
+                            spawn_new_list_item(&mut commands, &asset_server, list_entity, item, use_light_background);
+                            tx_history.items_loaded += 1;
+                        }
+                    }
+
+                    if tx_history.cursors.is_exhausted() {
+                        format!("{} transactions, no more history", tx_history.items_loaded)
+                    } else {
+                        format!("{} transactions", tx_history.items_loaded)
+                    }
+                },
+                Err(e) => {
+                    error!("Failed to fetch tx history: {}", e);
+                    "Failed to load history".to_string()
+                }
+            };
+
+            if let Ok(mut text) = query_status_text.get_single_mut() {
+                text.sections[0].value = status;
+            }
+
+            commands.entity(entity).remove::<TaskFetchTxHistory>();
+        }
+    }
+}
+
+pub fn handle_task_prepare_send_result(
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+    mut send_res: ResMut<SendResource>,
+    mut query: Query<(Entity, &mut TaskPrepareSend)>,
+    query_send_screen: Query<Entity, With<SendScreenNode>>,
+    mut query_status_text: Query<&mut Text, With<TextSendStatus>>,
+) {
+    for (entity, mut task) in &mut query.iter_mut() {
+        if let Some(result) = block_on(future::poll_once(&mut task.task)) {
+            send_res.preparing = false;
+            let status = match result {
+                Ok(preview) => {
+                    if let Ok(screen_entity) = query_send_screen.get_single() {
+                        commands.entity(screen_entity).with_children(|parent| {
+                            spawn_send_confirm_modal(parent, &asset_server, &preview);
+                        });
+                        send_res.pending = Some(preview);
+                    }
+                    "Review the transfer".to_string()
+                },
+                Err(e) => {
+                    error!("Failed to prepare transfer: {}", e);
+                    e
+                }
+            };
+
+            if let Ok(mut text) = query_status_text.get_single_mut() {
+                text.sections[0].value = status;
+            }
+
+            commands.entity(entity).remove::<TaskPrepareSend>();
+        }
+    }
+}
+
+pub fn handle_task_fetch_mining_proofs_result(
+    mut commands: Commands,
+    mut mining_proofs_res: ResMut<MiningProofsResource>,
+    treasury_res: Res<TreasuryAccountResource>,
+    mut query: Query<(Entity, &mut TaskFetchMiningProofs)>,
+) {
+    for (entity, mut task) in &mut query.iter_mut() {
+        if let Some(result) = block_on(future::poll_once(&mut task.task)) {
+            match result {
+                Ok(update) => {
+                    if update.full {
+                        mining_proofs_res.proofs.clear();
+                    }
+                    mining_proofs_res.proofs.extend(update.proofs);
+
+                    let epoch_duration = get_ore_epoch_duration();
+                    let epoch_start = if treasury_res.last_reset_at > 0 {
+                        treasury_res.last_reset_at
+                    } else {
+                        let now = get_unix_timestamp() as i64;
+                        now - now % epoch_duration
+                    };
+                    let stats = compute_network_stats(&mining_proofs_res.proofs, epoch_start, epoch_duration);
+
+                    mining_proofs_res.miners_this_epoch = stats.miners_this_epoch;
+                    mining_proofs_res.miners_last_epoch = stats.miners_last_epoch;
+                    mining_proofs_res.largest_difficulty_seen = mining_proofs_res
+                        .largest_difficulty_seen
+                        .max(stats.highest_difficulty);
+                    mining_proofs_res.top_staker = stats.top_staker;
+                    mining_proofs_res.total_stake = stats.total_stake;
+                    mining_proofs_res.stake_distribution = stats.stake_distribution;
+                },
+                Err(e) => {
+                    error!("Failed to fetch mining proofs: {}", e);
+                    // retry the full reload on the next refresh
+                    if mining_proofs_res.proofs.is_empty() {
+                        mining_proofs_res.last_full_reload = None;
+                    }
+                }
+            }
+
+            commands.entity(entity).remove::<TaskFetchMiningProofs>();
+        }
+    }
+}
+
+pub fn handle_task_export_mining_history_result(
+    mut commands: Commands,
+    mut query: Query<(Entity, &mut TaskExportMiningHistory)>,
+    mut query_status_text: Query<&mut Text, With<TextMiningExportStatus>>,
+) {
+    for (entity, mut task) in &mut query.iter_mut() {
+        if let Some(result) = block_on(future::poll_once(&mut task.task)) {
+            let status = match result {
+                Ok((rows, path)) => {
+                    info!("Exported {} mining history rows to {}", rows, path.display());
+                    format!("Exported {} rows to {}", rows, path.display())
+                },
+                Err(e) => {
+                    error!("Failed to export mining history: {}", e);
+                    format!("Export failed: {}", e)
+                }
+            };
+
+            // the screen may have been closed while exporting
+            if let Ok(mut text) = query_status_text.get_single_mut() {
+                text.sections[0].value = status;
+            }
+
+            commands.entity(entity).remove::<TaskExportMiningHistory>();
+        }
+    }
+}
+
+pub fn handle_task_create_app_backup_result(
+    mut commands: Commands,
+    mut query: Query<(Entity, &mut TaskCreateAppBackup)>,
+    mut query_status_text: Query<&mut Text, With<TextAppBackupStatus>>,
+) {
+    for (entity, mut task) in &mut query.iter_mut() {
+        if let Some(result) = block_on(future::poll_once(&mut task.task)) {
+            let status = match result {
+                Ok((files, path)) => {
+                    info!("Backed up {} files to {}", files, path.display());
+                    format!("Backed up {} files to {}", files, path.display())
+                },
+                Err(e) => {
+                    error!("Failed to create backup: {}", e);
+                    format!("Backup failed: {}", e)
+                }
+            };
+
+            if let Ok(mut text) = query_status_text.get_single_mut() {
+                text.sections[0].value = status;
+            }
+
+            commands.entity(entity).remove::<TaskCreateAppBackup>();
+        }
+    }
+}
+
+pub fn handle_task_create_support_bundle_result(
+    mut commands: Commands,
+    mut query: Query<(Entity, &mut TaskCreateSupportBundle)>,
+    mut query_status_text: Query<&mut Text, With<TextSupportBundleStatus>>,
+) {
+    for (entity, mut task) in &mut query.iter_mut() {
+        if let Some(result) = block_on(future::poll_once(&mut task.task)) {
+            let status = match result {
+                Ok((files, path)) => {
+                    info!("Support bundle with {} files written to {}", files, path.display());
+                    format!("Saved to {}", path.display())
+                },
+                Err(e) => {
+                    error!("Failed to create support bundle: {}", e);
+                    format!("Support bundle failed: {}", e)
+                }
+            };
+
+            if let Ok(mut text) = query_status_text.get_single_mut() {
+                text.sections[0].value = status;
+            }
+
+            commands.entity(entity).remove::<TaskCreateSupportBundle>();
+        }
+    }
+}
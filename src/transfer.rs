@@ -0,0 +1,215 @@
+use std::{fmt, str::FromStr};
+
+use solana_client::{
+    nonblocking::rpc_client::RpcClient, rpc_config::RpcSimulateTransactionConfig,
+};
+use solana_sdk::{
+    commitment_config::CommitmentConfig, instruction::Instruction,
+    program_pack::Pack, pubkey::Pubkey, signature::Keypair, signer::Signer,
+    system_instruction, transaction::Transaction,
+};
+use spl_associated_token_account::{
+    get_associated_token_address, instruction::create_associated_token_account_idempotent,
+};
+
+use crate::{
+    amount::{parse_units, Lamports, OreAmount, SOL_DECIMALS},
+    ore_utils::get_ore_mint,
+    priority_fees::{build_tx_with_priority_fee, PriorityFeeConfig, PriorityFeeEstimate},
+};
+
+pub const LAMPORTS_PER_SIGNATURE: Lamports = Lamports(5000);
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SendToken {
+    Sol,
+    Ore,
+}
+
+impl SendToken {
+    pub fn decimals(&self) -> u8 {
+        match self {
+            SendToken::Sol => SOL_DECIMALS,
+            SendToken::Ore => OreAmount::DECIMALS,
+        }
+    }
+
+    pub fn toggle(&self) -> Self {
+        match self {
+            SendToken::Sol => SendToken::Ore,
+            SendToken::Ore => SendToken::Sol,
+        }
+    }
+}
+
+impl fmt::Display for SendToken {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SendToken::Sol => write!(f, "SOL"),
+            SendToken::Ore => write!(f, "ORE"),
+        }
+    }
+}
+
+/// A signed transfer that has been simulated but not sent yet.
+#[derive(Clone)]
+pub struct SendPreview {
+    pub token: SendToken,
+    pub recipient: Pubkey,
+    pub amount: u64,
+    pub tx: Transaction,
+    pub priority_fee: PriorityFeeEstimate,
+    pub last_valid_block_height: u64,
+    pub network_fee: Lamports,
+    /// Rent paid for the recipient token account, 0 when it already exists.
+    pub ata_rent: Lamports,
+    pub units_consumed: Option<u64>,
+    pub simulation_error: Option<String>,
+}
+
+impl SendPreview {
+    /// Total lamports leaving the wallet, including the amount for SOL sends.
+    /// Saturates, a cost that overflows is more than any balance anyway.
+    pub fn total_sol_cost(&self) -> Lamports {
+        let amount = if self.token == SendToken::Sol { Lamports(self.amount) } else { Lamports::ZERO };
+        amount
+            .saturating_add(self.network_fee)
+            .saturating_add(self.priority_fee.fee_lamports())
+            .saturating_add(self.ata_rent)
+    }
+
+    pub fn is_ok(&self) -> bool {
+        self.simulation_error.is_none()
+    }
+}
+
+/// Checks the recipient and amount against the balances the app has already fetched.
+pub fn validate_send(
+    sender: &Pubkey,
+    recipient: &str,
+    amount: &str,
+    token: SendToken,
+    sol_balance: Lamports,
+    ore_balance: OreAmount,
+) -> Result<(Pubkey, u64), String> {
+    let recipient = Pubkey::from_str(recipient.trim())
+        .map_err(|_| "Invalid recipient address".to_string())?;
+    if recipient == *sender {
+        return Err("Recipient is this wallet".to_string());
+    }
+
+    let amount = parse_units(amount, token.decimals())?;
+    if amount == 0 {
+        return Err("Amount must be greater than 0".to_string());
+    }
+
+    let balance = match token {
+        SendToken::Sol => sol_balance.0,
+        SendToken::Ore => ore_balance.0,
+    };
+    if amount > balance {
+        return Err(format!("Insufficient {} balance", token));
+    }
+
+    Ok((recipient, amount))
+}
+
+/// Builds, signs and simulates the transfer. The recipient's ORE token account
+/// is created in the same transaction when it doesn't exist yet.
+pub async fn prepare_send(
+    client: &RpcClient,
+    signer: &Keypair,
+    recipient: Pubkey,
+    token: SendToken,
+    amount: u64,
+    sol_balance: Lamports,
+    fee_config: &PriorityFeeConfig,
+) -> Result<SendPreview, String> {
+    let sender = signer.pubkey();
+    let mut ata_rent = Lamports::ZERO;
+    let mut ixs: Vec<Instruction> = vec![];
+    match token {
+        SendToken::Sol => {
+            ixs.push(system_instruction::transfer(&sender, &recipient, amount));
+        },
+        SendToken::Ore => {
+            let mint = get_ore_mint();
+            let source = get_associated_token_address(&sender, &mint);
+            let destination = get_associated_token_address(&recipient, &mint);
+            // only a confirmed missing account is created, an rpc error
+            // isn't taken to mean the account doesn't exist
+            let destination_account = client
+                .get_account_with_commitment(&destination, CommitmentConfig::confirmed())
+                .await
+                .map_err(|e| format!("Failed to check the recipient token account: {}", e))?
+                .value;
+            if destination_account.is_none() {
+                ata_rent = client
+                    .get_minimum_balance_for_rent_exemption(spl_token::state::Account::LEN)
+                    .await
+                    .map(Lamports)
+                    .map_err(|e| format!("Failed to get rent: {}", e))?;
+                // idempotent, in case the account is created before this lands
+                ixs.push(create_associated_token_account_idempotent(
+                    &sender,
+                    &recipient,
+                    &mint,
+                    &spl_token::id(),
+                ));
+            }
+            ixs.push(
+                spl_token::instruction::transfer_checked(
+                    &spl_token::id(),
+                    &source,
+                    &mint,
+                    &destination,
+                    &sender,
+                    &[],
+                    amount,
+                    OreAmount::DECIMALS,
+                )
+                .map_err(|e| format!("Failed to build transfer: {}", e))?,
+            );
+        },
+    }
+
+    let (tx, priority_fee, last_valid_block_height) =
+        build_tx_with_priority_fee(client, signer, &ixs, fee_config).await?;
+
+    let sim_config = RpcSimulateTransactionConfig {
+        sig_verify: true,
+        commitment: Some(CommitmentConfig::confirmed()),
+        ..Default::default()
+    };
+    let (units_consumed, mut simulation_error) =
+        match client.simulate_transaction_with_config(&tx, sim_config).await {
+            Ok(result) => (
+                result.value.units_consumed,
+                result.value.err.map(|e| e.to_string()),
+            ),
+            Err(e) => (None, Some(e.to_string())),
+        };
+
+    let network_fee = Lamports(LAMPORTS_PER_SIGNATURE.0 * tx.message.header.num_required_signatures as u64);
+    let preview = SendPreview {
+        token,
+        recipient,
+        amount,
+        tx,
+        priority_fee,
+        last_valid_block_height,
+        network_fee,
+        ata_rent,
+        units_consumed,
+        simulation_error: None,
+    };
+
+    if simulation_error.is_none() && preview.total_sol_cost() > sol_balance {
+        simulation_error = Some("Insufficient SOL to cover the amount and fees".to_string());
+    }
+
+    Ok(SendPreview {
+        simulation_error,
+        ..preview
+    })
+}
@@ -0,0 +1,393 @@
+use std::time::Duration;
+
+use async_compat::Compat;
+use bevy::{prelude::*, tasks::IoTaskPool};
+use solana_client::{nonblocking::rpc_client::RpcClient, rpc_config::RpcSendTransactionConfig};
+use solana_sdk::{commitment_config::CommitmentConfig, signature::Signature};
+use solana_transaction_status::{TransactionConfirmationStatus, TransactionStatus};
+
+use crate::{
+    tasks::{SigCheckResults, TaskSendTx, TaskSigChecks},
+    AppConfig, EntityTaskHandler, EventCheckSigs, EventTxResult, OreAppState, ProofAccountResource,
+    TxProcessor, TxProcessorStatus, TxStatus, TxType,
+};
+
+/// getSignatureStatuses accepts at most 256 signatures per request.
+pub const MAX_SIG_STATUSES_PER_REQUEST: usize = 256;
+
+pub struct SigChecksTimer {
+    timer: Timer,
+}
+
+impl Default for SigChecksTimer {
+    fn default() -> Self {
+        Self {
+            timer: Timer::new(Duration::from_millis(1000), TimerMode::Once)
+        }
+    }
+}
+
+pub fn tx_processors_sigs_check(
+    mut event_writer: EventWriter<EventCheckSigs>,
+    mut sig_checks_timer: Local<SigChecksTimer>,
+    time: Res<Time>
+) {
+    sig_checks_timer.timer.tick(time.delta());
+    if sig_checks_timer.timer.just_finished() {
+        event_writer.send(EventCheckSigs);
+        sig_checks_timer.timer.reset();
+    }
+}
+
+/// Re-sends every transaction that hasn't landed yet on the configured interval.
+pub fn tx_processor_rebroadcast(
+    mut commands: Commands,
+    time: Res<Time>,
+    ore_app_state: Res<OreAppState>,
+    mut query: Query<(Entity, &mut TxProcessor), Without<TaskSendTx>>,
+) {
+    for (entity, mut tx_processor) in query.iter_mut() {
+        if tx_processor.status != TxProcessorStatus::Sending {
+            continue;
+        }
+        tx_processor.send_and_confirm_interval.tick(time.delta());
+        if !tx_processor.send_and_confirm_interval.just_finished() {
+            continue;
+        }
+
+        if let Some(tx) = tx_processor.signed_tx.clone() {
+            let rpc_url = ore_app_state.config.rpc_url.clone();
+            let pool = IoTaskPool::get();
+            let task = pool.spawn(Compat::new(async move {
+                let client = RpcClient::new(rpc_url);
+                let send_config = RpcSendTransactionConfig {
+                    skip_preflight: true,
+                    max_retries: Some(0),
+                    ..Default::default()
+                };
+
+                client
+                    .send_transaction_with_config(&tx, send_config)
+                    .await
+                    .map_err(|e| e.to_string())
+            }));
+
+            commands.entity(entity).insert(TaskSendTx { task });
+        }
+    }
+}
+
+pub fn handle_event_check_sigs(
+    mut commands: Commands,
+    mut event_reader: EventReader<EventCheckSigs>,
+    ore_app_state: Res<OreAppState>,
+    query_task_handler: Query<Entity, (With<EntityTaskHandler>, Without<TaskSigChecks>)>,
+    query_tx_processors: Query<(Entity, &TxProcessor)>,
+) {
+    for _ev in event_reader.read() {
+        let task_handler_entity = if let Ok(entity) = query_task_handler.get_single() {
+            entity
+        } else {
+            // previous batch is still in flight
+            continue;
+        };
+
+        let mut has_pending = false;
+        let mut ents = vec![];
+        let mut sigs = vec![];
+        for (entity, tx_processor) in query_tx_processors.iter() {
+            if !tx_processor.status.needs_status_check() {
+                continue;
+            }
+            has_pending = true;
+            if let Some(sig) = tx_processor.signature {
+                ents.push(entity);
+                sigs.push(sig);
+            }
+        }
+
+        if !has_pending {
+            continue;
+        }
+
+        let rpc_url = ore_app_state.config.rpc_url.clone();
+        let pool = IoTaskPool::get();
+        let task = pool.spawn(Compat::new(async move {
+            let client = RpcClient::new(rpc_url);
+
+            let mut sig_statuses = vec![];
+            for chunk in sig_status_batches(&sigs) {
+                match client.get_signature_statuses(chunk).await {
+                    Ok(statuses) => {
+                        sig_statuses.extend(statuses.value);
+                    },
+                    Err(e) => {
+                        return Err(e.to_string());
+                    }
+                }
+            }
+
+            let block_height = client
+                .get_block_height_with_commitment(CommitmentConfig::confirmed())
+                .await
+                .ok();
+
+            Ok(SigCheckResults {
+                ents,
+                sigs,
+                sig_statuses,
+                block_height,
+            })
+        }));
+
+        commands
+            .entity(task_handler_entity)
+            .insert(TaskSigChecks { task });
+    }
+}
+
+/// The getSignatureStatuses requests needed for `sigs`.
+pub fn sig_status_batches(sigs: &[Signature]) -> std::slice::Chunks<'_, Signature> {
+    sigs.chunks(MAX_SIG_STATUSES_PER_REQUEST)
+}
+
+/// What happens to a transaction once the chain reaches `block_height`.
+#[derive(Debug, PartialEq, Eq)]
+pub enum ExpiryAction {
+    /// Still valid, or no longer sending.
+    Wait,
+    /// Past its blockhash, re-sign it with a fresh one.
+    Resign,
+    /// Past its blockhash and out of re-signs.
+    Expire,
+}
+
+pub fn expiry_action(
+    tx_processor: &TxProcessor,
+    block_height: u64,
+    config: &AppConfig,
+    has_signer: bool,
+) -> ExpiryAction {
+    if tx_processor.status != TxProcessorStatus::Sending
+        || block_height <= tx_processor.last_valid_block_height
+    {
+        return ExpiryAction::Wait;
+    }
+
+    let can_resign = config.tx_resign_on_expiry
+        && tx_processor.resign_count < config.tx_max_resigns
+        && tx_processor.signed_tx.is_some()
+        && has_signer;
+    if can_resign {
+        ExpiryAction::Resign
+    } else {
+        ExpiryAction::Expire
+    }
+}
+
+/// Applies a signature status to the tx processor, recording the time each
+/// commitment level was first seen.
+pub fn apply_sig_status(tx_processor: &mut TxProcessor, sig_status: &TransactionStatus) {
+    let confirmation_status = if let Some(confirmation_status) = &sig_status.confirmation_status {
+        confirmation_status
+    } else {
+        return;
+    };
+
+    if let Err(e) = &sig_status.status {
+        tx_processor.status = TxProcessorStatus::Failed;
+        tx_processor.error = e.to_string();
+        return;
+    }
+
+    let elapsed = tx_processor.created_at.elapsed().as_millis() as u64;
+    let times = &mut tx_processor.commitment_times;
+    match confirmation_status {
+        TransactionConfirmationStatus::Processed => {
+            times.processed.get_or_insert(elapsed);
+            tx_processor.status = TxProcessorStatus::Processed;
+        },
+        TransactionConfirmationStatus::Confirmed => {
+            times.processed.get_or_insert(elapsed);
+            times.confirmed.get_or_insert(elapsed);
+            tx_processor.status = TxProcessorStatus::Confirmed;
+        },
+        TransactionConfirmationStatus::Finalized => {
+            times.processed.get_or_insert(elapsed);
+            times.confirmed.get_or_insert(elapsed);
+            times.finalized.get_or_insert(elapsed);
+            tx_processor.status = TxProcessorStatus::Finalized;
+        },
+    }
+}
+
+/// Emits one EventTxResult per transaction once it reaches a final status.
+pub fn tx_processor_result_checks(
+    mut commands: Commands,
+    mut event_writer: EventWriter<EventTxResult>,
+    proof_res: Res<ProofAccountResource>,
+    query_tx: Query<(Entity, &TxProcessor)>,
+) {
+    for (entity, tx_processor) in query_tx.iter() {
+        if !tx_processor.status.is_final() {
+            continue;
+        }
+
+        let mut status = tx_processor.status.to_string();
+        if tx_processor.tx_type == TxType::Mine && tx_processor.status.is_landed() {
+            if let Some(previous_staked_balance) = tx_processor.staked_balance {
+                if tx_processor.challenge.as_str() == proof_res.challenge
+                    && tx_processor.status != TxProcessorStatus::Finalized
+                {
+                    // wait for the proof account update to show the reward
+                    continue;
+                }
+                let staked_diff = proof_res.stake.saturating_sub(previous_staked_balance);
+                status = format!("{} +{} ORE.", status, staked_diff.to_locale_string(None));
+            }
+        }
+
+        let sig = if let Some(s) = tx_processor.signature {
+            s.to_string()
+        } else {
+            "FAILED".to_string()
+        };
+
+        event_writer.send(EventTxResult {
+            tx_type: tx_processor.tx_type,
+            sig,
+            hash_status: tx_processor.hash_status,
+            tx_time: tx_processor.created_at.elapsed().as_secs(),
+            tx_status: TxStatus {
+                status,
+                error: tx_processor.error.clone(),
+            },
+            commitment_times: tx_processor.commitment_times,
+            priority_fee: tx_processor.priority_fee,
+        });
+
+        commands.entity(entity).despawn_recursive();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Instant;
+
+    use solana_sdk::transaction::{Transaction, TransactionError};
+
+    use super::*;
+    use crate::TxCommitmentTimes;
+
+    fn tx_processor(status: TxProcessorStatus) -> TxProcessor {
+        TxProcessor {
+            tx_type: TxType::Mine,
+            status,
+            error: String::new(),
+            staked_balance: None,
+            challenge: String::new(),
+            signed_tx: Some(Transaction::default()),
+            signature: None,
+            hash_status: None,
+            priority_fee: None,
+            last_valid_block_height: 100,
+            resign_count: 0,
+            commitment_times: TxCommitmentTimes::default(),
+            created_at: Instant::now(),
+            send_and_confirm_interval: Timer::new(Duration::from_secs(1), TimerMode::Repeating),
+        }
+    }
+
+    fn sig_status(
+        status: Result<(), TransactionError>,
+        confirmation_status: Option<TransactionConfirmationStatus>,
+    ) -> TransactionStatus {
+        TransactionStatus {
+            slot: 1,
+            confirmations: None,
+            err: status.clone().err(),
+            status,
+            confirmation_status,
+        }
+    }
+
+    fn resign_config(enabled: bool, max_resigns: u32) -> AppConfig {
+        AppConfig {
+            tx_resign_on_expiry: enabled,
+            tx_max_resigns: max_resigns,
+            ..AppConfig::default()
+        }
+    }
+
+    #[test]
+    fn confirmed_status_records_commitment_times() {
+        let mut tx = tx_processor(TxProcessorStatus::Sending);
+        apply_sig_status(&mut tx, &sig_status(Ok(()), Some(TransactionConfirmationStatus::Confirmed)));
+
+        assert_eq!(tx.status, TxProcessorStatus::Confirmed);
+        assert!(tx.commitment_times.processed.is_some());
+        assert!(tx.commitment_times.confirmed.is_some());
+        assert!(tx.commitment_times.finalized.is_none());
+
+        apply_sig_status(&mut tx, &sig_status(Ok(()), Some(TransactionConfirmationStatus::Finalized)));
+        assert_eq!(tx.status, TxProcessorStatus::Finalized);
+        assert!(tx.commitment_times.finalized.is_some());
+    }
+
+    #[test]
+    fn failed_status_keeps_the_error() {
+        let mut tx = tx_processor(TxProcessorStatus::Sending);
+        apply_sig_status(
+            &mut tx,
+            &sig_status(Err(TransactionError::AccountNotFound), Some(TransactionConfirmationStatus::Processed)),
+        );
+
+        assert_eq!(tx.status, TxProcessorStatus::Failed);
+        assert_eq!(tx.error, TransactionError::AccountNotFound.to_string());
+        assert_eq!(tx.commitment_times, TxCommitmentTimes::default());
+    }
+
+    #[test]
+    fn status_without_commitment_is_still_pending() {
+        let mut tx = tx_processor(TxProcessorStatus::Sending);
+        apply_sig_status(&mut tx, &sig_status(Ok(()), None));
+
+        assert_eq!(tx.status, TxProcessorStatus::Sending);
+        assert_eq!(expiry_action(&tx, 100, &resign_config(true, 1), true), ExpiryAction::Wait);
+    }
+
+    #[test]
+    fn past_last_valid_block_height_resigns_then_expires() {
+        let mut tx = tx_processor(TxProcessorStatus::Sending);
+
+        assert_eq!(expiry_action(&tx, 101, &resign_config(true, 1), true), ExpiryAction::Resign);
+        assert_eq!(expiry_action(&tx, 101, &resign_config(false, 1), true), ExpiryAction::Expire);
+        // a locked wallet without a session signer can't re-sign
+        assert_eq!(expiry_action(&tx, 101, &resign_config(true, 1), false), ExpiryAction::Expire);
+
+        tx.resign_count = 1;
+        assert_eq!(expiry_action(&tx, 101, &resign_config(true, 1), true), ExpiryAction::Expire);
+
+        tx.resign_count = 0;
+        tx.signed_tx = None;
+        assert_eq!(expiry_action(&tx, 101, &resign_config(true, 1), true), ExpiryAction::Expire);
+    }
+
+    #[test]
+    fn landed_transactions_never_expire() {
+        let tx = tx_processor(TxProcessorStatus::Confirmed);
+        assert_eq!(expiry_action(&tx, 1_000, &resign_config(true, 1), true), ExpiryAction::Wait);
+    }
+
+    #[test]
+    fn status_requests_are_batched_by_256() {
+        let sigs: Vec<Signature> = (0..600).map(|_| Signature::new_unique()).collect();
+        let batches: Vec<usize> = sig_status_batches(&sigs).map(|batch| batch.len()).collect();
+        assert_eq!(batches, [256, 256, 88]);
+
+        let batches: Vec<usize> = sig_status_batches(&sigs[..256]).map(|batch| batch.len()).collect();
+        assert_eq!(batches, [256]);
+
+        assert_eq!(sig_status_batches(&[]).count(), 0);
+    }
+}
@@ -0,0 +1,481 @@
+use std::str::FromStr;
+
+use chrono::DateTime;
+use ore_api::ID as ORE_PROGRAM_ID;
+use solana_client::{
+    nonblocking::rpc_client::RpcClient,
+    rpc_client::GetConfirmedSignaturesForAddress2Config,
+    rpc_config::RpcTransactionConfig,
+};
+use solana_sdk::{
+    commitment_config::CommitmentConfig, instruction::CompiledInstruction, pubkey::Pubkey,
+    signature::Signature,
+};
+use solana_transaction_status::{UiTransactionEncoding, UiTransactionTokenBalance};
+use spl_token::instruction::TokenInstruction;
+
+use crate::{
+    amount::{format_units, OreAmount},
+    ore_utils::{get_ore_mint, proof_pubkey, ORE_TOKEN_DECIMALS},
+    utils::shorten_string,
+};
+
+pub const HISTORY_PAGE_SIZE: usize = 20;
+
+#[derive(Clone, Debug)]
+pub struct TxHistoryEntry {
+    pub signature: String,
+    pub slot: u64,
+    pub block_time: Option<i64>,
+    pub kind: String,
+    pub amount: String,
+    pub fee: u64,
+    pub status: String,
+}
+
+impl TxHistoryEntry {
+    pub fn time_string(&self) -> String {
+        if let Some(block_time) = self.block_time {
+            if let Some(dt) = DateTime::from_timestamp(block_time, 0) {
+                return dt.format("%m/%d %H:%M").to_string();
+            }
+        }
+        "-".to_string()
+    }
+}
+
+/// Pagination state for one address. `before` is the oldest signature
+/// already shown for that address.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct HistoryCursor {
+    pub before: Option<Signature>,
+    pub exhausted: bool,
+}
+
+#[derive(Clone, Copy, Debug, Default)]
+pub struct TxHistoryCursors {
+    pub wallet: HistoryCursor,
+    pub proof: HistoryCursor,
+}
+
+impl TxHistoryCursors {
+    pub fn is_exhausted(&self) -> bool {
+        self.wallet.exhausted && self.proof.exhausted
+    }
+}
+
+pub struct TxHistoryPage {
+    pub entries: Vec<TxHistoryEntry>,
+    pub cursors: TxHistoryCursors,
+}
+
+/// Fetches the next page of transactions touching the wallet or its proof account,
+/// newest first.
+pub async fn fetch_tx_history_page(
+    client: &RpcClient,
+    wallet: Pubkey,
+    cursors: TxHistoryCursors,
+    limit: usize,
+) -> Result<TxHistoryPage, String> {
+    let wallet_sigs = get_sigs_for_address(client, &wallet, &cursors.wallet, limit).await?;
+    let proof_sigs = get_sigs_for_address(client, &proof_pubkey(wallet), &cursors.proof, limit).await?;
+
+    let merged = merge_sigs(&wallet_sigs, &proof_sigs, limit);
+
+    let mut next_cursors = cursors;
+    next_cursors.wallet = advance_cursor(&cursors.wallet, &wallet_sigs, &merged, limit);
+    next_cursors.proof = advance_cursor(&cursors.proof, &proof_sigs, &merged, limit);
+
+    let mut entries = vec![];
+    for (sig, slot) in merged {
+        entries.push(get_history_entry(client, &wallet, sig, slot).await);
+    }
+
+    Ok(TxHistoryPage {
+        entries,
+        cursors: next_cursors,
+    })
+}
+
+async fn get_sigs_for_address(
+    client: &RpcClient,
+    address: &Pubkey,
+    cursor: &HistoryCursor,
+    limit: usize,
+) -> Result<Vec<(Signature, u64)>, String> {
+    if cursor.exhausted {
+        return Ok(vec![]);
+    }
+
+    let config = GetConfirmedSignaturesForAddress2Config {
+        before: cursor.before,
+        until: None,
+        limit: Some(limit),
+        commitment: Some(CommitmentConfig::confirmed()),
+    };
+
+    let statuses = client
+        .get_signatures_for_address_with_config(address, config)
+        .await
+        .map_err(|e| format!("Failed to get signatures for {}: {}", address, e))?;
+
+    let mut sigs = vec![];
+    for status in statuses {
+        if let Ok(sig) = Signature::from_str(&status.signature) {
+            sigs.push((sig, status.slot));
+        }
+    }
+
+    Ok(sigs)
+}
+
+/// Merges newest first and drops the sigs that show up for both addresses.
+fn merge_sigs(
+    wallet_sigs: &[(Signature, u64)],
+    proof_sigs: &[(Signature, u64)],
+    limit: usize,
+) -> Vec<(Signature, u64)> {
+    let mut merged: Vec<(Signature, u64)> = vec![];
+    for (sig, slot) in wallet_sigs.iter().chain(proof_sigs.iter()) {
+        if !merged.iter().any(|(s, _)| s == sig) {
+            merged.push((*sig, *slot));
+        }
+    }
+    merged.sort_by_key(|(_, slot)| std::cmp::Reverse(*slot));
+    merged.truncate(limit);
+    merged
+}
+
+fn advance_cursor(
+    cursor: &HistoryCursor,
+    fetched: &[(Signature, u64)],
+    shown: &[(Signature, u64)],
+    limit: usize,
+) -> HistoryCursor {
+    if cursor.exhausted {
+        return *cursor;
+    }
+
+    let mut next = *cursor;
+    let mut all_shown = true;
+    for (sig, _) in fetched {
+        if shown.iter().any(|(s, _)| s == sig) {
+            next.before = Some(*sig);
+        } else {
+            all_shown = false;
+        }
+    }
+    next.exhausted = all_shown && fetched.len() < limit;
+    next
+}
+
+/// Never fails, a transaction that can't be fetched or decoded is still listed.
+async fn get_history_entry(
+    client: &RpcClient,
+    wallet: &Pubkey,
+    sig: Signature,
+    slot: u64,
+) -> TxHistoryEntry {
+    let mut entry = TxHistoryEntry {
+        signature: sig.to_string(),
+        slot,
+        block_time: None,
+        kind: "Unknown".to_string(),
+        amount: "-".to_string(),
+        fee: 0,
+        status: "UNKNOWN".to_string(),
+    };
+
+    let config = RpcTransactionConfig {
+        encoding: Some(UiTransactionEncoding::Base64),
+        commitment: Some(CommitmentConfig::confirmed()),
+        max_supported_transaction_version: Some(0),
+    };
+
+    let tx = match client.get_transaction_with_config(&sig, config).await {
+        Ok(tx) => tx,
+        Err(_) => return entry,
+    };
+
+    entry.slot = tx.slot;
+    entry.block_time = tx.block_time;
+
+    let token_balances: Vec<UiTransactionTokenBalance> = if let Some(meta) = &tx.transaction.meta {
+        entry.fee = meta.fee;
+        entry.status = if meta.err.is_none() {
+            "SUCCESS".to_string()
+        } else {
+            "FAILED".to_string()
+        };
+        Option::<Vec<UiTransactionTokenBalance>>::from(meta.pre_token_balances.clone())
+            .unwrap_or_default()
+    } else {
+        vec![]
+    };
+
+    if let Some(versioned_tx) = tx.transaction.transaction.decode() {
+        let account_keys = versioned_tx.message.static_account_keys();
+        let decoded: Vec<(String, String)> = versioned_tx
+            .message
+            .instructions()
+            .iter()
+            .filter_map(|ix| decode_instruction(ix, account_keys, &token_balances, wallet))
+            .collect();
+
+        // ORE instructions describe the tx better than the token transfers they trigger
+        let ore_ix = decoded
+            .iter()
+            .find(|(kind, _)| kind != "Transfer" && kind != "Receive");
+        if let Some((kind, amount)) = ore_ix.or(decoded.first()) {
+            entry.kind = kind.clone();
+            entry.amount = amount.clone();
+        } else {
+            entry.kind = "Other".to_string();
+        }
+    }
+
+    entry
+}
+
+pub fn decode_instruction(
+    ix: &CompiledInstruction,
+    account_keys: &[Pubkey],
+    token_balances: &[UiTransactionTokenBalance],
+    wallet: &Pubkey,
+) -> Option<(String, String)> {
+    let program_id = account_keys.get(ix.program_id_index as usize)?;
+
+    if *program_id == ORE_PROGRAM_ID {
+        decode_ore_instruction(&ix.data)
+    } else if *program_id == spl_token::id() {
+        decode_token_transfer(ix, account_keys, token_balances, wallet)
+    } else {
+        None
+    }
+}
+
+/// ORE v2 instruction data is a one byte discriminator followed by the args.
+pub fn decode_ore_instruction(data: &[u8]) -> Option<(String, String)> {
+    let (discriminator, args) = data.split_first()?;
+    let amount = args
+        .get(0..8)
+        .map(|bytes| format_ore_amount(u64::from_le_bytes(bytes.try_into().unwrap())));
+
+    let result = match discriminator {
+        0 => ("Claim".to_string(), amount.unwrap_or("-".to_string())),
+        1 => ("Close".to_string(), "-".to_string()),
+        2 => ("Mine".to_string(), "-".to_string()),
+        3 => ("Open".to_string(), "-".to_string()),
+        4 => ("Reset".to_string(), "-".to_string()),
+        5 => ("Stake".to_string(), amount.unwrap_or("-".to_string())),
+        6 => ("Update".to_string(), "-".to_string()),
+        7 => ("Upgrade".to_string(), amount.unwrap_or("-".to_string())),
+        _ => return None,
+    };
+
+    Some(result)
+}
+
+fn decode_token_transfer(
+    ix: &CompiledInstruction,
+    account_keys: &[Pubkey],
+    token_balances: &[UiTransactionTokenBalance],
+    wallet: &Pubkey,
+) -> Option<(String, String)> {
+    let (amount, source_index, mint_decimals) = match TokenInstruction::unpack(&ix.data).ok()? {
+        TokenInstruction::Transfer { amount } => (amount, *ix.accounts.first()?, None),
+        TokenInstruction::TransferChecked { amount, decimals } => {
+            (amount, *ix.accounts.first()?, Some(decimals))
+        }
+        _ => return None,
+    };
+
+    let source_balance = token_balances
+        .iter()
+        .find(|b| b.account_index == source_index);
+    let mint = source_balance.map(|b| b.mint.clone()).unwrap_or_default();
+    let decimals = mint_decimals
+        .or(source_balance.map(|b| b.ui_token_amount.decimals))
+        .unwrap_or(ORE_TOKEN_DECIMALS);
+
+    let ui_amount = format_units(amount, decimals, Some(4));
+    let symbol = if mint == get_ore_mint().to_string() {
+        "ORE".to_string()
+    } else if mint.is_empty() {
+        "tokens".to_string()
+    } else {
+        shorten_string(mint, 6)
+    };
+
+    // the source token account is owned by the wallet for outgoing transfers
+    let is_outgoing = source_balance
+        .map(|b| Option::<String>::from(b.owner.clone()) == Some(wallet.to_string()))
+        .unwrap_or_else(|| {
+            ix.accounts
+                .last()
+                .and_then(|i| account_keys.get(*i as usize))
+                .map(|authority| authority == wallet)
+                .unwrap_or(false)
+        });
+    let kind = if is_outgoing { "Transfer" } else { "Receive" };
+
+    Some((kind.to_string(), format!("{} {}", ui_amount, symbol)))
+}
+
+pub fn format_ore_amount(amount: u64) -> String {
+    format!("{:.4} ORE", OreAmount(amount))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sig(n: u8) -> Signature {
+        Signature::from([n; 64])
+    }
+
+    #[test]
+    fn merge_drops_duplicates_and_sorts_newest_first() {
+        let wallet = [(sig(1), 10), (sig(2), 8), (sig(3), 6)];
+        let proof = [(sig(2), 8), (sig(4), 7)];
+        let merged = merge_sigs(&wallet, &proof, 10);
+        assert_eq!(merged, vec![(sig(1), 10), (sig(2), 8), (sig(4), 7), (sig(3), 6)]);
+    }
+
+    #[test]
+    fn merge_truncates_to_limit() {
+        let wallet = [(sig(1), 10), (sig(2), 8), (sig(3), 6)];
+        let proof = [(sig(2), 8), (sig(4), 7)];
+        let merged = merge_sigs(&wallet, &proof, 3);
+        assert_eq!(merged, vec![(sig(1), 10), (sig(2), 8), (sig(4), 7)]);
+    }
+
+    #[test]
+    fn cursors_advance_past_overlapping_sigs() {
+        let limit = 3;
+        let wallet = [(sig(1), 10), (sig(2), 8), (sig(3), 6)];
+        let proof = [(sig(2), 8), (sig(4), 7)];
+        let shown = merge_sigs(&wallet, &proof, limit);
+
+        // sig 3 was cut by the limit, so the wallet resumes after sig 2
+        let wallet_cursor = advance_cursor(&HistoryCursor::default(), &wallet, &shown, limit);
+        assert_eq!(wallet_cursor.before, Some(sig(2)));
+        assert!(!wallet_cursor.exhausted);
+
+        // the shared sig 2 counts as shown for the proof too
+        let proof_cursor = advance_cursor(&HistoryCursor::default(), &proof, &shown, limit);
+        assert_eq!(proof_cursor.before, Some(sig(4)));
+        assert!(proof_cursor.exhausted);
+    }
+
+    #[test]
+    fn cursor_exhausted_only_when_short_page_fully_shown() {
+        let limit = 2;
+        let full = [(sig(1), 10), (sig(2), 9)];
+        let cursor = advance_cursor(&HistoryCursor::default(), &full, &full, limit);
+        assert_eq!(cursor.before, Some(sig(2)));
+        assert!(!cursor.exhausted);
+
+        let short = [(sig(3), 8)];
+        let cursor = advance_cursor(&cursor, &short, &short, limit);
+        assert_eq!(cursor.before, Some(sig(3)));
+        assert!(cursor.exhausted);
+    }
+
+    #[test]
+    fn cursor_not_exhausted_when_short_page_partly_shown() {
+        let fetched = [(sig(1), 10), (sig(2), 9)];
+        let shown = [(sig(1), 10)];
+        let cursor = advance_cursor(&HistoryCursor::default(), &fetched, &shown, 5);
+        assert_eq!(cursor.before, Some(sig(1)));
+        assert!(!cursor.exhausted);
+    }
+
+    #[test]
+    fn cursor_with_nothing_fetched() {
+        let cursor = advance_cursor(&HistoryCursor::default(), &[], &[], 5);
+        assert_eq!(cursor.before, None);
+        assert!(cursor.exhausted);
+    }
+
+    #[test]
+    fn exhausted_cursor_is_left_alone() {
+        let exhausted = HistoryCursor {
+            before: Some(sig(9)),
+            exhausted: true,
+        };
+        let fetched = [(sig(1), 10)];
+        let cursor = advance_cursor(&exhausted, &fetched, &fetched, 5);
+        assert_eq!(cursor.before, Some(sig(9)));
+        assert!(cursor.exhausted);
+
+        let mut cursors = TxHistoryCursors::default();
+        assert!(!cursors.is_exhausted());
+        cursors.wallet = exhausted;
+        assert!(!cursors.is_exhausted());
+        cursors.proof = exhausted;
+        assert!(cursors.is_exhausted());
+    }
+
+    fn ore_ix(discriminator: u8, amount: Option<u64>) -> Vec<u8> {
+        let mut data = vec![discriminator];
+        if let Some(amount) = amount {
+            data.extend_from_slice(&amount.to_le_bytes());
+        }
+        data
+    }
+
+    #[test]
+    fn decodes_each_ore_discriminator() {
+        let one_ore = 10u64.pow(ORE_TOKEN_DECIMALS as u32);
+        let decoded = |discriminator: u8| decode_ore_instruction(&ore_ix(discriminator, Some(one_ore)));
+        let expected = [
+            (0, "Claim", "1.0000 ORE"),
+            (1, "Close", "-"),
+            (2, "Mine", "-"),
+            (3, "Open", "-"),
+            (4, "Reset", "-"),
+            (5, "Stake", "1.0000 ORE"),
+            (6, "Update", "-"),
+            (7, "Upgrade", "1.0000 ORE"),
+        ];
+        for (discriminator, kind, amount) in expected {
+            assert_eq!(
+                decoded(discriminator),
+                Some((kind.to_string(), amount.to_string())),
+                "discriminator {}",
+                discriminator
+            );
+        }
+    }
+
+    #[test]
+    fn amount_instructions_without_args_show_no_amount() {
+        for discriminator in [0, 5, 7] {
+            let (_, amount) = decode_ore_instruction(&ore_ix(discriminator, None)).unwrap();
+            assert_eq!(amount, "-");
+        }
+        // a short amount is not read
+        assert_eq!(
+            decode_ore_instruction(&[0, 1, 2, 3]),
+            Some(("Claim".to_string(), "-".to_string()))
+        );
+    }
+
+    #[test]
+    fn ore_amounts_are_truncated_to_four_decimals() {
+        let amount = 123_456_789_012u64; // 1.23456789012 ORE
+        assert_eq!(
+            decode_ore_instruction(&ore_ix(0, Some(amount))),
+            Some(("Claim".to_string(), "1.2345 ORE".to_string()))
+        );
+        assert_eq!(format_ore_amount(0), "0.0000 ORE");
+    }
+
+    #[test]
+    fn unknown_or_empty_ore_instructions() {
+        assert_eq!(decode_ore_instruction(&[]), None);
+        assert_eq!(decode_ore_instruction(&ore_ix(8, Some(1))), None);
+        assert_eq!(decode_ore_instruction(&[255]), None);
+    }
+}
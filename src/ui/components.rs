@@ -1,9 +1,10 @@
 use std::sync::Arc;
 
-use bevy::prelude::*;
+use bevy::{log::Level, prelude::*};
 use solana_sdk::signature::Keypair;
+use zeroize::Zeroize;
 
-use crate::NavItemScreen;
+use crate::{app_backup::RestoreMode, config::ConfigField, secret_string::SecretString, NavItemScreen, SecretAction};
 
 // Components
 #[derive(Component, Default)]
@@ -41,6 +42,9 @@ pub struct TextCrownStakeAmount;
 #[derive(Component)]
 pub struct TextCrownStakeBy;
 
+#[derive(Component)]
+pub struct TextStakeDistribution;
+
 #[derive(Component)]
 pub struct BaseScreenNode;
 
@@ -80,6 +84,15 @@ pub struct LockedScreenNode;
 #[derive(Component)]
 pub struct DashboardScreenNode;
 
+#[derive(Component)]
+pub struct HistoryScreenNode;
+
+#[derive(Component)]
+pub struct SendScreenNode;
+
+#[derive(Component)]
+pub struct SendConfirmModal;
+
 #[derive(Component)]
 pub struct SettingsConfigScreenNode;
 
@@ -99,13 +112,59 @@ pub struct TextInput {
     pub hidden: bool,
     pub numbers_only: bool,
     pub text: String,
+    /// Password inputs keep their value here instead of in `text`.
+    pub secret: Option<SecretString>,
+}
+
+impl TextInput {
+    pub fn value(&self) -> &str {
+        match &self.secret {
+            Some(secret) => secret.as_str(),
+            None => &self.text,
+        }
+    }
+
+    pub fn push_str(&mut self, s: &str) {
+        match &mut self.secret {
+            Some(secret) => secret.push_str(s),
+            None => self.text.push_str(s),
+        }
+    }
+
+    pub fn pop(&mut self) {
+        match &mut self.secret {
+            Some(secret) => secret.pop(),
+            None => {
+                self.text.pop();
+            }
+        }
+    }
+
+    pub fn set_value(&mut self, s: &str) {
+        match &mut self.secret {
+            Some(secret) => {
+                secret.clear();
+                secret.push_str(s);
+            }
+            None => self.text = s.to_string(),
+        }
+    }
+
+    /// Wipes the value, zeroing the buffer for secret inputs.
+    pub fn clear(&mut self) {
+        match &mut self.secret {
+            Some(secret) => secret.clear(),
+            None => self.text.zeroize(),
+        }
+    }
 }
 
 #[derive(Component)]
 pub struct TextCursor;
 
+/// Keypair waiting to be saved, with the seed phrase it came from if known.
 #[derive(Component)]
-pub struct TextGeneratedKeypair(pub Arc<Keypair>);
+pub struct TextGeneratedKeypair(pub Arc<Keypair>, pub Option<SecretString>);
 
 #[derive(Component)]
 pub struct TextMnemonicLine1;
@@ -134,6 +193,30 @@ pub struct DashboardProofUpdatesLogsListItem;
 #[derive(Component)]
 pub struct MiningScreenTxResultList;
 
+#[derive(Component)]
+pub struct HistoryScreenTxList;
+
+#[derive(Component)]
+pub struct TextHistoryStatus;
+
+#[derive(Component)]
+pub struct TextSendRecipient;
+
+#[derive(Component)]
+pub struct TextSendAmount;
+
+#[derive(Component)]
+pub struct TextSendRecipientName;
+
+#[derive(Component)]
+pub struct TextSendToken;
+
+#[derive(Component)]
+pub struct TextSendStatus;
+
+#[derive(Component)]
+pub struct AddressBookList;
+
 #[derive(Component)]
 pub struct ButtonOpenWebTxExplorer;
 
@@ -233,19 +316,20 @@ pub struct TextCurrentTxStatus;
 pub struct TextCurrentTxElapsed;
 
 #[derive(Component)]
-pub struct TextConfigInputRpcUrl;
+pub struct TextConfigInput(pub ConfigField);
 
+/// Inline validation message next to the input for the field.
 #[derive(Component)]
-pub struct TextConfigInputThreads;
+pub struct TextConfigFieldError(pub ConfigField);
 
 #[derive(Component)]
-pub struct TextConfigInputRpcFetchAccountsInterval;
+pub struct TextConfigStatus;
 
 #[derive(Component)]
-pub struct TextConfigInputRpcSendTxInterval;
+pub struct TextTxProcessorTxType;
 
 #[derive(Component)]
-pub struct TextTxProcessorTxType;
+pub struct TextTxProcessorStatus;
 
 #[derive(Component)]
 pub struct ButtonAutoScroll(pub bool);
@@ -275,9 +359,147 @@ pub struct ButtonSaveGeneratedWallet;
 #[derive(Component)]
 pub struct ButtonUnlock;
 
+#[derive(Component)]
+pub struct ButtonHistoryRefresh;
+
+#[derive(Component)]
+pub struct ButtonHistoryLoadMore;
+
+#[derive(Component)]
+pub struct ButtonSendToggleToken;
+
+#[derive(Component)]
+pub struct ButtonSendReview;
+
+#[derive(Component)]
+pub struct ButtonSendSaveRecipient;
+
+#[derive(Component)]
+pub struct ButtonSendConfirm;
+
+#[derive(Component)]
+pub struct ButtonSendCancel;
+
+#[derive(Component)]
+pub struct ButtonAddressBookEntry(pub String);
+
+#[derive(Component)]
+pub struct ButtonAddressBookRemove(pub String);
+
 #[derive(Component)]
 pub struct ButtonLock;
 
+#[derive(Component)]
+pub struct ButtonSelectProfile(pub String);
+
+#[derive(Component)]
+pub struct ButtonNewProfile;
+
+#[derive(Component)]
+pub struct ButtonSwitchProfile;
+
+#[derive(Component)]
+pub struct TextActiveProfile;
+
+#[derive(Component)]
+pub struct TextProfileNameInput;
+
+#[derive(Component)]
+pub struct TextMnemonicInput;
+
+#[derive(Component)]
+pub struct TextMnemonicPassphraseInput;
+
+#[derive(Component)]
+pub struct TextDerivationPathInput;
+
+#[derive(Component)]
+pub struct TextRestoreStatus;
+
+#[derive(Component)]
+pub struct RestorePreviewList;
+
+#[derive(Component)]
+pub struct ButtonRestoreMnemonic;
+
+#[derive(Component)]
+pub struct TextWatchPubkeyInput;
+
+#[derive(Component)]
+pub struct BackupVerificationNode;
+
+#[derive(Component)]
+pub struct BackupWordGrid;
+
+#[derive(Component)]
+pub struct TextBackupPrompt;
+
+#[derive(Component)]
+pub struct ButtonBackupWord(pub String);
+
+#[derive(Component)]
+pub struct ButtonAcceptBackupRisk;
+
+#[derive(Component)]
+pub struct TextAcceptBackupRisk;
+
+#[derive(Component)]
+pub struct ButtonAddWatchOnly;
+
+/// Actions that need the private key, hidden for watch-only wallets.
+#[derive(Component)]
+pub struct SigningAction;
+
+/// Derivation path of a previewed account.
+#[derive(Component)]
+pub struct ButtonRestoreAccount(pub String);
+
+#[derive(Component)]
+pub struct TextChangePasswordOld;
+
+#[derive(Component)]
+pub struct TextChangePasswordNew;
+
+#[derive(Component)]
+pub struct TextChangePasswordConfirm;
+
+#[derive(Component)]
+pub struct TextChangePasswordStatus;
+
+#[derive(Component)]
+pub struct TextPasswordStrength;
+
+/// Segment of the strength meter, lit when the score reaches it.
+#[derive(Component)]
+pub struct PasswordStrengthBar(pub u8);
+
+#[derive(Component)]
+pub struct ButtonChangePassword;
+
+#[derive(Component)]
+pub struct TextRevealPassword;
+
+#[derive(Component)]
+pub struct TextExportPath;
+
+#[derive(Component)]
+pub struct ButtonRevealSecret(pub SecretAction);
+
+#[derive(Component)]
+pub struct TextRevealStatus;
+
+#[derive(Component)]
+pub struct TextRevealedMnemonic;
+
+#[derive(Component)]
+pub struct TextLockedWalletPubkey;
+
+#[derive(Component)]
+pub struct TextLockedWalletLabel;
+
+#[derive(Component)]
+pub struct TextUnlockStatus;
+
 #[derive(Component)]
 pub struct ButtonClaimOreRewards;
 
@@ -301,3 +523,88 @@ pub struct SpinnerIcon;
 
 #[derive(Component)]
 pub struct ButtonCooldownSpinner;
+
+/// Shown across the top of the app while `config.toml` has changes that
+/// need a restart, or a reload was rejected.
+#[derive(Component)]
+pub struct ConfigBanner;
+
+#[derive(Component)]
+pub struct TextConfigBanner;
+
+#[derive(Component)]
+pub struct LogsScreenNode;
+
+#[derive(Component)]
+pub struct LogViewerList;
+
+#[derive(Component)]
+pub struct TextLogViewerStatus;
+
+#[derive(Component)]
+pub struct TextLogSearchInput;
+
+/// Shows records at this level and more severe, `None` shows everything.
+#[derive(Component)]
+pub struct ButtonLogLevelFilter(pub Option<Level>);
+
+#[derive(Component)]
+pub struct TextExportFromInput;
+
+#[derive(Component)]
+pub struct TextExportToInput;
+
+#[derive(Component)]
+pub struct ButtonExportFormat;
+
+#[derive(Component)]
+pub struct TextExportFormat;
+
+#[derive(Component)]
+pub struct ButtonExportMiningHistory;
+
+#[derive(Component)]
+pub struct TextMiningExportStatus;
+
+#[derive(Component)]
+pub struct TextAppBackupPasswordInput;
+
+#[derive(Component)]
+pub struct TextAppBackupConfirmInput;
+
+#[derive(Component)]
+pub struct ButtonCreateAppBackup;
+
+#[derive(Component)]
+pub struct TextAppBackupStatus;
+
+#[derive(Component)]
+pub struct TextAppRestorePathInput;
+
+#[derive(Component)]
+pub struct TextAppRestorePasswordInput;
+
+#[derive(Component)]
+pub struct ButtonOpenAppBackup;
+
+#[derive(Component)]
+pub struct ButtonRestoreAppBackup(pub RestoreMode);
+
+#[derive(Component)]
+pub struct TextAppRestoreStatus;
+
+#[derive(Component)]
+pub struct CrashRecoveryBanner;
+
+#[derive(Component)]
+pub struct TextCrashRecovery;
+
+/// `true` resumes mining, `false` dismisses the banner.
+#[derive(Component)]
+pub struct ButtonCrashRecovery(pub bool);
+
+#[derive(Component)]
+pub struct ButtonCreateSupportBundle;
+
+#[derive(Component)]
+pub struct TextSupportBundleStatus;
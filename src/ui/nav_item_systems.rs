@@ -2,7 +2,7 @@ use bevy::prelude::*;
 
 use crate::{AppScreenState, AppWallet, NavItemScreen};
 
-use super::{components::NavItem, styles::{HOVERED_BUTTON, NORMAL_BUTTON, PRESSED_BUTTON}};
+use super::{components::NavItem, styles::PRESSED_BUTTON};
 
 pub fn nav_item_interactions(
     mut interaction_query: Query<
@@ -19,7 +19,12 @@ pub fn nav_item_interactions(
                 border_color.0 = Color::RED;
 
                 // event_writer.send(EventNavItemClicked);
-                if let Some(_) = &app_wallet.wallet {
+                if app_wallet.pubkey().is_some() {
+                    if app_wallet.is_watch_only()
+                        && matches!(nav_item.0, NavItemScreen::Send | NavItemScreen::SettingsWallet)
+                    {
+                        continue;
+                    }
                     match nav_item.0 {
                         NavItemScreen::Dashboard => {
                             next_state.set(AppScreenState::Dashboard);
@@ -27,6 +32,12 @@ pub fn nav_item_interactions(
                         NavItemScreen::Mining => {
                             next_state.set(AppScreenState::Mining);
                         },
+                        NavItemScreen::History => {
+                            next_state.set(AppScreenState::History);
+                        },
+                        NavItemScreen::Send => {
+                            next_state.set(AppScreenState::Send);
+                        },
                         NavItemScreen::SettingsWallet => {
                             next_state.set(AppScreenState::SettingsWallet);
                         },
@@ -36,6 +47,9 @@ pub fn nav_item_interactions(
                         NavItemScreen::SettingsGeneral => {
                             next_state.set(AppScreenState::SettingsGeneral);
                         },
+                        NavItemScreen::Logs => {
+                            next_state.set(AppScreenState::Logs);
+                        },
                     }
                 }
             }
@@ -2,8 +2,11 @@ pub mod screen_base;
 pub mod screen_locked;
 pub mod screen_mining;
 pub mod screen_dashboard;
+pub mod screen_history;
+pub mod screen_send;
 pub mod screen_settings_config;
 pub mod screen_settings_general;
 pub mod screen_settings_wallet;
 pub mod screen_setup_wallet;
 
+pub mod screen_logs;
@@ -3,12 +3,10 @@ use bevy::
     prelude::*
 ;
 
-use crate::ui::styles::{hex_dark_mode_app_screen_background, hex_dark_mode_background, hex_dark_mode_header_border, hex_dark_mode_nav_title, hex_dark_mode_text_gray, hex_dark_mode_text_white, hex_dark_mode_text_white_2, DASHBOARD_ICON_WHITE, FONT_REGULAR, FONT_SIZE_LARGE, FONT_SIZE_MEDIUM, FONT_SIZE_SMALL, MINE_TOGGLE_BACKGROUND, MINE_TOGGLE_BUTTON, MINE_TOGGLE_OFF, MINING_ICON, NAV_ARROW_ICON, ORE_LOGO_WHITE};
+use crate::ui::styles::{hex_dark_mode_app_screen_background, hex_dark_mode_background, hex_dark_mode_header_border, hex_dark_mode_nav_title, hex_dark_mode_text_gray, hex_dark_mode_text_white, hex_dark_mode_text_white_2, DASHBOARD_ICON_WHITE, FONT_REGULAR, NORMAL_BUTTON, FONT_SIZE_LARGE, FONT_SIZE_MEDIUM, FONT_SIZE_SMALL, MINE_TOGGLE_BACKGROUND, MINE_TOGGLE_BUTTON, MINE_TOGGLE_OFF, MINING_ICON, NAV_ARROW_ICON, ORE_LOGO_WHITE};
 use crate::NavItemScreen;
 use crate::{
-    ui::
-        styles::
-            hex_black
+    amount::{Lamports, OreAmount}
         
     ,
     utils::shorten_string,
@@ -16,26 +14,24 @@ use crate::{
 };
 
 use crate::ui::
-    components::{AppScreenParent, BaseScreenNode, NavItem, NavItemArrow, NavItemIcon, NavItemText, NavItemWhiteSelectedBar, TextWalletOreBalance, TextWalletPubkey, TextWalletSolBalance, ToggleAutoMine, ToggleAutoMineParent, TxPopUpArea}
+    components::{AppScreenParent, BaseScreenNode, ButtonCrashRecovery, ConfigBanner, CrashRecoveryBanner, TextConfigBanner, TextCrashRecovery, ButtonSwitchProfile, NavItem, NavItemArrow, NavItemIcon, NavItemText, NavItemWhiteSelectedBar, SigningAction, TextActiveProfile, TextWalletOreBalance, TextWalletPubkey, TextWalletSolBalance, ToggleAutoMine, ToggleAutoMineParent, TxPopUpArea}
     
 ;
 
 use super::screen_dashboard::spawn_dashboard_screen;
-use super::screen_locked::spawn_locked_screen;
-use super::screen_mining::spawn_app_screen_mining;
 
 pub fn spawn_base_screen(
     mut commands: Commands,
     asset_server: Res<AssetServer>,
     address: String,
-    sol_balance: f64,
-    ore_balance: f64,
-    config: AppConfig,
+    sol_balance: Lamports,
+    ore_balance: OreAmount,
+    _config: AppConfig,
 ) {
     let full_addr = address.clone();
-    let wallet_str = shorten_string(full_addr, 10);
-    let sol_balance_str = sol_balance.to_string();
-    let ore_balance_str = ore_balance.to_string();
+    let _wallet_str = shorten_string(full_addr, 10);
+    let _sol_balance_str = sol_balance.to_locale_string(None);
+    let _ore_balance_str = ore_balance.to_locale_string(None);
 
     commands
         .spawn((
@@ -90,6 +86,101 @@ pub fn spawn_base_screen(
                     Name::new("TxPopUpArea"),
                 ));
             });
+            // config restart banner
+            parent.spawn((
+                NodeBundle {
+                    z_index: ZIndex::Global(14),
+                    visibility: Visibility::Hidden,
+                    background_color: Color::rgb(0.6, 0.35, 0.0).into(),
+                    style: Style {
+                        width: Val::Percent(100.0),
+                        padding: UiRect::all(Val::Px(6.0)),
+                        position_type: PositionType::Absolute,
+                        top: Val::Px(0.0),
+                        justify_content: JustifyContent::Center,
+                        ..default()
+                    },
+                    ..default()
+                },
+                ConfigBanner,
+                Name::new("Config Banner"),
+            )).with_children(|parent| {
+                parent.spawn((
+                    TextBundle::from_section(
+                        "",
+                        TextStyle {
+                            font: asset_server.load(FONT_REGULAR),
+                            font_size: FONT_SIZE_SMALL,
+                            color: hex_dark_mode_text_white(),
+                        },
+                    ),
+                    TextConfigBanner,
+                    Name::new("TextConfigBanner"),
+                ));
+            });
+            // offered after a crash, see crash_report
+            parent.spawn((
+                NodeBundle {
+                    z_index: ZIndex::Global(14),
+                    visibility: Visibility::Hidden,
+                    background_color: Color::rgb(0.55, 0.1, 0.1).into(),
+                    style: Style {
+                        width: Val::Percent(100.0),
+                        padding: UiRect::all(Val::Px(6.0)),
+                        position_type: PositionType::Absolute,
+                        bottom: Val::Px(0.0),
+                        flex_direction: FlexDirection::Row,
+                        align_items: AlignItems::Center,
+                        justify_content: JustifyContent::Center,
+                        column_gap: Val::Px(10.0),
+                        ..default()
+                    },
+                    ..default()
+                },
+                CrashRecoveryBanner,
+                Name::new("Crash Recovery Banner"),
+            )).with_children(|parent| {
+                parent.spawn((
+                    TextBundle::from_section(
+                        "",
+                        TextStyle {
+                            font: asset_server.load(FONT_REGULAR),
+                            font_size: FONT_SIZE_SMALL,
+                            color: hex_dark_mode_text_white(),
+                        },
+                    ),
+                    TextCrashRecovery,
+                    Name::new("TextCrashRecovery"),
+                ));
+                for (label, resume) in [("RESUME MINING", true), ("DISMISS", false)] {
+                    parent.spawn((
+                        ButtonBundle {
+                            style: Style {
+                                width: Val::Px(130.0),
+                                height: Val::Px(30.0),
+                                border: UiRect::all(Val::Px(2.5)),
+                                justify_content: JustifyContent::Center,
+                                align_items: AlignItems::Center,
+                                ..default()
+                            },
+                            border_color: BorderColor(Color::BLACK),
+                            background_color: NORMAL_BUTTON.into(),
+                            ..default()
+                        },
+                        ButtonCrashRecovery(resume),
+                        Name::new("ButtonCrashRecovery"),
+                    )).with_children(|parent| {
+                        parent.spawn(TextBundle::from_section(
+                            label,
+                            TextStyle {
+                                font: asset_server.load(FONT_REGULAR),
+                                font_size: FONT_SIZE_SMALL,
+                                color: Color::rgb(0.9, 0.9, 0.9),
+                            },
+                        ));
+                    });
+                }
+            });
             parent.spawn((
                 NodeBundle {
                     border_color: Color::PURPLE.into(),
@@ -178,7 +269,7 @@ pub fn spawn_base_screen(
                                 TextStyle {
                                     font: asset_server.load(FONT_REGULAR),
                                     font_size: FONT_SIZE_LARGE,
-                                    color: hex_dark_mode_text_gray().into()
+                                    color: hex_dark_mode_text_gray()
                                 },
                             ),
                             Name::new("TextAppScreenTitle"),
@@ -200,6 +291,7 @@ pub fn spawn_base_screen(
                         },
                         Name::new("Top Section Header Mine Toggle Section"),
                         ToggleAutoMineParent,
+                        SigningAction,
                     )).with_children(|parent| {
                         parent.spawn((
                             ButtonBundle {
@@ -270,16 +362,56 @@ pub fn spawn_base_screen(
                                 Name::new("Top Section Header Wallet Left"),
                             )).with_children(|parent| {
                                 parent.spawn((
-                                    TextBundle::from_section(
-                                        "Wallet",
-                                        TextStyle {
-                                            font: asset_server.load(FONT_REGULAR),
-                                            font_size: FONT_SIZE_MEDIUM,
-                                            color: hex_dark_mode_text_gray().into()
+                                    NodeBundle {
+                                        style: Style {
+                                            flex_direction: FlexDirection::Row,
+                                            align_items: AlignItems::Center,
+                                            column_gap: Val::Px(8.0),
+                                            ..default()
                                         },
-                                    ),
-                                    Name::new("TextWallet"),
-                                ));
+                                        ..default()
+                                    },
+                                    Name::new("Top Section Header Wallet Profile"),
+                                )).with_children(|parent| {
+                                    parent.spawn((
+                                        TextBundle::from_section(
+                                            "Wallet",
+                                            TextStyle {
+                                                font: asset_server.load(FONT_REGULAR),
+                                                font_size: FONT_SIZE_MEDIUM,
+                                                color: hex_dark_mode_text_gray()
+                                            },
+                                        ),
+                                        Name::new("TextWallet"),
+                                        TextActiveProfile,
+                                    ));
+                                    parent.spawn((
+                                        ButtonBundle {
+                                            style: Style {
+                                                height: Val::Px(20.0),
+                                                padding: UiRect::horizontal(Val::Px(6.0)),
+                                                border: UiRect::all(Val::Px(1.0)),
+                                                justify_content: JustifyContent::Center,
+                                                align_items: AlignItems::Center,
+                                                ..default()
+                                            },
+                                            border_color: BorderColor(Color::BLACK),
+                                            background_color: NORMAL_BUTTON.into(),
+                                            ..default()
+                                        },
+                                        ButtonSwitchProfile,
+                                        Name::new("ButtonSwitchProfile"),
+                                    )).with_children(|parent| {
+                                        parent.spawn(TextBundle::from_section(
+                                            "SWITCH",
+                                            TextStyle {
+                                                font: asset_server.load(FONT_REGULAR),
+                                                font_size: FONT_SIZE_SMALL,
+                                                color: hex_dark_mode_text_gray()
+                                            },
+                                        ));
+                                    });
+                                });
 
                                 parent.spawn((
                                     TextBundle::from_section(
@@ -287,7 +419,7 @@ pub fn spawn_base_screen(
                                         TextStyle {
                                             font: asset_server.load(FONT_REGULAR),
                                             font_size: FONT_SIZE_MEDIUM,
-                                            color: hex_dark_mode_text_gray().into()
+                                            color: hex_dark_mode_text_gray()
                                         },
                                     ),
                                     Name::new("TextSolBalance"),
@@ -324,7 +456,7 @@ pub fn spawn_base_screen(
                                         TextStyle {
                                             font: asset_server.load(FONT_REGULAR),
                                             font_size: FONT_SIZE_MEDIUM,
-                                            color: hex_dark_mode_text_gray().into()
+                                            color: hex_dark_mode_text_gray()
                                         },
                                     ),
                                     Name::new("TextWalletPubkey"),
@@ -336,7 +468,7 @@ pub fn spawn_base_screen(
                                         TextStyle {
                                             font: asset_server.load(FONT_REGULAR),
                                             font_size: FONT_SIZE_MEDIUM,
-                                            color: hex_dark_mode_text_gray().into()
+                                            color: hex_dark_mode_text_gray()
                                         },
                                     ),
                                     Name::new("TextOreBalance"),
@@ -394,7 +526,7 @@ pub fn spawn_base_screen(
                                 border_color: Color::BLUE.into(),
                                 style: Style {
                                     width: Val::Percent(100.0),
-                                    height: Val::Percent(40.0),
+                                    height: Val::Percent(70.0),
                                     flex_direction: FlexDirection::Column,
                                     ..default()
                                 },
@@ -425,7 +557,7 @@ pub fn spawn_base_screen(
                                         TextStyle {
                                             font: asset_server.load(FONT_REGULAR),
                                             font_size: FONT_SIZE_MEDIUM,
-                                            color: hex_dark_mode_text_white().into()
+                                            color: hex_dark_mode_text_white()
                                         },
                                     ),
                                     Name::new("TextMenu"),
@@ -436,7 +568,7 @@ pub fn spawn_base_screen(
                                 NodeBundle {
                                     style: Style {
                                         width: Val::Percent(100.0),
-                                        height: Val::Percent(40.0),
+                                        height: Val::Percent(80.0),
                                         flex_direction: FlexDirection::Column,
                                         ..default()
                                     },
@@ -535,7 +667,7 @@ pub fn spawn_base_screen(
                                                     TextStyle {
                                                         font: asset_server.load(FONT_REGULAR),
                                                         font_size: FONT_SIZE_SMALL,
-                                                        color: hex_dark_mode_text_gray().into()
+                                                        color: hex_dark_mode_text_gray()
                                                     },
                                                 ),
                                                 Name::new("TextDashboard"),
@@ -666,7 +798,7 @@ pub fn spawn_base_screen(
                                                     TextStyle {
                                                         font: asset_server.load(FONT_REGULAR),
                                                         font_size: FONT_SIZE_SMALL,
-                                                        color: hex_dark_mode_text_white_2().into()
+                                                        color: hex_dark_mode_text_white_2()
                                                     },
                                                 ),
                                                 Name::new("TextMining"),
@@ -707,6 +839,269 @@ pub fn spawn_base_screen(
                                     });
                                 });
 
+                                parent.spawn((
+                                    NodeBundle {
+                                        style: Style {
+                                            width: Val::Percent(100.0),
+                                            height: Val::Px(35.0),
+                                            flex_direction: FlexDirection::Row,
+                                            ..default()
+                                        },
+                                        ..default()
+                                    },
+                                    Name::new("Nav Bar Top Half Menu Items Item"),
+                                    Interaction::default(),
+                                    NavItem(NavItemScreen::History),
+                                )).with_children(|parent| {
+                                    // Menu Nav Item
+                                    parent.spawn((
+                                        NodeBundle {
+                                            style: Style {
+                                                width: Val::Percent(100.0),
+                                                height: Val::Percent(100.0),
+                                                flex_direction: FlexDirection::Row,
+                                                align_items: AlignItems::Center,
+                                                justify_content: JustifyContent::SpaceBetween,
+                                                ..default()
+                                            },
+                                            ..default()
+                                        },
+                                        Name::new("Nav Item"),
+                                    )).with_children(|parent| {
+                                        // Menu Nav Item
+                                        parent.spawn((
+                                            NodeBundle {
+                                                style: Style {
+                                                    width: Val::Percent(80.0),
+                                                    height: Val::Percent(100.0),
+                                                    align_items: AlignItems::Center,
+                                                    ..default()
+                                                },
+                                                ..default()
+                                            },
+                                            Name::new("Nav Item Left"),
+                                        )).with_children(|parent| {
+                                            parent.spawn((
+                                                NodeBundle {
+                                                    background_color: Color::WHITE.into(),
+                                                    visibility: Visibility::Hidden,
+                                                    style: Style {
+                                                        width: Val::Px(2.5),
+                                                        height: Val::Px(FONT_SIZE_SMALL),
+                                                        ..default()
+                                                    },
+                                                    ..default()
+                                                },
+                                                Name::new("Nav Item Selected"),
+                                                NavItemWhiteSelectedBar(NavItemScreen::History),
+                                            ));
+                                            parent.spawn((
+                                                NodeBundle {
+                                                    style: Style {
+                                                        width: Val::Px(10.0),
+                                                        height: Val::Px(FONT_SIZE_SMALL),
+                                                        ..default()
+                                                    },
+                                                    ..default()
+                                                },
+                                                Name::new("Nav Item Selected Margin"),
+                                            ));
+                                            parent.spawn((
+                                                NodeBundle {
+                                                    background_color: Color::WHITE.into(),
+                                                    style: Style {
+                                                        width: Val::Px(15.0),
+                                                        height: Val::Px(15.0),
+                                                        margin: UiRect::right(Val::Px(5.0)),
+                                                        ..default()
+                                                    },
+                                                    ..default()
+                                                },
+                                                UiImage::new(
+                                                        asset_server.load(DASHBOARD_ICON_WHITE),
+                                                    ),
+                                                Name::new("History Icon"),
+                                                NavItemIcon(NavItemScreen::History),
+                                            ));
+                                            parent.spawn((
+                                                TextBundle::from_section(
+                                                    "History",
+                                                    TextStyle {
+                                                        font: asset_server.load(FONT_REGULAR),
+                                                        font_size: FONT_SIZE_SMALL,
+                                                        color: hex_dark_mode_text_white_2()
+                                                    },
+                                                ),
+                                                Name::new("TextHistory"),
+                                                NavItemText(NavItemScreen::History)
+                                            ));
+                                        });
+
+                                        parent.spawn((
+                                            NodeBundle {
+                                                style: Style {
+                                                    width: Val::Percent(20.0),
+                                                    height: Val::Percent(100.0),
+                                                    justify_content: JustifyContent::End,
+                                                    align_items: AlignItems::Center,
+                                                    ..default()
+                                                },
+                                                ..default()
+                                            },
+                                            Name::new("Nav Item Right"),
+                                        )).with_children(|parent| {
+                                            parent.spawn((
+                                                NodeBundle {
+                                                    background_color: Color::WHITE.into(),
+                                                    style: Style {
+                                                        width: Val::Px(20.0),
+                                                        height: Val::Px(20.0),
+                                                        ..default()
+                                                    },
+                                                    ..default()
+                                                },
+                                                UiImage::new(
+                                                        asset_server.load(NAV_ARROW_ICON),
+                                                    ),
+                                                Name::new("Nav Arrow Icon"),
+                                                NavItemArrow(NavItemScreen::History),
+                                            ));
+                                        });
+                                    });
+                                });
+
+                                parent.spawn((
+                                    NodeBundle {
+                                        style: Style {
+                                            width: Val::Percent(100.0),
+                                            height: Val::Px(35.0),
+                                            flex_direction: FlexDirection::Row,
+                                            ..default()
+                                        },
+                                        ..default()
+                                    },
+                                    Name::new("Nav Bar Top Half Menu Items Item"),
+                                    Interaction::default(),
+                                    NavItem(NavItemScreen::Send),
+                                    SigningAction,
+                                )).with_children(|parent| {
+                                    // Menu Nav Item
+                                    parent.spawn((
+                                        NodeBundle {
+                                            style: Style {
+                                                width: Val::Percent(100.0),
+                                                height: Val::Percent(100.0),
+                                                flex_direction: FlexDirection::Row,
+                                                align_items: AlignItems::Center,
+                                                justify_content: JustifyContent::SpaceBetween,
+                                                ..default()
+                                            },
+                                            ..default()
+                                        },
+                                        Name::new("Nav Item"),
+                                    )).with_children(|parent| {
+                                        // Menu Nav Item
+                                        parent.spawn((
+                                            NodeBundle {
+                                                style: Style {
+                                                    width: Val::Percent(80.0),
+                                                    height: Val::Percent(100.0),
+                                                    align_items: AlignItems::Center,
+                                                    ..default()
+                                                },
+                                                ..default()
+                                            },
+                                            Name::new("Nav Item Left"),
+                                        )).with_children(|parent| {
+                                            parent.spawn((
+                                                NodeBundle {
+                                                    background_color: Color::WHITE.into(),
+                                                    visibility: Visibility::Hidden,
+                                                    style: Style {
+                                                        width: Val::Px(2.5),
+                                                        height: Val::Px(FONT_SIZE_SMALL),
+                                                        ..default()
+                                                    },
+                                                    ..default()
+                                                },
+                                                Name::new("Nav Item Selected"),
+                                                NavItemWhiteSelectedBar(NavItemScreen::Send),
+                                            ));
+                                            parent.spawn((
+                                                NodeBundle {
+                                                    style: Style {
+                                                        width: Val::Px(10.0),
+                                                        height: Val::Px(FONT_SIZE_SMALL),
+                                                        ..default()
+                                                    },
+                                                    ..default()
+                                                },
+                                                Name::new("Nav Item Selected Margin"),
+                                            ));
+                                            parent.spawn((
+                                                NodeBundle {
+                                                    background_color: Color::WHITE.into(),
+                                                    style: Style {
+                                                        width: Val::Px(15.0),
+                                                        height: Val::Px(15.0),
+                                                        margin: UiRect::right(Val::Px(5.0)),
+                                                        ..default()
+                                                    },
+                                                    ..default()
+                                                },
+                                                UiImage::new(
+                                                        asset_server.load(DASHBOARD_ICON_WHITE),
+                                                    ),
+                                                Name::new("Send Icon"),
+                                                NavItemIcon(NavItemScreen::Send),
+                                            ));
+                                            parent.spawn((
+                                                TextBundle::from_section(
+                                                    "Send",
+                                                    TextStyle {
+                                                        font: asset_server.load(FONT_REGULAR),
+                                                        font_size: FONT_SIZE_SMALL,
+                                                        color: hex_dark_mode_text_white_2()
+                                                    },
+                                                ),
+                                                Name::new("TextSend"),
+                                                NavItemText(NavItemScreen::Send)
+                                            ));
+                                        });
+
+                                        parent.spawn((
+                                            NodeBundle {
+                                                style: Style {
+                                                    width: Val::Percent(20.0),
+                                                    height: Val::Percent(100.0),
+                                                    justify_content: JustifyContent::End,
+                                                    align_items: AlignItems::Center,
+                                                    ..default()
+                                                },
+                                                ..default()
+                                            },
+                                            Name::new("Nav Item Right"),
+                                        )).with_children(|parent| {
+                                            parent.spawn((
+                                                NodeBundle {
+                                                    background_color: Color::WHITE.into(),
+                                                    style: Style {
+                                                        width: Val::Px(20.0),
+                                                        height: Val::Px(20.0),
+                                                        ..default()
+                                                    },
+                                                    ..default()
+                                                },
+                                                UiImage::new(
+                                                        asset_server.load(NAV_ARROW_ICON),
+                                                    ),
+                                                Name::new("Nav Arrow Icon"),
+                                                NavItemArrow(NavItemScreen::Send),
+                                            ));
+                                        });
+                                    });
+                                });
+
                             });
                         });
 
@@ -715,14 +1110,14 @@ pub fn spawn_base_screen(
                                 border_color: Color::ORANGE.into(),
                                 style: Style {
                                     width: Val::Percent(100.0),
-                                    height: Val::Percent(60.0),
+                                    height: Val::Percent(30.0),
                                     flex_direction: FlexDirection::Column,
                                     ..default()
                                 },
                                 ..default()
                             },
                             Name::new("Nav Bar Top Half Apps"),
-                        )).with_children(|parent| {
+                        )).with_children(|_parent| {
                             // Apps Nav Children
                         });
                     });
@@ -763,7 +1158,7 @@ pub fn spawn_base_screen(
                                     TextStyle {
                                         font: asset_server.load(FONT_REGULAR),
                                         font_size: FONT_SIZE_MEDIUM,
-                                        color: hex_dark_mode_text_white().into()
+                                        color: hex_dark_mode_text_white()
                                     },
                                 ),
                                 Name::new("TextSettings"),
@@ -874,7 +1269,7 @@ pub fn spawn_base_screen(
                                                 TextStyle {
                                                     font: asset_server.load(FONT_REGULAR),
                                                     font_size: FONT_SIZE_SMALL,
-                                                    color: hex_dark_mode_text_gray().into()
+                                                    color: hex_dark_mode_text_gray()
                                                 },
                                             ),
                                             Name::new("TextConfig"),
@@ -928,6 +1323,7 @@ pub fn spawn_base_screen(
                                 Name::new("Nav Bar Bottom Half Settings Items Item"),
                                 Interaction::default(),
                                 NavItem(NavItemScreen::SettingsWallet),
+                                SigningAction,
                             )).with_children(|parent| {
                                 // Menu Nav Item
                                 parent.spawn((
@@ -1005,7 +1401,7 @@ pub fn spawn_base_screen(
                                                 TextStyle {
                                                     font: asset_server.load(FONT_REGULAR),
                                                     font_size: FONT_SIZE_SMALL,
-                                                    color: hex_dark_mode_text_gray().into()
+                                                    color: hex_dark_mode_text_gray()
                                                 },
                                             ),
                                             Name::new("TextWallet"),
@@ -1136,7 +1532,7 @@ pub fn spawn_base_screen(
                                                 TextStyle {
                                                     font: asset_server.load(FONT_REGULAR),
                                                     font_size: FONT_SIZE_SMALL,
-                                                    color: hex_dark_mode_text_gray().into()
+                                                    color: hex_dark_mode_text_gray()
                                                 },
                                             ),
                                             Name::new("TextGeneral"),
@@ -1175,6 +1571,136 @@ pub fn spawn_base_screen(
                                     });
                                 });
                             });
+                            parent.spawn((
+                                NodeBundle {
+                                    style: Style {
+                                        width: Val::Percent(100.0),
+                                        height: Val::Px(28.0),
+                                        flex_direction: FlexDirection::Row,
+                                        padding: UiRect { left: Val::Px(0.0), right: Val::Px(0.0), top: Val::Px(5.0), bottom: Val::Px(5.0) },
+                                        ..default()
+                                    },
+                                    ..default()
+                                },
+                                Name::new("Nav Bar Bottom Half Menu Items Item"),
+                                Interaction::default(),
+                                NavItem(NavItemScreen::Logs),
+                            )).with_children(|parent| {
+                                // Menu Nav Item
+                                parent.spawn((
+                                    NodeBundle {
+                                        style: Style {
+                                            width: Val::Percent(100.0),
+                                            height: Val::Percent(100.0),
+                                            flex_direction: FlexDirection::Row,
+                                            align_items: AlignItems::Center,
+                                            justify_content: JustifyContent::SpaceBetween,
+                                            ..default()
+                                        },
+                                        ..default()
+                                    },
+                                    Name::new("Nav Item"),
+                                )).with_children(|parent| {
+                                    // Menu Nav Item
+                                    parent.spawn((
+                                        NodeBundle {
+                                            style: Style {
+                                                width: Val::Percent(80.0),
+                                                height: Val::Percent(100.0),
+                                                align_items: AlignItems::Center,
+                                                ..default()
+                                            },
+                                            ..default()
+                                        },
+                                        Name::new("Nav Item Left"),
+                                    )).with_children(|parent| {
+                                        parent.spawn((
+                                            NodeBundle {
+                                                background_color: Color::WHITE.into(),
+                                                visibility: Visibility::Hidden,
+                                                style: Style {
+                                                    width: Val::Px(2.5),
+                                                    height: Val::Px(FONT_SIZE_SMALL),
+                                                    ..default()
+                                                },
+                                                ..default()
+                                            },
+                                            Name::new("Nav Item Selected"),
+                                            NavItemWhiteSelectedBar(NavItemScreen::Logs),
+                                        ));
+                                        parent.spawn((
+                                            NodeBundle {
+                                                style: Style {
+                                                    width: Val::Px(10.0),
+                                                    height: Val::Px(FONT_SIZE_SMALL),
+                                                    ..default()
+                                                },
+                                                ..default()
+                                            },
+                                            Name::new("Nav Item Selected Margin"),
+                                        ));
+                                        parent.spawn((
+                                            NodeBundle {
+                                                background_color: Color::GRAY.into(),
+                                                style: Style {
+                                                    width: Val::Px(15.0),
+                                                    height: Val::Px(15.0),
+                                                    margin: UiRect::right(Val::Px(5.0)),
+                                                    ..default()
+                                                },
+                                                ..default()
+                                            },
+                                            UiImage::new(
+                                                    asset_server.load(MINING_ICON),
+                                                ),
+                                            Name::new("Logs Icon"),
+                                            NavItemIcon(NavItemScreen::Logs),
+                                        ));
+                                        parent.spawn((
+                                            TextBundle::from_section(
+                                                "Logs",
+                                                TextStyle {
+                                                    font: asset_server.load(FONT_REGULAR),
+                                                    font_size: FONT_SIZE_SMALL,
+                                                    color: hex_dark_mode_text_gray()
+                                                },
+                                            ),
+                                            Name::new("TextLogs"),
+                                            NavItemText(NavItemScreen::Logs),
+                                        ));
+                                    });
+
+                                    parent.spawn((
+                                        NodeBundle {
+                                            style: Style {
+                                                width: Val::Percent(20.0),
+                                                height: Val::Percent(100.0),
+                                                justify_content: JustifyContent::End,
+                                                ..default()
+                                            },
+                                            ..default()
+                                        },
+                                        Name::new("Nav Item Right"),
+                                    )).with_children(|parent| {
+                                        parent.spawn((
+                                            NodeBundle {
+                                                background_color: Color::GRAY.into(),
+                                                style: Style {
+                                                    width: Val::Px(20.0),
+                                                    height: Val::Px(20.0),
+                                                    ..default()
+                                                },
+                                                ..default()
+                                            },
+                                            UiImage::new(
+                                                    asset_server.load(NAV_ARROW_ICON),
+                                                ),
+                                            Name::new("Nav Arrow Icon"),
+                                            NavItemArrow(NavItemScreen::Logs),
+                                        ));
+                                    });
+                                });
+                            });
 
                         });
                     });
@@ -1,10 +1,10 @@
-use bevy::{ecs::storage::Column, prelude::*};
+use bevy::prelude::*;
 
 use crate::ui::{
     components::{
-        BaseScreenNode, ButtonCaptureTextInput, ButtonUnlock, DashboardProofUpdatesLogsList, DashboardScreenNode, LockedScreenNode, MovingScrollPanel, ScrollingList, ScrollingListNode, TextActiveMinersLastEpoch, TextActiveMinersThisEpoch, TextBus1, TextBus2, TextBus3, TextBus4, TextBus5, TextBus6, TextBus7, TextBus8, TextCrownStakeAmount, TextCursor, TextHighestDifficultySeen, TextInput, TextPasswordInput, TextPasswordLabel, TextTreasuryBalance, TextTreasuryRewardRate
+        DashboardProofUpdatesLogsList, DashboardScreenNode, MovingScrollPanel, ScrollingList, ScrollingListNode, TextActiveMinersLastEpoch, TextActiveMinersThisEpoch, TextBus1, TextBus2, TextBus3, TextBus4, TextBus5, TextBus6, TextBus7, TextBus8, TextCrownStakeAmount, TextCrownStakeBy, TextHighestDifficultySeen, TextStakeDistribution, TextTreasuryBalance, TextTreasuryRewardRate
     },
-    styles::{hex_dark_mode_background, hex_dark_mode_nav_title, hex_dark_mode_text_gray, hex_dark_mode_text_white_2, CONTENT_BACKGROUND_MEDIUM, CONTENT_BACKGROUND_SMALL, FONT_REGULAR, FONT_SIZE_LARGE, FONT_SIZE_MEDIUM, FONT_SIZE_SMALL, NORMAL_BUTTON},
+    styles::{hex_dark_mode_background, hex_dark_mode_nav_title, hex_dark_mode_text_gray, CONTENT_BACKGROUND_MEDIUM, CONTENT_BACKGROUND_SMALL, FONT_REGULAR, FONT_SIZE_MEDIUM, FONT_SIZE_SMALL},
 };
 
 pub fn spawn_dashboard_screen(
@@ -88,7 +88,7 @@ pub fn spawn_dashboard_screen(
                                 TextStyle {
                                     font: asset_server.load(FONT_REGULAR),
                                     font_size: FONT_SIZE_MEDIUM,
-                                    color: hex_dark_mode_text_gray().into()
+                                    color: hex_dark_mode_text_gray()
                                 },
                             ),
                             Name::new("TextTreasuryBalance"),
@@ -99,7 +99,7 @@ pub fn spawn_dashboard_screen(
                                 TextStyle {
                                     font: asset_server.load(FONT_REGULAR),
                                     font_size: FONT_SIZE_MEDIUM,
-                                    color: hex_dark_mode_text_gray().into()
+                                    color: hex_dark_mode_text_gray()
                                 },
                             ),
                             Name::new("TextTreasuryOreBalance"),
@@ -136,7 +136,7 @@ pub fn spawn_dashboard_screen(
                                 TextStyle {
                                     font: asset_server.load(FONT_REGULAR),
                                     font_size: FONT_SIZE_MEDIUM,
-                                    color: hex_dark_mode_text_gray().into()
+                                    color: hex_dark_mode_text_gray()
                                 },
                             ),
                             Name::new("TextTreasuryBalance"),
@@ -147,7 +147,7 @@ pub fn spawn_dashboard_screen(
                                 TextStyle {
                                     font: asset_server.load(FONT_REGULAR),
                                     font_size: FONT_SIZE_MEDIUM,
-                                    color: hex_dark_mode_text_gray().into()
+                                    color: hex_dark_mode_text_gray()
                                 },
                             ),
                             Name::new("TextBaseRewardRate"),
@@ -184,7 +184,7 @@ pub fn spawn_dashboard_screen(
                                 TextStyle {
                                     font: asset_server.load(FONT_REGULAR),
                                     font_size: FONT_SIZE_MEDIUM,
-                                    color: hex_dark_mode_text_gray().into()
+                                    color: hex_dark_mode_text_gray()
                                 },
                             ),
                             Name::new("TextHighestDifficultySeen"),
@@ -195,7 +195,7 @@ pub fn spawn_dashboard_screen(
                                 TextStyle {
                                     font: asset_server.load(FONT_REGULAR),
                                     font_size: FONT_SIZE_MEDIUM,
-                                    color: hex_dark_mode_text_gray().into()
+                                    color: hex_dark_mode_text_gray()
                                 },
                             ),
                             Name::new("TextHighestDifficultySeen"),
@@ -232,7 +232,7 @@ pub fn spawn_dashboard_screen(
                                 TextStyle {
                                     font: asset_server.load(FONT_REGULAR),
                                     font_size: FONT_SIZE_MEDIUM,
-                                    color: hex_dark_mode_text_gray().into()
+                                    color: hex_dark_mode_text_gray()
                                 },
                             ),
                             Name::new("TextTitleCrown"),
@@ -243,7 +243,7 @@ pub fn spawn_dashboard_screen(
                                 TextStyle {
                                     font: asset_server.load(FONT_REGULAR),
                                     font_size: FONT_SIZE_MEDIUM,
-                                    color: hex_dark_mode_text_gray().into()
+                                    color: hex_dark_mode_text_gray()
                                 },
                             ),
                             Name::new("TextCrownStakeAmount"),
@@ -255,7 +255,7 @@ pub fn spawn_dashboard_screen(
                                 TextStyle {
                                     font: asset_server.load(FONT_REGULAR),
                                     font_size: FONT_SIZE_MEDIUM,
-                                    color: hex_dark_mode_text_gray().into()
+                                    color: hex_dark_mode_text_gray()
                                 },
                             ),
                             Name::new("TextTitleCrownBy"),
@@ -266,11 +266,11 @@ pub fn spawn_dashboard_screen(
                                 TextStyle {
                                     font: asset_server.load(FONT_REGULAR),
                                     font_size: FONT_SIZE_MEDIUM,
-                                    color: hex_dark_mode_text_gray().into()
+                                    color: hex_dark_mode_text_gray()
                                 },
                             ),
-                            Name::new("TextCrownStakeAmount"),
-                            TextCrownStakeAmount,
+                            Name::new("TextCrownStakeBy"),
+                            TextCrownStakeBy,
                         ));
                     });
                 });
@@ -299,7 +299,7 @@ pub fn spawn_dashboard_screen(
                             TextStyle {
                                 font: asset_server.load(FONT_REGULAR),
                                 font_size: FONT_SIZE_MEDIUM,
-                                color: hex_dark_mode_text_gray().into()
+                                color: hex_dark_mode_text_gray()
                             },
                         ),
                         Name::new("TextBussesTitle"),
@@ -334,7 +334,7 @@ pub fn spawn_dashboard_screen(
                                 TextStyle {
                                     font: asset_server.load(FONT_REGULAR),
                                     font_size: FONT_SIZE_MEDIUM,
-                                    color: hex_dark_mode_text_gray().into()
+                                    color: hex_dark_mode_text_gray()
                                 },
                             ),
                             Name::new("TextBus1"),
@@ -345,7 +345,7 @@ pub fn spawn_dashboard_screen(
                                 TextStyle {
                                     font: asset_server.load(FONT_REGULAR),
                                     font_size: FONT_SIZE_MEDIUM,
-                                    color: hex_dark_mode_text_gray().into()
+                                    color: hex_dark_mode_text_gray()
                                 },
                             ),
                             Name::new("TextBus1"),
@@ -381,7 +381,7 @@ pub fn spawn_dashboard_screen(
                                 TextStyle {
                                     font: asset_server.load(FONT_REGULAR),
                                     font_size: FONT_SIZE_MEDIUM,
-                                    color: hex_dark_mode_text_gray().into()
+                                    color: hex_dark_mode_text_gray()
                                 },
                             ),
                             Name::new("TextBus2"),
@@ -392,7 +392,7 @@ pub fn spawn_dashboard_screen(
                                 TextStyle {
                                     font: asset_server.load(FONT_REGULAR),
                                     font_size: FONT_SIZE_MEDIUM,
-                                    color: hex_dark_mode_text_gray().into()
+                                    color: hex_dark_mode_text_gray()
                                 },
                             ),
                             Name::new("TextBus2"),
@@ -428,7 +428,7 @@ pub fn spawn_dashboard_screen(
                                 TextStyle {
                                     font: asset_server.load(FONT_REGULAR),
                                     font_size: FONT_SIZE_MEDIUM,
-                                    color: hex_dark_mode_text_gray().into()
+                                    color: hex_dark_mode_text_gray()
                                 },
                             ),
                             Name::new("TextBus3"),
@@ -439,7 +439,7 @@ pub fn spawn_dashboard_screen(
                                 TextStyle {
                                     font: asset_server.load(FONT_REGULAR),
                                     font_size: FONT_SIZE_MEDIUM,
-                                    color: hex_dark_mode_text_gray().into()
+                                    color: hex_dark_mode_text_gray()
                                 },
                             ),
                             Name::new("TextBus3"),
@@ -475,7 +475,7 @@ pub fn spawn_dashboard_screen(
                                 TextStyle {
                                     font: asset_server.load(FONT_REGULAR),
                                     font_size: FONT_SIZE_MEDIUM,
-                                    color: hex_dark_mode_text_gray().into()
+                                    color: hex_dark_mode_text_gray()
                                 },
                             ),
                             Name::new("TextBus4"),
@@ -486,7 +486,7 @@ pub fn spawn_dashboard_screen(
                                 TextStyle {
                                     font: asset_server.load(FONT_REGULAR),
                                     font_size: FONT_SIZE_MEDIUM,
-                                    color: hex_dark_mode_text_gray().into()
+                                    color: hex_dark_mode_text_gray()
                                 },
                             ),
                             Name::new("TextBus4"),
@@ -522,7 +522,7 @@ pub fn spawn_dashboard_screen(
                                 TextStyle {
                                     font: asset_server.load(FONT_REGULAR),
                                     font_size: FONT_SIZE_MEDIUM,
-                                    color: hex_dark_mode_text_gray().into()
+                                    color: hex_dark_mode_text_gray()
                                 },
                             ),
                             Name::new("TextBus5"),
@@ -533,7 +533,7 @@ pub fn spawn_dashboard_screen(
                                 TextStyle {
                                     font: asset_server.load(FONT_REGULAR),
                                     font_size: FONT_SIZE_MEDIUM,
-                                    color: hex_dark_mode_text_gray().into()
+                                    color: hex_dark_mode_text_gray()
                                 },
                             ),
                             Name::new("TextBus5"),
@@ -569,7 +569,7 @@ pub fn spawn_dashboard_screen(
                                 TextStyle {
                                     font: asset_server.load(FONT_REGULAR),
                                     font_size: FONT_SIZE_MEDIUM,
-                                    color: hex_dark_mode_text_gray().into()
+                                    color: hex_dark_mode_text_gray()
                                 },
                             ),
                             Name::new("TextBus6"),
@@ -580,7 +580,7 @@ pub fn spawn_dashboard_screen(
                                 TextStyle {
                                     font: asset_server.load(FONT_REGULAR),
                                     font_size: FONT_SIZE_MEDIUM,
-                                    color: hex_dark_mode_text_gray().into()
+                                    color: hex_dark_mode_text_gray()
                                 },
                             ),
                             Name::new("TextBus6"),
@@ -616,7 +616,7 @@ pub fn spawn_dashboard_screen(
                                 TextStyle {
                                     font: asset_server.load(FONT_REGULAR),
                                     font_size: FONT_SIZE_MEDIUM,
-                                    color: hex_dark_mode_text_gray().into()
+                                    color: hex_dark_mode_text_gray()
                                 },
                             ),
                             Name::new("TextBus7"),
@@ -627,7 +627,7 @@ pub fn spawn_dashboard_screen(
                                 TextStyle {
                                     font: asset_server.load(FONT_REGULAR),
                                     font_size: FONT_SIZE_MEDIUM,
-                                    color: hex_dark_mode_text_gray().into()
+                                    color: hex_dark_mode_text_gray()
                                 },
                             ),
                             Name::new("TextBus7"),
@@ -663,7 +663,7 @@ pub fn spawn_dashboard_screen(
                                 TextStyle {
                                     font: asset_server.load(FONT_REGULAR),
                                     font_size: FONT_SIZE_MEDIUM,
-                                    color: hex_dark_mode_text_gray().into()
+                                    color: hex_dark_mode_text_gray()
                                 },
                             ),
                             Name::new("TextBus8"),
@@ -674,7 +674,7 @@ pub fn spawn_dashboard_screen(
                                 TextStyle {
                                     font: asset_server.load(FONT_REGULAR),
                                     font_size: FONT_SIZE_MEDIUM,
-                                    color: hex_dark_mode_text_gray().into()
+                                    color: hex_dark_mode_text_gray()
                                 },
                             ),
                             Name::new("TextBus8"),
@@ -801,7 +801,7 @@ pub fn spawn_dashboard_screen(
                                 TextStyle {
                                     font: asset_server.load(FONT_REGULAR),
                                     font_size: FONT_SIZE_MEDIUM,
-                                    color: hex_dark_mode_text_gray().into()
+                                    color: hex_dark_mode_text_gray()
                                 },
                             ),
                             Name::new("TextActiveMinersTitle"),
@@ -812,7 +812,7 @@ pub fn spawn_dashboard_screen(
                                 TextStyle {
                                     font: asset_server.load(FONT_REGULAR),
                                     font_size: FONT_SIZE_MEDIUM,
-                                    color: hex_dark_mode_text_gray().into()
+                                    color: hex_dark_mode_text_gray()
                                 },
                             ),
                             Name::new("TextActiveMiners"),
@@ -824,7 +824,7 @@ pub fn spawn_dashboard_screen(
                                 TextStyle {
                                     font: asset_server.load(FONT_REGULAR),
                                     font_size: FONT_SIZE_MEDIUM,
-                                    color: hex_dark_mode_text_gray().into()
+                                    color: hex_dark_mode_text_gray()
                                 },
                             ),
                             Name::new("TextActiveMinersTitle"),
@@ -835,12 +835,35 @@ pub fn spawn_dashboard_screen(
                                 TextStyle {
                                     font: asset_server.load(FONT_REGULAR),
                                     font_size: FONT_SIZE_MEDIUM,
-                                    color: hex_dark_mode_text_gray().into()
+                                    color: hex_dark_mode_text_gray()
                                 },
                             ),
                             Name::new("TextActiveMinersLastEpoch"),
                             TextActiveMinersLastEpoch,
                         ));
+                        parent.spawn((
+                            TextBundle::from_section(
+                                "Stake Distribution (ORE):",
+                                TextStyle {
+                                    font: asset_server.load(FONT_REGULAR),
+                                    font_size: FONT_SIZE_MEDIUM,
+                                    color: hex_dark_mode_text_gray()
+                                },
+                            ),
+                            Name::new("TextStakeDistributionTitle"),
+                        ));
+                        parent.spawn((
+                            TextBundle::from_section(
+                                "loading...",
+                                TextStyle {
+                                    font: asset_server.load(FONT_REGULAR),
+                                    font_size: FONT_SIZE_SMALL,
+                                    color: hex_dark_mode_text_gray()
+                                },
+                            ),
+                            Name::new("TextStakeDistribution"),
+                            TextStakeDistribution,
+                        ));
                     });
 
                 });
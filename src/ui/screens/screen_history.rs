@@ -0,0 +1,444 @@
+use bevy::{
+    a11y::{
+        accesskit::{NodeBuilder, Role},
+        AccessibilityNode,
+    },
+    prelude::*,
+};
+
+use crate::{mining_history::ExportFormat, ui::{
+    components::{
+        ButtonCaptureTextInput, ButtonExportFormat, ButtonExportMiningHistory,
+        ButtonHistoryLoadMore, ButtonHistoryRefresh, HistoryScreenNode, HistoryScreenTxList,
+        MovingScrollPanel, ScrollingList, TextCursor, TextExportFormat, TextExportFromInput,
+        TextExportToInput, TextHistoryStatus, TextInput, TextMiningExportStatus,
+    },
+    styles::{
+        hex_dark_mode_text_gray, CONTENT_BACKGROUND_MEDIUM, FONT_REGULAR, FONT_SIZE_MEDIUM,
+        FONT_SIZE_SMALL as FONT_SIZE, LOG_ITEMS_BACKGROUND, NORMAL_BUTTON,
+    },
+}};
+
+/// Days covered by the export row's default range.
+const DEFAULT_EXPORT_DAYS: i64 = 30;
+
+pub fn spawn_history_screen(
+    parent: &mut ChildBuilder,
+    asset_server: &AssetServer,
+    export_format: ExportFormat,
+) {
+    let today = chrono::Utc::now().date_naive();
+    let export_from = (today - chrono::Duration::days(DEFAULT_EXPORT_DAYS)).format("%Y-%m-%d").to_string();
+    let export_to = today.format("%Y-%m-%d").to_string();
+
+    parent.spawn((
+        NodeBundle {
+            style: Style {
+                width: Val::Percent(100.0),
+                height: Val::Percent(100.0),
+                flex_direction: FlexDirection::Column,
+                align_items: AlignItems::Center,
+                justify_content: JustifyContent::Center,
+                position_type: PositionType::Absolute,
+                ..default()
+            },
+            ..default()
+        },
+        Name::new("History App Screen"),
+        HistoryScreenNode,
+    )).with_children(|parent| {
+        parent.spawn((
+            NodeBundle {
+                background_color: Color::WHITE.into(),
+                style: Style {
+                    width: Val::Percent(95.0),
+                    height: Val::Percent(95.0),
+                    flex_direction: FlexDirection::Column,
+                    align_items: AlignItems::Center,
+                    padding: UiRect::top(Val::Px(10.0)),
+                    ..default()
+                },
+                ..default()
+            },
+            UiImage::new(asset_server.load(CONTENT_BACKGROUND_MEDIUM)),
+            Name::new("History Content Node"),
+        )).with_children(|parent| {
+            // Title and Buttons
+            parent.spawn((
+                NodeBundle {
+                    style: Style {
+                        width: Val::Percent(95.0),
+                        height: Val::Px(50.0),
+                        flex_direction: FlexDirection::Row,
+                        align_items: AlignItems::Center,
+                        justify_content: JustifyContent::SpaceBetween,
+                        ..default()
+                    },
+                    ..default()
+                },
+                Name::new("History Title Node"),
+            )).with_children(|parent| {
+                parent.spawn((
+                    TextBundle::from_section(
+                        "Transaction History",
+                        TextStyle {
+                            font: asset_server.load(FONT_REGULAR),
+                            font_size: FONT_SIZE_MEDIUM,
+                            color: hex_dark_mode_text_gray(),
+                        },
+                    ),
+                    Name::new("TextHistoryTitle"),
+                ));
+                parent.spawn((
+                    TextBundle::from_section(
+                        "loading...",
+                        TextStyle {
+                            font: asset_server.load(FONT_REGULAR),
+                            font_size: FONT_SIZE,
+                            color: hex_dark_mode_text_gray(),
+                        },
+                    ),
+                    Name::new("TextHistoryStatus"),
+                    TextHistoryStatus,
+                ));
+                parent.spawn((
+                    NodeBundle {
+                        style: Style {
+                            flex_direction: FlexDirection::Row,
+                            column_gap: Val::Px(10.0),
+                            ..default()
+                        },
+                        ..default()
+                    },
+                    Name::new("History Buttons Node"),
+                )).with_children(|parent| {
+                    parent
+                        .spawn((
+                            ButtonBundle {
+                                style: Style {
+                                    width: Val::Px(100.0),
+                                    height: Val::Px(35.0),
+                                    border: UiRect::all(Val::Px(2.5)),
+                                    justify_content: JustifyContent::Center,
+                                    align_items: AlignItems::Center,
+                                    ..default()
+                                },
+                                border_color: BorderColor(Color::BLACK),
+                                background_color: NORMAL_BUTTON.into(),
+                                ..default()
+                            },
+                            ButtonHistoryRefresh,
+                            Name::new("ButtonHistoryRefresh"),
+                        ))
+                        .with_children(|parent| {
+                            parent.spawn(TextBundle::from_section(
+                                "Refresh",
+                                TextStyle {
+                                    font: asset_server.load(FONT_REGULAR),
+                                    font_size: FONT_SIZE,
+                                    color: Color::rgb(0.9, 0.9, 0.9),
+                                },
+                            ));
+                        });
+                    parent
+                        .spawn((
+                            ButtonBundle {
+                                style: Style {
+                                    width: Val::Px(100.0),
+                                    height: Val::Px(35.0),
+                                    border: UiRect::all(Val::Px(2.5)),
+                                    justify_content: JustifyContent::Center,
+                                    align_items: AlignItems::Center,
+                                    ..default()
+                                },
+                                border_color: BorderColor(Color::BLACK),
+                                background_color: NORMAL_BUTTON.into(),
+                                ..default()
+                            },
+                            ButtonHistoryLoadMore,
+                            Name::new("ButtonHistoryLoadMore"),
+                        ))
+                        .with_children(|parent| {
+                            parent.spawn(TextBundle::from_section(
+                                "Load More",
+                                TextStyle {
+                                    font: asset_server.load(FONT_REGULAR),
+                                    font_size: FONT_SIZE,
+                                    color: Color::rgb(0.9, 0.9, 0.9),
+                                },
+                            ));
+                        });
+                });
+            });
+
+            // Export mining results
+            parent.spawn((
+                NodeBundle {
+                    style: Style {
+                        width: Val::Percent(95.0),
+                        height: Val::Px(45.0),
+                        flex_direction: FlexDirection::Row,
+                        align_items: AlignItems::Center,
+                        column_gap: Val::Px(10.0),
+                        ..default()
+                    },
+                    ..default()
+                },
+                Name::new("History Export Node"),
+            )).with_children(|parent| {
+                spawn_export_date_input(parent, asset_server, "From: ", export_from, TextExportFromInput);
+                spawn_export_date_input(parent, asset_server, "To: ", export_to, TextExportToInput);
+                parent
+                    .spawn((
+                        ButtonBundle {
+                            style: Style {
+                                width: Val::Px(70.0),
+                                height: Val::Px(35.0),
+                                border: UiRect::all(Val::Px(2.5)),
+                                justify_content: JustifyContent::Center,
+                                align_items: AlignItems::Center,
+                                ..default()
+                            },
+                            border_color: BorderColor(Color::BLACK),
+                            background_color: NORMAL_BUTTON.into(),
+                            ..default()
+                        },
+                        ButtonExportFormat,
+                        Name::new("ButtonExportFormat"),
+                    ))
+                    .with_children(|parent| {
+                        parent.spawn((
+                            TextBundle::from_section(
+                                export_format.to_string(),
+                                TextStyle {
+                                    font: asset_server.load(FONT_REGULAR),
+                                    font_size: FONT_SIZE,
+                                    color: Color::rgb(0.9, 0.9, 0.9),
+                                },
+                            ),
+                            TextExportFormat,
+                        ));
+                    });
+                parent
+                    .spawn((
+                        ButtonBundle {
+                            style: Style {
+                                width: Val::Px(100.0),
+                                height: Val::Px(35.0),
+                                border: UiRect::all(Val::Px(2.5)),
+                                justify_content: JustifyContent::Center,
+                                align_items: AlignItems::Center,
+                                ..default()
+                            },
+                            border_color: BorderColor(Color::BLACK),
+                            background_color: NORMAL_BUTTON.into(),
+                            ..default()
+                        },
+                        ButtonExportMiningHistory,
+                        Name::new("ButtonExportMiningHistory"),
+                    ))
+                    .with_children(|parent| {
+                        parent.spawn(TextBundle::from_section(
+                            "Export",
+                            TextStyle {
+                                font: asset_server.load(FONT_REGULAR),
+                                font_size: FONT_SIZE,
+                                color: Color::rgb(0.9, 0.9, 0.9),
+                            },
+                        ));
+                    });
+                parent.spawn((
+                    TextBundle::from_section(
+                        "",
+                        TextStyle {
+                            font: asset_server.load(FONT_REGULAR),
+                            font_size: FONT_SIZE,
+                            color: hex_dark_mode_text_gray(),
+                        },
+                    ),
+                    Name::new("TextMiningExportStatus"),
+                    TextMiningExportStatus,
+                ));
+            });
+
+            parent.spawn((
+                NodeBundle {
+                    background_color: Color::WHITE.into(),
+                    style: Style {
+                        flex_direction: FlexDirection::Column,
+                        align_items: AlignItems::Center,
+                        height: Val::Percent(75.0),
+                        width: Val::Percent(95.0),
+                        ..default()
+                    },
+                    ..default()
+                },
+                UiImage::new(asset_server.load(LOG_ITEMS_BACKGROUND)),
+                Name::new("HistoryList Node"),
+            )).with_children(|parent| {
+                // Column Headers
+                parent.spawn((
+                    NodeBundle {
+                        style: Style {
+                            flex_direction: FlexDirection::Row,
+                            width: Val::Percent(100.0),
+                            justify_content: JustifyContent::Center,
+                            ..default()
+                        },
+                        ..default()
+                    },
+                    Name::new("History Title"),
+                )).with_children(|parent| {
+                    let headers = [
+                        ("Type", 60.0),
+                        ("Time", 80.0),
+                        ("Signature", 134.0),
+                        ("Amount", 80.0),
+                        ("Fee - Slot", 150.0),
+                        ("Status", 200.0),
+                    ];
+                    for (header, width) in headers {
+                        parent.spawn((
+                            NodeBundle {
+                                style: Style {
+                                    height: Val::Px(20.0),
+                                    width: Val::Px(width),
+                                    align_items: AlignItems::Center,
+                                    justify_content: JustifyContent::Center,
+                                    ..default()
+                                },
+                                ..default()
+                            },
+                            Name::new(header),
+                        )).with_children(|parent| {
+                            parent.spawn((
+                                TextBundle::from_section(
+                                    header,
+                                    TextStyle {
+                                        font: asset_server.load(FONT_REGULAR),
+                                        font_size: FONT_SIZE,
+                                        color: hex_dark_mode_text_gray(),
+                                    },
+                                ),
+                                Label,
+                            ));
+                        });
+                    }
+                });
+
+                // List with hidden overflow
+                parent
+                    .spawn((
+                        NodeBundle {
+                            style: Style {
+                                flex_direction: FlexDirection::Column,
+                                align_self: AlignSelf::Stretch,
+                                min_height: Val::Percent(90.0),
+                                max_height: Val::Percent(90.0),
+                                overflow: Overflow::clip_y(),
+                                ..default()
+                            },
+                            ..default()
+                        },
+                        Name::new("ScrollingList Node"),
+                    ))
+                    .with_children(|parent| {
+                        // Moving panel
+                        parent.spawn((
+                            NodeBundle {
+                                style: Style {
+                                    flex_direction: FlexDirection::Column,
+                                    align_items: AlignItems::Center,
+                                    ..default()
+                                },
+                                ..default()
+                            },
+                            ScrollingList::default(),
+                            AccessibilityNode(NodeBuilder::new(Role::List)),
+                            HistoryScreenTxList,
+                            MovingScrollPanel,
+                            Name::new("MovingScrollPanel"),
+                        ));
+                    });
+            });
+        });
+    });
+}
+
+fn spawn_export_date_input(
+    parent: &mut ChildBuilder,
+    asset_server: &AssetServer,
+    label: &str,
+    value: String,
+    marker: impl Component,
+) {
+    parent.spawn(TextBundle::from_section(
+        label,
+        TextStyle {
+            font: asset_server.load(FONT_REGULAR),
+            font_size: FONT_SIZE,
+            color: hex_dark_mode_text_gray(),
+        },
+    ));
+    parent
+        .spawn((
+            ButtonBundle {
+                style: Style {
+                    width: Val::Px(130.0),
+                    height: Val::Px(30.0),
+                    border: UiRect::all(Val::Px(2.5)),
+                    padding: UiRect::left(Val::Px(5.0)),
+                    align_items: AlignItems::Center,
+                    overflow: Overflow::clip_x(),
+                    ..default()
+                },
+                border_color: BorderColor(Color::BLACK),
+                background_color: NORMAL_BUTTON.into(),
+                ..default()
+            },
+            ButtonCaptureTextInput,
+            Name::new("ButtonCaptureText"),
+        ))
+        .with_children(|parent| {
+            parent.spawn((
+                TextBundle::from_section(
+                    value.clone(),
+                    TextStyle {
+                        font: asset_server.load(FONT_REGULAR),
+                        font_size: FONT_SIZE,
+                        color: Color::rgb(0.9, 0.9, 0.9),
+                    },
+                ),
+                TextInput {
+                    hidden: false,
+                    numbers_only: false,
+                    text: value,
+                    secret: None,
+                },
+                marker,
+            ));
+            parent.spawn((
+                NodeBundle {
+                    visibility: Visibility::Hidden,
+                    style: Style {
+                        width: Val::Px(8.0),
+                        height: Val::Px(18.0),
+                        ..default()
+                    },
+                    background_color: Color::WHITE.into(),
+                    ..default()
+                },
+                TextCursor,
+                Name::new("TextCursor"),
+            ));
+        });
+}
+
+pub fn despawn_history_screen(
+    mut commands: Commands,
+    query: Query<Entity, With<HistoryScreenNode>>,
+) {
+    if let Ok(screen_node) = query.get_single() {
+        commands.entity(screen_node).despawn_recursive();
+    }
+}
@@ -1,17 +1,59 @@
 use bevy::prelude::*;
+use chrono::DateTime;
 
-use crate::ui::{
-    components::{
-        BaseScreenNode, ButtonCaptureTextInput, ButtonUnlock, LockedScreenNode, TextCursor, TextInput, TextPasswordInput, TextPasswordLabel
+use crate::{
+    secret_string::SecretString,
+    ui::{
+        components::{
+            ButtonCaptureTextInput, ButtonNewProfile, ButtonSelectProfile, ButtonUnlock, LockedScreenNode, TextCursor, TextInput, TextLockedWalletLabel, TextLockedWalletPubkey, TextPasswordInput, TextPasswordLabel, TextUnlockStatus
+        },
+        styles::{hex_dark_mode_text_gray, FONT_SIZE_SMALL, NORMAL_BUTTON},
     },
-    styles::{FONT_SIZE_SMALL, NORMAL_BUTTON},
+    wallet_file::read_wallet_header,
+    wallet_profiles::{WalletProfile, WalletProfiles},
 };
 
+/// Pubkey and label lines for the wallet about to be unlocked, read from the
+/// plaintext header of its file.
+pub fn locked_wallet_info(profile: Option<&WalletProfile>) -> (String, String) {
+    let profile = if let Some(profile) = profile {
+        profile
+    } else {
+        return ("No wallet".to_string(), String::new());
+    };
+
+    if let Some(pubkey) = &profile.watch_pubkey {
+        return (
+            pubkey.clone(),
+            "Watch-only, no password needed.".to_string(),
+        );
+    }
+
+    match read_wallet_header(&profile.wallet_file()) {
+        Ok(Some(header)) => {
+            let created = DateTime::from_timestamp(header.created_at as i64, 0)
+                .map(|dt| dt.format("%Y-%m-%d").to_string())
+                .unwrap_or_default();
+            (
+                header.pubkey,
+                format!("Label: {}    Created: {}", header.label, created),
+            )
+        },
+        Ok(None) => (
+            "Unknown pubkey".to_string(),
+            "Older wallet file, it is upgraded to show its details on the next unlock.".to_string(),
+        ),
+        Err(e) => ("Unknown pubkey".to_string(), e),
+    }
+}
+
 pub fn spawn_locked_screen(
     parent: &mut ChildBuilder,
     asset_server: Res<AssetServer>,
+    profiles: &WalletProfiles,
 ) -> Option<Entity> {
     let mut password_capture_text_entity = None;
+    let (wallet_pubkey, wallet_label) = locked_wallet_info(profiles.active_profile());
     parent
         .spawn((
             NodeBundle {
@@ -30,6 +72,115 @@ pub fn spawn_locked_screen(
             LockedScreenNode,
         ))
         .with_children(|parent| {
+            parent
+                .spawn((
+                    NodeBundle {
+                        style: Style {
+                            justify_content: JustifyContent::Center,
+                            align_items: AlignItems::Center,
+                            flex_direction: FlexDirection::Row,
+                            flex_wrap: FlexWrap::Wrap,
+                            max_width: Val::Percent(80.0),
+                            column_gap: Val::Px(10.0),
+                            row_gap: Val::Px(10.0),
+                            ..default()
+                        },
+                        ..default()
+                    },
+                    Name::new("Profile Picker Node"),
+                ))
+                .with_children(|parent| {
+                    parent.spawn(TextBundle::from_section(
+                        "Profile: ",
+                        TextStyle {
+                            font: asset_server.load("fonts/FiraSans-Bold.ttf"),
+                            font_size: FONT_SIZE_SMALL,
+                            color: Color::rgb(0.9, 0.9, 0.9),
+                        },
+                    ));
+                    for profile in profiles.profiles.iter() {
+                        parent
+                            .spawn((
+                                ButtonBundle {
+                                    style: Style {
+                                        height: Val::Px(40.0),
+                                        padding: UiRect::horizontal(Val::Px(12.0)),
+                                        border: UiRect::all(Val::Px(2.5)),
+                                        justify_content: JustifyContent::Center,
+                                        align_items: AlignItems::Center,
+                                        ..default()
+                                    },
+                                    border_color: BorderColor(Color::BLACK),
+                                    background_color: NORMAL_BUTTON.into(),
+                                    ..default()
+                                },
+                                ButtonSelectProfile(profile.name.clone()),
+                                Name::new("ButtonSelectProfile"),
+                            ))
+                            .with_children(|parent| {
+                                parent.spawn(TextBundle::from_section(
+                                    profile.name.clone(),
+                                    TextStyle {
+                                        font: asset_server.load("fonts/FiraSans-Bold.ttf"),
+                                        font_size: FONT_SIZE_SMALL,
+                                        color: Color::rgb(0.9, 0.9, 0.9),
+                                    },
+                                ));
+                            });
+                    }
+                    parent
+                        .spawn((
+                            ButtonBundle {
+                                style: Style {
+                                    height: Val::Px(40.0),
+                                    padding: UiRect::horizontal(Val::Px(12.0)),
+                                    border: UiRect::all(Val::Px(2.5)),
+                                    justify_content: JustifyContent::Center,
+                                    align_items: AlignItems::Center,
+                                    ..default()
+                                },
+                                border_color: BorderColor(Color::BLACK),
+                                background_color: NORMAL_BUTTON.into(),
+                                ..default()
+                            },
+                            ButtonNewProfile,
+                            Name::new("ButtonNewProfile"),
+                        ))
+                        .with_children(|parent| {
+                            parent.spawn(TextBundle::from_section(
+                                "+ NEW",
+                                TextStyle {
+                                    font: asset_server.load("fonts/FiraSans-Bold.ttf"),
+                                    font_size: FONT_SIZE_SMALL,
+                                    color: Color::rgb(0.9, 0.9, 0.9),
+                                },
+                            ));
+                        });
+                });
+            parent.spawn((
+                TextBundle::from_section(
+                    wallet_pubkey,
+                    TextStyle {
+                        font: asset_server.load("fonts/FiraSans-Bold.ttf"),
+                        font_size: FONT_SIZE_SMALL,
+                        color: Color::rgb(0.9, 0.9, 0.9),
+                    },
+                ),
+                TextLockedWalletPubkey,
+                Name::new("TextLockedWalletPubkey"),
+            ));
+            parent.spawn((
+                TextBundle::from_section(
+                    wallet_label,
+                    TextStyle {
+                        font: asset_server.load("fonts/FiraSans-Bold.ttf"),
+                        font_size: FONT_SIZE_SMALL,
+                        color: hex_dark_mode_text_gray(),
+                    },
+                ),
+                TextLockedWalletLabel,
+                Name::new("TextLockedWalletLabel"),
+            ));
             parent
                 .spawn((
                     NodeBundle {
@@ -105,6 +256,7 @@ pub fn spawn_locked_screen(
                                                 hidden: true,
                                                 numbers_only: false,
                                                 text: "".to_string(),
+                                                secret: Some(SecretString::new()),
                                             },
                                             TextPasswordInput,
                                         ));
@@ -177,6 +329,18 @@ pub fn spawn_locked_screen(
                             ));
                         });
                 });
+            parent.spawn((
+                TextBundle::from_section(
+                    "",
+                    TextStyle {
+                        font: asset_server.load("fonts/FiraSans-Bold.ttf"),
+                        font_size: FONT_SIZE_SMALL,
+                        color: Color::RED,
+                    },
+                ),
+                TextUnlockStatus,
+                Name::new("TextUnlockStatus"),
+            ));
         });
 
     password_capture_text_entity
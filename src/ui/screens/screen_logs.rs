@@ -0,0 +1,325 @@
+use bevy::{
+    a11y::{
+        accesskit::{NodeBuilder, Role},
+        AccessibilityNode,
+    },
+    log::Level,
+    prelude::*,
+};
+
+use crate::ui::{
+    components::{
+        ButtonCaptureTextInput, ButtonCreateSupportBundle, ButtonLogLevelFilter, LogViewerList, LogsScreenNode,
+        MovingScrollPanel, ScrollingList, TextCursor, TextInput, TextLogSearchInput,
+        TextLogViewerStatus, TextSupportBundleStatus,
+    },
+    styles::{
+        hex_dark_mode_text_gray, CONTENT_BACKGROUND_MEDIUM, FONT_REGULAR, FONT_SIZE_MEDIUM,
+        FONT_SIZE_SMALL as FONT_SIZE, LOG_ITEMS_BACKGROUND, NORMAL_BUTTON,
+    },
+};
+
+pub fn spawn_logs_screen(parent: &mut ChildBuilder, asset_server: &AssetServer) {
+    parent.spawn((
+        NodeBundle {
+            style: Style {
+                width: Val::Percent(100.0),
+                height: Val::Percent(100.0),
+                flex_direction: FlexDirection::Column,
+                align_items: AlignItems::Center,
+                justify_content: JustifyContent::Center,
+                position_type: PositionType::Absolute,
+                ..default()
+            },
+            ..default()
+        },
+        Name::new("Logs App Screen"),
+        LogsScreenNode,
+    )).with_children(|parent| {
+        parent.spawn((
+            NodeBundle {
+                background_color: Color::WHITE.into(),
+                style: Style {
+                    width: Val::Percent(95.0),
+                    height: Val::Percent(95.0),
+                    flex_direction: FlexDirection::Column,
+                    align_items: AlignItems::Center,
+                    padding: UiRect::top(Val::Px(10.0)),
+                    ..default()
+                },
+                ..default()
+            },
+            UiImage::new(asset_server.load(CONTENT_BACKGROUND_MEDIUM)),
+            Name::new("Logs Content Node"),
+        )).with_children(|parent| {
+            // Title and status
+            parent.spawn((
+                NodeBundle {
+                    style: Style {
+                        width: Val::Percent(95.0),
+                        height: Val::Px(40.0),
+                        flex_direction: FlexDirection::Row,
+                        align_items: AlignItems::Center,
+                        justify_content: JustifyContent::SpaceBetween,
+                        ..default()
+                    },
+                    ..default()
+                },
+                Name::new("Logs Title Node"),
+            )).with_children(|parent| {
+                parent.spawn((
+                    TextBundle::from_section(
+                        "Logs",
+                        TextStyle {
+                            font: asset_server.load(FONT_REGULAR),
+                            font_size: FONT_SIZE_MEDIUM,
+                            color: hex_dark_mode_text_gray(),
+                        },
+                    ),
+                    Name::new("TextLogsTitle"),
+                ));
+                parent.spawn((
+                    TextBundle::from_section(
+                        "",
+                        TextStyle {
+                            font: asset_server.load(FONT_REGULAR),
+                            font_size: FONT_SIZE,
+                            color: hex_dark_mode_text_gray(),
+                        },
+                    ),
+                    Name::new("TextLogViewerStatus"),
+                    TextLogViewerStatus,
+                ));
+                parent.spawn((
+                    NodeBundle {
+                        style: Style {
+                            flex_direction: FlexDirection::Row,
+                            align_items: AlignItems::Center,
+                            column_gap: Val::Px(10.0),
+                            ..default()
+                        },
+                        ..default()
+                    },
+                    Name::new("Support Bundle Node"),
+                )).with_children(|parent| {
+                    parent.spawn((
+                        TextBundle::from_section(
+                            "",
+                            TextStyle {
+                                font: asset_server.load(FONT_REGULAR),
+                                font_size: FONT_SIZE,
+                                color: hex_dark_mode_text_gray(),
+                            },
+                        ),
+                        Name::new("TextSupportBundleStatus"),
+                        TextSupportBundleStatus,
+                    ));
+                    parent
+                        .spawn((
+                            ButtonBundle {
+                                style: Style {
+                                    width: Val::Px(220.0),
+                                    height: Val::Px(35.0),
+                                    border: UiRect::all(Val::Px(2.5)),
+                                    justify_content: JustifyContent::Center,
+                                    align_items: AlignItems::Center,
+                                    ..default()
+                                },
+                                border_color: BorderColor(Color::BLACK),
+                                background_color: NORMAL_BUTTON.into(),
+                                ..default()
+                            },
+                            ButtonCreateSupportBundle,
+                            Name::new("ButtonCreateSupportBundle"),
+                        ))
+                        .with_children(|parent| {
+                            parent.spawn(TextBundle::from_section(
+                                "Create support bundle",
+                                TextStyle {
+                                    font: asset_server.load(FONT_REGULAR),
+                                    font_size: FONT_SIZE,
+                                    color: Color::rgb(0.9, 0.9, 0.9),
+                                },
+                            ));
+                        });
+                });
+            });
+
+            // Level filters and search
+            parent.spawn((
+                NodeBundle {
+                    style: Style {
+                        width: Val::Percent(95.0),
+                        height: Val::Px(45.0),
+                        flex_direction: FlexDirection::Row,
+                        align_items: AlignItems::Center,
+                        column_gap: Val::Px(10.0),
+                        ..default()
+                    },
+                    ..default()
+                },
+                Name::new("Logs Filters Node"),
+            )).with_children(|parent| {
+                let filters = [
+                    ("All", None),
+                    ("Error", Some(Level::ERROR)),
+                    ("Warn", Some(Level::WARN)),
+                    ("Info", Some(Level::INFO)),
+                    ("Debug", Some(Level::DEBUG)),
+                ];
+                for (label, level) in filters {
+                    parent
+                        .spawn((
+                            ButtonBundle {
+                                style: Style {
+                                    width: Val::Px(70.0),
+                                    height: Val::Px(35.0),
+                                    border: UiRect::all(Val::Px(2.5)),
+                                    justify_content: JustifyContent::Center,
+                                    align_items: AlignItems::Center,
+                                    ..default()
+                                },
+                                border_color: BorderColor(Color::BLACK),
+                                background_color: NORMAL_BUTTON.into(),
+                                ..default()
+                            },
+                            ButtonLogLevelFilter(level),
+                            Name::new("ButtonLogLevelFilter"),
+                        ))
+                        .with_children(|parent| {
+                            parent.spawn(TextBundle::from_section(
+                                label,
+                                TextStyle {
+                                    font: asset_server.load(FONT_REGULAR),
+                                    font_size: FONT_SIZE,
+                                    color: Color::rgb(0.9, 0.9, 0.9),
+                                },
+                            ));
+                        });
+                }
+
+                parent.spawn(TextBundle::from_section(
+                    "Search: ",
+                    TextStyle {
+                        font: asset_server.load(FONT_REGULAR),
+                        font_size: FONT_SIZE,
+                        color: hex_dark_mode_text_gray(),
+                    },
+                ));
+                parent
+                    .spawn((
+                        ButtonBundle {
+                            style: Style {
+                                width: Val::Px(250.0),
+                                height: Val::Px(30.0),
+                                border: UiRect::all(Val::Px(2.5)),
+                                padding: UiRect::left(Val::Px(5.0)),
+                                align_items: AlignItems::Center,
+                                overflow: Overflow::clip_x(),
+                                ..default()
+                            },
+                            border_color: BorderColor(Color::BLACK),
+                            background_color: NORMAL_BUTTON.into(),
+                            ..default()
+                        },
+                        ButtonCaptureTextInput,
+                        Name::new("ButtonCaptureText"),
+                    ))
+                    .with_children(|parent| {
+                        parent.spawn((
+                            TextBundle::from_section(
+                                "",
+                                TextStyle {
+                                    font: asset_server.load(FONT_REGULAR),
+                                    font_size: FONT_SIZE,
+                                    color: Color::rgb(0.9, 0.9, 0.9),
+                                },
+                            ),
+                            TextInput {
+                                hidden: false,
+                                numbers_only: false,
+                                text: String::new(),
+                                secret: None,
+                            },
+                            TextLogSearchInput,
+                        ));
+                        parent.spawn((
+                            NodeBundle {
+                                visibility: Visibility::Hidden,
+                                style: Style {
+                                    width: Val::Px(8.0),
+                                    height: Val::Px(18.0),
+                                    ..default()
+                                },
+                                background_color: Color::WHITE.into(),
+                                ..default()
+                            },
+                            TextCursor,
+                            Name::new("TextCursor"),
+                        ));
+                    });
+            });
+
+            parent.spawn((
+                NodeBundle {
+                    background_color: Color::WHITE.into(),
+                    style: Style {
+                        flex_direction: FlexDirection::Column,
+                        align_items: AlignItems::Center,
+                        height: Val::Percent(80.0),
+                        width: Val::Percent(95.0),
+                        ..default()
+                    },
+                    ..default()
+                },
+                UiImage::new(asset_server.load(LOG_ITEMS_BACKGROUND)),
+                Name::new("LogsList Node"),
+            )).with_children(|parent| {
+                // List with hidden overflow
+                parent
+                    .spawn((
+                        NodeBundle {
+                            style: Style {
+                                flex_direction: FlexDirection::Column,
+                                align_self: AlignSelf::Stretch,
+                                min_height: Val::Percent(100.0),
+                                max_height: Val::Percent(100.0),
+                                overflow: Overflow::clip_y(),
+                                ..default()
+                            },
+                            ..default()
+                        },
+                        Name::new("ScrollingList Node"),
+                    ))
+                    .with_children(|parent| {
+                        // Moving panel
+                        parent.spawn((
+                            NodeBundle {
+                                style: Style {
+                                    flex_direction: FlexDirection::Column,
+                                    align_items: AlignItems::Start,
+                                    padding: UiRect::horizontal(Val::Px(10.0)),
+                                    ..default()
+                                },
+                                ..default()
+                            },
+                            ScrollingList::default(),
+                            AccessibilityNode(NodeBuilder::new(Role::List)),
+                            LogViewerList,
+                            MovingScrollPanel,
+                            Name::new("MovingScrollPanel"),
+                        ));
+                    });
+            });
+        });
+    });
+}
+
+pub fn despawn_logs_screen(
+    mut commands: Commands,
+    query: Query<Entity, With<LogsScreenNode>>,
+) {
+    if let Ok(screen_node) = query.get_single() {
+        commands.entity(screen_node).despawn_recursive();
+    }
+}
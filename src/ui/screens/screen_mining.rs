@@ -1,4 +1,3 @@
-use std::time::Duration;
 
 use bevy::{
     a11y::{
@@ -7,26 +6,21 @@ use bevy::{
     },
     prelude::*,
 };
-use solana_sdk::signer::Signer;
 
-use crate::{
-    ui::{
+use crate::ui::{
         components::{
-            AutoScrollCheckIcon, ButtonAutoScroll, ButtonCooldownSpinner, ButtonRequestAirdrop, ButtonStakeOre, MiningScreenNode, MiningScreenTxResultList, SpinnerIcon, TextBurnAmount, TextBus1, TextBus2, TextBus3, TextBus4, TextBus5, TextBus6, TextBus7, TextBus8, TextHashrate, TextLastClaimAt, TextLastHashAt, TextMinerStatusThreads, TxPopUpArea
+            AutoScrollCheckIcon, ButtonAutoScroll, MiningScreenNode, MiningScreenTxResultList, TextBus1, TextBus2, TextBus3, TextBus4, TextBus5, TextBus6, TextBus7, TextBus8, TextHashrate, TextLastHashAt, TextMinerStatusThreads
         },
-        spawn_utils::spawn_copyable_text,
         styles::{
-            hex_black, hex_dark_mode_app_screen_background, hex_dark_mode_background, hex_dark_mode_nav_title, hex_dark_mode_text_gray, BUTTON_CLAIM, BUTTON_GREEN_MEDIUM, BUTTON_RED_MEDIUM, BUTTON_STAKE, CHECKBOX, CHECK_ICON, CONTENT_BACKGROUND_MEDIUM, CONTENT_BACKGROUND_SMALL, FONT_REGULAR, FONT_SIZE_LARGE, FONT_SIZE_MEDIUM, LOG_ITEMS_BACKGROUND, MINE_TOGGLE_BUTTON, PROOF_ACCOUNT_BACKGROUND, SPINNER_ICON, SYSTEM_OVERVIEW_BACKGROUND, TOGGLE_OFF, TREASURY_BACKGROUND, TX_RESULTS_BACKGROUND
+            hex_dark_mode_app_screen_background, hex_dark_mode_background, hex_dark_mode_nav_title, hex_dark_mode_text_gray, CHECK_ICON, CONTENT_BACKGROUND_MEDIUM, CONTENT_BACKGROUND_SMALL, FONT_REGULAR, FONT_SIZE_MEDIUM, LOG_ITEMS_BACKGROUND, MINE_TOGGLE_BUTTON
         },
-    }, utils::shorten_string, AppConfig, AppWallet
-};
+    };
 
 use crate::ui::{
     components::{
-        BaseScreenNode, ButtonClaimOreRewards, MovingScrollPanel, ScrollingList,
-        TextCurrentChallenge, TextCurrentStake, TextMinerStatusCpuUsage, TextMinerStatusRamUsage,
-        TextMinerStatusStatus, TextMinerStatusTime, TextTotalHashes, TextTreasuryAdmin, TextTreasuryBalance, TextTreasuryLastResetAt,
-        TextTreasuryRewardRate, TextWalletOreBalance, TextWalletSolBalance, ToggleAutoMine,
+        MovingScrollPanel, ScrollingList,
+        TextCurrentChallenge, TextCurrentStake, TextMinerStatusCpuUsage, TextMinerStatusRamUsage, TextMinerStatusTime, TextTotalHashes, TextTreasuryLastResetAt,
+        TextTreasuryRewardRate,
     },
     styles::FONT_SIZE_SMALL as FONT_SIZE,
 };
@@ -102,7 +96,7 @@ pub fn spawn_app_screen_mining(parent: &mut ChildBuilder, asset_server: &AssetSe
                             TextStyle {
                                 font: asset_server.load(FONT_REGULAR),
                                 font_size: FONT_SIZE_MEDIUM,
-                                color: hex_dark_mode_text_gray().into()
+                                color: hex_dark_mode_text_gray()
                             },
                         ),
                         Name::new("TextBaseRewardRate"),
@@ -113,7 +107,7 @@ pub fn spawn_app_screen_mining(parent: &mut ChildBuilder, asset_server: &AssetSe
                             TextStyle {
                                 font: asset_server.load(FONT_REGULAR),
                                 font_size: FONT_SIZE_MEDIUM,
-                                color: hex_dark_mode_text_gray().into()
+                                color: hex_dark_mode_text_gray()
                             },
                         ),
                         Name::new("TextBaseRewardRate"),
@@ -150,7 +144,7 @@ pub fn spawn_app_screen_mining(parent: &mut ChildBuilder, asset_server: &AssetSe
                             TextStyle {
                                 font: asset_server.load(FONT_REGULAR),
                                 font_size: FONT_SIZE_MEDIUM,
-                                color: hex_dark_mode_text_gray().into()
+                                color: hex_dark_mode_text_gray()
                             },
                         ),
                         Name::new("TextLastResetAt"),
@@ -161,7 +155,7 @@ pub fn spawn_app_screen_mining(parent: &mut ChildBuilder, asset_server: &AssetSe
                             TextStyle {
                                 font: asset_server.load(FONT_REGULAR),
                                 font_size: FONT_SIZE_MEDIUM,
-                                color: hex_dark_mode_text_gray().into()
+                                color: hex_dark_mode_text_gray()
                             },
                         ),
                         Name::new("TextLastResetAt"),
@@ -213,7 +207,7 @@ pub fn spawn_app_screen_mining(parent: &mut ChildBuilder, asset_server: &AssetSe
                             TextStyle {
                                 font: asset_server.load(FONT_REGULAR),
                                 font_size: FONT_SIZE_MEDIUM,
-                                color: hex_dark_mode_text_gray().into()
+                                color: hex_dark_mode_text_gray()
                             },
                         ),
                         Name::new("TextBussesTitle"),
@@ -248,7 +242,7 @@ pub fn spawn_app_screen_mining(parent: &mut ChildBuilder, asset_server: &AssetSe
                                 TextStyle {
                                     font: asset_server.load(FONT_REGULAR),
                                     font_size: FONT_SIZE_MEDIUM,
-                                    color: hex_dark_mode_text_gray().into()
+                                    color: hex_dark_mode_text_gray()
                                 },
                             ),
                             Name::new("TextBus1"),
@@ -259,7 +253,7 @@ pub fn spawn_app_screen_mining(parent: &mut ChildBuilder, asset_server: &AssetSe
                                 TextStyle {
                                     font: asset_server.load(FONT_REGULAR),
                                     font_size: FONT_SIZE_MEDIUM,
-                                    color: hex_dark_mode_text_gray().into()
+                                    color: hex_dark_mode_text_gray()
                                 },
                             ),
                             Name::new("TextBus1"),
@@ -295,7 +289,7 @@ pub fn spawn_app_screen_mining(parent: &mut ChildBuilder, asset_server: &AssetSe
                                 TextStyle {
                                     font: asset_server.load(FONT_REGULAR),
                                     font_size: FONT_SIZE_MEDIUM,
-                                    color: hex_dark_mode_text_gray().into()
+                                    color: hex_dark_mode_text_gray()
                                 },
                             ),
                             Name::new("TextBus2"),
@@ -306,7 +300,7 @@ pub fn spawn_app_screen_mining(parent: &mut ChildBuilder, asset_server: &AssetSe
                                 TextStyle {
                                     font: asset_server.load(FONT_REGULAR),
                                     font_size: FONT_SIZE_MEDIUM,
-                                    color: hex_dark_mode_text_gray().into()
+                                    color: hex_dark_mode_text_gray()
                                 },
                             ),
                             Name::new("TextBus2"),
@@ -342,7 +336,7 @@ pub fn spawn_app_screen_mining(parent: &mut ChildBuilder, asset_server: &AssetSe
                                 TextStyle {
                                     font: asset_server.load(FONT_REGULAR),
                                     font_size: FONT_SIZE_MEDIUM,
-                                    color: hex_dark_mode_text_gray().into()
+                                    color: hex_dark_mode_text_gray()
                                 },
                             ),
                             Name::new("TextBus3"),
@@ -353,7 +347,7 @@ pub fn spawn_app_screen_mining(parent: &mut ChildBuilder, asset_server: &AssetSe
                                 TextStyle {
                                     font: asset_server.load(FONT_REGULAR),
                                     font_size: FONT_SIZE_MEDIUM,
-                                    color: hex_dark_mode_text_gray().into()
+                                    color: hex_dark_mode_text_gray()
                                 },
                             ),
                             Name::new("TextBus3"),
@@ -389,7 +383,7 @@ pub fn spawn_app_screen_mining(parent: &mut ChildBuilder, asset_server: &AssetSe
                                 TextStyle {
                                     font: asset_server.load(FONT_REGULAR),
                                     font_size: FONT_SIZE_MEDIUM,
-                                    color: hex_dark_mode_text_gray().into()
+                                    color: hex_dark_mode_text_gray()
                                 },
                             ),
                             Name::new("TextBus4"),
@@ -400,7 +394,7 @@ pub fn spawn_app_screen_mining(parent: &mut ChildBuilder, asset_server: &AssetSe
                                 TextStyle {
                                     font: asset_server.load(FONT_REGULAR),
                                     font_size: FONT_SIZE_MEDIUM,
-                                    color: hex_dark_mode_text_gray().into()
+                                    color: hex_dark_mode_text_gray()
                                 },
                             ),
                             Name::new("TextBus4"),
@@ -436,7 +430,7 @@ pub fn spawn_app_screen_mining(parent: &mut ChildBuilder, asset_server: &AssetSe
                                 TextStyle {
                                     font: asset_server.load(FONT_REGULAR),
                                     font_size: FONT_SIZE_MEDIUM,
-                                    color: hex_dark_mode_text_gray().into()
+                                    color: hex_dark_mode_text_gray()
                                 },
                             ),
                             Name::new("TextBus5"),
@@ -447,7 +441,7 @@ pub fn spawn_app_screen_mining(parent: &mut ChildBuilder, asset_server: &AssetSe
                                 TextStyle {
                                     font: asset_server.load(FONT_REGULAR),
                                     font_size: FONT_SIZE_MEDIUM,
-                                    color: hex_dark_mode_text_gray().into()
+                                    color: hex_dark_mode_text_gray()
                                 },
                             ),
                             Name::new("TextBus5"),
@@ -483,7 +477,7 @@ pub fn spawn_app_screen_mining(parent: &mut ChildBuilder, asset_server: &AssetSe
                                 TextStyle {
                                     font: asset_server.load(FONT_REGULAR),
                                     font_size: FONT_SIZE_MEDIUM,
-                                    color: hex_dark_mode_text_gray().into()
+                                    color: hex_dark_mode_text_gray()
                                 },
                             ),
                             Name::new("TextBus6"),
@@ -494,7 +488,7 @@ pub fn spawn_app_screen_mining(parent: &mut ChildBuilder, asset_server: &AssetSe
                                 TextStyle {
                                     font: asset_server.load(FONT_REGULAR),
                                     font_size: FONT_SIZE_MEDIUM,
-                                    color: hex_dark_mode_text_gray().into()
+                                    color: hex_dark_mode_text_gray()
                                 },
                             ),
                             Name::new("TextBus6"),
@@ -530,7 +524,7 @@ pub fn spawn_app_screen_mining(parent: &mut ChildBuilder, asset_server: &AssetSe
                                 TextStyle {
                                     font: asset_server.load(FONT_REGULAR),
                                     font_size: FONT_SIZE_MEDIUM,
-                                    color: hex_dark_mode_text_gray().into()
+                                    color: hex_dark_mode_text_gray()
                                 },
                             ),
                             Name::new("TextBus7"),
@@ -541,7 +535,7 @@ pub fn spawn_app_screen_mining(parent: &mut ChildBuilder, asset_server: &AssetSe
                                 TextStyle {
                                     font: asset_server.load(FONT_REGULAR),
                                     font_size: FONT_SIZE_MEDIUM,
-                                    color: hex_dark_mode_text_gray().into()
+                                    color: hex_dark_mode_text_gray()
                                 },
                             ),
                             Name::new("TextBus7"),
@@ -577,7 +571,7 @@ pub fn spawn_app_screen_mining(parent: &mut ChildBuilder, asset_server: &AssetSe
                                 TextStyle {
                                     font: asset_server.load(FONT_REGULAR),
                                     font_size: FONT_SIZE_MEDIUM,
-                                    color: hex_dark_mode_text_gray().into()
+                                    color: hex_dark_mode_text_gray()
                                 },
                             ),
                             Name::new("TextBus8"),
@@ -588,7 +582,7 @@ pub fn spawn_app_screen_mining(parent: &mut ChildBuilder, asset_server: &AssetSe
                                 TextStyle {
                                     font: asset_server.load(FONT_REGULAR),
                                     font_size: FONT_SIZE_MEDIUM,
-                                    color: hex_dark_mode_text_gray().into()
+                                    color: hex_dark_mode_text_gray()
                                 },
                             ),
                             Name::new("TextBus8"),
@@ -628,7 +622,7 @@ pub fn spawn_app_screen_mining(parent: &mut ChildBuilder, asset_server: &AssetSe
                             TextStyle {
                                 font: asset_server.load(FONT_REGULAR),
                                 font_size: FONT_SIZE_MEDIUM,
-                                color: hex_dark_mode_text_gray().into()
+                                color: hex_dark_mode_text_gray()
                             },
                         ),
                         Name::new("TextTitleStatus"),
@@ -663,7 +657,7 @@ pub fn spawn_app_screen_mining(parent: &mut ChildBuilder, asset_server: &AssetSe
                                 TextStyle {
                                     font: asset_server.load(FONT_REGULAR),
                                     font_size: FONT_SIZE_MEDIUM,
-                                    color: hex_dark_mode_text_gray().into()
+                                    color: hex_dark_mode_text_gray()
                                 },
                             ),
                             Name::new("TextTitleCurrentTime"),
@@ -674,7 +668,7 @@ pub fn spawn_app_screen_mining(parent: &mut ChildBuilder, asset_server: &AssetSe
                                 TextStyle {
                                     font: asset_server.load(FONT_REGULAR),
                                     font_size: FONT_SIZE_MEDIUM,
-                                    color: hex_dark_mode_text_gray().into()
+                                    color: hex_dark_mode_text_gray()
                                 },
                             ),
                             Name::new("TextCurrentTime"),
@@ -711,7 +705,7 @@ pub fn spawn_app_screen_mining(parent: &mut ChildBuilder, asset_server: &AssetSe
                                 TextStyle {
                                     font: asset_server.load(FONT_REGULAR),
                                     font_size: FONT_SIZE_MEDIUM,
-                                    color: hex_dark_mode_text_gray().into()
+                                    color: hex_dark_mode_text_gray()
                                 },
                             ),
                             Name::new("TextTitleThreads"),
@@ -722,7 +716,7 @@ pub fn spawn_app_screen_mining(parent: &mut ChildBuilder, asset_server: &AssetSe
                                 TextStyle {
                                     font: asset_server.load(FONT_REGULAR),
                                     font_size: FONT_SIZE_MEDIUM,
-                                    color: hex_dark_mode_text_gray().into()
+                                    color: hex_dark_mode_text_gray()
                                 },
                             ),
                             Name::new("TextThreads"),
@@ -759,7 +753,7 @@ pub fn spawn_app_screen_mining(parent: &mut ChildBuilder, asset_server: &AssetSe
                                 TextStyle {
                                     font: asset_server.load(FONT_REGULAR),
                                     font_size: FONT_SIZE_MEDIUM,
-                                    color: hex_dark_mode_text_gray().into()
+                                    color: hex_dark_mode_text_gray()
                                 },
                             ),
                             Name::new("TextTitleCpuUsage"),
@@ -770,7 +764,7 @@ pub fn spawn_app_screen_mining(parent: &mut ChildBuilder, asset_server: &AssetSe
                                 TextStyle {
                                     font: asset_server.load(FONT_REGULAR),
                                     font_size: FONT_SIZE_MEDIUM,
-                                    color: hex_dark_mode_text_gray().into()
+                                    color: hex_dark_mode_text_gray()
                                 },
                             ),
                             Name::new("TextTotalCpuUsage"),
@@ -807,7 +801,7 @@ pub fn spawn_app_screen_mining(parent: &mut ChildBuilder, asset_server: &AssetSe
                                 TextStyle {
                                     font: asset_server.load(FONT_REGULAR),
                                     font_size: FONT_SIZE_MEDIUM,
-                                    color: hex_dark_mode_text_gray().into()
+                                    color: hex_dark_mode_text_gray()
                                 },
                             ),
                             Name::new("TextTitleRamUsage"),
@@ -818,7 +812,7 @@ pub fn spawn_app_screen_mining(parent: &mut ChildBuilder, asset_server: &AssetSe
                                 TextStyle {
                                     font: asset_server.load(FONT_REGULAR),
                                     font_size: FONT_SIZE_MEDIUM,
-                                    color: hex_dark_mode_text_gray().into()
+                                    color: hex_dark_mode_text_gray()
                                 },
                             ),
                             Name::new("TextRamUsage"),
@@ -854,7 +848,7 @@ pub fn spawn_app_screen_mining(parent: &mut ChildBuilder, asset_server: &AssetSe
                                 TextStyle {
                                     font: asset_server.load(FONT_REGULAR),
                                     font_size: FONT_SIZE_MEDIUM,
-                                    color: hex_dark_mode_text_gray().into()
+                                    color: hex_dark_mode_text_gray()
                                 },
                             ),
                             Name::new("TextTitleHashrate"),
@@ -865,7 +859,7 @@ pub fn spawn_app_screen_mining(parent: &mut ChildBuilder, asset_server: &AssetSe
                                 TextStyle {
                                     font: asset_server.load(FONT_REGULAR),
                                     font_size: FONT_SIZE_MEDIUM,
-                                    color: hex_dark_mode_text_gray().into()
+                                    color: hex_dark_mode_text_gray()
                                 },
                             ),
                             Name::new("TextHashrate"),
@@ -904,7 +898,7 @@ pub fn spawn_app_screen_mining(parent: &mut ChildBuilder, asset_server: &AssetSe
                             TextStyle {
                                 font: asset_server.load(FONT_REGULAR),
                                 font_size: FONT_SIZE_MEDIUM,
-                                color: hex_dark_mode_text_gray().into()
+                                color: hex_dark_mode_text_gray()
                             },
                         ),
                         Name::new("TextTreasuryBalance"),
@@ -940,7 +934,7 @@ pub fn spawn_app_screen_mining(parent: &mut ChildBuilder, asset_server: &AssetSe
                                 TextStyle {
                                     font: asset_server.load(FONT_REGULAR),
                                     font_size: FONT_SIZE_MEDIUM,
-                                    color: hex_dark_mode_text_gray().into()
+                                    color: hex_dark_mode_text_gray()
                                 },
                             ),
                             Name::new("TextTitleCurrentChallenge"),
@@ -951,7 +945,7 @@ pub fn spawn_app_screen_mining(parent: &mut ChildBuilder, asset_server: &AssetSe
                                 TextStyle {
                                     font: asset_server.load(FONT_REGULAR),
                                     font_size: FONT_SIZE_MEDIUM,
-                                    color: hex_dark_mode_text_gray().into()
+                                    color: hex_dark_mode_text_gray()
                                 },
                             ),
                             Name::new("TextCurrentChallenge"),
@@ -989,7 +983,7 @@ pub fn spawn_app_screen_mining(parent: &mut ChildBuilder, asset_server: &AssetSe
                                 TextStyle {
                                     font: asset_server.load(FONT_REGULAR),
                                     font_size: FONT_SIZE_MEDIUM,
-                                    color: hex_dark_mode_text_gray().into()
+                                    color: hex_dark_mode_text_gray()
                                 },
                             ),
                             Name::new("TextTitleLastHashAt"),
@@ -1000,7 +994,7 @@ pub fn spawn_app_screen_mining(parent: &mut ChildBuilder, asset_server: &AssetSe
                                 TextStyle {
                                     font: asset_server.load(FONT_REGULAR),
                                     font_size: FONT_SIZE_MEDIUM,
-                                    color: hex_dark_mode_text_gray().into()
+                                    color: hex_dark_mode_text_gray()
                                 },
                             ),
                             Name::new("TextLastHashAt"),
@@ -1038,7 +1032,7 @@ pub fn spawn_app_screen_mining(parent: &mut ChildBuilder, asset_server: &AssetSe
                                 TextStyle {
                                     font: asset_server.load(FONT_REGULAR),
                                     font_size: FONT_SIZE_MEDIUM,
-                                    color: hex_dark_mode_text_gray().into()
+                                    color: hex_dark_mode_text_gray()
                                 },
                             ),
                             Name::new("TextTitleTotalHashes"),
@@ -1049,7 +1043,7 @@ pub fn spawn_app_screen_mining(parent: &mut ChildBuilder, asset_server: &AssetSe
                                 TextStyle {
                                     font: asset_server.load(FONT_REGULAR),
                                     font_size: FONT_SIZE_MEDIUM,
-                                    color: hex_dark_mode_text_gray().into()
+                                    color: hex_dark_mode_text_gray()
                                 },
                             ),
                             Name::new("TextTotalHashes"),
@@ -1087,7 +1081,7 @@ pub fn spawn_app_screen_mining(parent: &mut ChildBuilder, asset_server: &AssetSe
                                 TextStyle {
                                     font: asset_server.load(FONT_REGULAR),
                                     font_size: FONT_SIZE_MEDIUM,
-                                    color: hex_dark_mode_text_gray().into()
+                                    color: hex_dark_mode_text_gray()
                                 },
                             ),
                             Name::new("TextTitleStaked"),
@@ -1098,7 +1092,7 @@ pub fn spawn_app_screen_mining(parent: &mut ChildBuilder, asset_server: &AssetSe
                                 TextStyle {
                                     font: asset_server.load(FONT_REGULAR),
                                     font_size: FONT_SIZE_MEDIUM,
-                                    color: hex_dark_mode_text_gray().into()
+                                    color: hex_dark_mode_text_gray()
                                 },
                             ),
                             Name::new("TextStaked"),
@@ -1198,8 +1192,7 @@ pub fn spawn_app_screen_mining(parent: &mut ChildBuilder, asset_server: &AssetSe
                                                 TextStyle {
                                                     font: asset_server.load(FONT_REGULAR),
                                                     font_size: FONT_SIZE,
-                                                    color: hex_dark_mode_text_gray().into(),
-                                                    ..default()
+                                                    color: hex_dark_mode_text_gray(),
                                                 },
                                             ),
                                             Label,
@@ -1225,8 +1218,7 @@ pub fn spawn_app_screen_mining(parent: &mut ChildBuilder, asset_server: &AssetSe
                                                 TextStyle {
                                                     font: asset_server.load(FONT_REGULAR),
                                                     font_size: FONT_SIZE,
-                                                    color: hex_dark_mode_text_gray().into(),
-                                                    ..default()
+                                                    color: hex_dark_mode_text_gray(),
                                                 },
                                             ),
                                             Label,
@@ -1252,8 +1244,7 @@ pub fn spawn_app_screen_mining(parent: &mut ChildBuilder, asset_server: &AssetSe
                                                 TextStyle {
                                                     font: asset_server.load(FONT_REGULAR),
                                                     font_size: FONT_SIZE,
-                                                    color: hex_dark_mode_text_gray().into(),
-                                                    ..default()
+                                                    color: hex_dark_mode_text_gray(),
                                                 },
                                             ),
                                             Label,
@@ -1279,8 +1270,7 @@ pub fn spawn_app_screen_mining(parent: &mut ChildBuilder, asset_server: &AssetSe
                                                 TextStyle {
                                                     font: asset_server.load(FONT_REGULAR),
                                                     font_size: FONT_SIZE,
-                                                    color: hex_dark_mode_text_gray().into(),
-                                                    ..default()
+                                                    color: hex_dark_mode_text_gray(),
                                                 },
                                             ),
                                             Label,
@@ -1306,8 +1296,7 @@ pub fn spawn_app_screen_mining(parent: &mut ChildBuilder, asset_server: &AssetSe
                                                 TextStyle {
                                                     font: asset_server.load(FONT_REGULAR),
                                                     font_size: FONT_SIZE,
-                                                    color: hex_dark_mode_text_gray().into(),
-                                                    ..default()
+                                                    color: hex_dark_mode_text_gray(),
                                                 },
                                             ),
                                             Label,
@@ -1334,8 +1323,7 @@ pub fn spawn_app_screen_mining(parent: &mut ChildBuilder, asset_server: &AssetSe
                                                 TextStyle {
                                                     font: asset_server.load(FONT_REGULAR),
                                                     font_size: FONT_SIZE,
-                                                    color: hex_dark_mode_text_gray().into(),
-                                                    ..default()
+                                                    color: hex_dark_mode_text_gray(),
                                                 },
                                             ),
                                             Label,
@@ -0,0 +1,593 @@
+use bevy::{
+    a11y::{
+        accesskit::{NodeBuilder, Role},
+        AccessibilityNode,
+    },
+    prelude::*,
+};
+
+use crate::{
+    address_book::AddressBookEntry,
+    amount::format_units,
+    transfer::{SendPreview, SendToken},
+    ui::{
+        components::{
+            AddressBookList, ButtonAddressBookEntry, ButtonAddressBookRemove,
+            ButtonCaptureTextInput, ButtonSendCancel, ButtonSendConfirm, ButtonSendReview,
+            ButtonSendSaveRecipient, ButtonSendToggleToken, MovingScrollPanel, ScrollingList,
+            SendConfirmModal, SendScreenNode, TextCursor, TextInput, TextSendAmount,
+            TextSendRecipient, TextSendRecipientName, TextSendStatus, TextSendToken,
+        },
+        styles::{
+            hex_dark_mode_text_gray, CONTENT_BACKGROUND_MEDIUM, FONT_REGULAR, FONT_SIZE_MEDIUM,
+            FONT_SIZE_SMALL as FONT_SIZE, LOG_ITEMS_BACKGROUND, NORMAL_BUTTON,
+            TX_POP_UP_BACKGROUND,
+        },
+    },
+    utils::shorten_string,
+    SendResource,
+};
+
+pub fn spawn_send_screen(
+    parent: &mut ChildBuilder,
+    asset_server: &AssetServer,
+    token: SendToken,
+    address_book: &[AddressBookEntry],
+) {
+    parent.spawn((
+        NodeBundle {
+            style: Style {
+                width: Val::Percent(100.0),
+                height: Val::Percent(100.0),
+                flex_direction: FlexDirection::Column,
+                align_items: AlignItems::Center,
+                justify_content: JustifyContent::Center,
+                position_type: PositionType::Absolute,
+                ..default()
+            },
+            ..default()
+        },
+        Name::new("Send App Screen"),
+        SendScreenNode,
+    )).with_children(|parent| {
+        parent.spawn((
+            NodeBundle {
+                background_color: Color::WHITE.into(),
+                style: Style {
+                    width: Val::Percent(95.0),
+                    height: Val::Percent(95.0),
+                    flex_direction: FlexDirection::Column,
+                    align_items: AlignItems::Center,
+                    padding: UiRect::top(Val::Px(10.0)),
+                    row_gap: Val::Px(10.0),
+                    ..default()
+                },
+                ..default()
+            },
+            UiImage::new(asset_server.load(CONTENT_BACKGROUND_MEDIUM)),
+            Name::new("Send Content Node"),
+        )).with_children(|parent| {
+            // Title and Status
+            parent.spawn((
+                NodeBundle {
+                    style: Style {
+                        width: Val::Percent(95.0),
+                        height: Val::Px(50.0),
+                        flex_direction: FlexDirection::Row,
+                        align_items: AlignItems::Center,
+                        justify_content: JustifyContent::SpaceBetween,
+                        ..default()
+                    },
+                    ..default()
+                },
+                Name::new("Send Title Node"),
+            )).with_children(|parent| {
+                parent.spawn((
+                    TextBundle::from_section(
+                        "Send",
+                        TextStyle {
+                            font: asset_server.load(FONT_REGULAR),
+                            font_size: FONT_SIZE_MEDIUM,
+                            color: hex_dark_mode_text_gray(),
+                        },
+                    ),
+                    Name::new("TextSendTitle"),
+                ));
+                parent.spawn((
+                    TextBundle::from_section(
+                        "",
+                        TextStyle {
+                            font: asset_server.load(FONT_REGULAR),
+                            font_size: FONT_SIZE,
+                            color: hex_dark_mode_text_gray(),
+                        },
+                    ),
+                    Name::new("TextSendStatus"),
+                    TextSendStatus,
+                ));
+            });
+
+            parent.spawn((
+                NodeBundle {
+                    style: Style {
+                        width: Val::Percent(95.0),
+                        height: Val::Percent(85.0),
+                        flex_direction: FlexDirection::Row,
+                        justify_content: JustifyContent::SpaceBetween,
+                        ..default()
+                    },
+                    ..default()
+                },
+                Name::new("Send Body Node"),
+            )).with_children(|parent| {
+                // Send Form
+                parent.spawn((
+                    NodeBundle {
+                        style: Style {
+                            width: Val::Percent(55.0),
+                            flex_direction: FlexDirection::Column,
+                            row_gap: Val::Px(15.0),
+                            ..default()
+                        },
+                        ..default()
+                    },
+                    Name::new("Send Form Node"),
+                )).with_children(|parent| {
+                    spawn_send_form_row(parent, asset_server, "Token: ", |parent| {
+                        parent
+                            .spawn((
+                                ButtonBundle {
+                                    style: Style {
+                                        width: Val::Px(100.0),
+                                        height: Val::Px(35.0),
+                                        border: UiRect::all(Val::Px(2.5)),
+                                        justify_content: JustifyContent::Center,
+                                        align_items: AlignItems::Center,
+                                        ..default()
+                                    },
+                                    border_color: BorderColor(Color::BLACK),
+                                    background_color: NORMAL_BUTTON.into(),
+                                    ..default()
+                                },
+                                ButtonSendToggleToken,
+                                Name::new("ButtonSendToggleToken"),
+                            ))
+                            .with_children(|parent| {
+                                parent.spawn((
+                                    TextBundle::from_section(
+                                        token.to_string(),
+                                        TextStyle {
+                                            font: asset_server.load(FONT_REGULAR),
+                                            font_size: FONT_SIZE,
+                                            color: Color::rgb(0.9, 0.9, 0.9),
+                                        },
+                                    ),
+                                    TextSendToken,
+                                ));
+                            });
+                    });
+                    spawn_send_form_row(parent, asset_server, "Recipient: ", |parent| {
+                        spawn_send_text_input(parent, asset_server, 420.0, false, TextSendRecipient);
+                    });
+                    spawn_send_form_row(parent, asset_server, "Amount: ", |parent| {
+                        spawn_send_text_input(parent, asset_server, 200.0, true, TextSendAmount);
+                    });
+                    spawn_send_form_row(parent, asset_server, "Name: ", |parent| {
+                        spawn_send_text_input(parent, asset_server, 200.0, false, TextSendRecipientName);
+                        spawn_send_button(parent, asset_server, "Save Recipient", ButtonSendSaveRecipient);
+                    });
+                    spawn_send_form_row(parent, asset_server, "", |parent| {
+                        spawn_send_button(parent, asset_server, "Review", ButtonSendReview);
+                    });
+                });
+
+                // Address Book
+                parent.spawn((
+                    NodeBundle {
+                        background_color: Color::WHITE.into(),
+                        style: Style {
+                            width: Val::Percent(40.0),
+                            height: Val::Percent(100.0),
+                            flex_direction: FlexDirection::Column,
+                            align_items: AlignItems::Center,
+                            ..default()
+                        },
+                        ..default()
+                    },
+                    UiImage::new(asset_server.load(LOG_ITEMS_BACKGROUND)),
+                    Name::new("Address Book Node"),
+                )).with_children(|parent| {
+                    parent.spawn((
+                        TextBundle::from_section(
+                            "Address Book",
+                            TextStyle {
+                                font: asset_server.load(FONT_REGULAR),
+                                font_size: FONT_SIZE,
+                                color: hex_dark_mode_text_gray(),
+                            },
+                        ),
+                        Name::new("TextAddressBookTitle"),
+                    ));
+
+                    // List with hidden overflow
+                    parent
+                        .spawn((
+                            NodeBundle {
+                                style: Style {
+                                    flex_direction: FlexDirection::Column,
+                                    align_self: AlignSelf::Stretch,
+                                    min_height: Val::Percent(90.0),
+                                    max_height: Val::Percent(90.0),
+                                    overflow: Overflow::clip_y(),
+                                    ..default()
+                                },
+                                ..default()
+                            },
+                            Name::new("ScrollingList Node"),
+                        ))
+                        .with_children(|parent| {
+                            // Moving panel
+                            parent.spawn((
+                                NodeBundle {
+                                    style: Style {
+                                        flex_direction: FlexDirection::Column,
+                                        align_items: AlignItems::Center,
+                                        row_gap: Val::Px(5.0),
+                                        ..default()
+                                    },
+                                    ..default()
+                                },
+                                ScrollingList::default(),
+                                AccessibilityNode(NodeBuilder::new(Role::List)),
+                                AddressBookList,
+                                MovingScrollPanel,
+                                Name::new("MovingScrollPanel"),
+                            )).with_children(|parent| {
+                                for entry in address_book {
+                                    spawn_address_book_entry(parent, asset_server, entry);
+                                }
+                            });
+                        });
+                });
+            });
+        });
+    });
+}
+
+fn spawn_send_form_row(
+    parent: &mut ChildBuilder,
+    asset_server: &AssetServer,
+    label: &str,
+    spawn_children: impl FnOnce(&mut ChildBuilder),
+) {
+    parent.spawn((
+        NodeBundle {
+            style: Style {
+                flex_direction: FlexDirection::Row,
+                align_items: AlignItems::Center,
+                column_gap: Val::Px(10.0),
+                ..default()
+            },
+            ..default()
+        },
+        Name::new("Send Form Row"),
+    )).with_children(|parent| {
+        parent.spawn((
+            NodeBundle {
+                style: Style {
+                    width: Val::Px(100.0),
+                    justify_content: JustifyContent::End,
+                    ..default()
+                },
+                ..default()
+            },
+            Name::new("Send Form Label Node"),
+        )).with_children(|parent| {
+            parent.spawn(TextBundle::from_section(
+                label,
+                TextStyle {
+                    font: asset_server.load(FONT_REGULAR),
+                    font_size: FONT_SIZE,
+                    color: hex_dark_mode_text_gray(),
+                },
+            ));
+        });
+        spawn_children(parent);
+    });
+}
+
+fn spawn_send_text_input(
+    parent: &mut ChildBuilder,
+    asset_server: &AssetServer,
+    width: f32,
+    numbers_only: bool,
+    marker: impl Component,
+) {
+    parent
+        .spawn((
+            ButtonBundle {
+                style: Style {
+                    width: Val::Px(width),
+                    height: Val::Px(35.0),
+                    border: UiRect::all(Val::Px(2.5)),
+                    padding: UiRect::left(Val::Px(5.0)),
+                    align_items: AlignItems::Center,
+                    overflow: Overflow::clip_x(),
+                    ..default()
+                },
+                border_color: BorderColor(Color::BLACK),
+                background_color: NORMAL_BUTTON.into(),
+                ..default()
+            },
+            ButtonCaptureTextInput,
+            Name::new("ButtonCaptureText"),
+        ))
+        .with_children(|parent| {
+            parent.spawn((
+                TextBundle::from_section(
+                    "",
+                    TextStyle {
+                        font: asset_server.load(FONT_REGULAR),
+                        font_size: FONT_SIZE,
+                        color: Color::rgb(0.9, 0.9, 0.9),
+                    },
+                ),
+                TextInput {
+                    hidden: false,
+                    numbers_only,
+                    text: "".to_string(),
+                    secret: None,
+                },
+                marker,
+            ));
+            parent.spawn((
+                NodeBundle {
+                    visibility: Visibility::Hidden,
+                    style: Style {
+                        width: Val::Px(8.0),
+                        height: Val::Px(18.0),
+                        ..default()
+                    },
+                    background_color: Color::WHITE.into(),
+                    ..default()
+                },
+                TextCursor,
+                Name::new("TextCursor"),
+            ));
+        });
+}
+
+fn spawn_send_button(
+    parent: &mut ChildBuilder,
+    asset_server: &AssetServer,
+    text: &str,
+    marker: impl Component,
+) {
+    parent
+        .spawn((
+            ButtonBundle {
+                style: Style {
+                    width: Val::Px(150.0),
+                    height: Val::Px(35.0),
+                    border: UiRect::all(Val::Px(2.5)),
+                    justify_content: JustifyContent::Center,
+                    align_items: AlignItems::Center,
+                    ..default()
+                },
+                border_color: BorderColor(Color::BLACK),
+                background_color: NORMAL_BUTTON.into(),
+                ..default()
+            },
+            marker,
+            Name::new(format!("Button {}", text)),
+        ))
+        .with_children(|parent| {
+            parent.spawn(TextBundle::from_section(
+                text,
+                TextStyle {
+                    font: asset_server.load(FONT_REGULAR),
+                    font_size: FONT_SIZE,
+                    color: Color::rgb(0.9, 0.9, 0.9),
+                },
+            ));
+        });
+}
+
+pub fn spawn_address_book_entry(
+    parent: &mut ChildBuilder,
+    asset_server: &AssetServer,
+    entry: &AddressBookEntry,
+) {
+    parent.spawn((
+        NodeBundle {
+            style: Style {
+                width: Val::Percent(95.0),
+                height: Val::Px(35.0),
+                flex_direction: FlexDirection::Row,
+                align_items: AlignItems::Center,
+                column_gap: Val::Px(5.0),
+                ..default()
+            },
+            ..default()
+        },
+        AccessibilityNode(NodeBuilder::new(Role::ListItem)),
+        Name::new("Address Book Entry"),
+    )).with_children(|parent| {
+        parent
+            .spawn((
+                ButtonBundle {
+                    style: Style {
+                        flex_grow: 1.0,
+                        height: Val::Percent(100.0),
+                        border: UiRect::all(Val::Px(2.5)),
+                        padding: UiRect::horizontal(Val::Px(5.0)),
+                        justify_content: JustifyContent::SpaceBetween,
+                        align_items: AlignItems::Center,
+                        ..default()
+                    },
+                    border_color: BorderColor(Color::BLACK),
+                    background_color: NORMAL_BUTTON.into(),
+                    ..default()
+                },
+                ButtonAddressBookEntry(entry.address.clone()),
+                Name::new("ButtonAddressBookEntry"),
+            ))
+            .with_children(|parent| {
+                parent.spawn(TextBundle::from_section(
+                    entry.name.clone(),
+                    TextStyle {
+                        font: asset_server.load(FONT_REGULAR),
+                        font_size: FONT_SIZE,
+                        color: Color::rgb(0.9, 0.9, 0.9),
+                    },
+                ));
+                parent.spawn(TextBundle::from_section(
+                    shorten_string(entry.address.clone(), 10),
+                    TextStyle {
+                        font: asset_server.load(FONT_REGULAR),
+                        font_size: FONT_SIZE,
+                        color: hex_dark_mode_text_gray(),
+                    },
+                ));
+            });
+        parent
+            .spawn((
+                ButtonBundle {
+                    style: Style {
+                        width: Val::Px(35.0),
+                        height: Val::Percent(100.0),
+                        border: UiRect::all(Val::Px(2.5)),
+                        justify_content: JustifyContent::Center,
+                        align_items: AlignItems::Center,
+                        ..default()
+                    },
+                    border_color: BorderColor(Color::BLACK),
+                    background_color: NORMAL_BUTTON.into(),
+                    ..default()
+                },
+                ButtonAddressBookRemove(entry.address.clone()),
+                Name::new("ButtonAddressBookRemove"),
+            ))
+            .with_children(|parent| {
+                parent.spawn(TextBundle::from_section(
+                    "X",
+                    TextStyle {
+                        font: asset_server.load(FONT_REGULAR),
+                        font_size: FONT_SIZE,
+                        color: Color::rgb(0.9, 0.9, 0.9),
+                    },
+                ));
+            });
+    });
+}
+
+pub fn spawn_send_confirm_modal(
+    parent: &mut ChildBuilder,
+    asset_server: &AssetServer,
+    preview: &SendPreview,
+) {
+    let amount = format_units(preview.amount, preview.token.decimals(), None);
+    let mut lines = vec![
+        format!("Send {} {}", amount, preview.token.to_string()),
+        format!("To: {}", preview.recipient.to_string()),
+        format!("Network fee: {:.6} SOL", preview.network_fee),
+        format!("Priority fee: {}", preview.priority_fee.to_display_string()),
+    ];
+    if !preview.ata_rent.is_zero() {
+        lines.push(format!(
+            "Creates recipient token account: {:.6} SOL",
+            preview.ata_rent
+        ));
+    }
+    lines.push(format!("Total SOL cost: {:.6} SOL", preview.total_sol_cost()));
+    match &preview.simulation_error {
+        Some(e) => lines.push(format!("Simulation failed: {}", e)),
+        None => lines.push(format!(
+            "Simulation succeeded, {} compute units",
+            preview.units_consumed.unwrap_or(0)
+        )),
+    }
+
+    parent.spawn((
+        NodeBundle {
+            z_index: ZIndex::Global(20),
+            background_color: Color::rgba(0.0, 0.0, 0.0, 0.7).into(),
+            style: Style {
+                position_type: PositionType::Absolute,
+                width: Val::Percent(100.0),
+                height: Val::Percent(100.0),
+                align_items: AlignItems::Center,
+                justify_content: JustifyContent::Center,
+                ..default()
+            },
+            ..default()
+        },
+        Name::new("Send Confirm Modal"),
+        SendConfirmModal,
+    )).with_children(|parent| {
+        parent.spawn((
+            NodeBundle {
+                background_color: Color::WHITE.into(),
+                style: Style {
+                    width: Val::Px(600.0),
+                    flex_direction: FlexDirection::Column,
+                    align_items: AlignItems::Center,
+                    padding: UiRect::all(Val::Px(20.0)),
+                    row_gap: Val::Px(10.0),
+                    ..default()
+                },
+                ..default()
+            },
+            UiImage::new(asset_server.load(TX_POP_UP_BACKGROUND)),
+            Name::new("Send Confirm Modal Content"),
+        )).with_children(|parent| {
+            parent.spawn(TextBundle::from_section(
+                "Confirm Transfer",
+                TextStyle {
+                    font: asset_server.load(FONT_REGULAR),
+                    font_size: FONT_SIZE_MEDIUM,
+                    color: Color::WHITE,
+                },
+            ));
+            for line in lines {
+                parent.spawn(TextBundle::from_section(
+                    line,
+                    TextStyle {
+                        font: asset_server.load(FONT_REGULAR),
+                        font_size: FONT_SIZE,
+                        color: Color::WHITE,
+                    },
+                ));
+            }
+            parent.spawn((
+                NodeBundle {
+                    style: Style {
+                        flex_direction: FlexDirection::Row,
+                        column_gap: Val::Px(20.0),
+                        margin: UiRect::top(Val::Px(10.0)),
+                        ..default()
+                    },
+                    ..default()
+                },
+                Name::new("Send Confirm Buttons"),
+            )).with_children(|parent| {
+                if preview.is_ok() {
+                    spawn_send_button(parent, asset_server, "Confirm", ButtonSendConfirm);
+                }
+                spawn_send_button(parent, asset_server, "Cancel", ButtonSendCancel);
+            });
+        });
+    });
+}
+
+pub fn despawn_send_screen(
+    mut commands: Commands,
+    mut send_res: ResMut<SendResource>,
+    query: Query<Entity, With<SendScreenNode>>,
+) {
+    // the confirm modal goes away with the screen
+    send_res.pending = None;
+    if let Ok(screen_node) = query.get_single() {
+        commands.entity(screen_node).despawn_recursive();
+    }
+}
@@ -1,19 +1,22 @@
 use bevy::prelude::*;
 
-use crate::{ui::{
+use crate::{config::{validate_config, ConfigField}, ui::{
     components::{
-        BaseScreenNode, ButtonCaptureTextInput, ButtonSaveConfig, InitialSetupScreenNode, SettingsConfigScreenNode, TextConfigInputRpcFetchAccountsInterval, TextConfigInputRpcSendTxInterval, TextConfigInputRpcUrl, TextConfigInputThreads, TextCursor, TextInput
+        ButtonCaptureTextInput, ButtonSaveConfig, SettingsConfigScreenNode, TextConfigFieldError, TextConfigInput, TextConfigStatus, TextCursor, TextInput
     },
     styles::{
-        BUTTON, BUTTON_SAVE_CONFIG, CURRENT_TX_STATUS_BACKGROUND, FONT_REGULAR, FONT_SIZE_LARGE, FONT_SIZE_MEDIUM, MENU_BACKGROUND, SCREEN_BACKGROUND_1, SETTINGS_ICON, TITLE_BACKGROUND, TREASURY_BACKGROUND
+        BUTTON_SAVE_CONFIG, CURRENT_TX_STATUS_BACKGROUND, FONT_REGULAR, FONT_SIZE_MEDIUM, FONT_SIZE_SMALL, NORMAL_BUTTON, TREASURY_BACKGROUND
     },
 }, AppConfig};
 
 pub fn spawn_settings_config_screen(
     parent: &mut ChildBuilder,
     asset_server: Res<AssetServer>,
-    config: AppConfig
+    config: &AppConfig,
+    status: Option<String>,
 ) {
+    // a config loaded with bad values shows what to fix straight away
+    let errors = validate_config(config);
     parent
         .spawn((
             NodeBundle {
@@ -161,12 +164,13 @@ pub fn spawn_settings_config_screen(
                         .spawn((
                             NodeBundle {
                                 style: Style {
-                                    justify_content: JustifyContent::Center,
+                                    justify_content: JustifyContent::Start,
                                     width: Val::Percent(100.0),
                                     height: Val::Percent(75.0),
                                     align_items: AlignItems::Center,
-                                    flex_direction: FlexDirection::Row,
-                                    // row_gap: Val::Px(30.0),
+                                    flex_direction: FlexDirection::Column,
+                                    row_gap: Val::Px(4.0),
+                                    padding: UiRect::top(Val::Px(10.0)),
                                     ..default()
                                 },
                                 //background_color: Color::WHITE.into(),
@@ -176,102 +180,34 @@ pub fn spawn_settings_config_screen(
                             Name::new("Config Input Node"),
                         ))
                         .with_children(|parent| {
-                            parent
-                                .spawn((
-                                    NodeBundle {
-                                        style: Style {
-                                            width: Val::Percent(30.0),
-                                            height: Val::Percent(60.0),
-                                            padding: UiRect::right(Val::Px(20.0)),
-                                            // flex_direction: FlexDirection::Column,
-                                            // align_items: AlignItems::Center,
-                                            ..default()
-                                        },
-                                        //background_color: Color::WHITE.into(),
-                                        ..default()
-                                    },
-                                    //UiImage::new(asset_server.load(TITLE_BACKGROUND)),
-                                    Name::new("Config Input Field Headers Wrapper"),
-                                ))
-                                .with_children(|parent| {
-                                    parent
-                                        .spawn((
-                                            NodeBundle {
-                                                style: Style {
-                                                    flex_direction: FlexDirection::Column,
-                                                    height: Val::Percent(100.0),
-                                                    width: Val::Percent(100.0),
-                                                    justify_content: JustifyContent::SpaceBetween,
-                                                    align_items: AlignItems::End,
-                                                    ..default()
-                                                },
-                                                ..default()
-                                            },
-                                            Name::new("Config Input Field Headers"),
-                                        ))
-                                        .with_children(|parent| {
-                                            parent.spawn(TextBundle::from_section(
-                                                "RPC URL: ",
-                                                TextStyle {
-                                                    font: asset_server.load(FONT_REGULAR),
-                                                    font_size: FONT_SIZE_MEDIUM,
-                                                    color: Color::rgb(0.9, 0.9, 0.9),
-                                                },
-                                            ));
-                                            parent.spawn(TextBundle::from_section(
-                                                "Threads: ",
-                                                TextStyle {
-                                                    font: asset_server.load(FONT_REGULAR),
-                                                    font_size: FONT_SIZE_MEDIUM,
-                                                    color: Color::rgb(0.9, 0.9, 0.9),
-                                                },
-                                            ));
-                                            parent.spawn(TextBundle::from_section(
-                                                "UI Fetch Interval (ms): ",
-                                                TextStyle {
-                                                    font: asset_server.load(FONT_REGULAR),
-                                                    font_size: FONT_SIZE_MEDIUM,
-                                                    color: Color::rgb(0.9, 0.9, 0.9),
-                                                },
-                                            ));
-                                            parent.spawn(TextBundle::from_section(
-                                                "Tx Send Interval (ms): ",
-                                                TextStyle {
-                                                    font: asset_server.load(FONT_REGULAR),
-                                                    font_size: FONT_SIZE_MEDIUM,
-                                                    color: Color::rgb(0.9, 0.9, 0.9),
-                                                },
-                                            ));
-                                        });
-                                });
-                            parent
-                                .spawn((
-                                    NodeBundle {
-                                        style: Style {
-                                            flex_direction: FlexDirection::Column,
-                                            height: Val::Px(268.0),
-                                            width: Val::Px(351.0),
-                                            align_items: AlignItems::Start,
-                                            justify_content: JustifyContent::SpaceBetween,
-                                            ..default()
-                                        },
-                                        ..default()
+                            parent.spawn((
+                                TextBundle::from_section(
+                                    status.unwrap_or_default(),
+                                    TextStyle {
+                                        font: asset_server.load(FONT_REGULAR),
+                                        font_size: FONT_SIZE_SMALL,
+                                        color: Color::RED,
                                     },
-                                    Name::new("Config Input Field Values"),
-                                ))
-                                .with_children(|parent| {
-                                });
+                                ),
+                                TextConfigStatus,
+                                Name::new("TextConfigStatus"),
+                            ));
+                            for field in ConfigField::ALL {
+                                let error = errors
+                                    .iter()
+                                    .find(|(f, _)| *f == field)
+                                    .map(|(_, e)| e.clone())
+                                    .unwrap_or_default();
+                                spawn_config_input_row(parent, &asset_server, field, field.value(config), error);
+                            }
                             parent
                                 .spawn((
                                     NodeBundle {
                                         style: Style {
-                                            position_type: PositionType::Absolute,
                                             justify_content: JustifyContent::Center,
-                                            left: Val::Percent(30.0),
                                             width: Val::Percent(40.0),
-                                            height: Val::Percent(10.0),
+                                            height: Val::Px(60.0),
                                             align_items: AlignItems::Center,
-                                            align_self: AlignSelf::End,
                                             flex_direction: FlexDirection::Row,
                                             ..default()
                                         },
@@ -305,6 +241,121 @@ pub fn spawn_settings_config_screen(
         });
 }
 
+fn spawn_config_input_row(
+    parent: &mut ChildBuilder,
+    asset_server: &AssetServer,
+    field: ConfigField,
+    value: String,
+    error: String,
+) {
+    parent.spawn((
+        NodeBundle {
+            style: Style {
+                flex_direction: FlexDirection::Row,
+                align_items: AlignItems::Center,
+                column_gap: Val::Px(10.0),
+                ..default()
+            },
+            ..default()
+        },
+        Name::new("Config Input Row"),
+    )).with_children(|parent| {
+        parent.spawn((
+            NodeBundle {
+                style: Style {
+                    width: Val::Px(250.0),
+                    justify_content: JustifyContent::End,
+                    ..default()
+                },
+                ..default()
+            },
+            Name::new("Config Input Row Label Node"),
+        )).with_children(|parent| {
+            parent.spawn(TextBundle::from_section(
+                field.label(),
+                TextStyle {
+                    font: asset_server.load(FONT_REGULAR),
+                    font_size: FONT_SIZE_SMALL,
+                    color: Color::rgb(0.9, 0.9, 0.9),
+                },
+            ));
+        });
+        parent
+            .spawn((
+                ButtonBundle {
+                    style: Style {
+                        width: Val::Px(250.0),
+                        height: Val::Px(30.0),
+                        border: UiRect::all(Val::Px(2.5)),
+                        padding: UiRect::left(Val::Px(5.0)),
+                        align_items: AlignItems::Center,
+                        overflow: Overflow::clip_x(),
+                        ..default()
+                    },
+                    border_color: BorderColor(Color::BLACK),
+                    background_color: NORMAL_BUTTON.into(),
+                    ..default()
+                },
+                ButtonCaptureTextInput,
+                Name::new("ButtonCaptureText"),
+            ))
+            .with_children(|parent| {
+                parent.spawn((
+                    TextBundle::from_section(
+                        value.clone(),
+                        TextStyle {
+                            font: asset_server.load(FONT_REGULAR),
+                            font_size: FONT_SIZE_SMALL,
+                            color: Color::rgb(0.9, 0.9, 0.9),
+                        },
+                    ),
+                    TextInput {
+                        hidden: false,
+                        numbers_only: field.numbers_only(),
+                        text: value,
+                        secret: None,
+                    },
+                    TextConfigInput(field),
+                ));
+                parent.spawn((
+                    NodeBundle {
+                        visibility: Visibility::Hidden,
+                        style: Style {
+                            width: Val::Px(8.0),
+                            height: Val::Px(18.0),
+                            ..default()
+                        },
+                        background_color: Color::WHITE.into(),
+                        ..default()
+                    },
+                    TextCursor,
+                    Name::new("TextCursor"),
+                ));
+            });
+        parent.spawn((
+            NodeBundle {
+                style: Style {
+                    width: Val::Px(220.0),
+                    ..default()
+                },
+                ..default()
+            },
+            Name::new("Config Input Row Error Node"),
+        )).with_children(|parent| {
+            parent.spawn((
+                TextBundle::from_section(
+                    error,
+                    TextStyle {
+                        font: asset_server.load(FONT_REGULAR),
+                        font_size: FONT_SIZE_SMALL,
+                        color: Color::RED,
+                    },
+                ),
+                TextConfigFieldError(field),
+            ));
+        });
+    });
+}
 
 pub fn despawn_settings_config_screen(
     mut commands: Commands,
@@ -1,17 +1,24 @@
 use bevy::prelude::*;
 
-use crate::ui::{
-    components::
-        SettingsGeneralScreenNode
-    ,
-    styles::{
-        hex_dark_mode_text_white_2, FONT_REGULAR, FONT_SIZE_LARGE
+use crate::{
+    secret_string::SecretString,
+    ui::{
+        components::{
+            ButtonCaptureTextInput, ButtonCreateAppBackup, SettingsGeneralScreenNode,
+            TextAppBackupConfirmInput, TextAppBackupPasswordInput, TextAppBackupStatus, TextCursor,
+            TextInput,
+        },
+        styles::{
+            hex_dark_mode_text_gray, hex_dark_mode_text_white_2, FONT_REGULAR, FONT_SIZE_LARGE,
+            FONT_SIZE_SMALL, NORMAL_BUTTON,
+        },
     },
 };
 
 pub fn spawn_settings_general_screen(
     parent: &mut ChildBuilder,
     asset_server: Res<AssetServer>,
+    effective_config: Vec<String>,
 ) {
     parent
         .spawn((
@@ -19,8 +26,10 @@ pub fn spawn_settings_general_screen(
                 style: Style {
                     width: Val::Percent(100.0),
                     height: Val::Percent(100.0),
+                    flex_direction: FlexDirection::Column,
                     align_items: AlignItems::Center,
                     justify_content: JustifyContent::Center,
+                    row_gap: Val::Px(10.0),
                     ..default()
                 },
                 ..default()
@@ -35,11 +44,188 @@ pub fn spawn_settings_general_screen(
                     TextStyle {
                         font: asset_server.load(FONT_REGULAR),
                         font_size: FONT_SIZE_LARGE,
-                        color: hex_dark_mode_text_white_2().into()
+                        color: hex_dark_mode_text_white_2()
                     },
                 ),
                 Name::new("TextSETTINGSGENERALAPPSCREENBACKGROUND"),
             ));
+
+            parent.spawn((
+                TextBundle::from_section(
+                    "EFFECTIVE CONFIG",
+                    TextStyle {
+                        font: asset_server.load(FONT_REGULAR),
+                        font_size: FONT_SIZE_SMALL,
+                        color: hex_dark_mode_text_white_2()
+                    },
+                ),
+                Name::new("TextEffectiveConfigTitle"),
+            ));
+
+            parent.spawn((
+                NodeBundle {
+                    style: Style {
+                        flex_direction: FlexDirection::Column,
+                        align_items: AlignItems::Start,
+                        row_gap: Val::Px(4.0),
+                        ..default()
+                    },
+                    ..default()
+                },
+                Name::new("Effective Config List"),
+            )).with_children(|parent| {
+                for line in effective_config {
+                    parent.spawn((
+                        TextBundle::from_section(
+                            line,
+                            TextStyle {
+                                font: asset_server.load(FONT_REGULAR),
+                                font_size: FONT_SIZE_SMALL,
+                                color: hex_dark_mode_text_gray()
+                            },
+                        ),
+                        Name::new("TextEffectiveConfigLine"),
+                    ));
+                }
+            });
+
+            // wallets, config, address book and mining history in one encrypted file
+            parent.spawn((
+                TextBundle::from_section(
+                    "APP BACKUP",
+                    TextStyle {
+                        font: asset_server.load(FONT_REGULAR),
+                        font_size: FONT_SIZE_SMALL,
+                        color: hex_dark_mode_text_white_2()
+                    },
+                ),
+                Name::new("TextAppBackupTitle"),
+            ));
+
+            parent.spawn((
+                NodeBundle {
+                    style: Style {
+                        flex_direction: FlexDirection::Row,
+                        align_items: AlignItems::Center,
+                        column_gap: Val::Px(10.0),
+                        ..default()
+                    },
+                    ..default()
+                },
+                Name::new("App Backup Row"),
+            )).with_children(|parent| {
+                spawn_backup_password_input(parent, &asset_server, "Password:", TextAppBackupPasswordInput);
+                spawn_backup_password_input(parent, &asset_server, "Confirm:", TextAppBackupConfirmInput);
+                parent
+                    .spawn((
+                        ButtonBundle {
+                            style: Style {
+                                width: Val::Px(120.0),
+                                height: Val::Px(35.0),
+                                border: UiRect::all(Val::Px(2.5)),
+                                justify_content: JustifyContent::Center,
+                                align_items: AlignItems::Center,
+                                ..default()
+                            },
+                            border_color: BorderColor(Color::BLACK),
+                            background_color: NORMAL_BUTTON.into(),
+                            ..default()
+                        },
+                        ButtonCreateAppBackup,
+                        Name::new("ButtonCreateAppBackup"),
+                    ))
+                    .with_children(|parent| {
+                        parent.spawn(TextBundle::from_section(
+                            "BACKUP",
+                            TextStyle {
+                                font: asset_server.load(FONT_REGULAR),
+                                font_size: FONT_SIZE_SMALL,
+                                color: Color::rgb(0.9, 0.9, 0.9),
+                            },
+                        ));
+                    });
+            });
+
+            parent.spawn((
+                TextBundle::from_section(
+                    "",
+                    TextStyle {
+                        font: asset_server.load(FONT_REGULAR),
+                        font_size: FONT_SIZE_SMALL,
+                        color: hex_dark_mode_text_gray()
+                    },
+                ),
+                TextAppBackupStatus,
+                Name::new("TextAppBackupStatus"),
+            ));
+        });
+}
+
+fn spawn_backup_password_input(
+    parent: &mut ChildBuilder,
+    asset_server: &AssetServer,
+    label: &str,
+    marker: impl Component,
+) {
+    parent.spawn(TextBundle::from_section(
+        label,
+        TextStyle {
+            font: asset_server.load(FONT_REGULAR),
+            font_size: FONT_SIZE_SMALL,
+            color: hex_dark_mode_text_gray(),
+        },
+    ));
+    parent
+        .spawn((
+            ButtonBundle {
+                style: Style {
+                    width: Val::Px(200.0),
+                    height: Val::Px(35.0),
+                    border: UiRect::all(Val::Px(2.5)),
+                    padding: UiRect::left(Val::Px(5.0)),
+                    align_items: AlignItems::Center,
+                    overflow: Overflow::clip_x(),
+                    ..default()
+                },
+                border_color: BorderColor(Color::BLACK),
+                background_color: NORMAL_BUTTON.into(),
+                ..default()
+            },
+            ButtonCaptureTextInput,
+            Name::new("ButtonCaptureText"),
+        ))
+        .with_children(|parent| {
+            parent.spawn((
+                TextBundle::from_section(
+                    "",
+                    TextStyle {
+                        font: asset_server.load(FONT_REGULAR),
+                        font_size: FONT_SIZE_SMALL,
+                        color: Color::rgb(0.9, 0.9, 0.9),
+                    },
+                ),
+                TextInput {
+                    hidden: true,
+                    numbers_only: false,
+                    text: String::new(),
+                    secret: Some(SecretString::new()),
+                },
+                marker,
+            ));
+            parent.spawn((
+                NodeBundle {
+                    visibility: Visibility::Hidden,
+                    style: Style {
+                        width: Val::Px(8.0),
+                        height: Val::Px(18.0),
+                        ..default()
+                    },
+                    background_color: Color::WHITE.into(),
+                    ..default()
+                },
+                TextCursor,
+                Name::new("TextCursor"),
+            ));
         });
 }
 
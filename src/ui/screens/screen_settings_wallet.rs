@@ -1,17 +1,28 @@
 use bevy::prelude::*;
 
-use crate::ui::{
-    components::
-        SettingsWalletScreenNode
-    ,
-    styles::{
-        hex_dark_mode_text_white_2, FONT_REGULAR, FONT_SIZE_LARGE
+use crate::{
+    password_strength::{strength_label, MAX_PASSWORD_STRENGTH},
+    secret_string::SecretString,
+    SecretAction,
+    ui::{
+        components::{
+            ButtonCaptureTextInput, ButtonChangePassword, ButtonRevealSecret, PasswordStrengthBar,
+            SettingsWalletScreenNode, TextChangePasswordConfirm, TextChangePasswordNew,
+            TextChangePasswordOld, TextChangePasswordStatus, TextCursor, TextExportPath, TextInput,
+            TextPasswordStrength, TextRevealPassword, TextRevealStatus, TextRevealedMnemonic,
+        },
+        styles::{
+            hex_dark_mode_text_gray, hex_dark_mode_text_white_2, FONT_REGULAR, FONT_SIZE_LARGE,
+            FONT_SIZE_MEDIUM, NORMAL_BUTTON,
+        },
     },
 };
 
 pub fn spawn_settings_wallet_screen(
     parent: &mut ChildBuilder,
     asset_server: Res<AssetServer>,
+    profile_name: String,
+    min_password_strength: u8,
 ) {
     parent
         .spawn((
@@ -19,8 +30,10 @@ pub fn spawn_settings_wallet_screen(
                 style: Style {
                     width: Val::Percent(100.0),
                     height: Val::Percent(100.0),
+                    flex_direction: FlexDirection::Column,
                     align_items: AlignItems::Center,
                     justify_content: JustifyContent::Center,
+                    row_gap: Val::Px(10.0),
                     ..default()
                 },
                 ..default()
@@ -35,14 +48,326 @@ pub fn spawn_settings_wallet_screen(
                     TextStyle {
                         font: asset_server.load(FONT_REGULAR),
                         font_size: FONT_SIZE_LARGE,
-                        color: hex_dark_mode_text_white_2().into()
+                        color: hex_dark_mode_text_white_2()
                     },
                 ),
                 Name::new("TextSETTINGSWALLETAPPSCREENBACKGROUND"),
             ));
+            parent.spawn((
+                TextBundle::from_section(
+                    format!("Change Password - {}", profile_name),
+                    TextStyle {
+                        font: asset_server.load(FONT_REGULAR),
+                        font_size: FONT_SIZE_MEDIUM,
+                        color: hex_dark_mode_text_white_2()
+                    },
+                ),
+                Name::new("TextChangePasswordTitle"),
+            ));
+
+            spawn_input_row(parent, &asset_server, "Current Password:", true, "", TextChangePasswordOld);
+            spawn_input_row(parent, &asset_server, "New Password:", true, "", TextChangePasswordNew);
+            spawn_input_row(parent, &asset_server, "Confirm Password:", true, "", TextChangePasswordConfirm);
+
+            // strength meter
+            parent.spawn((
+                NodeBundle {
+                    style: Style {
+                        flex_direction: FlexDirection::Row,
+                        align_items: AlignItems::Center,
+                        column_gap: Val::Px(10.0),
+                        ..default()
+                    },
+                    ..default()
+                },
+                Name::new("Password Strength Row"),
+            )).with_children(|parent| {
+                parent.spawn(TextBundle::from_section(
+                    "Strength:",
+                    TextStyle {
+                        font: asset_server.load(FONT_REGULAR),
+                        font_size: FONT_SIZE_MEDIUM,
+                        color: hex_dark_mode_text_gray()
+                    },
+                ));
+                for i in 1..=MAX_PASSWORD_STRENGTH {
+                    parent.spawn((
+                        NodeBundle {
+                            style: Style {
+                                width: Val::Px(50.0),
+                                height: Val::Px(10.0),
+                                ..default()
+                            },
+                            background_color: NORMAL_BUTTON.into(),
+                            ..default()
+                        },
+                        PasswordStrengthBar(i),
+                        Name::new("PasswordStrengthBar"),
+                    ));
+                }
+                parent.spawn((
+                    TextBundle::from_section(
+                        "",
+                        TextStyle {
+                            font: asset_server.load(FONT_REGULAR),
+                            font_size: FONT_SIZE_MEDIUM,
+                            color: hex_dark_mode_text_gray()
+                        },
+                    ),
+                    TextPasswordStrength,
+                    Name::new("TextPasswordStrength"),
+                ));
+            });
+            parent.spawn((
+                TextBundle::from_section(
+                    format!("Minimum strength: {}", strength_label(min_password_strength)),
+                    TextStyle {
+                        font: asset_server.load(FONT_REGULAR),
+                        font_size: FONT_SIZE_MEDIUM,
+                        color: hex_dark_mode_text_gray()
+                    },
+                ),
+                Name::new("TextMinPasswordStrength"),
+            ));
+
+            parent
+                .spawn((
+                    ButtonBundle {
+                        style: Style {
+                            width: Val::Px(220.0),
+                            height: Val::Px(40.0),
+                            border: UiRect::all(Val::Px(2.5)),
+                            justify_content: JustifyContent::Center,
+                            align_items: AlignItems::Center,
+                            ..default()
+                        },
+                        border_color: BorderColor(Color::BLACK),
+                        background_color: NORMAL_BUTTON.into(),
+                        ..default()
+                    },
+                    ButtonChangePassword,
+                    Name::new("ButtonChangePassword"),
+                ))
+                .with_children(|parent| {
+                    parent.spawn(TextBundle::from_section(
+                        "CHANGE PASSWORD",
+                        TextStyle {
+                            font: asset_server.load(FONT_REGULAR),
+                            font_size: FONT_SIZE_MEDIUM,
+                            color: Color::rgb(0.9, 0.9, 0.9),
+                        },
+                    ));
+                });
+            parent.spawn((
+                TextBundle::from_section(
+                    "",
+                    TextStyle {
+                        font: asset_server.load(FONT_REGULAR),
+                        font_size: FONT_SIZE_MEDIUM,
+                        color: hex_dark_mode_text_gray()
+                    },
+                ),
+                TextChangePasswordStatus,
+                Name::new("TextChangePasswordStatus"),
+            ));
+
+            // reveal and export, every action asks for the password again
+            parent.spawn((
+                TextBundle::from_section(
+                    "Reveal / Export Secret",
+                    TextStyle {
+                        font: asset_server.load(FONT_REGULAR),
+                        font_size: FONT_SIZE_MEDIUM,
+                        color: hex_dark_mode_text_white_2()
+                    },
+                ),
+                Name::new("TextRevealTitle"),
+            ));
+            spawn_input_row(parent, &asset_server, "Password:", true, "", TextRevealPassword);
+            spawn_input_row(
+                parent,
+                &asset_server,
+                "Export Path:",
+                false,
+                &format!("{}-keypair.json", profile_name),
+                TextExportPath,
+            );
+            parent.spawn((
+                NodeBundle {
+                    style: Style {
+                        flex_direction: FlexDirection::Row,
+                        align_items: AlignItems::Center,
+                        column_gap: Val::Px(10.0),
+                        ..default()
+                    },
+                    ..default()
+                },
+                Name::new("Reveal Buttons Row"),
+            )).with_children(|parent| {
+                spawn_reveal_button(parent, &asset_server, "SHOW SEED PHRASE", SecretAction::ShowMnemonic);
+                spawn_reveal_button(parent, &asset_server, "EXPORT KEYPAIR", SecretAction::ExportKeypair);
+                spawn_reveal_button(parent, &asset_server, "COPY SECRET KEY", SecretAction::CopySecret);
+            });
+            parent.spawn((
+                TextBundle::from_section(
+                    "",
+                    TextStyle {
+                        font: asset_server.load(FONT_REGULAR),
+                        font_size: FONT_SIZE_MEDIUM,
+                        color: hex_dark_mode_text_gray()
+                    },
+                ),
+                TextRevealStatus,
+                Name::new("TextRevealStatus"),
+            ));
+            parent.spawn((
+                TextBundle::from_section(
+                    "",
+                    TextStyle {
+                        font: asset_server.load(FONT_REGULAR),
+                        font_size: FONT_SIZE_MEDIUM,
+                        color: hex_dark_mode_text_white_2()
+                    },
+                ).with_style(Style {
+                    max_width: Val::Px(700.0),
+                    ..default()
+                }),
+                TextRevealedMnemonic,
+                Name::new("TextRevealedMnemonic"),
+            ));
         });
 }
 
+fn spawn_reveal_button(
+    parent: &mut ChildBuilder,
+    asset_server: &AssetServer,
+    label: &str,
+    action: SecretAction,
+) {
+    parent
+        .spawn((
+            ButtonBundle {
+                style: Style {
+                    width: Val::Px(200.0),
+                    height: Val::Px(40.0),
+                    border: UiRect::all(Val::Px(2.5)),
+                    justify_content: JustifyContent::Center,
+                    align_items: AlignItems::Center,
+                    ..default()
+                },
+                border_color: BorderColor(Color::BLACK),
+                background_color: NORMAL_BUTTON.into(),
+                ..default()
+            },
+            ButtonRevealSecret(action),
+            Name::new("ButtonRevealSecret"),
+        ))
+        .with_children(|parent| {
+            parent.spawn(TextBundle::from_section(
+                label,
+                TextStyle {
+                    font: asset_server.load(FONT_REGULAR),
+                    font_size: FONT_SIZE_MEDIUM,
+                    color: Color::rgb(0.9, 0.9, 0.9),
+                },
+            ));
+        });
+}
+
+fn spawn_input_row(
+    parent: &mut ChildBuilder,
+    asset_server: &AssetServer,
+    label: &str,
+    hidden: bool,
+    text: &str,
+    marker: impl Component,
+) {
+    parent.spawn((
+        NodeBundle {
+            style: Style {
+                flex_direction: FlexDirection::Row,
+                align_items: AlignItems::Center,
+                column_gap: Val::Px(10.0),
+                ..default()
+            },
+            ..default()
+        },
+        Name::new("Input Row"),
+    )).with_children(|parent| {
+        parent.spawn((
+            NodeBundle {
+                style: Style {
+                    width: Val::Px(180.0),
+                    justify_content: JustifyContent::End,
+                    ..default()
+                },
+                ..default()
+            },
+            Name::new("Input Row Label Node"),
+        )).with_children(|parent| {
+            parent.spawn(TextBundle::from_section(
+                label,
+                TextStyle {
+                    font: asset_server.load(FONT_REGULAR),
+                    font_size: FONT_SIZE_MEDIUM,
+                    color: hex_dark_mode_text_gray(),
+                },
+            ));
+        });
+        parent
+            .spawn((
+                ButtonBundle {
+                    style: Style {
+                        width: Val::Px(250.0),
+                        height: Val::Px(40.0),
+                        border: UiRect::all(Val::Px(2.5)),
+                        padding: UiRect::left(Val::Px(5.0)),
+                        align_items: AlignItems::Center,
+                        overflow: Overflow::clip_x(),
+                        ..default()
+                    },
+                    border_color: BorderColor(Color::BLACK),
+                    background_color: NORMAL_BUTTON.into(),
+                    ..default()
+                },
+                ButtonCaptureTextInput,
+                Name::new("ButtonCaptureText"),
+            ))
+            .with_children(|parent| {
+                parent.spawn((
+                    TextBundle::from_section(
+                        text,
+                        TextStyle {
+                            font: asset_server.load(FONT_REGULAR),
+                            font_size: FONT_SIZE_MEDIUM,
+                            color: Color::rgb(0.9, 0.9, 0.9),
+                        },
+                    ),
+                    TextInput {
+                        hidden,
+                        numbers_only: false,
+                        text: text.to_string(),
+                        secret: hidden.then(SecretString::new),
+                    },
+                    marker,
+                ));
+                parent.spawn((
+                    NodeBundle {
+                        visibility: Visibility::Hidden,
+                        style: Style {
+                            width: Val::Px(8.0),
+                            height: Val::Px(18.0),
+                            ..default()
+                        },
+                        background_color: Color::WHITE.into(),
+                        ..default()
+                    },
+                    TextCursor,
+                    Name::new("TextCursor"),
+                ));
+            });
+    });
+}
 
 pub fn despawn_settings_wallet_screen(
     mut commands: Commands,
@@ -1,22 +1,38 @@
 use std::sync::Arc;
 
 use bevy::prelude::*;
-use solana_sdk::signature::Keypair;
+use solana_sdk::{pubkey::Pubkey, signature::Keypair};
 
-use crate::ui::{
-    components::{
-        BaseScreenNode, ButtonCaptureTextInput, ButtonGenerateWallet, ButtonSaveGeneratedWallet,
-        InitialSetupScreenNode, TextCursor, TextGeneratedKeypair, TextInput, TextMnemonicLine1,
-        TextMnemonicLine2, TextMnemonicLine3, TextPasswordInput, TextPasswordLabel,
-        WalletSetupScreenNode,
-    },
-    styles::{
-        BUTTON_GENERATE, BUTTON_SAVE_WALLET, FONT_REGULAR, FONT_SIZE_MEDIUM, NORMAL_BUTTON,
-        TREASURY_BACKGROUND,
+use crate::{
+    app_backup::RestoreMode,
+    secret_string::SecretString,
+    ui::{
+        components::{
+            BackupVerificationNode, BackupWordGrid, BaseScreenNode, ButtonAcceptBackupRisk,
+            ButtonAddWatchOnly, ButtonBackupWord, ButtonOpenAppBackup, ButtonRestoreAppBackup, ButtonCaptureTextInput, ButtonGenerateWallet, ButtonRestoreAccount,
+            ButtonRestoreMnemonic, ButtonSaveGeneratedWallet,
+            RestorePreviewList, TextCursor, TextDerivationPathInput, TextGeneratedKeypair,
+            TextInput, TextMnemonicInput, TextMnemonicLine1, TextMnemonicLine2,
+            TextMnemonicLine3, TextMnemonicPassphraseInput, TextPasswordInput,
+            TextAcceptBackupRisk, TextBackupPrompt, TextPasswordLabel, TextProfileNameInput,
+            TextRestoreStatus, TextWatchPubkeyInput, TextAppRestorePasswordInput,
+            TextAppRestorePathInput, TextAppRestoreStatus,
+            WalletSetupScreenNode,
+        },
+        styles::{
+            hex_dark_mode_text_gray, BUTTON_GENERATE, BUTTON_SAVE_WALLET, FONT_REGULAR,
+            FONT_SIZE_MEDIUM, NORMAL_BUTTON, TREASURY_BACKGROUND,
+        },
     },
+    utils::shorten_string,
+    wallet_restore::DEFAULT_DERIVATION_PATH,
 };
 
-pub fn spawn_wallet_setup_screen(parent: &mut ChildBuilder, asset_server: Res<AssetServer>) {
+pub fn spawn_wallet_setup_screen(
+    parent: &mut ChildBuilder,
+    asset_server: Res<AssetServer>,
+    profile_name: String,
+) {
     parent
         .spawn((
             NodeBundle {
@@ -85,7 +101,7 @@ pub fn spawn_wallet_setup_screen(parent: &mut ChildBuilder, asset_server: Res<As
                     NodeBundle {
                         style: Style {
                             width: Val::Percent(100.0),
-                            height: Val::Percent(75.0),
+                            height: Val::Percent(85.0),
                             flex_direction: FlexDirection::Column,
                             align_items: AlignItems::Center,
                             justify_content: JustifyContent::Center,
@@ -105,7 +121,7 @@ pub fn spawn_wallet_setup_screen(parent: &mut ChildBuilder, asset_server: Res<As
                             NodeBundle {
                                 style: Style {
                                     width: Val::Percent(100.0),
-                                    height: Val::Percent(40.0),
+                                    height: Val::Percent(27.0),
                                     flex_direction: FlexDirection::Row,
                                     align_items: AlignItems::Center,
                                     justify_content: JustifyContent::Center,
@@ -209,7 +225,7 @@ pub fn spawn_wallet_setup_screen(parent: &mut ChildBuilder, asset_server: Res<As
                                                     },
                                                 ),
                                                 Name::new("Text Generated Pubkey Value"),
-                                                TextGeneratedKeypair(Arc::new(Keypair::new())),
+                                                TextGeneratedKeypair(Arc::new(Keypair::new()), None),
                                             ));
                                             // spawn_copyable_text(
                                             //     parent,
@@ -354,16 +370,405 @@ pub fn spawn_wallet_setup_screen(parent: &mut ChildBuilder, asset_server: Res<As
                                         });
                                 });
                         });
+                    // generated seed phrase backup check
+                    parent
+                        .spawn((
+                            NodeBundle {
+                                style: Style {
+                                    width: Val::Percent(100.0),
+                                    height: Val::Percent(18.0),
+                                    display: Display::None,
+                                    flex_direction: FlexDirection::Column,
+                                    align_items: AlignItems::Center,
+                                    justify_content: JustifyContent::Center,
+                                    row_gap: Val::Px(6.0),
+                                    ..default()
+                                },
+                                ..default()
+                            },
+                            Name::new("Backup Verification Node"),
+                            BackupVerificationNode,
+                        ))
+                        .with_children(|parent| {
+                            parent.spawn((
+                                TextBundle::from_section(
+                                    "",
+                                    TextStyle {
+                                        font: asset_server.load(FONT_REGULAR),
+                                        font_size: FONT_SIZE_MEDIUM,
+                                        color: Color::rgb(0.9, 0.9, 0.9),
+                                    },
+                                ),
+                                Name::new("TextBackupPrompt"),
+                                TextBackupPrompt,
+                            ));
+                            parent.spawn((
+                                NodeBundle {
+                                    style: Style {
+                                        flex_direction: FlexDirection::Row,
+                                        flex_wrap: FlexWrap::Wrap,
+                                        justify_content: JustifyContent::Center,
+                                        column_gap: Val::Px(8.0),
+                                        row_gap: Val::Px(5.0),
+                                        max_width: Val::Percent(90.0),
+                                        ..default()
+                                    },
+                                    ..default()
+                                },
+                                Name::new("Backup Word Grid"),
+                                BackupWordGrid,
+                            ));
+                            parent
+                                .spawn((
+                                    ButtonBundle {
+                                        style: Style {
+                                            height: Val::Px(30.0),
+                                            padding: UiRect::horizontal(Val::Px(8.0)),
+                                            border: UiRect::all(Val::Px(2.5)),
+                                            justify_content: JustifyContent::Center,
+                                            align_items: AlignItems::Center,
+                                            ..default()
+                                        },
+                                        border_color: BorderColor(Color::BLACK),
+                                        background_color: NORMAL_BUTTON.into(),
+                                        ..default()
+                                    },
+                                    ButtonAcceptBackupRisk,
+                                    Name::new("ButtonAcceptBackupRisk"),
+                                ))
+                                .with_children(|parent| {
+                                    parent.spawn((
+                                        TextBundle::from_section(
+                                            "",
+                                            TextStyle {
+                                                font: asset_server.load(FONT_REGULAR),
+                                                font_size: FONT_SIZE_MEDIUM,
+                                                color: hex_dark_mode_text_gray(),
+                                            },
+                                        ),
+                                        TextAcceptBackupRisk,
+                                    ));
+                                });
+                        });
+                    // restore from seed phrase
+                    parent
+                        .spawn((
+                            NodeBundle {
+                                style: Style {
+                                    width: Val::Percent(100.0),
+                                    height: Val::Percent(24.0),
+                                    flex_direction: FlexDirection::Column,
+                                    align_items: AlignItems::Center,
+                                    justify_content: JustifyContent::Center,
+                                    row_gap: Val::Px(8.0),
+                                    ..default()
+                                },
+                                ..default()
+                            },
+                            Name::new("Restore Wallet Node"),
+                        ))
+                        .with_children(|parent| {
+                            parent
+                                .spawn((
+                                    NodeBundle {
+                                        style: Style {
+                                            flex_direction: FlexDirection::Row,
+                                            align_items: AlignItems::Center,
+                                            column_gap: Val::Px(10.0),
+                                            ..default()
+                                        },
+                                        ..default()
+                                    },
+                                    Name::new("Restore Seed Phrase Row"),
+                                ))
+                                .with_children(|parent| {
+                                    parent.spawn(TextBundle::from_section(
+                                        "Seed Phrase: ",
+                                        TextStyle {
+                                            font: asset_server.load(FONT_REGULAR),
+                                            font_size: FONT_SIZE_MEDIUM,
+                                            color: Color::rgb(0.9, 0.9, 0.9),
+                                        },
+                                    ));
+                                    spawn_restore_text_input(
+                                        parent,
+                                        &asset_server,
+                                        800.0,
+                                        false,
+                                        true,
+                                        "",
+                                        TextMnemonicInput,
+                                    );
+                                });
+                            parent
+                                .spawn((
+                                    NodeBundle {
+                                        style: Style {
+                                            flex_direction: FlexDirection::Row,
+                                            align_items: AlignItems::Center,
+                                            column_gap: Val::Px(10.0),
+                                            ..default()
+                                        },
+                                        ..default()
+                                    },
+                                    Name::new("Restore Options Row"),
+                                ))
+                                .with_children(|parent| {
+                                    parent.spawn(TextBundle::from_section(
+                                        "Passphrase (optional): ",
+                                        TextStyle {
+                                            font: asset_server.load(FONT_REGULAR),
+                                            font_size: FONT_SIZE_MEDIUM,
+                                            color: Color::rgb(0.9, 0.9, 0.9),
+                                        },
+                                    ));
+                                    spawn_restore_text_input(
+                                        parent,
+                                        &asset_server,
+                                        200.0,
+                                        true,
+                                        true,
+                                        "",
+                                        TextMnemonicPassphraseInput,
+                                    );
+                                    parent.spawn(TextBundle::from_section(
+                                        "Derivation Path: ",
+                                        TextStyle {
+                                            font: asset_server.load(FONT_REGULAR),
+                                            font_size: FONT_SIZE_MEDIUM,
+                                            color: Color::rgb(0.9, 0.9, 0.9),
+                                        },
+                                    ));
+                                    spawn_restore_text_input(
+                                        parent,
+                                        &asset_server,
+                                        200.0,
+                                        false,
+                                        false,
+                                        DEFAULT_DERIVATION_PATH,
+                                        TextDerivationPathInput,
+                                    );
+                                    parent
+                                        .spawn((
+                                            ButtonBundle {
+                                                style: Style {
+                                                    width: Val::Px(120.0),
+                                                    height: Val::Px(40.0),
+                                                    border: UiRect::all(Val::Px(2.5)),
+                                                    justify_content: JustifyContent::Center,
+                                                    align_items: AlignItems::Center,
+                                                    ..default()
+                                                },
+                                                border_color: BorderColor(Color::BLACK),
+                                                background_color: NORMAL_BUTTON.into(),
+                                                ..default()
+                                            },
+                                            ButtonRestoreMnemonic,
+                                            Name::new("ButtonRestoreMnemonic"),
+                                        ))
+                                        .with_children(|parent| {
+                                            parent.spawn(TextBundle::from_section(
+                                                "RESTORE",
+                                                TextStyle {
+                                                    font: asset_server.load(FONT_REGULAR),
+                                                    font_size: FONT_SIZE_MEDIUM,
+                                                    color: Color::rgb(0.9, 0.9, 0.9),
+                                                },
+                                            ));
+                                        });
+                                });
+                            parent.spawn((
+                                TextBundle::from_section(
+                                    "Paste (right click) or type a 12 or 24 word seed phrase, then click Restore.",
+                                    TextStyle {
+                                        font: asset_server.load(FONT_REGULAR),
+                                        font_size: FONT_SIZE_MEDIUM,
+                                        color: Color::rgb(0.9, 0.9, 0.9),
+                                    },
+                                ),
+                                Name::new("TextRestoreStatus"),
+                                TextRestoreStatus,
+                            ));
+                            parent.spawn((
+                                NodeBundle {
+                                    style: Style {
+                                        flex_direction: FlexDirection::Row,
+                                        flex_wrap: FlexWrap::Wrap,
+                                        justify_content: JustifyContent::Center,
+                                        column_gap: Val::Px(10.0),
+                                        row_gap: Val::Px(5.0),
+                                        max_width: Val::Percent(90.0),
+                                        ..default()
+                                    },
+                                    ..default()
+                                },
+                                Name::new("Restore Preview List"),
+                                RestorePreviewList,
+                            ));
+                            // watch-only, no private key
+                            parent
+                                .spawn((
+                                    NodeBundle {
+                                        style: Style {
+                                            flex_direction: FlexDirection::Row,
+                                            align_items: AlignItems::Center,
+                                            column_gap: Val::Px(10.0),
+                                            ..default()
+                                        },
+                                        ..default()
+                                    },
+                                    Name::new("Watch Only Row"),
+                                ))
+                                .with_children(|parent| {
+                                    parent.spawn(TextBundle::from_section(
+                                        "Watch-only Pubkey: ",
+                                        TextStyle {
+                                            font: asset_server.load(FONT_REGULAR),
+                                            font_size: FONT_SIZE_MEDIUM,
+                                            color: Color::rgb(0.9, 0.9, 0.9),
+                                        },
+                                    ));
+                                    spawn_restore_text_input(
+                                        parent,
+                                        &asset_server,
+                                        500.0,
+                                        false,
+                                        false,
+                                        "",
+                                        TextWatchPubkeyInput,
+                                    );
+                                    parent
+                                        .spawn((
+                                            ButtonBundle {
+                                                style: Style {
+                                                    width: Val::Px(120.0),
+                                                    height: Val::Px(40.0),
+                                                    border: UiRect::all(Val::Px(2.5)),
+                                                    justify_content: JustifyContent::Center,
+                                                    align_items: AlignItems::Center,
+                                                    ..default()
+                                                },
+                                                border_color: BorderColor(Color::BLACK),
+                                                background_color: NORMAL_BUTTON.into(),
+                                                ..default()
+                                            },
+                                            ButtonAddWatchOnly,
+                                            Name::new("ButtonAddWatchOnly"),
+                                        ))
+                                        .with_children(|parent| {
+                                            parent.spawn(TextBundle::from_section(
+                                                "WATCH",
+                                                TextStyle {
+                                                    font: asset_server.load(FONT_REGULAR),
+                                                    font_size: FONT_SIZE_MEDIUM,
+                                                    color: Color::rgb(0.9, 0.9, 0.9),
+                                                },
+                                            ));
+                                        });
+                                });
+                        });
+                    // restore an app backup made from the settings screen
+                    parent
+                        .spawn((
+                            NodeBundle {
+                                style: Style {
+                                    width: Val::Percent(100.0),
+                                    flex_direction: FlexDirection::Column,
+                                    align_items: AlignItems::Center,
+                                    justify_content: JustifyContent::Center,
+                                    row_gap: Val::Px(8.0),
+                                    ..default()
+                                },
+                                ..default()
+                            },
+                            Name::new("Restore App Backup Node"),
+                        ))
+                        .with_children(|parent| {
+                            parent
+                                .spawn((
+                                    NodeBundle {
+                                        style: Style {
+                                            flex_direction: FlexDirection::Row,
+                                            align_items: AlignItems::Center,
+                                            column_gap: Val::Px(10.0),
+                                            ..default()
+                                        },
+                                        ..default()
+                                    },
+                                    Name::new("Restore App Backup Row"),
+                                ))
+                                .with_children(|parent| {
+                                    parent.spawn(TextBundle::from_section(
+                                        "Backup File: ",
+                                        TextStyle {
+                                            font: asset_server.load(FONT_REGULAR),
+                                            font_size: FONT_SIZE_MEDIUM,
+                                            color: Color::rgb(0.9, 0.9, 0.9),
+                                        },
+                                    ));
+                                    spawn_restore_text_input(
+                                        parent,
+                                        &asset_server,
+                                        420.0,
+                                        false,
+                                        false,
+                                        "",
+                                        TextAppRestorePathInput,
+                                    );
+                                    parent.spawn(TextBundle::from_section(
+                                        "Password: ",
+                                        TextStyle {
+                                            font: asset_server.load(FONT_REGULAR),
+                                            font_size: FONT_SIZE_MEDIUM,
+                                            color: Color::rgb(0.9, 0.9, 0.9),
+                                        },
+                                    ));
+                                    spawn_restore_text_input(
+                                        parent,
+                                        &asset_server,
+                                        200.0,
+                                        true,
+                                        true,
+                                        "",
+                                        TextAppRestorePasswordInput,
+                                    );
+                                    spawn_app_backup_button(parent, &asset_server, "OPEN", ButtonOpenAppBackup);
+                                    spawn_app_backup_button(
+                                        parent,
+                                        &asset_server,
+                                        "MERGE",
+                                        ButtonRestoreAppBackup(RestoreMode::Merge),
+                                    );
+                                    spawn_app_backup_button(
+                                        parent,
+                                        &asset_server,
+                                        "REPLACE",
+                                        ButtonRestoreAppBackup(RestoreMode::Replace),
+                                    );
+                                });
+                            parent.spawn((
+                                TextBundle::from_section(
+                                    "Open a .orebak backup to see what it holds, then merge it into or replace the current profiles.",
+                                    TextStyle {
+                                        font: asset_server.load(FONT_REGULAR),
+                                        font_size: FONT_SIZE_MEDIUM,
+                                        color: Color::rgb(0.9, 0.9, 0.9),
+                                    },
+                                ),
+                                Name::new("TextAppRestoreStatus"),
+                                TextAppRestoreStatus,
+                            ));
+                        });
                     parent
                         .spawn((
                             NodeBundle {
                                 style: Style {
                                     width: Val::Percent(100.0),
-                                    height: Val::Percent(30.0),
+                                    height: Val::Percent(12.0),
                                     flex_direction: FlexDirection::Row,
                                     align_items: AlignItems::Center,
                                     justify_content: JustifyContent::Center,
-                                    // row_gap: Val::Px(30.0),
+                                    column_gap: Val::Px(30.0),
                                     ..default()
                                 },
                                 //background_color: Color::WHITE.into(),
@@ -372,6 +777,83 @@ pub fn spawn_wallet_setup_screen(parent: &mut ChildBuilder, asset_server: Res<As
                             Name::new("Password Inputs Node"),
                         ))
                         .with_children(|parent| {
+                            parent
+                                .spawn((
+                                    NodeBundle {
+                                        style: Style {
+                                            justify_content: JustifyContent::Center,
+                                            align_items: AlignItems::Center,
+                                            flex_direction: FlexDirection::Row,
+                                            ..default()
+                                        },
+                                        ..default()
+                                    },
+                                    Name::new("Profile Name Input Field"),
+                                ))
+                                .with_children(|parent| {
+                                    parent.spawn(TextBundle::from_section(
+                                        "Profile Name: ",
+                                        TextStyle {
+                                            font: asset_server.load("fonts/FiraSans-Bold.ttf"),
+                                            font_size: FONT_SIZE_MEDIUM,
+                                            color: Color::rgb(0.9, 0.9, 0.9),
+                                        },
+                                    ));
+                                    parent
+                                        .spawn((
+                                            ButtonBundle {
+                                                style: Style {
+                                                    width: Val::Px(200.0),
+                                                    height: Val::Px(50.0),
+                                                    border: UiRect::all(Val::Px(2.5)),
+                                                    // horizontally center child text
+                                                    justify_content: JustifyContent::Center,
+                                                    // vertically center child text
+                                                    align_items: AlignItems::Center,
+                                                    ..default()
+                                                },
+                                                border_color: BorderColor(Color::BLACK),
+                                                background_color: NORMAL_BUTTON.into(),
+                                                ..default()
+                                            },
+                                            ButtonCaptureTextInput,
+                                            Name::new("ButtonCaptureText"),
+                                        ))
+                                        .with_children(|parent| {
+                                            parent.spawn((
+                                                TextBundle::from_section(
+                                                    profile_name.clone(),
+                                                    TextStyle {
+                                                        font: asset_server
+                                                            .load("fonts/FiraSans-Bold.ttf"),
+                                                        font_size: FONT_SIZE_MEDIUM,
+                                                        color: Color::rgb(0.9, 0.9, 0.9),
+                                                    },
+                                                ),
+                                                TextInput {
+                                                    hidden: false,
+                                                    numbers_only: false,
+                                                    text: profile_name.clone(),
+                                                    secret: None,
+                                                },
+                                                TextProfileNameInput,
+                                            ));
+                                            parent.spawn((
+                                                NodeBundle {
+                                                    visibility: Visibility::Hidden,
+                                                    style: Style {
+                                                        width: Val::Px(8.0),
+                                                        height: Val::Px(18.0),
+                                                        ..default()
+                                                    },
+                                                    background_color: Color::WHITE.into(),
+                                                    ..default()
+                                                },
+                                                TextCursor,
+                                                Name::new("TextCursor"),
+                                            ));
+                                        });
+                                });
                             parent
                                 .spawn((
                                     NodeBundle {
@@ -432,6 +914,7 @@ pub fn spawn_wallet_setup_screen(parent: &mut ChildBuilder, asset_server: Res<As
                                                     hidden: true,
                                                     numbers_only: false,
                                                     text: "".to_string(),
+                                                    secret: Some(SecretString::new()),
                                                 },
                                                 TextPasswordInput,
                                             ));
@@ -457,7 +940,7 @@ pub fn spawn_wallet_setup_screen(parent: &mut ChildBuilder, asset_server: Res<As
                             NodeBundle {
                                 style: Style {
                                     width: Val::Percent(100.0),
-                                    height: Val::Percent(20.0),
+                                    height: Val::Percent(10.0),
                                     flex_direction: FlexDirection::Row,
                                     align_items: AlignItems::Center,
                                     justify_content: JustifyContent::Center,
@@ -488,6 +971,184 @@ pub fn spawn_wallet_setup_screen(parent: &mut ChildBuilder, asset_server: Res<As
         });
 }
 
+fn spawn_app_backup_button(
+    parent: &mut ChildBuilder,
+    asset_server: &AssetServer,
+    label: &str,
+    marker: impl Component,
+) {
+    parent
+        .spawn((
+            ButtonBundle {
+                style: Style {
+                    width: Val::Px(120.0),
+                    height: Val::Px(40.0),
+                    border: UiRect::all(Val::Px(2.5)),
+                    justify_content: JustifyContent::Center,
+                    align_items: AlignItems::Center,
+                    ..default()
+                },
+                border_color: BorderColor(Color::BLACK),
+                background_color: NORMAL_BUTTON.into(),
+                ..default()
+            },
+            marker,
+            Name::new("ButtonAppBackup"),
+        ))
+        .with_children(|parent| {
+            parent.spawn(TextBundle::from_section(
+                label,
+                TextStyle {
+                    font: asset_server.load(FONT_REGULAR),
+                    font_size: FONT_SIZE_MEDIUM,
+                    color: Color::rgb(0.9, 0.9, 0.9),
+                },
+            ));
+        });
+}
+
+fn spawn_restore_text_input(
+    parent: &mut ChildBuilder,
+    asset_server: &AssetServer,
+    width: f32,
+    hidden: bool,
+    secret: bool,
+    text: &str,
+    marker: impl Component,
+) {
+    parent
+        .spawn((
+            ButtonBundle {
+                style: Style {
+                    width: Val::Px(width),
+                    height: Val::Px(40.0),
+                    border: UiRect::all(Val::Px(2.5)),
+                    padding: UiRect::left(Val::Px(5.0)),
+                    align_items: AlignItems::Center,
+                    overflow: Overflow::clip_x(),
+                    ..default()
+                },
+                border_color: BorderColor(Color::BLACK),
+                background_color: NORMAL_BUTTON.into(),
+                ..default()
+            },
+            ButtonCaptureTextInput,
+            Name::new("ButtonCaptureText"),
+        ))
+        .with_children(|parent| {
+            parent.spawn((
+                TextBundle::from_section(
+                    text,
+                    TextStyle {
+                        font: asset_server.load(FONT_REGULAR),
+                        font_size: FONT_SIZE_MEDIUM,
+                        color: Color::rgb(0.9, 0.9, 0.9),
+                    },
+                ),
+                TextInput {
+                    hidden,
+                    numbers_only: false,
+                    text: text.to_string(),
+                    secret: secret.then(SecretString::new),
+                },
+                marker,
+            ));
+            parent.spawn((
+                NodeBundle {
+                    visibility: Visibility::Hidden,
+                    style: Style {
+                        width: Val::Px(8.0),
+                        height: Val::Px(18.0),
+                        ..default()
+                    },
+                    background_color: Color::WHITE.into(),
+                    ..default()
+                },
+                TextCursor,
+                Name::new("TextCursor"),
+            ));
+        });
+}
+
+/// One derived account in the restore preview, clicking it restores that account.
+pub fn spawn_restore_account_item(
+    parent: &mut ChildBuilder,
+    asset_server: &AssetServer,
+    derivation_path: &str,
+    pubkey: &Pubkey,
+    selected: bool,
+) {
+    parent
+        .spawn((
+            ButtonBundle {
+                style: Style {
+                    height: Val::Px(30.0),
+                    padding: UiRect::horizontal(Val::Px(8.0)),
+                    border: UiRect::all(Val::Px(2.5)),
+                    justify_content: JustifyContent::Center,
+                    align_items: AlignItems::Center,
+                    ..default()
+                },
+                border_color: BorderColor(Color::BLACK),
+                background_color: NORMAL_BUTTON.into(),
+                ..default()
+            },
+            ButtonRestoreAccount(derivation_path.to_string()),
+            Name::new("ButtonRestoreAccount"),
+        ))
+        .with_children(|parent| {
+            let color = if selected {
+                Color::WHITE
+            } else {
+                hex_dark_mode_text_gray()
+            };
+            parent.spawn(TextBundle::from_section(
+                format!("{}  {}", derivation_path, shorten_string(pubkey.to_string(), 10)),
+                TextStyle {
+                    font: asset_server.load(FONT_REGULAR),
+                    font_size: FONT_SIZE_MEDIUM,
+                    color,
+                },
+            ));
+        });
+}
+
+/// One word in the shuffled backup grid.
+pub fn spawn_backup_word_button(
+    parent: &mut ChildBuilder,
+    asset_server: &AssetServer,
+    word: &str,
+) {
+    parent
+        .spawn((
+            ButtonBundle {
+                style: Style {
+                    width: Val::Px(110.0),
+                    height: Val::Px(30.0),
+                    border: UiRect::all(Val::Px(2.5)),
+                    justify_content: JustifyContent::Center,
+                    align_items: AlignItems::Center,
+                    ..default()
+                },
+                border_color: BorderColor(Color::BLACK),
+                background_color: NORMAL_BUTTON.into(),
+                ..default()
+            },
+            ButtonBackupWord(word.to_string()),
+            Name::new("ButtonBackupWord"),
+        ))
+        .with_children(|parent| {
+            parent.spawn(TextBundle::from_section(
+                word,
+                TextStyle {
+                    font: asset_server.load(FONT_REGULAR),
+                    font_size: FONT_SIZE_MEDIUM,
+                    color: Color::rgb(0.9, 0.9, 0.9),
+                },
+            ));
+        });
+}
+
 pub fn despawn_wallet_create_screen(
     mut commands: Commands,
     query: Query<Entity, With<WalletSetupScreenNode>>,
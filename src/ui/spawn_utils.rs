@@ -75,8 +75,7 @@ pub fn spawn_new_list_item(
                         TextStyle {
                             font: asset_server.load(FONT_REGULAR),
                             font_size: FONT_SIZE_MEDIUM,
-                            color: hex_dark_mode_text_gray().into(),
-                            ..default()
+                            color: hex_dark_mode_text_gray(),
                         },
                     ),
                     Label,
@@ -111,8 +110,7 @@ pub fn spawn_new_list_item(
                         TextStyle {
                             font: asset_server.load(FONT_REGULAR),
                             font_size: FONT_SIZE_MEDIUM,
-                            color: hex_dark_mode_text_gray().into(),
-                            ..default()
+                            color: hex_dark_mode_text_gray(),
                         },
                     ),
                     Label,
@@ -154,8 +152,7 @@ pub fn spawn_new_list_item(
                         TextStyle {
                             font: asset_server.load(FONT_REGULAR),
                             font_size: FONT_SIZE_MEDIUM,
-                            color: hex_dark_mode_text_gray().into(),
-                            ..default()
+                            color: hex_dark_mode_text_gray(),
                         },
                     ),
                     Label,
@@ -181,8 +178,7 @@ pub fn spawn_new_list_item(
                         TextStyle {
                             font: asset_server.load(FONT_REGULAR),
                             font_size: FONT_SIZE_MEDIUM,
-                            color: hex_dark_mode_text_gray().into(),
-                            ..default()
+                            color: hex_dark_mode_text_gray(),
                         },
                     ),
                     Label,
@@ -213,8 +209,7 @@ pub fn spawn_new_list_item(
                         TextStyle {
                             font: asset_server.load(FONT_REGULAR),
                             font_size: FONT_SIZE_MEDIUM,
-                            color: hex_dark_mode_text_gray().into(),
-                            ..default()
+                            color: hex_dark_mode_text_gray(),
                         },
                     ),
                     Label,
@@ -324,7 +319,7 @@ pub fn spawn_web_link_icon(
                     TextStyle {
                         font: asset_server.load(FONT_REGULAR),
                         font_size: FONT_SIZE_MEDIUM,
-                        color: hex_dark_mode_text_gray().into(),
+                        color: hex_dark_mode_text_gray(),
                     },
                 ),
                 Name::new("WalletPubkeyText"),
@@ -1,15 +1,14 @@
-use std::time::Duration;
 
 use bevy::prelude::*;
 use copypasta::{ClipboardContext, ClipboardProvider};
 
 use crate::{
-    AppConfig, EventClaimOreRewards, EventGenerateWallet, EventLock, EventRequestAirdrop, EventSaveConfig, EventSaveWallet, EventStakeOre, EventStartStopMining, EventUnlock, OreAppState
+    AppScreenState, BackupVerificationResource, EventAddWatchOnly, EventBackupWordPicked, EventClaimOreRewards, EventFetchTxHistory, EventGenerateWallet, EventCancelSend, EventChangePassword, EventConfirmSend, EventLock, EventPrepareSend, EventRemoveRecipient, EventRequestAirdrop, EventRestoreMnemonic, EventRevealSecret, EventSaveConfig, EventSaveRecipient, EventSaveWallet, EventSelectProfile, EventStakeOre, EventStartStopMining, EventSwitchProfile, EventUnlock, EventExportMiningHistory, EventCreateAppBackup, EventCreateSupportBundle, EventCrashRecovery, EventOpenAppBackup, EventRestoreAppBackup, LogViewerResource, MiningExportResource, OreAppState, SendResource
 };
 
 use super::{
     components::{
-        AutoScrollCheckIcon, ButtonAutoScroll, ButtonCaptureTextInput, ButtonClaimOreRewards, ButtonCooldownSpinner, ButtonCopyText, ButtonGenerateWallet, ButtonLock, ButtonOpenWebTxExplorer, ButtonRequestAirdrop, ButtonSaveConfig, ButtonSaveGeneratedWallet, ButtonStakeOre, ButtonUnlock, CopyableText, TextConfigInputRpcFetchAccountsInterval, TextConfigInputRpcSendTxInterval, TextConfigInputRpcUrl, TextConfigInputThreads, TextInput, ToggleAutoMine
+        AutoScrollCheckIcon, ButtonAcceptBackupRisk, ButtonAddWatchOnly, ButtonAddressBookEntry, ButtonBackupWord, ButtonAddressBookRemove, ButtonAutoScroll, ButtonCaptureTextInput, ButtonChangePassword, ButtonClaimOreRewards, ButtonCooldownSpinner, ButtonCopyText, ButtonCrashRecovery, ButtonCreateAppBackup, ButtonCreateSupportBundle, ButtonGenerateWallet, ButtonHistoryLoadMore, ButtonHistoryRefresh, ButtonLock, ButtonLogLevelFilter, ButtonExportFormat, ButtonExportMiningHistory, TextExportFormat, TextExportFromInput, TextExportToInput, ButtonNewProfile, ButtonOpenAppBackup, ButtonOpenWebTxExplorer, ButtonRequestAirdrop, ButtonRestoreAccount, ButtonRestoreAppBackup, ButtonRestoreMnemonic, ButtonRevealSecret, ButtonSaveConfig, ButtonSaveGeneratedWallet, ButtonSelectProfile, ButtonSendCancel, ButtonSendConfirm, ButtonSendReview, ButtonSendSaveRecipient, ButtonSendToggleToken, ButtonStakeOre, ButtonSwitchProfile, ButtonUnlock, CopyableText, TextConfigInput, TextDerivationPathInput, TextInput, TextSendAmount, TextSendRecipient, TextSendRecipientName, TextSendToken, ToggleAutoMine
     },
     styles::{hex_dark_mode_app_screen_background, HOVERED_BUTTON, NORMAL_BUTTON, PRESSED_BUTTON},
 };
@@ -21,7 +20,7 @@ pub fn button_copy_text(
     >,
     text_query: Query<(&CopyableText, &Children)>,
 ) {
-    for (entity, interaction, mut color, mut border_color) in &mut interaction_query {
+    for (entity, interaction, mut color, _border_color) in &mut interaction_query {
         match *interaction {
             Interaction::Pressed => {
                 *color = PRESSED_BUTTON.into();
@@ -35,9 +34,9 @@ pub fn button_copy_text(
                     }
                 }
                 if let Some(text) = text {
-                    let mut ctx = ClipboardContext::new();
+                    let ctx = ClipboardContext::new();
                     if let Ok(mut ctx) = ctx {
-                        if let Err(_) = ctx.set_contents(text) {
+                        if ctx.set_contents(text).is_err() {
                             error!("Failed to set clipboard content.");
                         } 
                     } else {
@@ -64,7 +63,7 @@ pub fn button_open_web_tx_explorer(
     >,
     text_query: Query<(&CopyableText, &Children)>,
 ) {
-    for (entity, interaction, mut color, mut border_color) in &mut interaction_query {
+    for (entity, interaction, mut color, _border_color) in &mut interaction_query {
         match *interaction {
             Interaction::Pressed => {
                 *color = PRESSED_BUTTON.into();
@@ -79,7 +78,7 @@ pub fn button_open_web_tx_explorer(
                 }
                 if let Some(text) = text {
                     let url = format!("https://solscan.io/tx/{}", text);
-                    if let Err(_) = open::that(url) {
+                    if open::that(url).is_err() {
                         error!("Failed to open web tx explorer with default web browser.");
                     }
                 } else {
@@ -102,118 +101,650 @@ pub fn button_start_stop_mining(
         (Entity, &Interaction, &mut BackgroundColor, &mut BorderColor),
         (Changed<Interaction>, With<ToggleAutoMine>),
     >,
+) {
+    for (_entity, interaction, mut color, _border_color) in &mut interaction_query {
+        match *interaction {
+            Interaction::Pressed => {
+                *color = PRESSED_BUTTON.into();
+                // border_color.0 = Color::RED;
+
+                ev_start_stop_mining.send(EventStartStopMining);
+            }
+            Interaction::Hovered => {
+                *color = HOVERED_BUTTON.into();
+                // border_color.0 = Color::WHITE;
+            }
+            Interaction::None => {
+                *color = Color::WHITE.into();
+                // *color = NORMAL_BUTTON.into();
+                // border_color.0 = Color::BLACK;
+            }
+        }
+    }
+}
+
+pub fn button_generate_wallet(
+    mut event_writer: EventWriter<EventGenerateWallet>,
+    mut interaction_query: Query<
+        (Entity, &Interaction, &mut BackgroundColor, &mut BorderColor),
+        (Changed<Interaction>, With<ButtonGenerateWallet>),
+    >,
+) {
+    for (_entity, interaction, mut color, _border_color) in &mut interaction_query {
+        match *interaction {
+            Interaction::Pressed => {
+                *color = PRESSED_BUTTON.into();
+                // border_color.0 = Color::RED;
+
+                event_writer.send(EventGenerateWallet);
+            }
+            Interaction::Hovered => {
+                *color = HOVERED_BUTTON.into();
+                // border_color.0 = Color::WHITE;
+            }
+            Interaction::None => {
+                *color = Color::WHITE.into();
+                // *color = NORMAL_BUTTON.into();
+                // border_color.0 = Color::BLACK;
+            }
+        }
+    }
+}
+
+pub fn button_claim_ore_rewards(
+    mut interaction_query: Query<
+        (&Interaction, &mut BackgroundColor, &mut BorderColor),
+        (Changed<Interaction>, With<ButtonClaimOreRewards>),
+    >,
+    mut event_writer: EventWriter<EventClaimOreRewards>,
+) {
+    for (interaction, mut color, _border_color) in &mut interaction_query {
+        match *interaction {
+            Interaction::Pressed => {
+                *color = PRESSED_BUTTON.into();
+                // border_color.0 = Color::RED;
+
+                event_writer.send(EventClaimOreRewards);
+            }
+            Interaction::Hovered => {
+                *color = HOVERED_BUTTON.into();
+                // border_color.0 = Color::WHITE;
+            }
+            Interaction::None => {
+                *color = Color::WHITE.into();
+                // *color = NORMAL_BUTTON.into();
+                // border_color.0 = Color::BLACK;
+            }
+        }
+    }
+}
+
+pub fn button_stake_ore(
+    mut interaction_query: Query<
+        (&Interaction, &mut BackgroundColor, &mut BorderColor),
+        (Changed<Interaction>, With<ButtonStakeOre>),
+    >,
+    mut event_writer: EventWriter<EventStakeOre>,
+) {
+    for (interaction, mut color, _border_color) in &mut interaction_query {
+        match *interaction {
+            Interaction::Pressed => {
+                *color = PRESSED_BUTTON.into();
+                // border_color.0 = Color::RED;
+
+                event_writer.send(EventStakeOre);
+            }
+            Interaction::Hovered => {
+                *color = HOVERED_BUTTON.into();
+                // border_color.0 = Color::WHITE;
+            }
+            Interaction::None => {
+                *color = Color::WHITE.into();
+                // *color = NORMAL_BUTTON.into();
+                // border_color.0 = Color::BLACK;
+            }
+        }
+    }
+}
+
+pub fn button_lock(
+    mut interaction_query: Query<
+        (Entity, &Interaction, &mut BackgroundColor, &mut BorderColor),
+        (Changed<Interaction>, With<ButtonLock>),
+    >,
+    mut event_writer: EventWriter<EventLock>,
+) {
+    for (_entity, interaction, mut color, mut border_color) in &mut interaction_query {
+        match *interaction {
+            Interaction::Pressed => {
+                *color = PRESSED_BUTTON.into();
+                border_color.0 = Color::RED;
+
+                event_writer.send(EventLock);
+            }
+            Interaction::Hovered => {
+                *color = HOVERED_BUTTON.into();
+                border_color.0 = Color::WHITE;
+            }
+            Interaction::None => {
+                *color = NORMAL_BUTTON.into();
+                border_color.0 = Color::BLACK;
+            }
+        }
+    }
+}
+
+pub fn button_unlock(
+    mut interaction_query: Query<
+        (Entity, &Interaction, &mut BackgroundColor, &mut BorderColor),
+        (Changed<Interaction>, With<ButtonUnlock>),
+    >,
+    mut event_writer: EventWriter<EventUnlock>,
+) {
+    for (_entity, interaction, mut color, mut border_color) in &mut interaction_query {
+        match *interaction {
+            Interaction::Pressed => {
+                *color = PRESSED_BUTTON.into();
+                border_color.0 = Color::RED;
+
+                event_writer.send(EventUnlock);
+            }
+            Interaction::Hovered => {
+                *color = HOVERED_BUTTON.into();
+                border_color.0 = Color::WHITE;
+            }
+            Interaction::None => {
+                *color = NORMAL_BUTTON.into();
+                border_color.0 = Color::BLACK;
+            }
+        }
+    }
+}
+
+pub fn button_select_profile(
+    mut interaction_query: Query<
+        (&Interaction, &mut BackgroundColor, &mut BorderColor, &ButtonSelectProfile),
+        Changed<Interaction>,
+    >,
+    mut event_writer: EventWriter<EventSelectProfile>,
+) {
+    for (interaction, mut color, mut border_color, profile) in &mut interaction_query {
+        match *interaction {
+            Interaction::Pressed => {
+                *color = PRESSED_BUTTON.into();
+                border_color.0 = Color::RED;
+
+                event_writer.send(EventSelectProfile(profile.0.clone()));
+            }
+            Interaction::Hovered => {
+                *color = HOVERED_BUTTON.into();
+                border_color.0 = Color::WHITE;
+            }
+            Interaction::None => {
+                *color = NORMAL_BUTTON.into();
+                border_color.0 = Color::BLACK;
+            }
+        }
+    }
+}
+
+pub fn button_new_profile(
+    mut interaction_query: Query<
+        (Entity, &Interaction, &mut BackgroundColor, &mut BorderColor),
+        (Changed<Interaction>, With<ButtonNewProfile>),
+    >,
+    mut next_state: ResMut<NextState<AppScreenState>>,
+) {
+    for (_entity, interaction, mut color, mut border_color) in &mut interaction_query {
+        match *interaction {
+            Interaction::Pressed => {
+                *color = PRESSED_BUTTON.into();
+                border_color.0 = Color::RED;
+
+                next_state.set(AppScreenState::WalletSetup);
+            }
+            Interaction::Hovered => {
+                *color = HOVERED_BUTTON.into();
+                border_color.0 = Color::WHITE;
+            }
+            Interaction::None => {
+                *color = NORMAL_BUTTON.into();
+                border_color.0 = Color::BLACK;
+            }
+        }
+    }
+}
+
+pub fn button_switch_profile(
+    mut interaction_query: Query<
+        (Entity, &Interaction, &mut BackgroundColor, &mut BorderColor),
+        (Changed<Interaction>, With<ButtonSwitchProfile>),
+    >,
+    mut event_writer: EventWriter<EventSwitchProfile>,
+) {
+    for (_entity, interaction, mut color, mut border_color) in &mut interaction_query {
+        match *interaction {
+            Interaction::Pressed => {
+                *color = PRESSED_BUTTON.into();
+                border_color.0 = Color::RED;
+
+                event_writer.send(EventSwitchProfile);
+            }
+            Interaction::Hovered => {
+                *color = HOVERED_BUTTON.into();
+                border_color.0 = Color::WHITE;
+            }
+            Interaction::None => {
+                *color = NORMAL_BUTTON.into();
+                border_color.0 = Color::BLACK;
+            }
+        }
+    }
+}
+
+pub fn button_restore_mnemonic(
+    mut interaction_query: Query<
+        (Entity, &Interaction, &mut BackgroundColor, &mut BorderColor),
+        (Changed<Interaction>, With<ButtonRestoreMnemonic>),
+    >,
+    mut event_writer: EventWriter<EventRestoreMnemonic>,
+) {
+    for (_entity, interaction, mut color, mut border_color) in &mut interaction_query {
+        match *interaction {
+            Interaction::Pressed => {
+                *color = PRESSED_BUTTON.into();
+                border_color.0 = Color::RED;
+
+                event_writer.send(EventRestoreMnemonic);
+            }
+            Interaction::Hovered => {
+                *color = HOVERED_BUTTON.into();
+                border_color.0 = Color::WHITE;
+            }
+            Interaction::None => {
+                *color = NORMAL_BUTTON.into();
+                border_color.0 = Color::BLACK;
+            }
+        }
+    }
+}
+
+pub fn button_add_watch_only(
+    mut interaction_query: Query<
+        (Entity, &Interaction, &mut BackgroundColor, &mut BorderColor),
+        (Changed<Interaction>, With<ButtonAddWatchOnly>),
+    >,
+    mut event_writer: EventWriter<EventAddWatchOnly>,
+) {
+    for (_entity, interaction, mut color, mut border_color) in &mut interaction_query {
+        match *interaction {
+            Interaction::Pressed => {
+                *color = PRESSED_BUTTON.into();
+                border_color.0 = Color::RED;
+
+                event_writer.send(EventAddWatchOnly);
+            }
+            Interaction::Hovered => {
+                *color = HOVERED_BUTTON.into();
+                border_color.0 = Color::WHITE;
+            }
+            Interaction::None => {
+                *color = NORMAL_BUTTON.into();
+                border_color.0 = Color::BLACK;
+            }
+        }
+    }
+}
+
+pub fn button_backup_word(
+    mut interaction_query: Query<
+        (&Interaction, &mut BackgroundColor, &mut BorderColor, &ButtonBackupWord),
+        Changed<Interaction>,
+    >,
+    mut event_writer: EventWriter<EventBackupWordPicked>,
+) {
+    for (interaction, mut color, mut border_color, button) in &mut interaction_query {
+        match *interaction {
+            Interaction::Pressed => {
+                *color = PRESSED_BUTTON.into();
+                border_color.0 = Color::RED;
+
+                event_writer.send(EventBackupWordPicked(button.0.clone()));
+            }
+            Interaction::Hovered => {
+                *color = HOVERED_BUTTON.into();
+                border_color.0 = Color::WHITE;
+            }
+            Interaction::None => {
+                *color = NORMAL_BUTTON.into();
+                border_color.0 = Color::BLACK;
+            }
+        }
+    }
+}
+
+pub fn button_accept_backup_risk(
+    mut interaction_query: Query<
+        (&Interaction, &mut BackgroundColor, &mut BorderColor),
+        (Changed<Interaction>, With<ButtonAcceptBackupRisk>),
+    >,
+    mut backup: ResMut<BackupVerificationResource>,
+) {
+    for (interaction, mut color, mut border_color) in &mut interaction_query {
+        match *interaction {
+            Interaction::Pressed => {
+                *color = PRESSED_BUTTON.into();
+                border_color.0 = Color::RED;
+
+                if backup.challenge.is_some() {
+                    backup.accept_risk = !backup.accept_risk;
+                }
+            }
+            Interaction::Hovered => {
+                *color = HOVERED_BUTTON.into();
+                border_color.0 = Color::WHITE;
+            }
+            Interaction::None => {
+                *color = NORMAL_BUTTON.into();
+                border_color.0 = Color::BLACK;
+            }
+        }
+    }
+}
+
+pub fn button_restore_account(
+    mut interaction_query: Query<
+        (&Interaction, &mut BackgroundColor, &mut BorderColor, &ButtonRestoreAccount),
+        Changed<Interaction>,
+    >,
+    mut derivation_path_query: Query<&mut TextInput, With<TextDerivationPathInput>>,
+    mut event_writer: EventWriter<EventRestoreMnemonic>,
+) {
+    for (interaction, mut color, mut border_color, account) in &mut interaction_query {
+        match *interaction {
+            Interaction::Pressed => {
+                *color = PRESSED_BUTTON.into();
+                border_color.0 = Color::RED;
+
+                if let Ok(mut text_input) = derivation_path_query.get_single_mut() {
+                    text_input.text = account.0.clone();
+                }
+                event_writer.send(EventRestoreMnemonic);
+            }
+            Interaction::Hovered => {
+                *color = HOVERED_BUTTON.into();
+                border_color.0 = Color::WHITE;
+            }
+            Interaction::None => {
+                *color = NORMAL_BUTTON.into();
+                border_color.0 = Color::BLACK;
+            }
+        }
+    }
+}
+
+pub fn button_change_password(
+    mut interaction_query: Query<
+        (Entity, &Interaction, &mut BackgroundColor, &mut BorderColor),
+        (Changed<Interaction>, With<ButtonChangePassword>),
+    >,
+    mut event_writer: EventWriter<EventChangePassword>,
+) {
+    for (_entity, interaction, mut color, mut border_color) in &mut interaction_query {
+        match *interaction {
+            Interaction::Pressed => {
+                *color = PRESSED_BUTTON.into();
+                border_color.0 = Color::RED;
+
+                event_writer.send(EventChangePassword);
+            }
+            Interaction::Hovered => {
+                *color = HOVERED_BUTTON.into();
+                border_color.0 = Color::WHITE;
+            }
+            Interaction::None => {
+                *color = NORMAL_BUTTON.into();
+                border_color.0 = Color::BLACK;
+            }
+        }
+    }
+}
+
+pub fn button_reveal_secret(
+    mut interaction_query: Query<
+        (&Interaction, &mut BackgroundColor, &mut BorderColor, &ButtonRevealSecret),
+        Changed<Interaction>,
+    >,
+    mut event_writer: EventWriter<EventRevealSecret>,
+) {
+    for (interaction, mut color, mut border_color, button) in &mut interaction_query {
+        match *interaction {
+            Interaction::Pressed => {
+                *color = PRESSED_BUTTON.into();
+                border_color.0 = Color::RED;
+
+                event_writer.send(EventRevealSecret(button.0));
+            }
+            Interaction::Hovered => {
+                *color = HOVERED_BUTTON.into();
+                border_color.0 = Color::WHITE;
+            }
+            Interaction::None => {
+                *color = NORMAL_BUTTON.into();
+                border_color.0 = Color::BLACK;
+            }
+        }
+    }
+}
+
+pub fn button_history_refresh(
+    mut interaction_query: Query<
+        (Entity, &Interaction, &mut BackgroundColor, &mut BorderColor),
+        (Changed<Interaction>, With<ButtonHistoryRefresh>),
+    >,
+    mut event_writer: EventWriter<EventFetchTxHistory>,
+) {
+    for (_entity, interaction, mut color, mut border_color) in &mut interaction_query {
+        match *interaction {
+            Interaction::Pressed => {
+                *color = PRESSED_BUTTON.into();
+                border_color.0 = Color::RED;
+
+                event_writer.send(EventFetchTxHistory { reset: true });
+            }
+            Interaction::Hovered => {
+                *color = HOVERED_BUTTON.into();
+                border_color.0 = Color::WHITE;
+            }
+            Interaction::None => {
+                *color = NORMAL_BUTTON.into();
+                border_color.0 = Color::BLACK;
+            }
+        }
+    }
+}
+
+pub fn button_log_level_filter(
+    mut interaction_query: Query<
+        (Entity, &Interaction, &mut BackgroundColor, &mut BorderColor, &ButtonLogLevelFilter),
+        Changed<Interaction>,
+    >,
+    mut log_viewer: ResMut<LogViewerResource>,
+) {
+    for (_entity, interaction, mut color, mut border_color, filter) in &mut interaction_query {
+        match *interaction {
+            Interaction::Pressed => {
+                *color = PRESSED_BUTTON.into();
+                border_color.0 = Color::RED;
+
+                log_viewer.level = filter.0;
+            }
+            Interaction::Hovered => {
+                *color = HOVERED_BUTTON.into();
+                border_color.0 = Color::WHITE;
+            }
+            Interaction::None => {
+                *color = NORMAL_BUTTON.into();
+                border_color.0 = Color::BLACK;
+            }
+        }
+    }
+}
+
+pub fn button_export_format(
+    mut interaction_query: Query<
+        (Entity, &Interaction, &mut BackgroundColor, &mut BorderColor),
+        (Changed<Interaction>, With<ButtonExportFormat>),
+    >,
+    mut mining_export: ResMut<MiningExportResource>,
+    mut text_query: Query<&mut Text, With<TextExportFormat>>,
+) {
+    for (_entity, interaction, mut color, mut border_color) in &mut interaction_query {
+        match *interaction {
+            Interaction::Pressed => {
+                *color = PRESSED_BUTTON.into();
+                border_color.0 = Color::RED;
+
+                mining_export.format = mining_export.format.toggled();
+                if let Ok(mut text) = text_query.get_single_mut() {
+                    text.sections[0].value = mining_export.format.to_string();
+                }
+            }
+            Interaction::Hovered => {
+                *color = HOVERED_BUTTON.into();
+                border_color.0 = Color::WHITE;
+            }
+            Interaction::None => {
+                *color = NORMAL_BUTTON.into();
+                border_color.0 = Color::BLACK;
+            }
+        }
+    }
+}
+
+pub fn button_export_mining_history(
+    mut interaction_query: Query<
+        (Entity, &Interaction, &mut BackgroundColor, &mut BorderColor),
+        (Changed<Interaction>, With<ButtonExportMiningHistory>),
+    >,
+    mining_export: Res<MiningExportResource>,
+    from_query: Query<&TextInput, With<TextExportFromInput>>,
+    to_query: Query<&TextInput, With<TextExportToInput>>,
+    mut event_writer: EventWriter<EventExportMiningHistory>,
 ) {
     for (_entity, interaction, mut color, mut border_color) in &mut interaction_query {
         match *interaction {
             Interaction::Pressed => {
                 *color = PRESSED_BUTTON.into();
-                // border_color.0 = Color::RED;
+                border_color.0 = Color::RED;
 
-                ev_start_stop_mining.send(EventStartStopMining);
+                let from = from_query.get_single().map(|input| input.text.clone()).unwrap_or_default();
+                let to = to_query.get_single().map(|input| input.text.clone()).unwrap_or_default();
+                event_writer.send(EventExportMiningHistory {
+                    from,
+                    to,
+                    format: mining_export.format,
+                });
             }
             Interaction::Hovered => {
                 *color = HOVERED_BUTTON.into();
-                // border_color.0 = Color::WHITE;
+                border_color.0 = Color::WHITE;
             }
             Interaction::None => {
-                *color = Color::WHITE.into();
-                // *color = NORMAL_BUTTON.into();
-                // border_color.0 = Color::BLACK;
+                *color = NORMAL_BUTTON.into();
+                border_color.0 = Color::BLACK;
             }
         }
     }
 }
 
-pub fn button_generate_wallet(
-    mut event_writer: EventWriter<EventGenerateWallet>,
+pub fn button_create_app_backup(
     mut interaction_query: Query<
         (Entity, &Interaction, &mut BackgroundColor, &mut BorderColor),
-        (Changed<Interaction>, With<ButtonGenerateWallet>),
+        (Changed<Interaction>, With<ButtonCreateAppBackup>),
     >,
+    mut event_writer: EventWriter<EventCreateAppBackup>,
 ) {
     for (_entity, interaction, mut color, mut border_color) in &mut interaction_query {
         match *interaction {
             Interaction::Pressed => {
                 *color = PRESSED_BUTTON.into();
-                // border_color.0 = Color::RED;
+                border_color.0 = Color::RED;
 
-                event_writer.send(EventGenerateWallet);
+                event_writer.send(EventCreateAppBackup);
             }
             Interaction::Hovered => {
                 *color = HOVERED_BUTTON.into();
-                // border_color.0 = Color::WHITE;
+                border_color.0 = Color::WHITE;
             }
             Interaction::None => {
-                *color = Color::WHITE.into();
-                // *color = NORMAL_BUTTON.into();
-                // border_color.0 = Color::BLACK;
+                *color = NORMAL_BUTTON.into();
+                border_color.0 = Color::BLACK;
             }
         }
     }
 }
 
-pub fn button_claim_ore_rewards(
+pub fn button_open_app_backup(
     mut interaction_query: Query<
-        (&Interaction, &mut BackgroundColor, &mut BorderColor),
-        (Changed<Interaction>, With<ButtonClaimOreRewards>),
+        (Entity, &Interaction, &mut BackgroundColor, &mut BorderColor),
+        (Changed<Interaction>, With<ButtonOpenAppBackup>),
     >,
-    mut event_writer: EventWriter<EventClaimOreRewards>,
+    mut event_writer: EventWriter<EventOpenAppBackup>,
 ) {
-    for (interaction, mut color, mut border_color) in &mut interaction_query {
+    for (_entity, interaction, mut color, mut border_color) in &mut interaction_query {
         match *interaction {
             Interaction::Pressed => {
                 *color = PRESSED_BUTTON.into();
-                // border_color.0 = Color::RED;
+                border_color.0 = Color::RED;
 
-                event_writer.send(EventClaimOreRewards);
+                event_writer.send(EventOpenAppBackup);
             }
             Interaction::Hovered => {
                 *color = HOVERED_BUTTON.into();
-                // border_color.0 = Color::WHITE;
+                border_color.0 = Color::WHITE;
             }
             Interaction::None => {
-                *color = Color::WHITE.into();
-                // *color = NORMAL_BUTTON.into();
-                // border_color.0 = Color::BLACK;
+                *color = NORMAL_BUTTON.into();
+                border_color.0 = Color::BLACK;
             }
         }
     }
 }
 
-pub fn button_stake_ore(
+pub fn button_restore_app_backup(
     mut interaction_query: Query<
-        (&Interaction, &mut BackgroundColor, &mut BorderColor),
-        (Changed<Interaction>, With<ButtonStakeOre>),
+        (&Interaction, &mut BackgroundColor, &mut BorderColor, &ButtonRestoreAppBackup),
+        Changed<Interaction>,
     >,
-    mut event_writer: EventWriter<EventStakeOre>,
+    mut event_writer: EventWriter<EventRestoreAppBackup>,
 ) {
-    for (interaction, mut color, mut border_color) in &mut interaction_query {
+    for (interaction, mut color, mut border_color, button) in &mut interaction_query {
         match *interaction {
             Interaction::Pressed => {
                 *color = PRESSED_BUTTON.into();
-                // border_color.0 = Color::RED;
+                border_color.0 = Color::RED;
 
-                event_writer.send(EventStakeOre);
+                event_writer.send(EventRestoreAppBackup(button.0));
             }
             Interaction::Hovered => {
                 *color = HOVERED_BUTTON.into();
-                // border_color.0 = Color::WHITE;
+                border_color.0 = Color::WHITE;
             }
             Interaction::None => {
-                *color = Color::WHITE.into();
-                // *color = NORMAL_BUTTON.into();
-                // border_color.0 = Color::BLACK;
+                *color = NORMAL_BUTTON.into();
+                border_color.0 = Color::BLACK;
             }
         }
     }
 }
 
-pub fn button_lock(
+pub fn button_create_support_bundle(
     mut interaction_query: Query<
         (Entity, &Interaction, &mut BackgroundColor, &mut BorderColor),
-        (Changed<Interaction>, With<ButtonLock>),
+        (Changed<Interaction>, With<ButtonCreateSupportBundle>),
     >,
-    mut event_writer: EventWriter<EventLock>,
+    mut event_writer: EventWriter<EventCreateSupportBundle>,
 ) {
     for (_entity, interaction, mut color, mut border_color) in &mut interaction_query {
         match *interaction {
@@ -221,7 +752,7 @@ pub fn button_lock(
                 *color = PRESSED_BUTTON.into();
                 border_color.0 = Color::RED;
 
-                event_writer.send(EventLock);
+                event_writer.send(EventCreateSupportBundle);
             }
             Interaction::Hovered => {
                 *color = HOVERED_BUTTON.into();
@@ -235,12 +766,39 @@ pub fn button_lock(
     }
 }
 
-pub fn button_unlock(
+pub fn button_crash_recovery(
+    mut interaction_query: Query<
+        (&Interaction, &mut BackgroundColor, &mut BorderColor, &ButtonCrashRecovery),
+        Changed<Interaction>,
+    >,
+    mut event_writer: EventWriter<EventCrashRecovery>,
+) {
+    for (interaction, mut color, mut border_color, button) in &mut interaction_query {
+        match *interaction {
+            Interaction::Pressed => {
+                *color = PRESSED_BUTTON.into();
+                border_color.0 = Color::RED;
+
+                event_writer.send(EventCrashRecovery { resume: button.0 });
+            }
+            Interaction::Hovered => {
+                *color = HOVERED_BUTTON.into();
+                border_color.0 = Color::WHITE;
+            }
+            Interaction::None => {
+                *color = NORMAL_BUTTON.into();
+                border_color.0 = Color::BLACK;
+            }
+        }
+    }
+}
+
+pub fn button_history_load_more(
     mut interaction_query: Query<
         (Entity, &Interaction, &mut BackgroundColor, &mut BorderColor),
-        (Changed<Interaction>, With<ButtonUnlock>),
+        (Changed<Interaction>, With<ButtonHistoryLoadMore>),
     >,
-    mut event_writer: EventWriter<EventUnlock>,
+    mut event_writer: EventWriter<EventFetchTxHistory>,
 ) {
     for (_entity, interaction, mut color, mut border_color) in &mut interaction_query {
         match *interaction {
@@ -248,7 +806,222 @@ pub fn button_unlock(
                 *color = PRESSED_BUTTON.into();
                 border_color.0 = Color::RED;
 
-                event_writer.send(EventUnlock);
+                event_writer.send(EventFetchTxHistory { reset: false });
+            }
+            Interaction::Hovered => {
+                *color = HOVERED_BUTTON.into();
+                border_color.0 = Color::WHITE;
+            }
+            Interaction::None => {
+                *color = NORMAL_BUTTON.into();
+                border_color.0 = Color::BLACK;
+            }
+        }
+    }
+}
+
+pub fn button_send_toggle_token(
+    mut interaction_query: Query<
+        (Entity, &Interaction, &mut BackgroundColor, &mut BorderColor),
+        (Changed<Interaction>, With<ButtonSendToggleToken>),
+    >,
+    mut send_res: ResMut<SendResource>,
+    mut text_query: Query<&mut Text, With<TextSendToken>>,
+) {
+    for (_entity, interaction, mut color, mut border_color) in &mut interaction_query {
+        match *interaction {
+            Interaction::Pressed => {
+                *color = PRESSED_BUTTON.into();
+                border_color.0 = Color::RED;
+
+                send_res.token = send_res.token.toggle();
+                if let Ok(mut text) = text_query.get_single_mut() {
+                    text.sections[0].value = send_res.token.to_string();
+                }
+            }
+            Interaction::Hovered => {
+                *color = HOVERED_BUTTON.into();
+                border_color.0 = Color::WHITE;
+            }
+            Interaction::None => {
+                *color = NORMAL_BUTTON.into();
+                border_color.0 = Color::BLACK;
+            }
+        }
+    }
+}
+
+pub fn button_send_review(
+    mut interaction_query: Query<
+        (Entity, &Interaction, &mut BackgroundColor, &mut BorderColor),
+        (Changed<Interaction>, With<ButtonSendReview>),
+    >,
+    recipient_query: Query<&TextInput, With<TextSendRecipient>>,
+    amount_query: Query<&TextInput, With<TextSendAmount>>,
+    mut event_writer: EventWriter<EventPrepareSend>,
+) {
+    for (_entity, interaction, mut color, mut border_color) in &mut interaction_query {
+        match *interaction {
+            Interaction::Pressed => {
+                *color = PRESSED_BUTTON.into();
+                border_color.0 = Color::RED;
+
+                let recipient = recipient_query
+                    .get_single()
+                    .map(|t| t.text.clone())
+                    .unwrap_or_default();
+                let amount = amount_query
+                    .get_single()
+                    .map(|t| t.text.clone())
+                    .unwrap_or_default();
+                event_writer.send(EventPrepareSend { recipient, amount });
+            }
+            Interaction::Hovered => {
+                *color = HOVERED_BUTTON.into();
+                border_color.0 = Color::WHITE;
+            }
+            Interaction::None => {
+                *color = NORMAL_BUTTON.into();
+                border_color.0 = Color::BLACK;
+            }
+        }
+    }
+}
+
+pub fn button_send_save_recipient(
+    mut interaction_query: Query<
+        (Entity, &Interaction, &mut BackgroundColor, &mut BorderColor),
+        (Changed<Interaction>, With<ButtonSendSaveRecipient>),
+    >,
+    recipient_query: Query<&TextInput, With<TextSendRecipient>>,
+    name_query: Query<&TextInput, With<TextSendRecipientName>>,
+    mut event_writer: EventWriter<EventSaveRecipient>,
+) {
+    for (_entity, interaction, mut color, mut border_color) in &mut interaction_query {
+        match *interaction {
+            Interaction::Pressed => {
+                *color = PRESSED_BUTTON.into();
+                border_color.0 = Color::RED;
+
+                let address = recipient_query
+                    .get_single()
+                    .map(|t| t.text.clone())
+                    .unwrap_or_default();
+                let name = name_query
+                    .get_single()
+                    .map(|t| t.text.clone())
+                    .unwrap_or_default();
+                event_writer.send(EventSaveRecipient { name, address });
+            }
+            Interaction::Hovered => {
+                *color = HOVERED_BUTTON.into();
+                border_color.0 = Color::WHITE;
+            }
+            Interaction::None => {
+                *color = NORMAL_BUTTON.into();
+                border_color.0 = Color::BLACK;
+            }
+        }
+    }
+}
+
+pub fn button_send_confirm(
+    mut interaction_query: Query<
+        (Entity, &Interaction, &mut BackgroundColor, &mut BorderColor),
+        (Changed<Interaction>, With<ButtonSendConfirm>),
+    >,
+    mut event_writer: EventWriter<EventConfirmSend>,
+) {
+    for (_entity, interaction, mut color, mut border_color) in &mut interaction_query {
+        match *interaction {
+            Interaction::Pressed => {
+                *color = PRESSED_BUTTON.into();
+                border_color.0 = Color::RED;
+
+                event_writer.send(EventConfirmSend);
+            }
+            Interaction::Hovered => {
+                *color = HOVERED_BUTTON.into();
+                border_color.0 = Color::WHITE;
+            }
+            Interaction::None => {
+                *color = NORMAL_BUTTON.into();
+                border_color.0 = Color::BLACK;
+            }
+        }
+    }
+}
+
+pub fn button_send_cancel(
+    mut interaction_query: Query<
+        (Entity, &Interaction, &mut BackgroundColor, &mut BorderColor),
+        (Changed<Interaction>, With<ButtonSendCancel>),
+    >,
+    mut event_writer: EventWriter<EventCancelSend>,
+) {
+    for (_entity, interaction, mut color, mut border_color) in &mut interaction_query {
+        match *interaction {
+            Interaction::Pressed => {
+                *color = PRESSED_BUTTON.into();
+                border_color.0 = Color::RED;
+
+                event_writer.send(EventCancelSend);
+            }
+            Interaction::Hovered => {
+                *color = HOVERED_BUTTON.into();
+                border_color.0 = Color::WHITE;
+            }
+            Interaction::None => {
+                *color = NORMAL_BUTTON.into();
+                border_color.0 = Color::BLACK;
+            }
+        }
+    }
+}
+
+pub fn button_address_book_entry(
+    mut interaction_query: Query<
+        (&Interaction, &mut BackgroundColor, &mut BorderColor, &ButtonAddressBookEntry),
+        Changed<Interaction>,
+    >,
+    mut recipient_query: Query<&mut TextInput, With<TextSendRecipient>>,
+) {
+    for (interaction, mut color, mut border_color, entry) in &mut interaction_query {
+        match *interaction {
+            Interaction::Pressed => {
+                *color = PRESSED_BUTTON.into();
+                border_color.0 = Color::RED;
+
+                if let Ok(mut text_input) = recipient_query.get_single_mut() {
+                    text_input.text = entry.0.clone();
+                }
+            }
+            Interaction::Hovered => {
+                *color = HOVERED_BUTTON.into();
+                border_color.0 = Color::WHITE;
+            }
+            Interaction::None => {
+                *color = NORMAL_BUTTON.into();
+                border_color.0 = Color::BLACK;
+            }
+        }
+    }
+}
+
+pub fn button_address_book_remove(
+    mut interaction_query: Query<
+        (&Interaction, &mut BackgroundColor, &mut BorderColor, &ButtonAddressBookRemove),
+        Changed<Interaction>,
+    >,
+    mut event_writer: EventWriter<EventRemoveRecipient>,
+) {
+    for (interaction, mut color, mut border_color, entry) in &mut interaction_query {
+        match *interaction {
+            Interaction::Pressed => {
+                *color = PRESSED_BUTTON.into();
+                border_color.0 = Color::RED;
+
+                event_writer.send(EventRemoveRecipient(entry.0.clone()));
             }
             Interaction::Hovered => {
                 *color = HOVERED_BUTTON.into();
@@ -289,14 +1062,9 @@ pub fn button_save_config(
         (Changed<Interaction>, With<ButtonSaveConfig>),
     >,
     mut event_writer: EventWriter<EventSaveConfig>,
-    mut set: ParamSet<(
-        Query<&TextInput, With<TextConfigInputRpcUrl>>,
-        Query<&TextInput, With<TextConfigInputThreads>>,
-        Query<&TextInput, With<TextConfigInputRpcFetchAccountsInterval>>,
-        Query<&TextInput, With<TextConfigInputRpcSendTxInterval>>,
-    )>,
+    query_inputs: Query<(&TextInput, &TextConfigInput)>,
 ) {
-    for (_entity, interaction, mut ui_image, mut color) in &mut interaction_query {
+    for (_entity, interaction, _ui_image, mut color) in &mut interaction_query {
         match *interaction {
             Interaction::Pressed => {
                 *color = PRESSED_BUTTON.into();
@@ -304,24 +1072,11 @@ pub fn button_save_config(
                 //     ui_image.flip_y = true;
                 // }
 
-                // let text_rpc_fetch_interval = if let Ok(single) = set.p2().get_single() {
-                //     let parsed = single.text.clone().parse::<u64>();
-                //     if let Ok(parsed) = parsed {
-                //         parsed
-                //     } else {
-                //         error!("Failed to parse text_rpc_fetch_interval.");
-                //         break;
-                //     }
-                // } else {
-                //     error!("Failed to get text_rpc_fetch_interval.");
-                //     break;
-                // };
-
-                event_writer.send(EventSaveConfig(AppConfig {
-                    server_url: "ec1ipse.me".to_string(),
-                    ui_fetch_interval: 1000,
-                    ..Default::default()
-                }));
+                let inputs = query_inputs
+                    .iter()
+                    .map(|(text_input, field)| (field.0, text_input.value().to_string()))
+                    .collect();
+                event_writer.send(EventSaveConfig(inputs));
             }
             Interaction::Hovered => {
                 *color = HOVERED_BUTTON.into();
@@ -344,9 +1099,15 @@ pub fn button_save_wallet(
         (Entity, &Interaction, &mut UiImage, &mut BackgroundColor),
         (Changed<Interaction>, With<ButtonSaveGeneratedWallet>),
     >,
+    backup: Res<BackupVerificationResource>,
     mut event_writer: EventWriter<EventSaveWallet>,
 ) {
-    for (_entity, interaction, mut ui_image, mut color) in &mut interaction_query {
+    for (_entity, interaction, _ui_image, mut color) in &mut interaction_query {
+        // stays dimmed until the generated seed phrase backup is verified
+        if !backup.can_save() {
+            *color = Color::DARK_GRAY.into();
+            continue;
+        }
         match *interaction {
             Interaction::Pressed => {
                 *color = PRESSED_BUTTON.into();
@@ -380,7 +1141,7 @@ pub fn button_auto_scroll(
     mut query_check_icon: Query<&mut BackgroundColor, (With<AutoScrollCheckIcon>, Without<ButtonAutoScroll>)>,
     // mut event_writer: EventWriter<EventToggleAutoScroll>,
 ) {
-    for (_entity, interaction, mut ui_image, mut color, mut button_auto_scroll) in &mut interaction_query {
+    for (_entity, interaction, _ui_image, mut color, mut button_auto_scroll) in &mut interaction_query {
         match *interaction {
             Interaction::Pressed => {
                 *color = PRESSED_BUTTON.into();
@@ -419,20 +1180,6 @@ pub fn button_auto_scroll(
     }
 }
 
-pub struct ButtonCooldown {
-    clicked: bool,
-    timer: Timer
-}
-
-impl Default for ButtonCooldown {
-    fn default() -> Self {
-        Self { 
-            clicked: false,
-            timer: Timer::new(Duration::from_secs(5), TimerMode::Once)
-        }
-    }
-}
-
 pub fn tick_button_cooldowns(
     mut query: Query<(&mut ButtonRequestAirdrop, &mut BackgroundColor, &Children)>,
     mut query_spinner: Query<&mut Visibility, With<ButtonCooldownSpinner>>,
@@ -464,7 +1211,7 @@ pub fn button_request_airdrop(
     >,
     mut query_spinner: Query<&mut Visibility, With<ButtonCooldownSpinner>>
 ) {
-    for (_entity, interaction, mut color, mut border_color, mut button_cooldown, children) in &mut interaction_query {
+    for (_entity, interaction, mut color, _border_color, mut button_cooldown, children) in &mut interaction_query {
         match *interaction {
             Interaction::Pressed => {
                 *color = PRESSED_BUTTON.into();
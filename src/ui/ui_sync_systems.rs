@@ -2,28 +2,60 @@ use bevy::diagnostic::DiagnosticsStore;
 use bevy::diagnostic::FrameTimeDiagnosticsPlugin;
 use bevy::input::mouse::MouseScrollUnit;
 use bevy::input::mouse::MouseWheel;
+use bevy::log::Level;
 use bevy::prelude::*;
 use chrono::DateTime;
-use solana_sdk::signer::Signer;
+use std::time::{Duration, Instant};
 
-use crate::ore_utils::get_ore_decimals;
-use crate::ore_utils::ORE_TOKEN_DECIMALS;
+use crate::amount::OreAmount;
 use crate::utils::{get_unix_timestamp, human_bytes, shorten_string};
 use crate::AppWallet;
+use crate::BackupVerificationResource;
 use crate::BussesResource;
 use crate::HashrateResource;
 use crate::MinerStatusResource;
 use crate::MiningProofsResource;
 use crate::OreAppState;
+use crate::ProfilesResource;
+use crate::password_strength::{password_strength, strength_label};
 use crate::ProofAccountResource;
 use crate::TreasuryAccountResource;
+use crate::TxProcessor;
+use crate::UnlockThrottleResource;
+use crate::ConfigWatchResource;
+use crate::LogViewerResource;
+use crate::CrashRecoveryResource;
+use crate::file_log::{log_file_path, records_logged, recent_records};
 
 use super::components::ButtonCaptureTextInput;
+use super::components::ButtonSelectProfile;
+use super::components::TextUnlockStatus;
+use super::components::ConfigBanner;
+use super::components::TextConfigBanner;
+use super::components::CrashRecoveryBanner;
+use super::components::TextCrashRecovery;
+use super::components::ButtonCrashRecovery;
+use super::components::LogViewerList;
+use super::components::TextLogSearchInput;
+use super::components::TextLogViewerStatus;
+use super::components::PasswordStrengthBar;
+use super::components::BackupVerificationNode;
+use super::components::ButtonSaveGeneratedWallet;
+use super::components::TextAcceptBackupRisk;
+use super::components::TextBackupPrompt;
+use super::components::SigningAction;
+use super::components::TextChangePasswordNew;
+use super::components::TextPasswordStrength;
 use super::components::FpsRoot;
 use super::components::FpsText;
 use super::components::ScrollingList;
 use super::components::TextActiveMinersLastEpoch;
 use super::components::TextActiveMinersThisEpoch;
+use super::components::TextActiveProfile;
+use super::components::TextCrownStakeAmount;
+use super::components::TextCrownStakeBy;
+use super::components::TextHighestDifficultySeen;
+use super::components::TextStakeDistribution;
 use super::components::TextBurnAmount;
 use super::components::TextBus1;
 use super::components::TextBus2;
@@ -42,18 +74,19 @@ use super::components::TextLastClaimAt;
 use super::components::TextLastHashAt;
 use super::components::TextMinerStatusCpuUsage;
 use super::components::TextMinerStatusRamUsage;
-use super::components::TextMinerStatusStatus;
 use super::components::TextMinerStatusThreads;
 use super::components::TextMinerStatusTime;
 use super::components::TextTotalHashes;
-use super::components::TextTreasuryAdmin;
 use super::components::TextTreasuryBalance;
 use super::components::TextTreasuryLastResetAt;
 use super::components::TextTreasuryRewardRate;
+use super::components::TextTxProcessorStatus;
 use super::components::TextWalletOreBalance;
 use super::components::TextWalletPubkey;
 use super::components::TextWalletSolBalance;
 use super::styles::hex_dark_mode_text_gray;
+use super::styles::NORMAL_BUTTON;
+use super::styles::{FONT_REGULAR, FONT_SIZE_X_SMALL};
 
 pub fn mouse_scroll(
     mut mouse_wheel_events: EventReader<MouseWheel>,
@@ -90,19 +123,19 @@ pub fn update_app_wallet_ui(
         Query<&mut Text, With<TextWalletPubkey>>,
     )>,
 ) {
-    if let Some(wallet) = &app_wallet.wallet {
+    if let Some(pubkey) = app_wallet.pubkey() {
         let mut text_sol_balance_query = set.p0();
         let mut text_sol_balance = text_sol_balance_query.single_mut();
-        text_sol_balance.sections[0].value = app_wallet.sol_balance.to_string() + " SOL";
+        text_sol_balance.sections[0].value = app_wallet.sol_balance.to_locale_string(None) + " SOL";
 
         let mut text_ore_balance_query = set.p1();
         let mut text_ore_balance = text_ore_balance_query.single_mut();
-        text_ore_balance.sections[0].value = app_wallet.ore_balance.to_string() + " ORE";
+        text_ore_balance.sections[0].value = app_wallet.ore_balance.to_locale_string(None) + " ORE";
 
         let mut text_wallet_pubkey_query = set.p2();
         let mut text_wallet_pubkey = text_wallet_pubkey_query.single_mut();
 
-        let pubkey = shorten_string(wallet.pubkey().to_string(), 10);
+        let pubkey = shorten_string(pubkey.to_string(), 10);
         text_wallet_pubkey.sections[0].value = pubkey;
     } else {
         let mut text_sol_balance_query = set.p0();
@@ -120,6 +153,173 @@ pub fn update_app_wallet_ui(
     }
 }
 
+pub fn update_active_profile_ui(
+    profiles_res: Res<ProfilesResource>,
+    mut query: Query<&mut Text, With<TextActiveProfile>>,
+) {
+    if !profiles_res.is_changed() {
+        return;
+    }
+    let name = if let Some(profile) = profiles_res.profiles.active_profile() {
+        profile.name.clone()
+    } else {
+        "Wallet".to_string()
+    };
+    for mut text in query.iter_mut() {
+        text.sections[0].value = name.clone();
+    }
+}
+
+pub fn update_backup_verification_ui(
+    backup: Res<BackupVerificationResource>,
+    mut query_node: Query<&mut Style, With<BackupVerificationNode>>,
+    mut query_save: Query<&mut BackgroundColor, With<ButtonSaveGeneratedWallet>>,
+    mut set: ParamSet<(
+        Query<&mut Text, With<TextBackupPrompt>>,
+        Query<&mut Text, With<TextAcceptBackupRisk>>,
+    )>,
+) {
+    if !backup.is_changed() {
+        return;
+    }
+
+    let display = if backup.challenge.is_some() {
+        Display::Flex
+    } else {
+        Display::None
+    };
+    for mut style in query_node.iter_mut() {
+        style.display = display;
+    }
+
+    let prompt = backup
+        .challenge
+        .as_ref()
+        .map(|challenge| challenge.prompt())
+        .unwrap_or_default();
+    for mut text in set.p0().iter_mut() {
+        text.sections[0].value = prompt.clone();
+    }
+    let check = if backup.accept_risk { "[x]" } else { "[ ]" };
+    for mut text in set.p1().iter_mut() {
+        text.sections[0].value = format!("{} Skip verification, I accept the risk", check);
+    }
+
+    let save_color = if backup.can_save() {
+        Color::WHITE
+    } else {
+        Color::DARK_GRAY
+    };
+    for mut color in query_save.iter_mut() {
+        *color = save_color.into();
+    }
+}
+
+pub fn update_watch_only_ui(
+    app_wallet: Res<AppWallet>,
+    mut query: Query<&mut Style, With<SigningAction>>,
+) {
+    let display = if app_wallet.is_watch_only() {
+        Display::None
+    } else {
+        Display::Flex
+    };
+    for mut style in query.iter_mut() {
+        if style.display != display {
+            style.display = display;
+        }
+    }
+}
+
+pub fn update_profile_picker_ui(
+    profiles_res: Res<ProfilesResource>,
+    query_buttons: Query<(&ButtonSelectProfile, &Children)>,
+    mut query_text: Query<&mut Text>,
+) {
+    let active = profiles_res
+        .profiles
+        .active_profile()
+        .map(|p| p.name.clone())
+        .unwrap_or_default();
+    for (profile, children) in query_buttons.iter() {
+        for child in children.iter() {
+            if let Ok(mut text) = query_text.get_mut(*child) {
+                text.sections[0].style.color = if profile.0 == active {
+                    Color::WHITE
+                } else {
+                    hex_dark_mode_text_gray()
+                };
+            }
+        }
+    }
+}
+
+pub fn update_unlock_status_ui(
+    unlock_throttle: Res<UnlockThrottleResource>,
+    profiles_res: Res<ProfilesResource>,
+    mut query: Query<&mut Text, With<TextUnlockStatus>>,
+) {
+    let mut text = if let Ok(text) = query.get_single_mut() {
+        text
+    } else {
+        return;
+    };
+
+    let profile = profiles_res
+        .profiles
+        .active_profile()
+        .map(|p| p.name.clone())
+        .unwrap_or_default();
+    let failures = unlock_throttle.attempts.failures(&profile);
+    let remaining = unlock_throttle.attempts.remaining_delay(&profile, get_unix_timestamp());
+
+    let mut status = match &unlock_throttle.error {
+        Some(error) => error.clone(),
+        None if failures > 0 => format!("{} failed attempts.", failures),
+        None => String::new(),
+    };
+    if remaining > 0 {
+        status = format!("{} Try again in {}s.", status, remaining);
+    }
+    if text.sections[0].value != status {
+        text.sections[0].value = status;
+    }
+}
+
+pub fn update_password_strength_ui(
+    query_new_password: Query<&TextInput, (With<TextChangePasswordNew>, Changed<TextInput>)>,
+    mut query_bars: Query<(&mut BackgroundColor, &PasswordStrengthBar)>,
+    mut query_label: Query<&mut Text, With<TextPasswordStrength>>,
+) {
+    let text_input = if let Ok(text_input) = query_new_password.get_single() {
+        text_input
+    } else {
+        return;
+    };
+
+    let score = password_strength(text_input.value());
+    let color = match score {
+        0 | 1 => Color::RED,
+        2 => Color::ORANGE,
+        3 => Color::YELLOW,
+        _ => Color::GREEN,
+    };
+    for (mut background_color, bar) in query_bars.iter_mut() {
+        *background_color = if bar.0 <= score {
+            color.into()
+        } else {
+            NORMAL_BUTTON.into()
+        };
+    }
+    if let Ok(mut text) = query_label.get_single_mut() {
+        text.sections[0].value = if text_input.text.is_empty() {
+            String::new()
+        } else {
+            strength_label(score).to_string()
+        };
+    }
+}
+
 pub fn update_busses_ui(
     busses_res: Res<BussesResource>,
     miner_status: Res<MinerStatusResource>,
@@ -137,8 +337,8 @@ pub fn update_busses_ui(
     if busses_res.busses.len() > 7 {
         let mut text_bus_query = set.p0();
         for mut text_component in text_bus_query.iter_mut() {
-            let rewards = (busses_res.busses[0].rewards as f64) / 10f64.powf(ORE_TOKEN_DECIMALS as f64);
-            text_component.sections[0].value = format!("{}", rewards);
+            let rewards = OreAmount(busses_res.busses[0].rewards);
+            text_component.sections[0].value = rewards.to_locale_string(None);
             let selected_color = if miner_status.miner_status.as_str() == "PROCESSING" {
                 Color::GREEN
             } else {
@@ -146,7 +346,7 @@ pub fn update_busses_ui(
             };
             
             if busses_res.current_bus_id == 0 {
-                text_component.sections[0].style.color = selected_color.into();
+                text_component.sections[0].style.color = selected_color;
             } else {
                 text_component.sections[0].style.color = hex_dark_mode_text_gray();
             }
@@ -154,8 +354,8 @@ pub fn update_busses_ui(
 
         let mut text_bus_query = set.p1();
         for mut text_component in text_bus_query.iter_mut() {
-            let rewards = (busses_res.busses[1].rewards as f64) / 10f64.powf(ORE_TOKEN_DECIMALS as f64);
-            text_component.sections[0].value = format!("{}", rewards);
+            let rewards = OreAmount(busses_res.busses[1].rewards);
+            text_component.sections[0].value = rewards.to_locale_string(None);
             let selected_color = if miner_status.miner_status.as_str() == "PROCESSING" {
                 Color::GREEN
             } else {
@@ -163,7 +363,7 @@ pub fn update_busses_ui(
             };
             
             if busses_res.current_bus_id == 1 {
-                text_component.sections[0].style.color = selected_color.into();
+                text_component.sections[0].style.color = selected_color;
             } else {
                 text_component.sections[0].style.color = hex_dark_mode_text_gray();
             }
@@ -172,8 +372,8 @@ pub fn update_busses_ui(
 
         let mut text_bus_query = set.p2();
         for mut text_component in text_bus_query.iter_mut() {
-            let rewards = (busses_res.busses[2].rewards as f64) / 10f64.powf(ORE_TOKEN_DECIMALS as f64);
-            text_component.sections[0].value = format!("{}", rewards);
+            let rewards = OreAmount(busses_res.busses[2].rewards);
+            text_component.sections[0].value = rewards.to_locale_string(None);
             let selected_color = if miner_status.miner_status.as_str() == "PROCESSING" {
                 Color::GREEN
             } else {
@@ -181,7 +381,7 @@ pub fn update_busses_ui(
             };
             
             if busses_res.current_bus_id == 2 {
-                text_component.sections[0].style.color = selected_color.into();
+                text_component.sections[0].style.color = selected_color;
             } else {
                 text_component.sections[0].style.color = hex_dark_mode_text_gray();
             }
@@ -189,8 +389,8 @@ pub fn update_busses_ui(
 
         let mut text_bus_query = set.p3();
         for mut text_component in text_bus_query.iter_mut() {
-            let rewards = (busses_res.busses[3].rewards as f64) / 10f64.powf(ORE_TOKEN_DECIMALS as f64);
-            text_component.sections[0].value = format!("{}", rewards);
+            let rewards = OreAmount(busses_res.busses[3].rewards);
+            text_component.sections[0].value = rewards.to_locale_string(None);
             let selected_color = if miner_status.miner_status.as_str() == "PROCESSING" {
                 Color::GREEN
             } else {
@@ -198,7 +398,7 @@ pub fn update_busses_ui(
             };
             
             if busses_res.current_bus_id == 3 {
-                text_component.sections[0].style.color = selected_color.into();
+                text_component.sections[0].style.color = selected_color;
             } else {
                 text_component.sections[0].style.color = hex_dark_mode_text_gray();
             }
@@ -206,8 +406,8 @@ pub fn update_busses_ui(
 
         let mut text_bus_query = set.p4();
         for mut text_component in text_bus_query.iter_mut() {
-            let rewards = (busses_res.busses[4].rewards as f64) / 10f64.powf(ORE_TOKEN_DECIMALS as f64);
-            text_component.sections[0].value = format!("{}", rewards);
+            let rewards = OreAmount(busses_res.busses[4].rewards);
+            text_component.sections[0].value = rewards.to_locale_string(None);
             let selected_color = if miner_status.miner_status.as_str() == "PROCESSING" {
                 Color::GREEN
             } else {
@@ -215,7 +415,7 @@ pub fn update_busses_ui(
             };
             
             if busses_res.current_bus_id == 4 {
-                text_component.sections[0].style.color = selected_color.into();
+                text_component.sections[0].style.color = selected_color;
             } else {
                 text_component.sections[0].style.color = hex_dark_mode_text_gray();
             }
@@ -223,8 +423,8 @@ pub fn update_busses_ui(
 
         let mut text_bus_query = set.p5();
         for mut text_component in text_bus_query.iter_mut() {
-            let rewards = (busses_res.busses[5].rewards as f64) / 10f64.powf(ORE_TOKEN_DECIMALS as f64);
-            text_component.sections[0].value = format!("{}", rewards);
+            let rewards = OreAmount(busses_res.busses[5].rewards);
+            text_component.sections[0].value = rewards.to_locale_string(None);
             let selected_color = if miner_status.miner_status.as_str() == "PROCESSING" {
                 Color::GREEN
             } else {
@@ -232,7 +432,7 @@ pub fn update_busses_ui(
             };
             
             if busses_res.current_bus_id == 5 {
-                text_component.sections[0].style.color = selected_color.into();
+                text_component.sections[0].style.color = selected_color;
             } else {
                 text_component.sections[0].style.color = hex_dark_mode_text_gray();
             }
@@ -240,8 +440,8 @@ pub fn update_busses_ui(
 
         let mut text_bus_query = set.p6();
         for mut text_component in text_bus_query.iter_mut() {
-            let rewards = (busses_res.busses[6].rewards as f64) / 10f64.powf(ORE_TOKEN_DECIMALS as f64);
-            text_component.sections[0].value = format!("{}", rewards);
+            let rewards = OreAmount(busses_res.busses[6].rewards);
+            text_component.sections[0].value = rewards.to_locale_string(None);
             let selected_color = if miner_status.miner_status.as_str() == "PROCESSING" {
                 Color::GREEN
             } else {
@@ -249,7 +449,7 @@ pub fn update_busses_ui(
             };
             
             if busses_res.current_bus_id == 6 {
-                text_component.sections[0].style.color = selected_color.into();
+                text_component.sections[0].style.color = selected_color;
             } else {
                 text_component.sections[0].style.color = hex_dark_mode_text_gray();
             }
@@ -257,8 +457,8 @@ pub fn update_busses_ui(
 
         let mut text_bus_query = set.p7();
         for mut text_component in text_bus_query.iter_mut() {
-            let rewards = (busses_res.busses[7].rewards as f64) / 10f64.powf(ORE_TOKEN_DECIMALS as f64);
-            text_component.sections[0].value = format!("{}", rewards);
+            let rewards = OreAmount(busses_res.busses[7].rewards);
+            text_component.sections[0].value = rewards.to_locale_string(None);
             let selected_color = if miner_status.miner_status.as_str() == "PROCESSING" {
                 Color::GREEN
             } else {
@@ -266,7 +466,7 @@ pub fn update_busses_ui(
             };
             
             if busses_res.current_bus_id == 7 {
-                text_component.sections[0].style.color = selected_color.into();
+                text_component.sections[0].style.color = selected_color;
             } else {
                 text_component.sections[0].style.color = hex_dark_mode_text_gray();
             }
@@ -279,8 +479,13 @@ pub fn update_active_miners_ui(
     mut set: ParamSet<(
         Query<&mut Text, With<TextActiveMinersThisEpoch>>,
         Query<&mut Text, With<TextActiveMinersLastEpoch>>,
+        Query<&mut Text, With<TextHighestDifficultySeen>>,
+        Query<&mut Text, With<TextCrownStakeAmount>>,
+        Query<&mut Text, With<TextCrownStakeBy>>,
+        Query<&mut Text, With<TextStakeDistribution>>,
     )>,
 ) {
+
     let mut text_active_miners_this_epoch = set.p0();
     if let Ok(mut text_component) = text_active_miners_this_epoch.get_single_mut() {
         text_component.sections[0].value =
@@ -292,6 +497,34 @@ pub fn update_active_miners_ui(
         text_component.sections[0].value =
             mining_proofs_res.miners_last_epoch.to_string();
     }
+
+    let mut text_highest_difficulty = set.p2();
+    if let Ok(mut text_component) = text_highest_difficulty.get_single_mut() {
+        text_component.sections[0].value =
+            mining_proofs_res.largest_difficulty_seen.to_string();
+    }
+
+    let (crown_amount, crown_by) = if let Some((authority, stake)) = mining_proofs_res.top_staker {
+        (format!("{} ORE", stake.to_locale_string(None)), shorten_string(authority.to_string(), 10))
+    } else {
+        ("-".to_string(), "-".to_string())
+    };
+
+    let mut text_crown_stake_amount = set.p3();
+    if let Ok(mut text_component) = text_crown_stake_amount.get_single_mut() {
+        text_component.sections[0].value = crown_amount;
+    }
+
+    let mut text_crown_stake_by = set.p4();
+    if let Ok(mut text_component) = text_crown_stake_by.get_single_mut() {
+        text_component.sections[0].value = crown_by;
+    }
+
+    let mut text_stake_distribution = set.p5();
+    if let Ok(mut text_component) = text_stake_distribution.get_single_mut() {
+        text_component.sections[0].value =
+            mining_proofs_res.stake_distribution.to_display_string();
+    }
 }
 
 pub fn update_hash_rate_ui(
@@ -333,9 +566,7 @@ pub fn update_proof_account_ui(
 
     let mut text_claimable_rewards_query = set.p3();
     if let Ok(mut text_component) = text_claimable_rewards_query.get_single_mut() {
-        let amount =
-            (proof_account_res.stake as f64) / 10f64.powf(get_ore_decimals() as f64);
-        text_component.sections[0].value = format!("{}", amount);
+        text_component.sections[0].value = proof_account_res.stake.to_locale_string(None);
     }
 
     let mut text_query_3 = set.p4();
@@ -347,7 +578,7 @@ pub fn update_proof_account_ui(
                 "Err".to_string()
             };
 
-        text_component.sections[0].value = format!("{}", date_time);
+        text_component.sections[0].value = date_time.to_string();
     }
 }
 
@@ -361,7 +592,9 @@ pub fn update_treasury_account_ui(
 ) {
     let mut text_query_0 = set.p0();
     for mut text_0 in text_query_0.iter_mut() {
-        text_0.sections[0].value = treasury_account_res.balance.clone();
+        text_0.sections[0].value = treasury_account_res
+            .balance
+            .map_or("loading...".to_string(), |balance| balance.to_locale_string(None));
     }
 
     if treasury_account_res.last_reset_at != 0 {
@@ -373,7 +606,7 @@ pub fn update_treasury_account_ui(
                 } else {
                     "Err".to_string()
                 };
-            text_3.sections[0].value = format!("{}", date_time);
+            text_3.sections[0].value = date_time.to_string();
         }
     }
 
@@ -404,7 +637,7 @@ pub fn update_miner_status_ui(
     let mut text_query_1 = set.p0();
     if let Ok(mut text_1) = text_query_1.get_single_mut() {
         let mut cpu_usage = 0.0;
-        for (_index, cpu) in res.sys_info.cpus().iter().enumerate() {
+        for cpu in res.sys_info.cpus().iter() {
             cpu_usage += cpu.cpu_usage();
         }
 
@@ -439,7 +672,7 @@ pub fn update_miner_status_ui(
             "Err".to_string()
         };
 
-        text_3.sections[0].value = format!("{}", date_time);
+        text_3.sections[0].value = date_time.to_string();
     }
 
 
@@ -450,17 +683,33 @@ pub fn update_miner_status_ui(
 
 }
 
+pub fn update_tx_processor_status_ui(
+    query_tx_processors: Query<(&TxProcessor, &Children), Changed<TxProcessor>>,
+    mut query_status_text: Query<&mut Text, With<TextTxProcessorStatus>>,
+) {
+    for (tx_processor, children) in query_tx_processors.iter() {
+        for child in children {
+            if let Ok(mut text) = query_status_text.get_mut(*child) {
+                let status = tx_processor.status.to_string();
+                if text.sections[0].value != status {
+                    text.sections[0].value = status;
+                }
+            }
+        }
+    }
+}
+
 pub fn update_text_input_ui(mut active_text_query: Query<(&mut Text, &TextInput)>) {
     for (mut active_text_text, text_input) in active_text_query.iter_mut() {
         if text_input.hidden {
-            let text_len = text_input.text.len();
+            let text_len = text_input.value().chars().count();
             let mut displayed_text = String::with_capacity(text_len);
             for _ in 0..text_len {
                 displayed_text.push('*');
             }
             active_text_text.sections[0].value = displayed_text;
         } else {
-            active_text_text.sections[0].value = text_input.text.clone();
+            active_text_text.sections[0].value = text_input.value().to_string();
         }
     }
 }
@@ -545,3 +794,165 @@ pub fn fps_counter_showhide(
         };
     }
 }
+
+pub fn update_config_banner_ui(
+    config_watch: Res<ConfigWatchResource>,
+    mut query_banner: Query<&mut Visibility, With<ConfigBanner>>,
+    mut query_text: Query<&mut Text, With<TextConfigBanner>>,
+) {
+    let message = if let Some(error) = &config_watch.reload_error {
+        Some(error.clone())
+    } else if !config_watch.restart_required.is_empty() {
+        Some(format!(
+            "config.toml changed {}, restart the app to apply it.",
+            config_watch.restart_required.join(", "),
+        ))
+    } else {
+        None
+    };
+
+    // the base screen is respawned on unlock, so check every frame but only
+    // touch the UI when it's out of date
+    if let Ok(mut visibility) = query_banner.get_single_mut() {
+        let wanted = if message.is_some() {
+            Visibility::Visible
+        } else {
+            Visibility::Hidden
+        };
+        if *visibility != wanted {
+            *visibility = wanted;
+        }
+    }
+    if let Ok(mut text) = query_text.get_single_mut() {
+        let message = message.unwrap_or_default();
+        if text.sections[0].value != message {
+            text.sections[0].value = message;
+        }
+    }
+}
+
+pub fn update_crash_recovery_ui(
+    crash_recovery: Res<CrashRecoveryResource>,
+    mut query_banner: Query<&mut Visibility, With<CrashRecoveryBanner>>,
+    mut query_buttons: Query<(&mut Visibility, &ButtonCrashRecovery), Without<CrashRecoveryBanner>>,
+    mut query_text: Query<&mut Text, With<TextCrashRecovery>>,
+) {
+    let pending = crash_recovery.pending.as_ref();
+
+    // respawned with the base screen, same as the config banner
+    if let Ok(mut visibility) = query_banner.get_single_mut() {
+        let wanted = if pending.is_some() {
+            Visibility::Visible
+        } else {
+            Visibility::Hidden
+        };
+        if *visibility != wanted {
+            *visibility = wanted;
+        }
+    }
+    for (mut visibility, button) in query_buttons.iter_mut() {
+        // resume only makes sense if it was mining
+        let wanted = if button.0 && !pending.is_some_and(|p| p.mining) {
+            Visibility::Hidden
+        } else {
+            Visibility::Inherited
+        };
+        if *visibility != wanted {
+            *visibility = wanted;
+        }
+    }
+    if let Ok(mut text) = query_text.get_single_mut() {
+        let message = pending
+            .map(|p| format!("The app crashed at {}. Report saved to {}", p.time, p.report.display()))
+            .unwrap_or_default();
+        if text.sections[0].value != message {
+            text.sections[0].value = message;
+        }
+    }
+}
+
+/// Newest records drawn in the log viewer.
+const LOG_VIEWER_MAX_LINES: usize = 300;
+
+pub fn update_log_viewer_ui(
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+    mut log_viewer: ResMut<LogViewerResource>,
+    mut last_drawn_at: Local<Option<Instant>>,
+    query_search: Query<&TextInput, With<TextLogSearchInput>>,
+    query_list: Query<Entity, With<LogViewerList>>,
+    mut query_status: Query<&mut Text, With<TextLogViewerStatus>>,
+) {
+    let search = query_search
+        .get_single()
+        .map(|input| input.text.to_lowercase())
+        .unwrap_or_default();
+    let logged = records_logged();
+    let level = log_viewer.level;
+
+    let filter_changed = match &log_viewer.drawn {
+        Some((_, drawn_level, drawn_search)) => *drawn_level != level || *drawn_search != search,
+        None => true,
+    };
+    let new_records = log_viewer.drawn.as_ref().is_none_or(|(drawn, _, _)| *drawn != logged);
+    // busy logs would otherwise redraw every frame
+    let redraw_due = last_drawn_at.is_none_or(|at| at.elapsed() >= Duration::from_secs(1));
+    if !(filter_changed || new_records && redraw_due) {
+        return;
+    }
+    let list_entity = if let Ok(entity) = query_list.get_single() {
+        entity
+    } else {
+        return;
+    };
+    log_viewer.drawn = Some((logged, level, search.clone()));
+    *last_drawn_at = Some(Instant::now());
+
+    let (records, total) = recent_records();
+    let matching: Vec<_> = records
+        .iter()
+        .rev()
+        .filter(|record| level.is_none_or(|level| record.level() <= level))
+        .filter(|record| {
+            search.is_empty()
+                || record.message.to_lowercase().contains(&search)
+                || record.target.to_lowercase().contains(&search)
+        })
+        .take(LOG_VIEWER_MAX_LINES)
+        .collect();
+
+    if let Ok(mut text) = query_status.get_single_mut() {
+        let file = log_file_path()
+            .map(|path| path.display().to_string())
+            .unwrap_or_else(|| "console only".to_string());
+        text.sections[0].value = format!("{} of {} records, {}", matching.len(), total, file);
+    }
+
+    commands.entity(list_entity).despawn_descendants();
+    commands.entity(list_entity).with_children(|parent| {
+        for record in matching {
+            let color = match record.level() {
+                Level::ERROR => Color::rgb(0.9, 0.3, 0.3),
+                Level::WARN => Color::rgb(0.9, 0.7, 0.3),
+                _ => Color::rgb(0.9, 0.9, 0.9),
+            };
+            // the date is the same for nearly every line, keep the time
+            let time = record.timestamp.get(11..23).unwrap_or(&record.timestamp);
+            let mut line = format!("{} {:<5} {}: {}", time, record.level, record.target, record.message);
+            for (key, value) in &record.fields {
+                line.push_str(&format!(" {}={}", key, value));
+            }
+            parent.spawn((
+                TextBundle::from_section(
+                    line,
+                    TextStyle {
+                        font: asset_server.load(FONT_REGULAR),
+                        font_size: FONT_SIZE_X_SMALL,
+                        color,
+                    },
+                ),
+                Label,
+            ));
+        }
+    });
+}
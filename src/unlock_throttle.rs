@@ -0,0 +1,126 @@
+use std::{collections::BTreeMap, fs};
+
+use serde::{Deserialize, Serialize};
+
+use crate::{app_dirs::data_path, wallet_file::write_file_atomic};
+
+pub const UNLOCK_ATTEMPTS_PATH: &str = "unlock_attempts.toml";
+
+/// Failed attempts allowed before the back-off starts.
+const FREE_ATTEMPTS: u32 = 3;
+const BASE_DELAY_SECONDS: u64 = 2;
+const MAX_DELAY_SECONDS: u64 = 15 * 60;
+
+#[derive(Serialize, Deserialize, Clone, Debug, Default)]
+pub struct FailedUnlocks {
+    pub count: u32,
+    /// Unix timestamp of the last failure.
+    pub last_failure: u64,
+}
+
+/// Failed unlock attempts per profile, kept on disk so restarting the app
+/// doesn't reset the back-off.
+#[derive(Serialize, Deserialize, Clone, Debug, Default)]
+pub struct UnlockAttempts {
+    #[serde(default)]
+    pub profiles: BTreeMap<String, FailedUnlocks>,
+}
+
+impl UnlockAttempts {
+    /// A missing or unreadable file is treated as no failures.
+    pub fn load() -> Self {
+        let path = data_path(UNLOCK_ATTEMPTS_PATH);
+        if !path.exists() {
+            return Self::default();
+        }
+
+        fs::read_to_string(path)
+            .ok()
+            .and_then(|s| toml::from_str(&s).ok())
+            .unwrap_or_default()
+    }
+
+    pub fn save(&self) -> Result<(), String> {
+        let toml_string = toml::to_string(self).map_err(|e| e.to_string())?;
+        write_file_atomic(&data_path(UNLOCK_ATTEMPTS_PATH), toml_string.as_bytes())
+    }
+
+    pub fn failures(&self, profile: &str) -> u32 {
+        self.profiles.get(profile).map_or(0, |f| f.count)
+    }
+
+    /// Seconds left before the profile may be tried again.
+    pub fn remaining_delay(&self, profile: &str, now: u64) -> u64 {
+        let failed = if let Some(failed) = self.profiles.get(profile) {
+            failed
+        } else {
+            return 0;
+        };
+        let allowed_at = failed.last_failure + backoff_seconds(failed.count);
+        allowed_at.saturating_sub(now)
+    }
+
+    /// Returns the new failure count.
+    pub fn record_failure(&mut self, profile: &str, now: u64) -> u32 {
+        let failed = self.profiles.entry(profile.to_string()).or_default();
+        failed.count += 1;
+        failed.last_failure = now;
+        failed.count
+    }
+
+    pub fn record_success(&mut self, profile: &str) {
+        self.profiles.remove(profile);
+    }
+}
+
+/// Delay after `failures` failed attempts, doubling from `BASE_DELAY_SECONDS`
+/// once the free attempts are used up.
+pub fn backoff_seconds(failures: u32) -> u64 {
+    if failures < FREE_ATTEMPTS {
+        return 0;
+    }
+    let doublings = (failures - FREE_ATTEMPTS).min(20);
+    (BASE_DELAY_SECONDS << doublings).min(MAX_DELAY_SECONDS)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn backoff_doubles_after_free_attempts() {
+        for failures in 0..FREE_ATTEMPTS {
+            assert_eq!(backoff_seconds(failures), 0);
+        }
+        assert_eq!(backoff_seconds(3), 2);
+        assert_eq!(backoff_seconds(4), 4);
+        assert_eq!(backoff_seconds(5), 8);
+        assert_eq!(backoff_seconds(11), 512);
+    }
+
+    #[test]
+    fn backoff_is_capped() {
+        assert_eq!(backoff_seconds(12), MAX_DELAY_SECONDS);
+        assert_eq!(backoff_seconds(40), MAX_DELAY_SECONDS);
+        assert_eq!(backoff_seconds(u32::MAX), MAX_DELAY_SECONDS);
+    }
+
+    #[test]
+    fn remaining_delay_counts_down_from_last_failure() {
+        let mut attempts = UnlockAttempts::default();
+        assert_eq!(attempts.remaining_delay("main", 1_000), 0);
+
+        for _ in 0..4 {
+            attempts.record_failure("main", 1_000);
+        }
+        assert_eq!(attempts.failures("main"), 4);
+        assert_eq!(attempts.remaining_delay("main", 1_000), 4);
+        assert_eq!(attempts.remaining_delay("main", 1_003), 1);
+        assert_eq!(attempts.remaining_delay("main", 1_004), 0);
+        assert_eq!(attempts.remaining_delay("main", 2_000), 0);
+        assert_eq!(attempts.remaining_delay("other", 1_000), 0);
+
+        attempts.record_success("main");
+        assert_eq!(attempts.remaining_delay("main", 1_000), 0);
+    }
+}
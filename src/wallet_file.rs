@@ -0,0 +1,462 @@
+use std::{
+    fs::{self, File},
+    io::Write,
+    path::{Path, PathBuf},
+};
+
+use cocoon::Cocoon;
+use serde::{Deserialize, Serialize};
+use solana_sdk::{signature::Keypair, signer::Signer};
+use zeroize::{Zeroize, Zeroizing};
+
+use crate::{secret_string::SecretString, utils::get_unix_timestamp};
+
+/// Wallet files start with this, anything else is a bare cocoon blob from
+/// before the container existed.
+pub const WALLET_MAGIC: &[u8; 8] = b"OREHQWLT";
+pub const WALLET_FORMAT_VERSION: u16 = 1;
+/// Magic, format version and header length.
+const PREAMBLE_LEN: usize = 8 + 2 + 4;
+const MAX_HEADER_LEN: usize = 64 * 1024;
+const KEYPAIR_LEN: usize = 64;
+
+/// The KDF and cipher `Cocoon::new` uses.
+pub const KDF_ALGORITHM: &str = "pbkdf2-sha256";
+pub const KDF_ITERATIONS: u32 = 100_000;
+pub const CIPHER: &str = "chacha20-poly1305";
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct KdfParams {
+    pub algorithm: String,
+    pub iterations: u32,
+}
+
+impl Default for KdfParams {
+    fn default() -> Self {
+        Self {
+            algorithm: KDF_ALGORITHM.to_string(),
+            iterations: KDF_ITERATIONS,
+        }
+    }
+}
+
+/// Whether the seed phrase of a generated wallet was checked before saving.
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum BackupStatus {
+    Verified,
+    /// The user accepted the risk and saved without verifying.
+    Skipped,
+}
+
+/// Plaintext part of the wallet container, readable without the password.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct WalletHeader {
+    pub pubkey: String,
+    pub label: String,
+    /// Unix timestamp in seconds.
+    pub created_at: u64,
+    pub kdf: KdfParams,
+    pub cipher: String,
+    /// Only set for wallets generated in the app.
+    #[serde(default)]
+    pub backup: Option<BackupStatus>,
+}
+
+impl WalletHeader {
+    pub fn new(keypair: &Keypair, label: String) -> Self {
+        Self {
+            pubkey: keypair.pubkey().to_string(),
+            label,
+            created_at: get_unix_timestamp(),
+            kdf: KdfParams::default(),
+            cipher: CIPHER.to_string(),
+            backup: None,
+        }
+    }
+}
+
+/// Everything the encrypted body holds. The seed phrase is only there for
+/// wallets that were generated or restored from one. Both are wiped when
+/// dropped, the keypair by ed25519-dalek and the phrase by `SecretString`.
+pub struct WalletSecrets {
+    pub keypair: Keypair,
+    pub mnemonic: Option<SecretString>,
+}
+
+struct WalletContainer {
+    header: Option<WalletHeader>,
+    body: Vec<u8>,
+}
+
+fn parse_container(bytes: Vec<u8>) -> Result<WalletContainer, String> {
+    if bytes.len() < WALLET_MAGIC.len() || &bytes[..WALLET_MAGIC.len()] != WALLET_MAGIC {
+        return Ok(WalletContainer { header: None, body: bytes });
+    }
+    if bytes.len() < PREAMBLE_LEN {
+        return Err("Wallet file is truncated".to_string());
+    }
+
+    let version = u16::from_le_bytes([bytes[8], bytes[9]]);
+    if version > WALLET_FORMAT_VERSION {
+        return Err(format!(
+            "Wallet file format {} is newer than this app supports ({})",
+            version, WALLET_FORMAT_VERSION
+        ));
+    }
+
+    let header_len = u32::from_le_bytes([bytes[10], bytes[11], bytes[12], bytes[13]]) as usize;
+    if header_len > MAX_HEADER_LEN || bytes.len() < PREAMBLE_LEN + header_len {
+        return Err("Wallet file header is corrupt".to_string());
+    }
+    let header_str = std::str::from_utf8(&bytes[PREAMBLE_LEN..PREAMBLE_LEN + header_len])
+        .map_err(|_| "Wallet file header is corrupt".to_string())?;
+    let header: WalletHeader = toml::from_str(header_str)
+        .map_err(|e| format!("Wallet file header is corrupt: {}", e))?;
+
+    Ok(WalletContainer {
+        header: Some(header),
+        body: bytes[PREAMBLE_LEN + header_len..].to_vec(),
+    })
+}
+
+fn read_container(path: &Path) -> Result<WalletContainer, String> {
+    let bytes = fs::read(path)
+        .map_err(|e| format!("Failed to open wallet file {}: {}", path.display(), e))?;
+    parse_container(bytes)
+}
+
+/// Reads the plaintext header. `None` means the file predates the container
+/// and is migrated on the next unlock.
+pub fn read_wallet_header(path: &Path) -> Result<Option<WalletHeader>, String> {
+    Ok(read_container(path)?.header)
+}
+
+/// Decrypts a wallet file in either format.
+pub fn read_wallet_file(path: &Path, password: &str) -> Result<Keypair, String> {
+    Ok(read_wallet_secrets(path, password)?.keypair)
+}
+
+/// Decrypts the keypair and, if one was stored, the seed phrase. The body is
+/// the 64 keypair bytes followed by the phrase.
+pub fn read_wallet_secrets(path: &Path, password: &str) -> Result<WalletSecrets, String> {
+    let container = read_container(path)?;
+
+    let cocoon = Cocoon::new(password.as_bytes());
+    let decrypted = Zeroizing::new(
+        cocoon
+            .parse(&mut container.body.as_slice())
+            .map_err(|_| "Failed to decrypt wallet file, wrong password?".to_string())?,
+    );
+    if decrypted.len() < KEYPAIR_LEN {
+        return Err("Failed to parse keypair: wallet body is too short".to_string());
+    }
+    let keypair = Keypair::from_bytes(&decrypted[..KEYPAIR_LEN])
+        .map_err(|e| format!("Failed to parse keypair: {}", e))?;
+    let mnemonic = if decrypted.len() > KEYPAIR_LEN {
+        let phrase = std::str::from_utf8(&decrypted[KEYPAIR_LEN..])
+            .map_err(|_| "Failed to parse stored seed phrase".to_string())?;
+        Some(SecretString::copy_from(phrase))
+    } else {
+        None
+    };
+
+    // the header isn't covered by the encryption, so make sure it wasn't swapped
+    if let Some(header) = container.header {
+        if header.pubkey != keypair.pubkey().to_string() {
+            return Err("Wallet file header does not match the encrypted keypair".to_string());
+        }
+    }
+
+    Ok(WalletSecrets { keypair, mnemonic })
+}
+
+/// Encrypts the keypair into a new container and writes it with
+/// `write_file_atomic`, so a crash never leaves a half written wallet behind.
+pub fn write_wallet_file(
+    path: &Path,
+    password: &str,
+    keypair: &Keypair,
+    mnemonic: Option<&str>,
+    header: &WalletHeader,
+) -> Result<(), String> {
+    let header_str = toml::to_string(header).map_err(|e| e.to_string())?;
+    let mut data = Vec::with_capacity(PREAMBLE_LEN + header_str.len());
+    data.extend_from_slice(WALLET_MAGIC);
+    data.extend_from_slice(&WALLET_FORMAT_VERSION.to_le_bytes());
+    data.extend_from_slice(&(header_str.len() as u32).to_le_bytes());
+    data.extend_from_slice(header_str.as_bytes());
+
+    // sized up front so the plaintext is never left behind in a reallocation
+    let mut body = Vec::with_capacity(KEYPAIR_LEN + mnemonic.map_or(0, str::len));
+    let mut keypair_bytes = keypair.to_bytes();
+    body.extend_from_slice(&keypair_bytes);
+    keypair_bytes.zeroize();
+    if let Some(mnemonic) = mnemonic {
+        body.extend_from_slice(mnemonic.as_bytes());
+    }
+    // cocoon encrypts the body in place, so what it drops is ciphertext
+    let cocoon = Cocoon::new(password.as_bytes());
+    cocoon
+        .dump(body, &mut data)
+        .map_err(|e| format!("Failed to encrypt wallet: {:?}", e))?;
+
+    write_file_atomic(path, &data)
+}
+
+/// Writes `data` to a temp file next to `path`, fsyncs it, renames it over
+/// `path` and syncs the directory, so `path` is either the old or the new
+/// contents after a crash or power loss.
+pub fn write_file_atomic(path: &Path, data: &[u8]) -> Result<(), String> {
+    if let Some(dir) = path.parent() {
+        if !dir.as_os_str().is_empty() {
+            fs::create_dir_all(dir)
+                .map_err(|e| format!("Failed to create directory {}: {}", dir.display(), e))?;
+        }
+    }
+
+    let tmp_path = sibling_path(path, "tmp");
+    let mut file = File::create(&tmp_path)
+        .map_err(|e| format!("Failed to create file {}: {}", tmp_path.display(), e))?;
+    let written = file
+        .write_all(data)
+        .and_then(|_| file.sync_all())
+        .map_err(|e| format!("Failed to write {}: {}", tmp_path.display(), e));
+    drop(file);
+    if let Err(e) = written {
+        let _ = fs::remove_file(&tmp_path);
+        return Err(e);
+    }
+
+    fs::rename(&tmp_path, path).map_err(|e| {
+        let _ = fs::remove_file(&tmp_path);
+        format!("Failed to replace {}: {}", path.display(), e)
+    })?;
+    sync_parent_dir(path);
+
+    Ok(())
+}
+
+/// Rewrites a pre-container wallet file in the current format. Returns
+/// whether the file was migrated.
+pub fn migrate_wallet_file(
+    path: &Path,
+    password: &str,
+    keypair: &Keypair,
+    label: String,
+) -> Result<bool, String> {
+    if read_wallet_header(path)?.is_some() {
+        return Ok(false);
+    }
+
+    let mut header = WalletHeader::new(keypair, label);
+    if let Some(created) = fs::metadata(path)
+        .and_then(|m| m.modified())
+        .ok()
+        .and_then(|t| t.duration_since(std::time::UNIX_EPOCH).ok())
+    {
+        header.created_at = created.as_secs();
+    }
+    write_wallet_file(path, password, keypair, None, &header)?;
+
+    Ok(true)
+}
+
+/// Re-encrypts the wallet with a new password. `secrets` were read with the
+/// old one, so the caller can count a wrong password. The previous file is
+/// kept as `<path>.bak` until the next change.
+pub fn change_wallet_password(
+    path: &Path,
+    secrets: &WalletSecrets,
+    new_password: &str,
+    label: String,
+) -> Result<PathBuf, String> {
+    let header = read_wallet_header(path)?
+        .unwrap_or_else(|| WalletHeader::new(&secrets.keypair, label));
+
+    // durable before the wallet itself is rewritten, a power loss can't
+    // leave a truncated backup next to the new file
+    let backup_path = backup_path(path);
+    let current = fs::read(path).map_err(|e| format!("Failed to read {}: {}", path.display(), e))?;
+    write_file_atomic(&backup_path, &current)
+        .map_err(|e| format!("Failed to back up wallet file to {}: {}", backup_path.display(), e))?;
+
+    write_wallet_file(
+        path,
+        new_password,
+        &secrets.keypair,
+        secrets.mnemonic.as_ref().map(SecretString::as_str),
+        &header,
+    )?;
+
+    Ok(backup_path)
+}
+
+pub fn backup_path(path: &Path) -> PathBuf {
+    sibling_path(path, "bak")
+}
+
+fn sibling_path(path: &Path, extension: &str) -> PathBuf {
+    let mut file_name = path.file_name().unwrap_or_default().to_os_string();
+    file_name.push(".");
+    file_name.push(extension);
+    path.with_file_name(file_name)
+}
+
+/// Makes the rename durable. Directories can't be opened on every platform,
+/// so this is best effort.
+fn sync_parent_dir(path: &Path) {
+    let dir = match path.parent() {
+        Some(dir) if !dir.as_os_str().is_empty() => dir,
+        _ => Path::new("."),
+    };
+    if let Ok(dir) = File::open(dir) {
+        let _ = dir.sync_all();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PASSWORD: &str = "correct horse";
+    const PHRASE: &str = "abandon ability able about above absent absorb abstract absurd abuse access accident";
+
+    fn temp_path(name: &str) -> PathBuf {
+        std::env::temp_dir().join(format!("ore-hq-wallet-test-{}-{}.data", std::process::id(), name))
+    }
+
+    fn remove(path: &Path) {
+        let _ = fs::remove_file(path);
+        let _ = fs::remove_file(backup_path(path));
+    }
+
+    fn preamble(version: u16, header_len: u32) -> Vec<u8> {
+        let mut bytes = WALLET_MAGIC.to_vec();
+        bytes.extend_from_slice(&version.to_le_bytes());
+        bytes.extend_from_slice(&header_len.to_le_bytes());
+        bytes
+    }
+
+    fn write_legacy(path: &Path, keypair: &Keypair) {
+        let mut data = Vec::new();
+        Cocoon::new(PASSWORD.as_bytes()).dump(keypair.to_bytes().to_vec(), &mut data).unwrap();
+        fs::write(path, data).unwrap();
+    }
+
+    #[test]
+    fn truncated_preamble_is_rejected() {
+        let bytes = preamble(WALLET_FORMAT_VERSION, 0)[..PREAMBLE_LEN - 1].to_vec();
+        assert_eq!(parse_container(bytes).err().unwrap(), "Wallet file is truncated");
+    }
+
+    #[test]
+    fn newer_format_is_rejected() {
+        let err = parse_container(preamble(WALLET_FORMAT_VERSION + 1, 0)).err().unwrap();
+        assert!(err.contains("newer than this app supports"), "{}", err);
+    }
+
+    #[test]
+    fn corrupt_header_is_rejected() {
+        let oversized = preamble(WALLET_FORMAT_VERSION, MAX_HEADER_LEN as u32 + 1);
+        assert!(parse_container(oversized).is_err());
+
+        let mut short = preamble(WALLET_FORMAT_VERSION, 16);
+        short.extend_from_slice(b"pubkey");
+        assert!(parse_container(short).is_err());
+
+        let mut not_utf8 = preamble(WALLET_FORMAT_VERSION, 2);
+        not_utf8.extend_from_slice(&[0xff, 0xfe]);
+        assert_eq!(parse_container(not_utf8).err().unwrap(), "Wallet file header is corrupt");
+    }
+
+    #[test]
+    fn legacy_blob_has_no_header() {
+        let path = temp_path("legacy");
+        let keypair = Keypair::new();
+        write_legacy(&path, &keypair);
+
+        let header = read_wallet_header(&path);
+        let read = read_wallet_file(&path, PASSWORD);
+        remove(&path);
+
+        assert_eq!(header.unwrap(), None);
+        assert_eq!(read.unwrap().pubkey(), keypair.pubkey());
+    }
+
+    #[test]
+    fn write_and_read_round_trip() {
+        let path = temp_path("round-trip");
+        let keypair = Keypair::new();
+        let header = WalletHeader::new(&keypair, "main".to_string());
+        write_wallet_file(&path, PASSWORD, &keypair, Some(PHRASE), &header).unwrap();
+
+        let read_header = read_wallet_header(&path);
+        let secrets = read_wallet_secrets(&path, PASSWORD);
+        let wrong = read_wallet_secrets(&path, "battery staple");
+        remove(&path);
+
+        assert_eq!(read_header.unwrap(), Some(header));
+        let secrets = secrets.unwrap();
+        assert_eq!(secrets.keypair.to_bytes(), keypair.to_bytes());
+        assert_eq!(secrets.mnemonic.unwrap().as_str(), PHRASE);
+        assert_eq!(wrong.err().unwrap(), "Failed to decrypt wallet file, wrong password?");
+    }
+
+    #[test]
+    fn swapped_header_is_rejected() {
+        let path = temp_path("swapped-header");
+        let keypair = Keypair::new();
+        let header = WalletHeader::new(&Keypair::new(), "main".to_string());
+        write_wallet_file(&path, PASSWORD, &keypair, None, &header).unwrap();
+
+        let result = read_wallet_secrets(&path, PASSWORD);
+        remove(&path);
+
+        assert_eq!(result.err().unwrap(), "Wallet file header does not match the encrypted keypair");
+    }
+
+    #[test]
+    fn legacy_wallet_is_migrated_once() {
+        let path = temp_path("migrate");
+        let keypair = Keypair::new();
+        write_legacy(&path, &keypair);
+
+        let first = migrate_wallet_file(&path, PASSWORD, &keypair, "main".to_string());
+        let header = read_wallet_header(&path);
+        let second = migrate_wallet_file(&path, PASSWORD, &keypair, "other".to_string());
+        let read = read_wallet_file(&path, PASSWORD);
+        remove(&path);
+
+        assert!(first.unwrap());
+        let header = header.unwrap().unwrap();
+        assert_eq!(header.pubkey, keypair.pubkey().to_string());
+        assert_eq!(header.label, "main");
+        assert!(!second.unwrap());
+        assert_eq!(read.unwrap().pubkey(), keypair.pubkey());
+    }
+
+    #[test]
+    fn change_password_keeps_secrets_and_backup() {
+        let path = temp_path("change-password");
+        let keypair = Keypair::new();
+        let header = WalletHeader::new(&keypair, "main".to_string());
+        write_wallet_file(&path, PASSWORD, &keypair, Some(PHRASE), &header).unwrap();
+
+        let secrets = read_wallet_secrets(&path, PASSWORD).unwrap();
+        let backup = change_wallet_password(&path, &secrets, "battery staple", "main".to_string());
+        let old = read_wallet_secrets(&path, PASSWORD);
+        let new = read_wallet_secrets(&path, "battery staple");
+        let read_header = read_wallet_header(&path);
+        let from_backup = read_wallet_file(&backup_path(&path), PASSWORD);
+        remove(&path);
+
+        assert_eq!(backup.unwrap(), backup_path(&path));
+        assert!(old.is_err());
+        let new = new.unwrap();
+        assert_eq!(new.keypair.pubkey(), keypair.pubkey());
+        assert_eq!(new.mnemonic.unwrap().as_str(), PHRASE);
+        assert_eq!(read_header.unwrap(), Some(header));
+        assert_eq!(from_backup.unwrap().pubkey(), keypair.pubkey());
+    }
+}
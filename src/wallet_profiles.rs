@@ -0,0 +1,258 @@
+use std::{fs, path::PathBuf, str::FromStr};
+
+use serde::{Deserialize, Serialize};
+use solana_sdk::pubkey::Pubkey;
+
+use crate::{app_dirs::data_path, priority_fees::PriorityFeeConfig, wallet_file::write_file_atomic, AppConfig};
+
+pub const PROFILES_PATH: &str = "profiles.toml";
+pub const WALLETS_DIR: &str = "wallets";
+pub const DEFAULT_PROFILE_NAME: &str = "default";
+/// Wallet file used before profiles existed, kept as the path of the migrated default profile.
+pub const LEGACY_WALLET_PATH: &str = "save.data";
+pub const MAX_PROFILE_NAME_LEN: usize = 32;
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct WalletProfile {
+    pub name: String,
+    pub wallet_path: String,
+    /// Overrides for the matching `AppConfig` fields while this profile is unlocked.
+    #[serde(default)]
+    pub server_url: Option<String>,
+    #[serde(default)]
+    pub rpc_url: Option<String>,
+    #[serde(default)]
+    pub priority_fee: Option<PriorityFeeConfig>,
+    /// Set for watch-only profiles, which have no wallet file and only
+    /// monitor this pubkey.
+    #[serde(default)]
+    pub watch_pubkey: Option<String>,
+}
+
+impl WalletProfile {
+    pub fn new(name: String) -> Self {
+        let wallet_path = format!("{}/{}.data", WALLETS_DIR, name);
+        Self {
+            name,
+            wallet_path,
+            server_url: None,
+            rpc_url: None,
+            priority_fee: None,
+            watch_pubkey: None,
+        }
+    }
+
+    pub fn new_watch_only(name: String, pubkey: &Pubkey) -> Self {
+        Self {
+            wallet_path: String::new(),
+            watch_pubkey: Some(pubkey.to_string()),
+            ..Self::new(name)
+        }
+    }
+
+    pub fn is_watch_only(&self) -> bool {
+        self.watch_pubkey.is_some()
+    }
+
+    pub fn watch_pubkey(&self) -> Option<Pubkey> {
+        self.watch_pubkey
+            .as_ref()
+            .and_then(|pubkey| Pubkey::from_str(pubkey).ok())
+    }
+
+    /// `wallet_path` is kept relative to the data directory, so a data
+    /// directory can be moved as a whole.
+    pub fn wallet_file(&self) -> PathBuf {
+        data_path(&self.wallet_path)
+    }
+
+    /// Watch-only profiles count as existing wallets, there is no file to check.
+    pub fn wallet_exists(&self) -> bool {
+        self.is_watch_only() || self.wallet_file().exists()
+    }
+
+    pub fn apply_overrides(&self, config: &AppConfig) -> AppConfig {
+        let mut config = config.clone();
+        if let Some(server_url) = &self.server_url {
+            config.server_url = server_url.clone();
+        }
+        if let Some(rpc_url) = &self.rpc_url {
+            config.rpc_url = rpc_url.clone();
+        }
+        if let Some(priority_fee) = &self.priority_fee {
+            config.priority_fee = priority_fee.clone();
+        }
+        config
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, Default)]
+pub struct WalletProfiles {
+    /// Name of the profile the lock screen unlocks.
+    #[serde(default)]
+    pub active: String,
+    #[serde(default)]
+    pub profiles: Vec<WalletProfile>,
+}
+
+impl WalletProfiles {
+    /// Loads `profiles.toml`. Without one, an existing `save.data` becomes the default profile.
+    pub fn load() -> Self {
+        let path = data_path(PROFILES_PATH);
+        if path.exists() {
+            if let Some(profiles) = fs::read_to_string(path)
+                .ok()
+                .and_then(|s| toml::from_str::<WalletProfiles>(&s).ok())
+            {
+                return profiles;
+            }
+        }
+
+        let mut profiles = Self::default();
+        if data_path(LEGACY_WALLET_PATH).exists() {
+            profiles.profiles.push(WalletProfile {
+                wallet_path: LEGACY_WALLET_PATH.to_string(),
+                ..WalletProfile::new(DEFAULT_PROFILE_NAME.to_string())
+            });
+            profiles.active = DEFAULT_PROFILE_NAME.to_string();
+        }
+        profiles
+    }
+
+    pub fn save(&self) -> Result<(), String> {
+        let toml_string = toml::to_string(self).map_err(|e| e.to_string())?;
+        write_file_atomic(&data_path(PROFILES_PATH), toml_string.as_bytes())
+    }
+
+    pub fn get(&self, name: &str) -> Option<&WalletProfile> {
+        self.profiles.iter().find(|p| p.name == name)
+    }
+
+    /// Falls back to the first profile when the active name is missing.
+    pub fn active_profile(&self) -> Option<&WalletProfile> {
+        self.get(&self.active).or(self.profiles.first())
+    }
+
+    pub fn has_wallets(&self) -> bool {
+        self.profiles.iter().any(|p| p.wallet_exists())
+    }
+
+    pub fn set_active(&mut self, name: &str) -> Result<(), String> {
+        if self.get(name).is_none() {
+            return Err(format!("Profile {} does not exist", name));
+        }
+        self.active = name.to_string();
+        Ok(())
+    }
+
+    /// Adds a profile for a new wallet file or watched pubkey and makes it the active one.
+    pub fn add(&mut self, profile: WalletProfile) -> Result<(), String> {
+        validate_profile_name(&profile.name)?;
+        if let Some(existing) = self.get(&profile.name) {
+            if existing.wallet_exists() {
+                return Err(format!("Profile {} already exists", profile.name));
+            }
+        }
+
+        self.profiles.retain(|p| p.name != profile.name);
+        self.active = profile.name.clone();
+        self.profiles.push(profile);
+        Ok(())
+    }
+
+    /// Suggested name for the next new profile.
+    pub fn next_profile_name(&self) -> String {
+        if self.get(DEFAULT_PROFILE_NAME).is_none() {
+            return DEFAULT_PROFILE_NAME.to_string();
+        }
+        let mut i = self.profiles.len() + 1;
+        while self.get(&format!("wallet-{}", i)).is_some() {
+            i += 1;
+        }
+        format!("wallet-{}", i)
+    }
+}
+
+/// Profile names become file names, so only a safe subset of characters is allowed.
+pub fn validate_profile_name(name: &str) -> Result<(), String> {
+    if name.is_empty() {
+        return Err("Profile name is empty".to_string());
+    }
+    if name.len() > MAX_PROFILE_NAME_LEN {
+        return Err(format!("Profile name is longer than {} characters", MAX_PROFILE_NAME_LEN));
+    }
+    if !name.chars().all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_') {
+        return Err("Profile name can only use letters, numbers, - and _".to_string());
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn watch_only(name: &str) -> WalletProfile {
+        WalletProfile::new_watch_only(name.to_string(), &Pubkey::new_unique())
+    }
+
+    #[test]
+    fn profile_names_are_validated() {
+        assert!(validate_profile_name("main").is_ok());
+        assert!(validate_profile_name("rig_2-a").is_ok());
+        assert!(validate_profile_name(&"a".repeat(MAX_PROFILE_NAME_LEN)).is_ok());
+
+        assert_eq!(validate_profile_name("").err().unwrap(), "Profile name is empty");
+        assert!(validate_profile_name(&"a".repeat(MAX_PROFILE_NAME_LEN + 1)).is_err());
+        for name in ["../main", "a/b", "a b", "main.data", "wälLet"] {
+            assert!(validate_profile_name(name).is_err(), "{}", name);
+        }
+    }
+
+    #[test]
+    fn add_makes_the_profile_active() {
+        let mut profiles = WalletProfiles::default();
+        profiles.add(watch_only("main")).unwrap();
+        profiles.add(watch_only("rig")).unwrap();
+
+        assert_eq!(profiles.active, "rig");
+        assert_eq!(profiles.profiles.len(), 2);
+        assert!(profiles.add(watch_only("bad name")).is_err());
+        assert_eq!(profiles.profiles.len(), 2);
+    }
+
+    #[test]
+    fn add_rejects_an_existing_wallet() {
+        let mut profiles = WalletProfiles::default();
+        profiles.add(watch_only("main")).unwrap();
+
+        assert_eq!(profiles.add(watch_only("main")).err().unwrap(), "Profile main already exists");
+        assert_eq!(profiles.profiles.len(), 1);
+    }
+
+    #[test]
+    fn add_replaces_a_profile_without_a_wallet_file() {
+        let mut profiles = WalletProfiles::default();
+        let name = format!("missing-{}", std::process::id());
+        profiles.add(WalletProfile::new(name.clone())).unwrap();
+        profiles.add(watch_only(&name)).unwrap();
+
+        assert_eq!(profiles.profiles.len(), 1);
+        assert!(profiles.get(&name).unwrap().is_watch_only());
+    }
+
+    #[test]
+    fn next_profile_name_skips_taken_names() {
+        let mut profiles = WalletProfiles::default();
+        assert_eq!(profiles.next_profile_name(), DEFAULT_PROFILE_NAME);
+
+        profiles.add(watch_only(DEFAULT_PROFILE_NAME)).unwrap();
+        assert_eq!(profiles.next_profile_name(), "wallet-2");
+
+        profiles.add(watch_only("wallet-2")).unwrap();
+        profiles.add(watch_only("wallet-4")).unwrap();
+        assert_eq!(profiles.next_profile_name(), "wallet-5");
+
+        profiles.add(watch_only("wallet-5")).unwrap();
+        assert_eq!(profiles.next_profile_name(), "wallet-6");
+    }
+}
@@ -0,0 +1,57 @@
+use bip39::{Language, Mnemonic, Seed};
+use solana_sdk::{
+    derivation_path::DerivationPath, pubkey::Pubkey, signature::Keypair, signer::{SeedDerivable, Signer},
+};
+
+pub const DEFAULT_DERIVATION_PATH: &str = "m/44'/501'/0'/0'";
+/// Number of accounts derived for the restore preview.
+pub const RESTORE_PREVIEW_ACCOUNTS: u32 = 5;
+
+/// Normalizes the whitespace and case of a typed or pasted phrase and checks
+/// the word count and checksum.
+pub fn parse_mnemonic(phrase: &str) -> Result<Mnemonic, String> {
+    let words: Vec<String> = phrase
+        .split_whitespace()
+        .map(|w| w.to_lowercase())
+        .collect();
+    if words.len() != 12 && words.len() != 24 {
+        return Err(format!("Seed phrase must be 12 or 24 words, got {}", words.len()));
+    }
+
+    Mnemonic::from_phrase(&words.join(" "), Language::English)
+        .map_err(|e| format!("Invalid seed phrase: {}", e))
+}
+
+pub fn derive_keypair(
+    mnemonic: &Mnemonic,
+    passphrase: &str,
+    derivation_path: &str,
+) -> Result<Keypair, String> {
+    let derivation_path = DerivationPath::from_absolute_path_str(derivation_path.trim())
+        .map_err(|e| format!("Invalid derivation path: {}", e))?;
+    let seed = Seed::new(mnemonic, passphrase);
+
+    Keypair::from_seed_and_derivation_path(seed.as_bytes(), Some(derivation_path))
+        .map_err(|e| format!("Failed to derive keypair: {}", e))
+}
+
+/// Path of the n-th account in the layout used by most Solana wallets.
+pub fn account_derivation_path(account: u32) -> String {
+    format!("m/44'/501'/{}'/0'", account)
+}
+
+/// The first few accounts of the phrase, so the user can spot the one they used.
+pub fn preview_accounts(
+    mnemonic: &Mnemonic,
+    passphrase: &str,
+    count: u32,
+) -> Vec<(String, Pubkey)> {
+    (0..count)
+        .filter_map(|account| {
+            let path = account_derivation_path(account);
+            derive_keypair(mnemonic, passphrase, &path)
+                .ok()
+                .map(|keypair| (path, keypair.pubkey()))
+        })
+        .collect()
+}